@@ -529,8 +529,10 @@ def compute_ascendant(lst_deg: float, lat_deg: float, obl_deg: float) -> float:
     lat_rad = lat_deg * DEG2RAD
     obl_rad = obl_deg * DEG2RAD
 
-    y = -math.cos(lst_rad)
-    x = math.sin(obl_rad) * math.tan(lat_rad) + math.cos(obl_rad) * math.sin(lst_rad)
+    # atan2(-cos θ, sin ε tan φ + cos ε sin θ) lands on the descending
+    # point; negating both arguments selects the rising (eastern) one.
+    y = math.cos(lst_rad)
+    x = -(math.sin(obl_rad) * math.tan(lat_rad) + math.cos(obl_rad) * math.sin(lst_rad))
 
     asc = math.atan2(y, x) * RAD2DEG
     return _norm_deg(asc)
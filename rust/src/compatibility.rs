@@ -0,0 +1,174 @@
+use std::fmt;
+
+use crate::engines::astrology::calculate_natal_chart;
+use crate::engines::astrology::synastry::synastry;
+use crate::engines::bazi::{calculate_bazi, zodiac_pairing, BaziError};
+use crate::engines::numerology::{life_path, life_path_compatibility, NumerologyError};
+use crate::types::{
+    BirthData, BranchRelation, CompatibilityReport, CompatibilitySystem, LifePathRelation,
+    SystemScore,
+};
+
+// ---------------------------------------------------------------------------
+// Compatibility report — synastry, zodiac animals and life paths together
+// ---------------------------------------------------------------------------
+//
+// The Chinese zodiac and life path only need birth dates. Synastry needs
+// a full birth time and place for both people and is left out of the
+// report, and of the aggregate, when either is missing. The aggregate is
+// the plain mean of the systems that could be read.
+
+/// Why a compatibility report could not be produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompatibilityError {
+    /// A birth the BaZi engine could not chart
+    Bazi(BaziError),
+    /// A birth date the numerology engine rejected
+    Numerology(NumerologyError),
+}
+
+impl fmt::Display for CompatibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bazi(e) => write!(f, "zodiac pairing: {}", e),
+            Self::Numerology(e) => write!(f, "life path: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CompatibilityError {}
+
+fn has_full_birth(birth: &BirthData) -> bool {
+    birth.day.is_some()
+        && birth.hour.is_some()
+        && birth.minute.is_some()
+        && birth.latitude.is_some()
+        && birth.longitude.is_some()
+        && birth.timezone.is_some()
+}
+
+fn relation_phrase(relation: BranchRelation) -> &'static str {
+    match relation {
+        BranchRelation::SixHarmony => "combine in six harmony",
+        BranchRelation::ThreeHarmony => "share a trine",
+        BranchRelation::Same => "are the same animal",
+        BranchRelation::Neutral => "sit neutrally together",
+        BranchRelation::Harm => "harm each other",
+        BranchRelation::Clash => "clash",
+    }
+}
+
+/// Compare two people by synastry, zodiac animal and life path.
+///
+/// # Errors
+/// Returns an error if either birth lacks a day or has a field out of
+/// range.
+pub fn compatibility_report(
+    first: &BirthData,
+    second: &BirthData,
+) -> Result<CompatibilityReport, CompatibilityError> {
+    let zodiac = zodiac_pairing(
+        calculate_bazi(first).map_err(CompatibilityError::Bazi)?.year.branch,
+        calculate_bazi(second).map_err(CompatibilityError::Bazi)?.year.branch,
+    );
+    // calculate_bazi has already checked that both days are present
+    let path = |b: &BirthData| life_path(b.year, b.month, b.day.unwrap_or(0));
+    let life_path = life_path_compatibility(
+        path(first).map_err(CompatibilityError::Numerology)?,
+        path(second).map_err(CompatibilityError::Numerology)?,
+    );
+    let synastry = (has_full_birth(first) && has_full_birth(second))
+        .then(|| synastry(&calculate_natal_chart(first), &calculate_natal_chart(second)));
+
+    let mut scores = Vec::new();
+    if let Some(s) = &synastry {
+        let tightest = s.aspects.first().map_or_else(
+            || "no close contacts".to_string(),
+            |a| format!("tightest contact {} {} {}", a.planet1, a.aspect_name, a.planet2),
+        );
+        scores.push(SystemScore {
+            system: CompatibilitySystem::Synastry,
+            score: s.score,
+            summary: format!("{} contacts, {}", s.aspects.len(), tightest),
+        });
+    }
+    scores.push(SystemScore {
+        system: CompatibilitySystem::ChineseZodiac,
+        score: zodiac.score,
+        summary: format!(
+            "the {} and the {} {}",
+            zodiac.first.animal(),
+            zodiac.second.animal(),
+            relation_phrase(zodiac.relation)
+        ),
+    });
+    scores.push(SystemScore {
+        system: CompatibilitySystem::LifePath,
+        score: life_path.score,
+        summary: format!(
+            "life paths {} and {} are {}",
+            life_path.first.value,
+            life_path.second.value,
+            match life_path.relation {
+                LifePathRelation::Natural => "a natural match",
+                LifePathRelation::Compatible => "compatible",
+                LifePathRelation::Challenging => "challenging",
+            }
+        ),
+    });
+    let total: u32 = scores.iter().map(|s| u32::from(s.score)).sum();
+    let aggregate = (f64::from(total) / scores.len() as f64).round() as u8;
+
+    Ok(CompatibilityReport {
+        synastry,
+        zodiac,
+        life_path,
+        scores,
+        aggregate,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::birth_on;
+
+    #[test]
+    fn all_three_systems() {
+        let report =
+            compatibility_report(&birth_on(1990, 6, 15, Some(14)), &birth_on(1989, 11, 2, Some(8)))
+                .unwrap();
+        let systems: Vec<CompatibilitySystem> = report.scores.iter().map(|s| s.system).collect();
+        assert_eq!(
+            systems,
+            [
+                CompatibilitySystem::Synastry,
+                CompatibilitySystem::ChineseZodiac,
+                CompatibilitySystem::LifePath
+            ]
+        );
+        // Horse and snake are neighbours with no special relation
+        assert_eq!(report.zodiac.relation, BranchRelation::Neutral);
+        assert_eq!(report.scores[1].summary, "the horse and the snake sit neutrally together");
+        assert_eq!(report.life_path.relation, LifePathRelation::Natural);
+        let mean = report.scores.iter().map(|s| f64::from(s.score)).sum::<f64>() / 3.0;
+        assert_eq!(report.aggregate, mean.round() as u8);
+    }
+
+    #[test]
+    fn synastry_needs_birth_times() {
+        let report =
+            compatibility_report(&birth_on(1985, 1, 20, None), &birth_on(1990, 6, 15, Some(14)))
+                .unwrap();
+        assert!(report.synastry.is_none());
+        assert_eq!(report.scores.len(), 2);
+        assert_eq!(report.zodiac.relation, BranchRelation::Clash);
+
+        let mut undated = birth_on(1990, 6, 15, None);
+        undated.day = None;
+        assert_eq!(
+            compatibility_report(&undated, &undated).unwrap_err(),
+            CompatibilityError::Bazi(BaziError::MissingField("day"))
+        );
+    }
+}
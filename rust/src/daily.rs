@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::engines::astrology::{self, calculate_natal_chart};
+use crate::engines::iching::{hexagram_of_the_day, IChingError};
+use crate::engines::tarot::TarotEngine;
+use crate::journal::{Journal, JournalError, JournalStore};
+use crate::types::{BirthData, DailyReading, JournalPayload, NatalChart};
+
+// ---------------------------------------------------------------------------
+// Daily reading — one call for the card, hexagram, Moon and transits
+// ---------------------------------------------------------------------------
+//
+// The card and hexagram are the engines' hashed "of the day" draws, so a
+// user gets the same reading however often they ask. The Moon and the
+// transiting planets are taken at 12:00 UT, like the daily horoscope. Natal
+// charts are stored per user so callers only pass a user id after the first
+// reading.
+
+/// How many transits a reading keeps, tightest orb first.
+pub const KEY_TRANSIT_COUNT: usize = 5;
+
+/// Why a daily reading could not be produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DailyReadingError {
+    /// The date was rejected by the I Ching engine
+    IChing(IChingError),
+    /// No journal entry has this id
+    UnknownEntry(String),
+    /// The journal entry does not record a natal chart
+    NotAChart(String),
+    /// The journal could not be read
+    Journal(JournalError),
+}
+
+impl fmt::Display for DailyReadingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IChing(e) => write!(f, "hexagram of the day: {}", e),
+            Self::UnknownEntry(id) => write!(f, "unknown journal entry: {}", id),
+            Self::NotAChart(id) => write!(f, "journal entry {} is not a chart", id),
+            Self::Journal(e) => write!(f, "journal: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for DailyReadingError {}
+
+/// Composes the tarot, I Ching and astrology engines into a daily reading,
+/// remembering each user's natal chart.
+pub struct DailyReader {
+    tarot: TarotEngine,
+    charts: HashMap<String, NatalChart>,
+}
+
+impl DailyReader {
+    /// A reader drawing from the default tarot deck.
+    pub fn new() -> Self {
+        Self::with_tarot(TarotEngine::new())
+    }
+
+    /// A reader drawing the card of the day from `tarot`'s primary deck.
+    pub fn with_tarot(tarot: TarotEngine) -> Self {
+        Self {
+            tarot,
+            charts: HashMap::new(),
+        }
+    }
+
+    pub fn tarot(&self) -> &TarotEngine {
+        &self.tarot
+    }
+
+    /// Store `user`'s natal chart, replacing any stored before.
+    pub fn store_chart(&mut self, user: &str, chart: NatalChart) {
+        self.charts.insert(user.to_string(), chart);
+    }
+
+    /// Compute and store `user`'s natal chart from their birth data.
+    pub fn store_birth_data(&mut self, user: &str, birth_data: &BirthData) {
+        self.store_chart(user, calculate_natal_chart(birth_data));
+    }
+
+    /// Store the chart recorded in journal entry `entry_id` for `user`.
+    ///
+    /// # Errors
+    /// Returns an error if the journal cannot be read, has no such entry or
+    /// the entry is not a chart.
+    pub fn store_chart_from_journal<S: JournalStore>(
+        &mut self,
+        user: &str,
+        journal: &Journal<S>,
+        entry_id: &str,
+    ) -> Result<(), DailyReadingError> {
+        let entry = journal
+            .get(entry_id)
+            .map_err(DailyReadingError::Journal)?
+            .ok_or_else(|| DailyReadingError::UnknownEntry(entry_id.to_string()))?;
+        match entry.payload {
+            JournalPayload::Chart(chart) => {
+                self.store_chart(user, *chart);
+                Ok(())
+            }
+            _ => Err(DailyReadingError::NotAChart(entry_id.to_string())),
+        }
+    }
+
+    pub fn chart(&self, user: &str) -> Option<&NatalChart> {
+        self.charts.get(user)
+    }
+
+    /// Forget `user`'s chart, returning it if one was stored.
+    pub fn remove_chart(&mut self, user: &str) -> Option<NatalChart> {
+        self.charts.remove(user)
+    }
+
+    /// The reading for a calendar date. With a `user`, the card and hexagram
+    /// are personalised for them and transits are measured to their stored
+    /// chart, if any.
+    ///
+    /// # Errors
+    /// Returns [`DailyReadingError::IChing`] for a month outside 1–12 or a
+    /// day the month does not have.
+    pub fn reading(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        user: Option<&str>,
+    ) -> Result<DailyReading, DailyReadingError> {
+        let hexagram =
+            hexagram_of_the_day(year, month, day, user).map_err(DailyReadingError::IChing)?;
+        let jd = astrology::to_julian_day(year, month, day, 12, 0);
+        let transits = user
+            .and_then(|u| self.charts.get(u))
+            .map(|chart| {
+                let mut aspects = astrology::transits_to_chart(chart, jd);
+                aspects.truncate(KEY_TRANSIT_COUNT);
+                aspects
+            })
+            .unwrap_or_default();
+
+        Ok(DailyReading {
+            year,
+            month,
+            day,
+            user: user.map(str::to_string),
+            card: self.tarot.card_of_the_day(year, month, day, user),
+            hexagram,
+            moon: astrology::moon_phase(jd),
+            transits,
+        })
+    }
+}
+
+impl Default for DailyReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::card_of_the_day;
+    use crate::journal::MemoryStore;
+    use crate::test_support::birth;
+    use crate::types::LunationPhase;
+
+    #[test]
+    fn composes_the_engines() {
+        let mut reader = DailyReader::new();
+        let anonymous = reader.reading(2024, 4, 9, None).unwrap();
+        assert!(anonymous.transits.is_empty());
+        assert_eq!(anonymous.card.card.id, card_of_the_day(2024, 4, 9, None).card.id);
+        assert_eq!(
+            anonymous.hexagram.hexagram_number,
+            hexagram_of_the_day(2024, 4, 9, None).unwrap().hexagram_number
+        );
+        // The day after the new Moon of the 8 April 2024 eclipse
+        assert_eq!(anonymous.moon.phase, LunationPhase::New);
+        assert!(anonymous.moon.waxing && anonymous.moon.illumination < 0.02);
+
+        reader.store_birth_data("ada", &birth());
+        let personal = reader.reading(2024, 4, 9, Some("ada")).unwrap();
+        assert_eq!(personal.user.as_deref(), Some("ada"));
+        assert!(!personal.transits.is_empty() && personal.transits.len() <= KEY_TRANSIT_COUNT);
+        assert!(personal.transits.windows(2).all(|w| w[0].orb <= w[1].orb));
+        assert!(reader.reading(2024, 13, 1, None).is_err());
+    }
+
+    #[test]
+    fn charts_from_the_journal() {
+        let mut journal = Journal::new(MemoryStore::default());
+        let chart = calculate_natal_chart(&birth());
+        let chart_id = journal.record_chart(chart, 1_700_000_000_000, &["natal"]).unwrap();
+
+        let mut reader = DailyReader::new();
+        reader.store_chart_from_journal("ada", &journal, &chart_id).unwrap();
+        assert!(reader.chart("ada").is_some());
+        assert_eq!(
+            reader.store_chart_from_journal("ada", &journal, "journal:nope"),
+            Err(DailyReadingError::UnknownEntry("journal:nope".to_string()))
+        );
+        assert!(reader.remove_chart("ada").is_some());
+        assert!(reader.chart("ada").is_none());
+    }
+}
@@ -1,4 +1,17 @@
-use crate::types::{BirthData, ChartAspect, NatalChart, PlanetPosition, SignPosition};
+pub mod cycles;
+pub mod lunar_calendar;
+pub mod panchanga;
+pub mod precession;
+pub mod rectification;
+pub mod synastry;
+
+use crate::types::{
+    AstrologicalAge, BirthData, ChartAspect, ChartExplanation, ChartOptions, DailyHoroscope,
+    ExplainedChart, ExplainedTransits, HoroscopeTheme, LocalSpacePosition, Lot, LunarCalendar,
+    LunationPhase, MoonPhase, NatalChart, Panchanga, PlanetPosition, ProgressedLunation,
+    RectificationCandidate, RectificationOptions, ReleasingPeriod, Sect, SectAnalysis,
+    SignPosition, SkySnapshot, Synastry, SynodicEvent, TransitOptions, ZodiacalReleasing,
+};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -7,6 +20,7 @@ use crate::types::{BirthData, ChartAspect, NatalChart, PlanetPosition, SignPosit
 const DEG2RAD: f64 = std::f64::consts::PI / 180.0;
 const RAD2DEG: f64 = 180.0 / std::f64::consts::PI;
 const J2000: f64 = 2_451_545.0; // Julian Day of J2000.0 epoch
+const TROPICAL_YEAR_DAYS: f64 = 365.242_19;
 
 /// Sign order (tropical zodiac).
 const SIGN_ORDER: [&str; 12] = [
@@ -356,22 +370,13 @@ pub fn sun_longitude(jd: f64) -> f64 {
 }
 
 // ---------------------------------------------------------------------------
-// Moon longitude (simplified — Meeus Ch. 47 principal terms)
+// Moon longitude and latitude (simplified — Meeus Ch. 47 principal terms)
 // ---------------------------------------------------------------------------
 
-/// Compute the Moon's geocentric ecliptic longitude.
-pub fn moon_longitude(jd: f64) -> f64 {
-    let t = julian_centuries(jd);
-
-    // Moon's mean longitude
-    let lp = norm_deg(
-        218.3164477
-            + 481267.88123421 * t
-            - 0.0015786 * t * t
-            + t * t * t / 538841.0
-            - t * t * t * t / 65194000.0,
-    );
-
+/// The Moon's mean elongation D, the Sun's mean anomaly M, the Moon's mean
+/// anomaly M′ and its argument of latitude F, in degrees, at `t` Julian
+/// centuries from J2000.0 (Meeus ch. 47).
+fn lunar_arguments(t: f64) -> [f64; 4] {
     // Moon's mean elongation
     let d = norm_deg(
         297.8501921
@@ -404,6 +409,24 @@ pub fn moon_longitude(jd: f64) -> f64 {
             + t * t * t * t / 863310000.0,
     );
 
+    [d, m, mp, f]
+}
+
+/// Compute the Moon's geocentric ecliptic longitude.
+pub fn moon_longitude(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+
+    // Moon's mean longitude
+    let lp = norm_deg(
+        218.3164477
+            + 481267.88123421 * t
+            - 0.0015786 * t * t
+            + t * t * t / 538841.0
+            - t * t * t * t / 65194000.0,
+    );
+
+    let [d, m, mp, f] = lunar_arguments(t);
+
     let d_rad = d * DEG2RAD;
     let m_rad = m * DEG2RAD;
     let mp_rad = mp * DEG2RAD;
@@ -440,6 +463,39 @@ pub fn moon_longitude(jd: f64) -> f64 {
     norm_deg(lp + sum_l / 1_000_000.0)
 }
 
+/// Compute the Moon's geocentric ecliptic latitude, north positive.
+pub fn moon_latitude(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    let [d, m, mp, f] = lunar_arguments(t);
+    let d_rad = d * DEG2RAD;
+    let m_rad = m * DEG2RAD;
+    let mp_rad = mp * DEG2RAD;
+    let f_rad = f * DEG2RAD;
+
+    // Principal terms for latitude (simplified from Meeus Table 47.B)
+    let mut sum_b: f64 = 0.0;
+    sum_b += 5_128_122.0 * f_rad.sin();
+    sum_b += 280_602.0 * (mp_rad + f_rad).sin();
+    sum_b += 277_693.0 * (mp_rad - f_rad).sin();
+    sum_b += 173_237.0 * (2.0 * d_rad - f_rad).sin();
+    sum_b += 55_413.0 * (2.0 * d_rad - mp_rad + f_rad).sin();
+    sum_b += 46_271.0 * (2.0 * d_rad - mp_rad - f_rad).sin();
+    sum_b += 32_573.0 * (2.0 * d_rad + f_rad).sin();
+    sum_b += 17_198.0 * (2.0 * mp_rad + f_rad).sin();
+    sum_b += 9_266.0 * (2.0 * d_rad + mp_rad - f_rad).sin();
+    sum_b += 8_822.0 * (2.0 * mp_rad - f_rad).sin();
+    sum_b += 8_216.0 * (2.0 * d_rad - m_rad - f_rad).sin();
+    sum_b += 4_324.0 * (2.0 * d_rad - 2.0 * mp_rad - f_rad).sin();
+    sum_b += 4_200.0 * (2.0 * d_rad + mp_rad + f_rad).sin();
+    sum_b += -3_359.0 * (2.0 * d_rad + m_rad - f_rad).sin();
+    sum_b += 2_463.0 * (2.0 * d_rad - m_rad - mp_rad + f_rad).sin();
+    sum_b += 2_211.0 * (2.0 * d_rad - m_rad + f_rad).sin();
+    sum_b += 2_065.0 * (2.0 * d_rad - m_rad - mp_rad - f_rad).sin();
+
+    // Convert from 0.000001 degrees to degrees
+    sum_b / 1_000_000.0
+}
+
 // ---------------------------------------------------------------------------
 // Retrograde detection
 // ---------------------------------------------------------------------------
@@ -489,8 +545,10 @@ pub fn compute_ascendant(lst_deg: f64, lat_deg: f64, obl_deg: f64) -> f64 {
     let lat_rad = lat_deg * DEG2RAD;
     let obl_rad = obl_deg * DEG2RAD;
 
-    let y = -lst_rad.cos();
-    let x = obl_rad.sin() * lat_rad.tan() + obl_rad.cos() * lst_rad.sin();
+    // atan2(-cos θ, sin ε tan φ + cos ε sin θ) lands on the descending
+    // point; negating both arguments selects the rising (eastern) one.
+    let y = lst_rad.cos();
+    let x = -(obl_rad.sin() * lat_rad.tan() + obl_rad.cos() * lst_rad.sin());
 
     norm_deg(y.atan2(x) * RAD2DEG)
 }
@@ -616,6 +674,23 @@ pub fn calculate_natal_chart(birth_data: &BirthData) -> NatalChart {
     // Calculate Julian Day
     let jd = to_julian_day(birth_data.year, birth_data.month, day, ut_hour, ut_minute);
 
+    chart_at(jd, latitude, geo_longitude)
+}
+
+/// Julian Day (UT) of the birth moment.
+///
+/// # Panics
+/// Panics if `day`, `hour`, `minute` or `timezone` is `None`.
+pub fn birth_julian_day(birth_data: &BirthData) -> f64 {
+    let day = birth_data.day.expect("day is required for birth Julian Day");
+    let hour = birth_data.hour.expect("hour is required for birth Julian Day");
+    let minute = birth_data.minute.expect("minute is required for birth Julian Day");
+    let timezone = birth_data.timezone.expect("timezone is required for birth Julian Day");
+    to_julian_day(birth_data.year, birth_data.month, day, hour - timezone as i32, minute)
+}
+
+/// Compute a full chart for a Julian Day (UT) at a geographic location.
+fn chart_at(jd: f64, latitude: f64, geo_longitude: f64) -> NatalChart {
     // Obliquity of the ecliptic
     let obl = obliquity(jd);
 
@@ -683,6 +758,743 @@ pub fn calculate_natal_chart(birth_data: &BirthData) -> NatalChart {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Incremental sky updates (live "current sky" widgets)
+// ---------------------------------------------------------------------------
+
+/// Maximum time (in days) for which slow planets are carried over from the
+/// snapshot that last computed them. Jupiter moves ~0.25°/day at most, so
+/// beyond this the snapshot is recomputed in full.
+pub const INCREMENTAL_MAX_STEP_DAYS: f64 = 1.0;
+
+/// Compute a full sky snapshot for a Julian Day (UT) at a location.
+pub fn calculate_sky(jd: f64, latitude: f64, longitude: f64) -> SkySnapshot {
+    SkySnapshot {
+        jd,
+        latitude,
+        longitude,
+        slow_jd: jd,
+        chart: chart_at(jd, latitude, longitude),
+    }
+}
+
+/// Advance a sky snapshot to a new Julian Day (UT).
+///
+/// Only the fast-moving bodies (Sun, Moon, Mercury, Venus, Mars) and the
+/// angles are recomputed; Jupiter through Pluto keep their previous
+/// longitudes and are re-housed against the new cusps. Aspects are rebuilt
+/// from the merged positions. Once `jd` lies more than
+/// [`INCREMENTAL_MAX_STEP_DAYS`] from the last full computation
+/// (`previous.slow_jd`) the snapshot is recomputed from scratch, so a chain
+/// of small steps cannot let the slow planets drift.
+pub fn update_sky(previous: &SkySnapshot, jd: f64) -> SkySnapshot {
+    if (jd - previous.slow_jd).abs() > INCREMENTAL_MAX_STEP_DAYS {
+        return calculate_sky(jd, previous.latitude, previous.longitude);
+    }
+
+    let prev = &previous.chart;
+    let obl = obliquity(jd);
+    let lst = local_sidereal_time(jd, previous.longitude);
+    let asc_deg = compute_ascendant(lst, previous.latitude, obl);
+    let mc_deg = compute_midheaven(lst, obl);
+    let cusps = equal_house_cusps(asc_deg);
+
+    let sun = build_position("sun", sun_longitude(jd), &cusps, false);
+    let moon = build_position("moon", moon_longitude(jd), &cusps, false);
+    let mercury = build_position(
+        "mercury", geocentric_longitude(MERCURY, jd), &cusps, is_retrograde(MERCURY, jd),
+    );
+    let venus = build_position(
+        "venus", geocentric_longitude(VENUS, jd), &cusps, is_retrograde(VENUS, jd),
+    );
+    let mars = build_position(
+        "mars", geocentric_longitude(MARS, jd), &cusps, is_retrograde(MARS, jd),
+    );
+
+    let rehouse = |p: &PlanetPosition| PlanetPosition {
+        house: house_for_longitude(p.total_degrees, &cusps),
+        ..p.clone()
+    };
+    let jupiter = rehouse(&prev.jupiter);
+    let saturn = rehouse(&prev.saturn);
+    let uranus = rehouse(&prev.uranus);
+    let neptune = rehouse(&prev.neptune);
+    let pluto = rehouse(&prev.pluto);
+
+    let all_positions = vec![
+        sun.clone(), moon.clone(), mercury.clone(), venus.clone(),
+        mars.clone(), jupiter.clone(), saturn.clone(), uranus.clone(),
+        neptune.clone(), pluto.clone(),
+    ];
+    let aspects = calculate_aspects(&all_positions);
+
+    SkySnapshot {
+        jd,
+        latitude: previous.latitude,
+        longitude: previous.longitude,
+        slow_jd: previous.slow_jd,
+        chart: NatalChart {
+            sun,
+            moon,
+            mercury,
+            venus,
+            mars,
+            jupiter,
+            saturn,
+            uranus,
+            neptune,
+            pluto,
+            ascendant: degrees_to_sign(asc_deg),
+            midheaven: degrees_to_sign(mc_deg),
+            aspects,
+            house_cusps: cusps,
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lunation phases & secondary progressions
+// ---------------------------------------------------------------------------
+
+/// Classify a Sun/Moon pair into one of the eight lunation phases.
+pub fn lunation_phase(sun_lon: f64, moon_lon: f64) -> LunationPhase {
+    const PHASES: [LunationPhase; 8] = [
+        LunationPhase::New,
+        LunationPhase::Crescent,
+        LunationPhase::FirstQuarter,
+        LunationPhase::Gibbous,
+        LunationPhase::Full,
+        LunationPhase::Disseminating,
+        LunationPhase::LastQuarter,
+        LunationPhase::Balsamic,
+    ];
+    let angle = norm_deg(moon_lon - sun_lon);
+    PHASES[((angle / 45.0).floor() as usize).min(7)]
+}
+
+/// Secondary-progressed Julian Day: one day after birth per year of life.
+pub fn progressed_julian_day(birth_jd: f64, target_jd: f64) -> f64 {
+    birth_jd + (target_jd - birth_jd) / TROPICAL_YEAR_DAYS
+}
+
+/// Progressed Sun-Moon phase for the given birth data at `target_jd` (UT).
+///
+/// # Panics
+/// Panics if the birth date/time fields required by [`birth_julian_day`] are
+/// `None`.
+pub fn progressed_lunation(birth_data: &BirthData, target_jd: f64) -> ProgressedLunation {
+    let progressed_jd = progressed_julian_day(birth_julian_day(birth_data), target_jd);
+    let sun = sun_longitude(progressed_jd);
+    let moon = moon_longitude(progressed_jd);
+
+    ProgressedLunation {
+        phase: lunation_phase(sun, moon),
+        phase_angle: (norm_deg(moon - sun) * 100.0).round() / 100.0,
+        progressed_jd,
+        progressed_sun: degrees_to_sign(sun),
+        progressed_moon: degrees_to_sign(moon),
+    }
+}
+
+/// The Moon's phase at a Julian Day (UT).
+pub fn moon_phase(jd: f64) -> MoonPhase {
+    let sun = sun_longitude(jd);
+    let moon = moon_longitude(jd);
+    let angle = norm_deg(moon - sun);
+
+    MoonPhase {
+        phase: lunation_phase(sun, moon),
+        phase_angle: (angle * 100.0).round() / 100.0,
+        illumination: ((1.0 - (angle * DEG2RAD).cos()) / 2.0 * 1000.0).round() / 1000.0,
+        waxing: angle < 180.0,
+        moon: degrees_to_sign(moon),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Local space (horizon compass directions)
+// ---------------------------------------------------------------------------
+
+const COMPASS_POINTS: [&str; 16] = [
+    "north", "north-northeast", "northeast", "east-northeast",
+    "east", "east-southeast", "southeast", "south-southeast",
+    "south", "south-southwest", "southwest", "west-southwest",
+    "west", "west-northwest", "northwest", "north-northwest",
+];
+
+/// Name of the 16-point compass direction nearest to an azimuth.
+pub fn compass_point(azimuth: f64) -> &'static str {
+    COMPASS_POINTS[((norm_deg(azimuth) + 11.25) / 22.5).floor() as usize % 16]
+}
+
+/// Convert an ecliptic longitude (latitude assumed 0) to right ascension and
+/// declination, both in degrees.
+fn ecliptic_to_equatorial(lon_deg: f64, obl_deg: f64) -> (f64, f64) {
+    let lon = lon_deg * DEG2RAD;
+    let obl = obl_deg * DEG2RAD;
+    let ra = (lon.sin() * obl.cos()).atan2(lon.cos()) * RAD2DEG;
+    let dec = (obl.sin() * lon.sin()).asin() * RAD2DEG;
+    (norm_deg(ra), dec)
+}
+
+/// Convert equatorial coordinates to (azimuth from north, altitude).
+fn equatorial_to_horizontal(ra_deg: f64, dec_deg: f64, lst_deg: f64, lat_deg: f64) -> (f64, f64) {
+    let h = (lst_deg - ra_deg) * DEG2RAD;
+    let dec = dec_deg * DEG2RAD;
+    let lat = lat_deg * DEG2RAD;
+
+    // Meeus 13.5 measures azimuth from the south; add 180° for north-based.
+    let az = h.sin().atan2(h.cos() * lat.sin() - dec.tan() * lat.cos()) * RAD2DEG + 180.0;
+    let alt = (lat.sin() * dec.sin() + lat.cos() * dec.cos() * h.cos()).asin() * RAD2DEG;
+    (norm_deg(az), alt)
+}
+
+/// Compass bearing and altitude of each planet as seen from the birth
+/// location at the moment of birth.
+///
+/// # Panics
+/// Panics under the same conditions as [`calculate_natal_chart`].
+pub fn local_space_chart(birth_data: &BirthData) -> Vec<LocalSpacePosition> {
+    let chart = calculate_natal_chart(birth_data);
+    let latitude = birth_data.latitude.expect("latitude is required for local space");
+    let geo_longitude = birth_data.longitude.expect("longitude is required for local space");
+    let jd = birth_julian_day(birth_data);
+    let obl = obliquity(jd);
+    let lst = local_sidereal_time(jd, geo_longitude);
+
+    let planets = [
+        &chart.sun, &chart.moon, &chart.mercury, &chart.venus, &chart.mars,
+        &chart.jupiter, &chart.saturn, &chart.uranus, &chart.neptune, &chart.pluto,
+    ];
+    planets
+        .iter()
+        .map(|p| {
+            let (ra, dec) = ecliptic_to_equatorial(p.total_degrees, obl);
+            let (azimuth, altitude) = equatorial_to_horizontal(ra, dec, lst, latitude);
+            LocalSpacePosition {
+                planet: p.planet.clone(),
+                azimuth: (azimuth * 100.0).round() / 100.0,
+                altitude: (altitude * 100.0).round() / 100.0,
+                direction: compass_point(azimuth).to_string(),
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Sun-sign daily horoscope data
+// ---------------------------------------------------------------------------
+
+/// Life areas scored by the daily horoscope: (area, solar houses, planets).
+const HOROSCOPE_AREAS: [(&str, &[usize], &[&str]); 3] = [
+    ("love", &[5, 7], &["venus", "moon", "mars"]),
+    ("career", &[10, 6, 2], &["saturn", "jupiter", "sun", "mars", "mercury"]),
+    ("health", &[6, 1, 12], &["mars", "moon", "sun", "saturn"]),
+];
+
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (1, 11) | (2, 12) | (3, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+fn horoscope_theme(
+    area: &str,
+    houses: &[usize],
+    planets: &[&str],
+    transits: &[PlanetPosition],
+    aspects: &[ChartAspect],
+) -> HoroscopeTheme {
+    let mut score = 0.0;
+    let mut harmonious = 0.0;
+    let mut challenging = 0.0;
+    let mut drivers = Vec::new();
+
+    for t in transits.iter().filter(|t| houses.contains(&t.house)) {
+        score += 1.5;
+        drivers.push(format!("{} transiting the {} house", t.planet, ordinal(t.house)));
+    }
+
+    for a in aspects.iter().filter(|a| planets.contains(&a.planet1.as_str())) {
+        let max_orb = ASPECT_DEFS
+            .iter()
+            .find(|d| d.name == a.aspect_name)
+            .map_or(8.0, |d| d.orb);
+        let weight = 2.0 * (1.0 - a.orb / max_orb).max(0.0) + 0.5;
+        score += weight;
+        match a.nature.as_str() {
+            "harmonious" => harmonious += weight,
+            "challenging" => challenging += weight,
+            _ => {}
+        }
+        drivers.push(format!(
+            "{} {} natal sun",
+            a.planet1,
+            a.aspect_name.to_lowercase()
+        ));
+    }
+
+    let tone = if score == 0.0 {
+        "quiet"
+    } else if harmonious > challenging * 1.5 {
+        "harmonious"
+    } else if challenging > harmonious * 1.5 {
+        "challenging"
+    } else {
+        "mixed"
+    };
+
+    HoroscopeTheme {
+        area: area.to_string(),
+        intensity: score.round().min(10.0) as u8,
+        tone: tone.to_string(),
+        drivers,
+    }
+}
+
+/// Generate daily horoscope data for a sun sign on a calendar date.
+///
+/// Transits are computed for 12:00 UT and placed in whole-sign solar houses
+/// (the sun sign is the 1st house). Aspects are measured to a generic natal
+/// Sun at 15° of the sign.
+///
+/// # Errors
+/// Returns an error string if `sign` is not a zodiac sign id (e.g. `"leo"`).
+pub fn daily_horoscope(
+    sign: &str,
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Result<DailyHoroscope, String> {
+    let sign_index = SIGN_ORDER
+        .iter()
+        .position(|s| *s == sign)
+        .ok_or_else(|| format!("Unknown zodiac sign: {}", sign))?;
+
+    let jd = to_julian_day(year, month, day, 12, 0);
+    let sky = chart_at(jd, 0.0, 0.0);
+    let solar_house = |lon: f64| ((lon / 30.0).floor() as usize + 12 - sign_index) % 12 + 1;
+
+    let transits: Vec<PlanetPosition> = [
+        &sky.sun, &sky.moon, &sky.mercury, &sky.venus, &sky.mars,
+        &sky.jupiter, &sky.saturn, &sky.uranus, &sky.neptune, &sky.pluto,
+    ]
+    .iter()
+    .map(|p| PlanetPosition {
+        house: solar_house(p.total_degrees),
+        ..(*p).clone()
+    })
+    .collect();
+
+    let natal_sun = build_position("sun", sign_index as f64 * 30.0 + 15.0, &[0.0; 12], false);
+    let aspects: Vec<ChartAspect> = transits
+        .iter()
+        .flat_map(|t| calculate_aspects(&[t.clone(), natal_sun.clone()]))
+        .collect();
+
+    let [love, career, health] = HOROSCOPE_AREAS
+        .map(|(area, houses, planets)| horoscope_theme(area, houses, planets, &transits, &aspects));
+
+    Ok(DailyHoroscope {
+        sign: sign.to_string(),
+        year,
+        month,
+        day,
+        transits,
+        aspects,
+        love,
+        career,
+        health,
+    })
+}
+
+/// Aspects from the planets at a Julian Day (UT) to the planets and angles
+/// of a natal chart, tightest orb first. `planet1` is the transiting planet
+/// and `planet2` the natal point.
+pub fn transits_to_chart(natal: &NatalChart, jd: f64) -> Vec<ChartAspect> {
+    let sky = chart_at(jd, 0.0, 0.0);
+    let transiting = [
+        &sky.sun, &sky.moon, &sky.mercury, &sky.venus, &sky.mars,
+        &sky.jupiter, &sky.saturn, &sky.uranus, &sky.neptune, &sky.pluto,
+    ];
+    let mut natal_points: Vec<PlanetPosition> = [
+        &natal.sun, &natal.moon, &natal.mercury, &natal.venus, &natal.mars,
+        &natal.jupiter, &natal.saturn, &natal.uranus, &natal.neptune, &natal.pluto,
+    ]
+    .into_iter()
+    .cloned()
+    .collect();
+    for (name, angle) in [("ascendant", &natal.ascendant), ("midheaven", &natal.midheaven)] {
+        natal_points.push(build_position(name, angle.total_degrees, &natal.house_cusps, false));
+    }
+
+    let mut aspects: Vec<ChartAspect> = transiting
+        .iter()
+        .flat_map(|t| {
+            natal_points
+                .iter()
+                .flat_map(|n| calculate_aspects(&[(*t).clone(), n.clone()]))
+        })
+        .collect();
+    aspects.sort_by(|a, b| a.orb.total_cmp(&b.orb));
+    aspects
+}
+
+// ---------------------------------------------------------------------------
+// Sect
+// ---------------------------------------------------------------------------
+
+/// A chart is diurnal when the Sun is above the horizon (houses 7–12).
+pub fn chart_sect(chart: &NatalChart) -> Sect {
+    if chart.sun.house >= 7 { Sect::Day } else { Sect::Night }
+}
+
+fn is_day_chart(chart: &NatalChart) -> bool {
+    chart_sect(chart) == Sect::Day
+}
+
+/// Determine the chart's sect and the luminary, benefic and malefic of sect.
+pub fn sect_analysis(chart: &NatalChart) -> SectAnalysis {
+    let sect = chart_sect(chart);
+    let (luminary, benefic, malefic, contrary_benefic, contrary_malefic) = match sect {
+        Sect::Day => ("sun", "jupiter", "saturn", "venus", "mars"),
+        Sect::Night => ("moon", "venus", "mars", "jupiter", "saturn"),
+    };
+
+    // Mercury is a morning star when it sits behind the Sun in zodiacal order.
+    let mut elongation = chart.mercury.total_degrees - chart.sun.total_degrees;
+    if elongation > 180.0 { elongation -= 360.0; }
+    if elongation < -180.0 { elongation += 360.0; }
+    let mercury_sect = if elongation < 0.0 { Sect::Day } else { Sect::Night };
+
+    SectAnalysis {
+        sect,
+        luminary_of_sect: luminary.to_string(),
+        benefic_of_sect: benefic.to_string(),
+        malefic_of_sect: malefic.to_string(),
+        benefic_contrary_to_sect: contrary_benefic.to_string(),
+        malefic_contrary_to_sect: contrary_malefic.to_string(),
+        mercury_sect,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lots (Arabic parts)
+// ---------------------------------------------------------------------------
+
+/// Lot of Fortune: Asc + Moon − Sun by day, Asc + Sun − Moon by night.
+pub fn lot_of_fortune(chart: &NatalChart) -> SignPosition {
+    let (asc, sun, moon) = (
+        chart.ascendant.total_degrees,
+        chart.sun.total_degrees,
+        chart.moon.total_degrees,
+    );
+    if is_day_chart(chart) {
+        degrees_to_sign(asc + moon - sun)
+    } else {
+        degrees_to_sign(asc + sun - moon)
+    }
+}
+
+/// Lot of Spirit: Asc + Sun − Moon by day, Asc + Moon − Sun by night.
+pub fn lot_of_spirit(chart: &NatalChart) -> SignPosition {
+    let (asc, sun, moon) = (
+        chart.ascendant.total_degrees,
+        chart.sun.total_degrees,
+        chart.moon.total_degrees,
+    );
+    if is_day_chart(chart) {
+        degrees_to_sign(asc + sun - moon)
+    } else {
+        degrees_to_sign(asc + moon - sun)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Zodiacal releasing
+// ---------------------------------------------------------------------------
+
+/// Length of a releasing "year" in days (Valens' 360-day calendar).
+const RELEASING_YEAR_DAYS: f64 = 360.0;
+
+/// Minor years of each sign's ruler, in SIGN_ORDER. Capricorn takes 27
+/// rather than Saturn's 30, per Valens.
+const RELEASING_YEARS: [u32; 12] = [15, 8, 20, 25, 19, 20, 8, 15, 12, 27, 30, 12];
+
+/// Split `[start, end)` into consecutive sign periods starting from
+/// `first_sign`, each lasting `RELEASING_YEARS[sign] * unit_days`.
+///
+/// After all twelve signs have been used the sequence would return to its
+/// first sign; instead it jumps to the opposite sign (the loosing of the bond)
+/// and continues from there.
+fn release_periods(
+    level: u8,
+    first_sign: usize,
+    start: f64,
+    end: f64,
+    unit_days: f64,
+) -> Vec<ReleasingPeriod> {
+    let mut periods = Vec::new();
+    let mut sign = first_sign;
+    let mut t = start;
+    let mut steps = 0;
+    let mut loosed = false;
+
+    while t < end {
+        let mut loosing = false;
+        if steps == 12 && !loosed {
+            sign = (first_sign + 6) % 12;
+            loosing = true;
+            loosed = true;
+        }
+        let period_end = (t + RELEASING_YEARS[sign] as f64 * unit_days).min(end);
+        periods.push(ReleasingPeriod {
+            level,
+            sign: SIGN_ORDER[sign].to_string(),
+            start_jd: t,
+            end_jd: period_end,
+            loosing_of_the_bond: loosing,
+        });
+        t = period_end;
+        sign = (sign + 1) % 12;
+        steps += 1;
+    }
+    periods
+}
+
+/// Zodiacal releasing from the Lot of Fortune or Spirit, covering `years`
+/// (360-day years) from birth. Level 2 sub-periods use 30-day months.
+///
+/// # Panics
+/// Panics under the same conditions as [`calculate_natal_chart`].
+pub fn zodiacal_releasing(birth_data: &BirthData, lot: Lot, years: u32) -> ZodiacalReleasing {
+    let chart = calculate_natal_chart(birth_data);
+    let lot_position = match lot {
+        Lot::Fortune => lot_of_fortune(&chart),
+        Lot::Spirit => lot_of_spirit(&chart),
+    };
+    let start_sign = (lot_position.total_degrees / 30.0).floor() as usize % 12;
+    let birth_jd = birth_julian_day(birth_data);
+    let end_jd = birth_jd + years as f64 * RELEASING_YEAR_DAYS;
+
+    // The last level 1 period runs its full length even past the requested
+    // span so that its sub-periods are complete.
+    let mut level1 = release_periods(1, start_sign, birth_jd, end_jd, RELEASING_YEAR_DAYS);
+    if let Some(last) = level1.last_mut() {
+        let sign = SIGN_ORDER.iter().position(|s| *s == last.sign).unwrap_or(0);
+        last.end_jd = last.start_jd + RELEASING_YEARS[sign] as f64 * RELEASING_YEAR_DAYS;
+    }
+
+    let level2 = level1
+        .iter()
+        .flat_map(|l1| {
+            let sign = SIGN_ORDER.iter().position(|s| *s == l1.sign).unwrap_or(0);
+            release_periods(2, sign, l1.start_jd, l1.end_jd, RELEASING_YEAR_DAYS / 12.0)
+        })
+        .collect();
+
+    ZodiacalReleasing { lot, lot_position, level1, level2 }
+}
+
+// ---------------------------------------------------------------------------
+// Glossary & learning-mode explanations
+// ---------------------------------------------------------------------------
+
+/// A glossary term with a plain-language definition.
+pub struct GlossaryEntry {
+    pub term: &'static str,
+    pub definition: &'static str,
+}
+
+static GLOSSARY: [GlossaryEntry; 9] = [
+    GlossaryEntry {
+        term: "julian_day",
+        definition: "A continuous count of days since 4713 BC used by astronomers so that time differences are simple subtraction.",
+    },
+    GlossaryEntry {
+        term: "ecliptic_longitude",
+        definition: "Position along the Sun's apparent yearly path, measured 0-360° from 0° Aries; each sign spans 30°.",
+    },
+    GlossaryEntry {
+        term: "sidereal_time",
+        definition: "Time measured by the rotation of the Earth relative to the stars rather than the Sun; it fixes which degree of the zodiac is rising.",
+    },
+    GlossaryEntry {
+        term: "ascendant",
+        definition: "The degree of the zodiac rising on the eastern horizon at the moment and place of birth.",
+    },
+    GlossaryEntry {
+        term: "midheaven",
+        definition: "The degree of the zodiac culminating on the meridian, the highest point the ecliptic reaches overhead.",
+    },
+    GlossaryEntry {
+        term: "equal_houses",
+        definition: "A house system that starts the first house at the Ascendant and gives each of the twelve houses exactly 30°.",
+    },
+    GlossaryEntry {
+        term: "aspect",
+        definition: "A significant angle between two planets (0°, 60°, 90°, 120° or 180°) describing how their energies interact.",
+    },
+    GlossaryEntry {
+        term: "orb",
+        definition: "How far an angle may deviate from an exact aspect and still count; tighter orbs mean stronger aspects.",
+    },
+    GlossaryEntry {
+        term: "retrograde",
+        definition: "Apparent backward motion of a planet through the zodiac, caused by Earth overtaking it (or it overtaking Earth) in orbit.",
+    },
+];
+
+/// All glossary entries used by learning-mode explanations.
+pub fn glossary() -> &'static [GlossaryEntry] {
+    &GLOSSARY
+}
+
+/// Look up a glossary entry by term (e.g. `"orb"`).
+pub fn glossary_entry(term: &str) -> Option<&'static GlossaryEntry> {
+    GLOSSARY.iter().find(|g| g.term == term)
+}
+
+fn explanation(topic: &str, term: &str, detail: String) -> ChartExplanation {
+    let definition = glossary_entry(term).map_or("", |g| g.definition);
+    ChartExplanation {
+        topic: topic.to_string(),
+        term: term.to_string(),
+        note: format!("{} {}", definition, detail),
+    }
+}
+
+/// Build "how this was computed" notes for a chart cast at `jd` (UT).
+pub fn explain_chart(chart: &NatalChart, jd: f64) -> Vec<ChartExplanation> {
+    let mut notes = vec![
+        explanation(
+            "time",
+            "julian_day",
+            format!("This chart was computed for JD {:.5} (UT).", jd),
+        ),
+        explanation(
+            "sun",
+            "ecliptic_longitude",
+            format!(
+                "The Sun at {:.2}° falls {:.2}° into {} because each sign covers 30°.",
+                chart.sun.total_degrees, chart.sun.degrees, chart.sun.sign
+            ),
+        ),
+        explanation(
+            "ascendant",
+            "ascendant",
+            format!(
+                "It was found from local sidereal time, the birth latitude and the \
+                 obliquity of the ecliptic, giving {:.2}° {}.",
+                chart.ascendant.degrees, chart.ascendant.sign
+            ),
+        ),
+        explanation(
+            "midheaven",
+            "midheaven",
+            format!(
+                "It was found from local sidereal time and the obliquity of the \
+                 ecliptic, giving {:.2}° {}.",
+                chart.midheaven.degrees, chart.midheaven.sign
+            ),
+        ),
+        explanation(
+            "houses",
+            "equal_houses",
+            format!("The first house therefore begins at {:.2}°.", chart.house_cusps[0]),
+        ),
+    ];
+
+    if let Some(tightest) = chart.aspects.first() {
+        notes.push(explanation(
+            "aspects",
+            "orb",
+            format!(
+                "The tightest aspect is {} {} {}, {:.2}° from exact.",
+                tightest.planet1, tightest.aspect_name, tightest.planet2, tightest.orb
+            ),
+        ));
+    }
+
+    let planets = [
+        &chart.mercury, &chart.venus, &chart.mars, &chart.jupiter,
+        &chart.saturn, &chart.uranus, &chart.neptune, &chart.pluto,
+    ];
+    for p in planets.iter().filter(|p| p.retrograde) {
+        notes.push(explanation(
+            &p.planet,
+            "retrograde",
+            format!(
+                "{} is marked retrograde because its longitude one day later is lower \
+                 than one day earlier.",
+                p.planet
+            ),
+        ));
+    }
+
+    notes
+}
+
+/// Calculate a natal chart, attaching explanations when `options.explain`
+/// is set.
+///
+/// # Panics
+/// Panics under the same conditions as [`calculate_natal_chart`].
+pub fn calculate_natal_chart_with_options(
+    birth_data: &BirthData,
+    options: &ChartOptions,
+) -> ExplainedChart {
+    let chart = calculate_natal_chart(birth_data);
+    let explanations = if options.explain {
+        explain_chart(&chart, birth_julian_day(birth_data))
+    } else {
+        Vec::new()
+    };
+    ExplainedChart { chart, explanations }
+}
+
+/// Build "how this was computed" notes for transits at `jd` (UT): one per
+/// aspect, naming the transiting planet, the natal point and the orb.
+pub fn explain_transits(aspects: &[ChartAspect], jd: f64) -> Vec<ChartExplanation> {
+    let mut notes = vec![explanation(
+        "time",
+        "julian_day",
+        format!("These transits were computed for JD {:.5} (UT).", jd),
+    )];
+    for a in aspects {
+        notes.push(explanation(
+            &a.planet1,
+            "orb",
+            format!(
+                "Transiting {} makes a {} to natal {}, {:.2}° from exact.",
+                a.planet1, a.aspect_name, a.planet2, a.orb
+            ),
+        ));
+    }
+    notes
+}
+
+/// Transits to a natal chart at `jd` (UT), attaching explanations when
+/// `options.explain` is set.
+pub fn transits_to_chart_with_options(
+    natal: &NatalChart,
+    jd: f64,
+    options: &TransitOptions,
+) -> ExplainedTransits {
+    let aspects = transits_to_chart(natal, jd);
+    let explanations = if options.explain {
+        explain_transits(&aspects, jd)
+    } else {
+        Vec::new()
+    };
+    ExplainedTransits { aspects, explanations }
+}
+
 // ---------------------------------------------------------------------------
 // Aspect calculation
 // ---------------------------------------------------------------------------
@@ -791,6 +1603,125 @@ impl AstrologyEngine {
     pub fn degrees_to_sign(&self, total_degrees: f64) -> SignPosition {
         degrees_to_sign(total_degrees)
     }
+
+    /// Calculate a natal chart with optional learning-mode explanations.
+    pub fn calculate_natal_chart_with_options(
+        &self,
+        birth_data: &BirthData,
+        options: &ChartOptions,
+    ) -> ExplainedChart {
+        calculate_natal_chart_with_options(birth_data, options)
+    }
+
+    /// Exact conjunctions and oppositions between two bodies in a date range.
+    pub fn find_synodic_events(
+        &self,
+        body1: &str,
+        body2: &str,
+        start_jd: f64,
+        end_jd: f64,
+    ) -> Result<Vec<SynodicEvent>, String> {
+        cycles::find_synodic_events(body1, body2, start_jd, end_jd)
+    }
+
+    /// The astrological age at a Julian Day.
+    pub fn astrological_age(&self, jd: f64) -> AstrologicalAge {
+        precession::astrological_age(jd)
+    }
+
+    /// Scan candidate birth times around the recorded one.
+    pub fn scan_birth_times(
+        &self,
+        birth_data: &BirthData,
+        options: &RectificationOptions,
+    ) -> Result<Vec<RectificationCandidate>, String> {
+        rectification::scan_birth_times(birth_data, options)
+    }
+
+    /// Sect of a chart with its benefic and malefic of sect.
+    pub fn sect_analysis(&self, chart: &NatalChart) -> SectAnalysis {
+        sect_analysis(chart)
+    }
+
+    /// Structured daily horoscope data for a sun sign.
+    pub fn daily_horoscope(
+        &self,
+        sign: &str,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<DailyHoroscope, String> {
+        daily_horoscope(sign, year, month, day)
+    }
+
+    /// Compass bearing of each planet from the birth location.
+    pub fn local_space_chart(&self, birth_data: &BirthData) -> Vec<LocalSpacePosition> {
+        local_space_chart(birth_data)
+    }
+
+    /// Zodiacal releasing timeline from a lot, covering `years` from birth.
+    pub fn zodiacal_releasing(
+        &self,
+        birth_data: &BirthData,
+        lot: Lot,
+        years: u32,
+    ) -> ZodiacalReleasing {
+        zodiacal_releasing(birth_data, lot, years)
+    }
+
+    /// Progressed Sun-Moon lunation phase at `target_jd` (UT).
+    pub fn progressed_lunation(&self, birth_data: &BirthData, target_jd: f64) -> ProgressedLunation {
+        progressed_lunation(birth_data, target_jd)
+    }
+
+    /// Compute a full sky snapshot for a Julian Day (UT) at a location.
+    pub fn calculate_sky(&self, jd: f64, latitude: f64, longitude: f64) -> SkySnapshot {
+        calculate_sky(jd, latitude, longitude)
+    }
+
+    /// Advance a sky snapshot, recomputing only the fast-moving bodies.
+    pub fn update_sky(&self, previous: &SkySnapshot, jd: f64) -> SkySnapshot {
+        update_sky(previous, jd)
+    }
+
+    /// Tithi, vara, nakshatra, yoga and karana for a Julian Day (UT) at a
+    /// location.
+    pub fn panchanga(&self, jd: f64, latitude: f64, longitude: f64) -> Panchanga {
+        panchanga::calculate_panchanga(jd, latitude, longitude)
+    }
+
+    /// The Moon's phase at a Julian Day (UT).
+    pub fn moon_phase(&self, jd: f64) -> MoonPhase {
+        moon_phase(jd)
+    }
+
+    /// Inter-chart aspects between two natal charts, scored for harmony.
+    pub fn synastry(&self, first: &NatalChart, second: &NatalChart) -> Synastry {
+        synastry::synastry(first, second)
+    }
+
+    /// Aspects from the planets at `jd` (UT) to a natal chart.
+    pub fn transits_to_chart(&self, natal: &NatalChart, jd: f64) -> Vec<ChartAspect> {
+        transits_to_chart(natal, jd)
+    }
+
+    /// Transits to a natal chart with optional learning-mode explanations.
+    pub fn transits_to_chart_with_options(
+        &self,
+        natal: &NatalChart,
+        jd: f64,
+        options: &TransitOptions,
+    ) -> ExplainedTransits {
+        transits_to_chart_with_options(natal, jd, options)
+    }
+
+    /// Phases, eclipses and void-of-course windows for a month (UT).
+    ///
+    /// # Errors
+    /// Returns an error string if `month` is not 1–12.
+    pub fn lunar_calendar(&self, year: i32, month: u32) -> Result<LunarCalendar, String> {
+        lunar_calendar::lunar_calendar(year, month)
+    }
 }
 
 impl Default for AstrologyEngine {
@@ -806,6 +1737,7 @@ impl Default for AstrologyEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::birth;
 
     #[test]
     fn julian_day_j2000() {
@@ -818,7 +1750,7 @@ mod tests {
     fn julian_day_known_date() {
         // 1957-10-04 19:28 UT → JD 2436116.31111 (Sputnik launch)
         let jd = to_julian_day(1957, 10, 4, 19, 28);
-        assert!((jd - 2_436_116.31111).abs() < 0.001, "Sputnik JD mismatch: {}", jd);
+        assert!((jd - 2_436_116.311_11).abs() < 0.001, "Sputnik JD mismatch: {}", jd);
     }
 
     #[test]
@@ -883,6 +1815,219 @@ mod tests {
         assert!(lon > 279.0 && lon < 282.0, "Sun at J2000.0 = {}°", lon);
     }
 
+    #[test]
+    fn incremental_update_tracks_full_recompute() {
+        let jd = to_julian_day(2024, 3, 20, 12, 0);
+        let sky = calculate_sky(jd, 51.5, -0.13);
+        let next = jd + 10.0 / 1440.0;
+
+        let updated = update_sky(&sky, next);
+        let full = calculate_sky(next, 51.5, -0.13);
+
+        assert_eq!(updated.jd, next);
+        assert!((updated.chart.moon.total_degrees - full.chart.moon.total_degrees).abs() < 1e-9);
+        assert!((updated.chart.ascendant.total_degrees - full.chart.ascendant.total_degrees).abs() < 1e-9);
+        // Slow planets are carried over unchanged.
+        assert_eq!(updated.chart.pluto.total_degrees, sky.chart.pluto.total_degrees);
+        assert!((updated.chart.jupiter.total_degrees - full.chart.jupiter.total_degrees).abs() < 0.05);
+    }
+
+    #[test]
+    fn incremental_update_falls_back_for_large_steps() {
+        let jd = to_julian_day(2024, 3, 20, 12, 0);
+        let sky = calculate_sky(jd, 51.5, -0.13);
+        let later = jd + 30.0;
+
+        let updated = update_sky(&sky, later);
+        let full = calculate_sky(later, 51.5, -0.13);
+        assert_eq!(updated.chart.pluto.total_degrees, full.chart.pluto.total_degrees);
+    }
+
+    #[test]
+    fn chained_small_steps_refresh_slow_planets() {
+        let jd = to_julian_day(2024, 3, 20, 12, 0);
+        let mut sky = calculate_sky(jd, 51.5, -0.13);
+        let step = 1.0 / 1440.0;
+        // Three days of one-minute ticks
+        for i in 1..=3 * 1440 {
+            sky = update_sky(&sky, jd + f64::from(i) * step);
+        }
+        let full = calculate_sky(sky.jd, 51.5, -0.13);
+
+        assert!(sky.jd - sky.slow_jd <= INCREMENTAL_MAX_STEP_DAYS);
+        for (a, b) in [
+            (&sky.chart.jupiter, &full.chart.jupiter),
+            (&sky.chart.saturn, &full.chart.saturn),
+            (&sky.chart.pluto, &full.chart.pluto),
+        ] {
+            let delta = a.total_degrees - b.total_degrees;
+            assert!(delta.abs() < 0.3, "{} off by {}°", a.planet, delta);
+        }
+        assert!((sky.chart.moon.total_degrees - full.chart.moon.total_degrees).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lunation_phase_boundaries() {
+        assert_eq!(lunation_phase(10.0, 20.0), LunationPhase::New);
+        assert_eq!(lunation_phase(10.0, 100.0), LunationPhase::FirstQuarter);
+        assert_eq!(lunation_phase(10.0, 190.0), LunationPhase::Full);
+        assert_eq!(lunation_phase(350.0, 0.0), LunationPhase::New);
+        assert_eq!(lunation_phase(20.0, 0.0), LunationPhase::Balsamic);
+    }
+
+    #[test]
+    fn progressed_lunation_advances_one_day_per_year() {
+        let birth = birth();
+        let birth_jd = birth_julian_day(&birth);
+        let target = birth_jd + 30.0 * TROPICAL_YEAR_DAYS;
+
+        let p = progressed_lunation(&birth, target);
+        assert!((p.progressed_jd - (birth_jd + 30.0)).abs() < 1e-6);
+        assert_eq!(p.phase, lunation_phase(
+            p.progressed_sun.total_degrees,
+            p.progressed_moon.total_degrees,
+        ));
+    }
+
+    #[test]
+    fn explain_option_attaches_glossary_notes() {
+        let birth = birth();
+
+        let plain = calculate_natal_chart_with_options(&birth, &ChartOptions::default());
+        assert!(plain.explanations.is_empty());
+
+        let explained = calculate_natal_chart_with_options(&birth, &ChartOptions { explain: true });
+        assert!(explained.explanations.iter().any(|e| e.term == "ascendant"));
+        for note in &explained.explanations {
+            assert!(glossary_entry(&note.term).is_some(), "unknown term {}", note.term);
+        }
+        let retro = explained.explanations.iter().filter(|e| e.term == "retrograde").count();
+        let expected = [
+            &explained.chart.mercury, &explained.chart.venus, &explained.chart.mars,
+            &explained.chart.jupiter, &explained.chart.saturn, &explained.chart.uranus,
+            &explained.chart.neptune, &explained.chart.pluto,
+        ]
+        .iter()
+        .filter(|p| p.retrograde)
+        .count();
+        assert_eq!(retro, expected);
+
+        let jd = to_julian_day(2024, 1, 1, 12, 0);
+        let options = TransitOptions { explain: true };
+        let transits = transits_to_chart_with_options(&explained.chart, jd, &options);
+        assert_eq!(transits.explanations.len(), transits.aspects.len() + 1);
+        let first = &transits.aspects[0];
+        let note = &transits.explanations[1];
+        assert_eq!((note.topic.as_str(), note.term.as_str()), (first.planet1.as_str(), "orb"));
+        assert!(note.note.contains(&format!("Transiting {}", first.planet1)));
+        assert!(note.note.contains(&format!("natal {}", first.planet2)));
+        assert!(note.note.contains(&format!("{:.2}°", first.orb)));
+        let options = TransitOptions::default();
+        let plain = transits_to_chart_with_options(&explained.chart, jd, &options);
+        assert!(plain.explanations.is_empty());
+    }
+
+    #[test]
+    fn fortune_and_spirit_mirror_around_ascendant() {
+        let chart = calculate_natal_chart(&birth());
+        let fortune = lot_of_fortune(&chart).total_degrees;
+        let spirit = lot_of_spirit(&chart).total_degrees;
+        let asc = chart.ascendant.total_degrees;
+        assert!((norm_deg(fortune + spirit) - norm_deg(2.0 * asc)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn releasing_periods_loose_the_bond_after_twelve_signs() {
+        // Aquarius L1 (30 years) is longer than the 211 months of one full
+        // L2 cycle, so its sub-periods must jump to Leo.
+        let subs = release_periods(2, 10, 0.0, 30.0 * RELEASING_YEAR_DAYS, 30.0);
+        assert_eq!(subs[0].sign, "aquarius");
+        assert_eq!(subs[12].sign, "leo");
+        assert!(subs[12].loosing_of_the_bond);
+        assert_eq!(subs.iter().filter(|p| p.loosing_of_the_bond).count(), 1);
+        assert_eq!(subs.last().unwrap().end_jd, 30.0 * RELEASING_YEAR_DAYS);
+    }
+
+    #[test]
+    fn zodiacal_releasing_timeline_is_contiguous() {
+        let birth = birth();
+        let zr = zodiacal_releasing(&birth, Lot::Spirit, 40);
+        assert_eq!(zr.level1[0].sign, zr.lot_position.sign);
+        assert!(zr.level1.last().unwrap().end_jd >= birth_julian_day(&birth) + 40.0 * 360.0);
+        for pair in zr.level2.windows(2) {
+            assert!((pair[0].end_jd - pair[1].start_jd).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn compass_points_wrap() {
+        assert_eq!(compass_point(0.0), "north");
+        assert_eq!(compass_point(44.0), "northeast");
+        assert_eq!(compass_point(350.0), "north");
+        assert_eq!(compass_point(-90.0), "west");
+    }
+
+    #[test]
+    fn local_space_sun_is_south_at_local_noon() {
+        // Solar noon in New York on 1990-06-15 is around 12:57 EDT.
+        let birth = BirthData {
+            hour: Some(13),
+            minute: Some(0),
+            ..birth()
+        };
+        let positions = local_space_chart(&birth);
+        assert_eq!(positions.len(), 10);
+
+        let sun = &positions[0];
+        assert_eq!(sun.planet, "sun");
+        assert!((sun.azimuth - 180.0).abs() < 15.0, "Sun azimuth {}", sun.azimuth);
+        assert!(sun.altitude > 65.0 && sun.altitude < 75.0, "Sun altitude {}", sun.altitude);
+        assert_eq!(sun.direction, "south");
+    }
+
+    #[test]
+    fn daily_horoscope_uses_solar_houses() {
+        let h = daily_horoscope("leo", 2024, 8, 10).unwrap();
+        // The Sun is in Leo on 2024-08-10, so it sits in the 1st solar house.
+        assert_eq!(h.transits[0].sign, "leo");
+        assert_eq!(h.transits[0].house, 1);
+        assert!(h.aspects.iter().any(|a| a.planet1 == "sun" && a.aspect_name == "Conjunction"));
+        for theme in [&h.love, &h.career, &h.health] {
+            assert!(theme.intensity <= 10);
+        }
+        assert!(daily_horoscope("ophiuchus", 2024, 8, 10).is_err());
+    }
+
+    #[test]
+    fn ordinals() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(12), "12th");
+    }
+
+    #[test]
+    fn afternoon_chart_is_diurnal() {
+        let chart = calculate_natal_chart(&birth());
+        let analysis = sect_analysis(&chart);
+        assert_eq!(analysis.sect, Sect::Day);
+        assert_eq!(analysis.benefic_of_sect, "jupiter");
+        assert_eq!(analysis.malefic_contrary_to_sect, "mars");
+    }
+
+    #[test]
+    fn midnight_chart_is_nocturnal() {
+        let chart = calculate_natal_chart(&BirthData {
+            hour: Some(0),
+            ..birth()
+        });
+        let analysis = sect_analysis(&chart);
+        assert_eq!(analysis.sect, Sect::Night);
+        assert_eq!(analysis.luminary_of_sect, "moon");
+        assert_eq!(analysis.malefic_of_sect, "mars");
+    }
+
     #[test]
     fn engine_api() {
         let engine = AstrologyEngine::new();
@@ -0,0 +1,197 @@
+use crate::types::SynodicEvent;
+
+use super::{
+    degrees_to_sign, geocentric_longitude, moon_longitude, sun_longitude, JUPITER, MARS, MERCURY,
+    NEPTUNE, PLUTO, SATURN, URANUS, VENUS,
+};
+
+// ---------------------------------------------------------------------------
+// Body lookup
+// ---------------------------------------------------------------------------
+
+/// Geocentric ecliptic longitude of a body by name (`"sun"`, `"moon"`,
+/// `"mercury"` … `"pluto"`).
+pub fn body_longitude(body: &str, jd: f64) -> Option<f64> {
+    let idx = match body {
+        "sun" => return Some(sun_longitude(jd)),
+        "moon" => return Some(moon_longitude(jd)),
+        "mercury" => MERCURY,
+        "venus" => VENUS,
+        "mars" => MARS,
+        "jupiter" => JUPITER,
+        "saturn" => SATURN,
+        "uranus" => URANUS,
+        "neptune" => NEPTUNE,
+        "pluto" => PLUTO,
+        _ => return None,
+    };
+    Some(geocentric_longitude(idx, jd))
+}
+
+/// Scan step in days that cannot skip over a perfection involving `body`.
+fn scan_step(body: &str) -> f64 {
+    match body {
+        "moon" => 0.25,
+        "sun" | "mercury" | "venus" | "mars" => 1.0,
+        _ => 5.0,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Aspect perfection solver
+// ---------------------------------------------------------------------------
+
+/// Signed distance (−180, 180] of the separation `lon1 − lon2` from `angle`.
+fn perfection_offset(body1: &str, body2: &str, angle: f64, jd: f64) -> f64 {
+    let lon1 = body_longitude(body1, jd).unwrap_or(0.0);
+    let lon2 = body_longitude(body2, jd).unwrap_or(0.0);
+    let mut d = (lon1 - lon2 - angle) % 360.0;
+    if d > 180.0 { d -= 360.0; }
+    if d <= -180.0 { d += 360.0; }
+    d
+}
+
+/// Find every moment in `[start_jd, end_jd]` when `lon(body1) − lon(body2)`
+/// equals `angle` exactly (e.g. 0° for a conjunction, 180° for an
+/// opposition). Retrograde stations can produce several perfections in a
+/// row; each one is reported.
+///
+/// # Errors
+/// Returns an error string if either body is unknown or the range is empty.
+pub fn find_aspect_perfections(
+    body1: &str,
+    body2: &str,
+    angle: f64,
+    start_jd: f64,
+    end_jd: f64,
+) -> Result<Vec<f64>, String> {
+    for body in [body1, body2] {
+        if body_longitude(body, start_jd).is_none() {
+            return Err(format!("Unknown body: {}", body));
+        }
+    }
+    if end_jd <= start_jd {
+        return Err("end_jd must be after start_jd".to_string());
+    }
+
+    let step = scan_step(body1).min(scan_step(body2));
+    let f = |jd: f64| perfection_offset(body1, body2, angle, jd);
+
+    let mut roots = Vec::new();
+    let mut a = start_jd;
+    let mut fa = f(a);
+    while a < end_jd {
+        let b = (a + step).min(end_jd);
+        let fb = f(b);
+        // A sign change near zero is a perfection; one near ±180 is the
+        // wrap-around of the opposite point and is ignored.
+        if fa.signum() != fb.signum() && (fa - fb).abs() < 180.0 {
+            let (mut lo, mut hi, mut flo) = (a, b, fa);
+            for _ in 0..50 {
+                let mid = (lo + hi) / 2.0;
+                let fm = f(mid);
+                if fm.signum() == flo.signum() {
+                    lo = mid;
+                    flo = fm;
+                } else {
+                    hi = mid;
+                }
+                if hi - lo < 1e-5 {
+                    break;
+                }
+            }
+            roots.push((lo + hi) / 2.0);
+        }
+        a = b;
+        fa = fb;
+    }
+    Ok(roots)
+}
+
+// ---------------------------------------------------------------------------
+// Synodic cycles
+// ---------------------------------------------------------------------------
+
+/// Exact conjunctions and oppositions between two bodies over a date range,
+/// in chronological order.
+///
+/// # Errors
+/// Returns an error string if either body is unknown or the range is empty.
+pub fn find_synodic_events(
+    body1: &str,
+    body2: &str,
+    start_jd: f64,
+    end_jd: f64,
+) -> Result<Vec<SynodicEvent>, String> {
+    let mut events = Vec::new();
+    for (aspect, angle) in [("conjunction", 0.0), ("opposition", 180.0)] {
+        for jd in find_aspect_perfections(body1, body2, angle, start_jd, end_jd)? {
+            events.push(SynodicEvent {
+                body1: body1.to_string(),
+                body2: body2.to_string(),
+                aspect: aspect.to_string(),
+                jd,
+                position: degrees_to_sign(body_longitude(body1, jd).unwrap_or(0.0)),
+            });
+        }
+    }
+    events.sort_by(|a, b| a.jd.partial_cmp(&b.jd).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(events)
+}
+
+/// The first conjunction of two bodies after `from_jd`, searching up to
+/// `max_years` ahead.
+///
+/// # Errors
+/// Returns an error string if either body is unknown.
+pub fn next_conjunction(
+    body1: &str,
+    body2: &str,
+    from_jd: f64,
+    max_years: f64,
+) -> Result<Option<SynodicEvent>, String> {
+    let end_jd = from_jd + max_years * 365.25;
+    let first = find_aspect_perfections(body1, body2, 0.0, from_jd, end_jd)?
+        .into_iter()
+        .next();
+    Ok(first.map(|jd| SynodicEvent {
+        body1: body1.to_string(),
+        body2: body2.to_string(),
+        aspect: "conjunction".to_string(),
+        jd,
+        position: degrees_to_sign(body_longitude(body1, jd).unwrap_or(0.0)),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::to_julian_day;
+
+    #[test]
+    fn finds_2020_great_conjunction() {
+        // Jupiter and Saturn were exactly conjunct on 2020-12-21 in early Aquarius.
+        let from = to_julian_day(2019, 1, 1, 0, 0);
+        let event = next_conjunction("jupiter", "saturn", from, 30.0).unwrap().unwrap();
+        let expected = to_julian_day(2020, 12, 21, 18, 0);
+        assert!((event.jd - expected).abs() < 3.0, "off by {} days", event.jd - expected);
+        assert_eq!(event.position.sign, "aquarius");
+    }
+
+    #[test]
+    fn lunations_alternate_between_new_and_full() {
+        let start = to_julian_day(2024, 1, 1, 0, 0);
+        let events = find_synodic_events("moon", "sun", start, start + 90.0).unwrap();
+        assert!(events.len() >= 6);
+        for pair in events.windows(2) {
+            assert_ne!(pair[0].aspect, pair[1].aspect);
+            let gap = pair[1].jd - pair[0].jd;
+            assert!(gap > 13.0 && gap < 16.5, "gap {}", gap);
+        }
+    }
+
+    #[test]
+    fn unknown_body_is_rejected() {
+        assert!(find_synodic_events("vulcan", "sun", 0.0, 10.0).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+use crate::text::capitalize;
+use crate::types::{LunarCalendar, LunarEvent, LunarEventKind};
+
+use super::cycles::find_aspect_perfections;
+use super::{degrees_to_sign, moon_latitude, moon_longitude, to_julian_day};
+
+// ---------------------------------------------------------------------------
+// Lunar ritual calendar
+// ---------------------------------------------------------------------------
+//
+// Phases are the exact Sun–Moon angles found by the aspect solver. A new or
+// full moon is an eclipse when the Moon lies close enough to the ecliptic;
+// the true limit moves between about 1.4° and 1.6° of latitude with the
+// distances of the Sun and Moon, so a fixed limit can misjudge the rare
+// grazing eclipse, penumbral ones included. The Moon is void of course
+// from its last major aspect to the Sun or a planet in a sign until it
+// enters the next sign, the modern convention that counts aspects to the
+// outer planets.
+
+/// Largest lunar latitude in degrees at which a new or full moon is taken
+/// as an eclipse.
+const ECLIPSE_LATITUDE_LIMIT: f64 = 1.5;
+
+/// Days either side of a range searched for the ingresses and aspects that
+/// bound a void-of-course window; the Moon never spends three days in a
+/// sign.
+const VOID_MARGIN_DAYS: f64 = 3.0;
+
+/// Scan step for sign ingresses; the Moon moves under 4° in this time.
+const INGRESS_STEP_DAYS: f64 = 0.25;
+
+/// Bodies whose aspects to the Moon end a void-of-course window.
+const ASPECT_BODIES: [&str; 9] = [
+    "sun", "mercury", "venus", "mars", "jupiter", "saturn", "uranus", "neptune", "pluto",
+];
+
+/// Moon-minus-body angles of the major aspects, waxing and waning.
+const ASPECT_ANGLES: [f64; 8] = [0.0, 60.0, 90.0, 120.0, 180.0, 240.0, 270.0, 300.0];
+
+/// Moon-minus-Sun angle, name and ritual theme of each principal phase.
+const PHASES: [(LunarEventKind, f64, &str, &str); 4] = [
+    (LunarEventKind::NewMoon, 0.0, "New moon", "set intentions and begin new work"),
+    (
+        LunarEventKind::FirstQuarter,
+        90.0,
+        "First quarter moon",
+        "act on intentions and push through resistance",
+    ),
+    (LunarEventKind::FullMoon, 180.0, "Full moon", "celebrate, give thanks and release"),
+    (
+        LunarEventKind::LastQuarter,
+        270.0,
+        "Last quarter moon",
+        "review, let go and clear space for the next cycle",
+    ),
+];
+
+fn sign_index(jd: f64) -> i64 {
+    (moon_longitude(jd) / 30.0).floor() as i64
+}
+
+/// Moments in `[start_jd, end_jd]` when the Moon enters a new sign.
+pub fn moon_ingresses(start_jd: f64, end_jd: f64) -> Vec<f64> {
+    let mut ingresses = Vec::new();
+    let mut a = start_jd;
+    while a < end_jd {
+        let b = (a + INGRESS_STEP_DAYS).min(end_jd);
+        if sign_index(a) != sign_index(b) {
+            let (mut lo, mut hi) = (a, b);
+            let before = sign_index(a);
+            while hi - lo > 1e-5 {
+                let mid = (lo + hi) / 2.0;
+                if sign_index(mid) == before {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            ingresses.push(hi);
+        }
+        a = b;
+    }
+    ingresses
+}
+
+/// Void-of-course windows overlapping `[start_jd, end_jd]`, in order.
+pub fn void_of_course(start_jd: f64, end_jd: f64) -> Vec<LunarEvent> {
+    let (from, to) = (start_jd - VOID_MARGIN_DAYS, end_jd + VOID_MARGIN_DAYS);
+    let mut aspects: Vec<f64> = ASPECT_BODIES
+        .iter()
+        .flat_map(|body| {
+            ASPECT_ANGLES.iter().flat_map(move |&angle| {
+                find_aspect_perfections("moon", body, angle, from, to).unwrap_or_default()
+            })
+        })
+        .collect();
+    aspects.sort_by(f64::total_cmp);
+
+    moon_ingresses(from, to)
+        .windows(2)
+        .filter_map(|pair| {
+            let (enter, leave) = (pair[0], pair[1]);
+            let begin = aspects
+                .iter()
+                .rev()
+                .find(|&&jd| jd > enter && jd < leave)
+                .copied()
+                .unwrap_or(enter);
+            if begin >= end_jd || leave <= start_jd {
+                return None;
+            }
+            let moon = degrees_to_sign(moon_longitude(begin));
+            let next = degrees_to_sign(moon_longitude(leave + 1e-4)).sign;
+            Some(LunarEvent {
+                kind: LunarEventKind::VoidOfCourse,
+                jd: begin,
+                end_jd: Some(leave),
+                description: format!(
+                    "Moon void of course in {} until it enters {}: rest and reflect rather \
+                     than begin new work",
+                    capitalize(&moon.sign),
+                    capitalize(&next)
+                ),
+                moon,
+                next_sign: Some(next),
+            })
+        })
+        .collect()
+}
+
+fn moment(kind: LunarEventKind, jd: f64, description: impl Fn(&str) -> String) -> LunarEvent {
+    let moon = degrees_to_sign(moon_longitude(jd));
+    LunarEvent {
+        kind,
+        jd,
+        end_jd: None,
+        description: description(&capitalize(&moon.sign)),
+        moon,
+        next_sign: None,
+    }
+}
+
+/// Principal phases, eclipses and void-of-course windows in
+/// `[start_jd, end_jd]`, in time order. An eclipse follows the new or full
+/// moon it falls on.
+pub fn lunar_events(start_jd: f64, end_jd: f64) -> Vec<LunarEvent> {
+    let mut events = Vec::new();
+    for (kind, angle, name, theme) in PHASES {
+        let perfections = find_aspect_perfections("moon", "sun", angle, start_jd, end_jd);
+        for jd in perfections.unwrap_or_default() {
+            events.push(moment(kind, jd, |sign| format!("{} in {}: {}", name, sign, theme)));
+            let near_node = moon_latitude(jd).abs() <= ECLIPSE_LATITUDE_LIMIT;
+            let eclipse = match kind {
+                LunarEventKind::NewMoon if near_node => {
+                    Some((LunarEventKind::SolarEclipse, "Solar eclipse", "sudden beginnings"))
+                }
+                LunarEventKind::FullMoon if near_node => {
+                    Some((LunarEventKind::LunarEclipse, "Lunar eclipse", "revelations and endings"))
+                }
+                _ => None,
+            };
+            if let Some((kind, name, theme)) = eclipse {
+                events.push(moment(kind, jd, |sign| {
+                    format!("{} in {}: {}; observe before acting", name, sign, theme)
+                }));
+            }
+        }
+    }
+    events.extend(void_of_course(start_jd, end_jd));
+    // Stable, so an eclipse stays after its lunation
+    events.sort_by(|a, b| a.jd.total_cmp(&b.jd));
+    events
+}
+
+/// The lunar calendar for a month, from 00:00 UT on the first to 00:00 UT
+/// on the first of the next month.
+///
+/// # Errors
+/// Returns an error string if `month` is not 1–12.
+pub fn lunar_calendar(year: i32, month: u32) -> Result<LunarCalendar, String> {
+    if !(1..=12).contains(&month) {
+        return Err(format!("Invalid month: {}", month));
+    }
+    let start = to_julian_day(year, month, 1, 0, 0);
+    let end = if month == 12 {
+        to_julian_day(year + 1, 1, 1, 0, 0)
+    } else {
+        to_julian_day(year, month + 1, 1, 0, 0)
+    };
+    Ok(LunarCalendar {
+        year,
+        month,
+        events: lunar_events(start, end),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(calendar: &LunarCalendar, kind: LunarEventKind) -> Vec<&LunarEvent> {
+        calendar.events.iter().filter(|e| e.kind == kind).collect()
+    }
+
+    #[test]
+    fn april_2024_phases_and_eclipse() {
+        let calendar = lunar_calendar(2024, 4).unwrap();
+        let new = kinds(&calendar, LunarEventKind::NewMoon);
+        assert_eq!(new.len(), 1);
+        // 2024-04-08 18:21 UT in Aries
+        let expected = to_julian_day(2024, 4, 8, 18, 21);
+        assert!((new[0].jd - expected).abs() < 0.1, "off by {} days", new[0].jd - expected);
+        assert_eq!(new[0].moon.sign, "aries");
+        assert!(new[0].description.starts_with("New moon in Aries: "));
+
+        let eclipses = kinds(&calendar, LunarEventKind::SolarEclipse);
+        assert_eq!(eclipses.len(), 1);
+        assert_eq!(eclipses[0].jd, new[0].jd);
+        assert_eq!(kinds(&calendar, LunarEventKind::FullMoon).len(), 1);
+        assert!(kinds(&calendar, LunarEventKind::LunarEclipse).is_empty());
+        assert!(calendar.events.windows(2).all(|w| w[0].jd <= w[1].jd));
+        assert!(lunar_calendar(2024, 13).is_err());
+    }
+
+    #[test]
+    fn eclipse_seasons() {
+        let count = |year, month, kind| kinds(&lunar_calendar(year, month).unwrap(), kind).len();
+        // Total lunar eclipse 2025-03-14, partial solar 2025-03-29
+        assert_eq!(count(2025, 3, LunarEventKind::LunarEclipse), 1);
+        assert_eq!(count(2025, 3, LunarEventKind::SolarEclipse), 1);
+        // Penumbral lunar eclipse 2024-03-25
+        assert_eq!(count(2024, 3, LunarEventKind::LunarEclipse), 1);
+        // No eclipses mid-year in 2024
+        assert_eq!(count(2024, 6, LunarEventKind::SolarEclipse), 0);
+        assert_eq!(count(2024, 6, LunarEventKind::LunarEclipse), 0);
+    }
+
+    #[test]
+    fn void_of_course_windows_end_at_ingress() {
+        let calendar = lunar_calendar(2024, 4).unwrap();
+        let voids = kinds(&calendar, LunarEventKind::VoidOfCourse);
+        // One per sign the Moon passes through, about thirteen a month
+        assert!((11..=15).contains(&voids.len()), "{} windows", voids.len());
+        for void in voids {
+            let end = void.end_jd.unwrap();
+            assert!(end > void.jd && end - void.jd < 2.6);
+            let next = void.next_sign.as_deref().unwrap();
+            assert_eq!(degrees_to_sign(moon_longitude(end + 0.01)).sign, next);
+            assert_ne!(void.moon.sign, next);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::types::{Karana, Nakshatra, Paksha, Panchanga, Tithi, Vara, Yoga};
+
+use super::precession::lahiri_ayanamsa;
+use super::{julian_centuries, moon_longitude, norm_deg, obliquity, sun_longitude, DEG2RAD, RAD2DEG};
+
+// ---------------------------------------------------------------------------
+// Panchanga
+// ---------------------------------------------------------------------------
+//
+// The five limbs of the Hindu almanac, from the Sun's and Moon's longitudes
+// in the sidereal (Lahiri) zodiac. Tithi and karana depend only on the
+// Moon's elongation from the Sun, so the ayanamsa cancels out of them;
+// nakshatra and yoga need the sidereal positions. The vara runs from
+// sunrise to sunrise, so a moment before local sunrise belongs to the
+// previous weekday.
+
+const TITHI_NAMES: [&str; 15] = [
+    "Pratipada", "Dwitiya", "Tritiya", "Chaturthi", "Panchami",
+    "Shashthi", "Saptami", "Ashtami", "Navami", "Dashami",
+    "Ekadashi", "Dwadashi", "Trayodashi", "Chaturdashi", "Purnima",
+];
+
+const VARA_NAMES: [(&str, &str); 7] = [
+    ("Ravivara", "Sun"),
+    ("Somavara", "Moon"),
+    ("Mangalavara", "Mars"),
+    ("Budhavara", "Mercury"),
+    ("Guruvara", "Jupiter"),
+    ("Shukravara", "Venus"),
+    ("Shanivara", "Saturn"),
+];
+
+const NAKSHATRA_NAMES: [&str; 27] = [
+    "Ashwini", "Bharani", "Krittika", "Rohini", "Mrigashira", "Ardra",
+    "Punarvasu", "Pushya", "Ashlesha", "Magha", "Purva Phalguni",
+    "Uttara Phalguni", "Hasta", "Chitra", "Swati", "Vishakha", "Anuradha",
+    "Jyeshtha", "Mula", "Purva Ashadha", "Uttara Ashadha", "Shravana",
+    "Dhanishta", "Shatabhisha", "Purva Bhadrapada", "Uttara Bhadrapada", "Revati",
+];
+
+/// Vimshottari lords, repeating every nine nakshatras from Ashwini.
+const NAKSHATRA_LORDS: [&str; 9] = [
+    "Ketu", "Venus", "Sun", "Moon", "Mars", "Rahu", "Jupiter", "Saturn", "Mercury",
+];
+
+const YOGA_NAMES: [&str; 27] = [
+    "Vishkambha", "Priti", "Ayushman", "Saubhagya", "Shobhana", "Atiganda",
+    "Sukarma", "Dhriti", "Shula", "Ganda", "Vriddhi", "Dhruva", "Vyaghata",
+    "Harshana", "Vajra", "Siddhi", "Vyatipata", "Variyana", "Parigha", "Shiva",
+    "Siddha", "Sadhya", "Shubha", "Shukla", "Brahma", "Indra", "Vaidhriti",
+];
+
+/// The seven movable karanas, repeated eight times from the second half of
+/// the first tithi.
+const MOVABLE_KARANAS: [&str; 7] = [
+    "Bava", "Balava", "Kaulava", "Taitila", "Garaja", "Vanija", "Vishti",
+];
+
+/// Width of a nakshatra or yoga: 13°20'.
+const MANSION_DEG: f64 = 360.0 / 27.0;
+
+/// Altitude of the Sun's centre at sunrise, allowing for refraction and the
+/// solar semi-diameter.
+const SUNRISE_ALTITUDE: f64 = -0.833;
+
+/// Split an angle into a 0-based part number and how far through it lies.
+fn part(degrees: f64, width: f64) -> (u32, f64) {
+    let parts = norm_deg(degrees) / width;
+    (parts.floor() as u32, parts.fract())
+}
+
+/// The lunar day (numbered 1-30) for the Moon's elongation from the Sun.
+pub fn tithi(elongation: f64) -> Tithi {
+    let (index, elapsed) = part(elongation, 12.0);
+    let (name, paksha) = match index {
+        0..=14 => (TITHI_NAMES[index as usize], Paksha::Shukla),
+        29 => ("Amavasya", Paksha::Krishna),
+        _ => (TITHI_NAMES[index as usize - 15], Paksha::Krishna),
+    };
+    Tithi {
+        number: index + 1,
+        name: name.to_string(),
+        paksha,
+        elapsed,
+    }
+}
+
+/// The half tithi (numbered 1-60) for the Moon's elongation from the Sun.
+pub fn karana(elongation: f64) -> Karana {
+    let (index, elapsed) = part(elongation, 6.0);
+    let name = match index {
+        0 => "Kimstughna",
+        57 => "Shakuni",
+        58 => "Chatushpada",
+        59 => "Naga",
+        i => MOVABLE_KARANAS[(i as usize - 1) % 7],
+    };
+    Karana {
+        number: index + 1,
+        name: name.to_string(),
+        elapsed,
+    }
+}
+
+/// The lunar mansion (numbered 1-27) and its pada for the sidereal Moon.
+pub fn nakshatra(sidereal_moon: f64) -> Nakshatra {
+    let (index, elapsed) = part(sidereal_moon, MANSION_DEG);
+    Nakshatra {
+        number: index + 1,
+        name: NAKSHATRA_NAMES[index as usize].to_string(),
+        lord: NAKSHATRA_LORDS[index as usize % 9].to_string(),
+        pada: (elapsed * 4.0).floor() as u32 + 1,
+        elapsed,
+    }
+}
+
+/// The yoga (numbered 1-27) for the sum of the sidereal Sun and Moon.
+pub fn yoga(sidereal_sun: f64, sidereal_moon: f64) -> Yoga {
+    let (index, elapsed) = part(sidereal_sun + sidereal_moon, MANSION_DEG);
+    Yoga {
+        number: index + 1,
+        name: YOGA_NAMES[index as usize].to_string(),
+        elapsed,
+    }
+}
+
+fn vara(day_number: i64) -> Vara {
+    // Julian Day Number 0 fell on a Monday
+    let number = (day_number + 1).rem_euclid(7) as u32;
+    let (name, lord) = VARA_NAMES[number as usize];
+    Vara {
+        number,
+        name: name.to_string(),
+        lord: lord.to_string(),
+    }
+}
+
+/// Julian Day (UT) of sunrise on the local calendar day whose noon is
+/// Julian Day Number `day_number`, at `latitude` and `longitude` (east
+/// positive). Returns `None` during polar day or night.
+pub fn sunrise(day_number: i64, latitude: f64, longitude: f64) -> Option<f64> {
+    let mut rise = day_number as f64 - 0.25 - longitude / 360.0;
+    // Two passes: the Sun's position at the first estimate refines it
+    for _ in 0..2 {
+        let eps = obliquity(rise) * DEG2RAD;
+        let lambda = sun_longitude(rise) * DEG2RAD;
+        let alpha = norm_deg((eps.cos() * lambda.sin()).atan2(lambda.cos()) * RAD2DEG);
+        let delta = (eps.sin() * lambda.sin()).asin();
+
+        // Equation of time from the mean Sun and the true right ascension
+        let mean_sun = norm_deg(280.46646 + 36000.76983 * julian_centuries(rise));
+        let equation_of_time = (mean_sun - 0.0057183 - alpha + 540.0).rem_euclid(360.0) - 180.0;
+        let transit = day_number as f64 - longitude / 360.0 - equation_of_time / 360.0;
+
+        let phi = latitude * DEG2RAD;
+        let cos_h = ((SUNRISE_ALTITUDE * DEG2RAD).sin() - phi.sin() * delta.sin())
+            / (phi.cos() * delta.cos());
+        if !(-1.0..=1.0).contains(&cos_h) {
+            return None;
+        }
+        rise = transit - cos_h.acos() * RAD2DEG / 360.0;
+    }
+    Some(rise)
+}
+
+/// The panchanga for Julian Day `jd` (UT) at a place.
+pub fn calculate_panchanga(jd: f64, latitude: f64, longitude: f64) -> Panchanga {
+    let ayanamsa = lahiri_ayanamsa(jd);
+    let sun = norm_deg(sun_longitude(jd) - ayanamsa);
+    let moon = norm_deg(moon_longitude(jd) - ayanamsa);
+    let elongation = norm_deg(moon - sun);
+
+    // Local calendar day by mean solar time, stepped back if the Sun has
+    // not yet risen
+    let mut day_number = (jd + longitude / 360.0).round() as i64;
+    let mut sunrise_jd = sunrise(day_number, latitude, longitude);
+    if sunrise_jd.is_some_and(|rise| jd < rise) {
+        day_number -= 1;
+        sunrise_jd = sunrise(day_number, latitude, longitude);
+    }
+
+    Panchanga {
+        jd,
+        sunrise_jd,
+        ayanamsa,
+        sun_longitude: sun,
+        moon_longitude: moon,
+        tithi: tithi(elongation),
+        vara: vara(day_number),
+        nakshatra: nakshatra(moon),
+        yoga: yoga(sun, moon),
+        karana: karana(elongation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::to_julian_day;
+
+    const DELHI: (f64, f64) = (28.61, 77.21);
+
+    #[test]
+    fn full_moon_of_january_2024() {
+        // Paush Purnima, a Thursday, with the Moon in Pushya
+        let jd = to_julian_day(2024, 1, 25, 12, 0);
+        let p = calculate_panchanga(jd, DELHI.0, DELHI.1);
+        assert_eq!((p.tithi.number, p.tithi.name.as_str()), (15, "Purnima"));
+        assert_eq!(p.tithi.paksha, Paksha::Shukla);
+        assert_eq!(p.vara.name, "Guruvara");
+        assert_eq!(p.nakshatra.name, "Pushya");
+        assert_eq!(p.nakshatra.lord, "Saturn");
+        assert_eq!(p.karana.number, p.tithi.number * 2 - u32::from(p.tithi.elapsed < 0.5));
+        assert!((1..=27).contains(&p.yoga.number));
+        assert!((p.ayanamsa - 24.19).abs() < 0.05, "{}", p.ayanamsa);
+    }
+
+    #[test]
+    fn vara_turns_at_sunrise() {
+        // Sunrise in Delhi on 25 January 2024 was at about 07:14 IST
+        let jd = to_julian_day(2024, 1, 25, 12, 0);
+        let rise = calculate_panchanga(jd, DELHI.0, DELHI.1).sunrise_jd.unwrap();
+        let expected = to_julian_day(2024, 1, 25, 1, 44);
+        assert!((rise - expected).abs() * 1440.0 < 5.0, "{} min", (rise - expected) * 1440.0);
+
+        let before = calculate_panchanga(to_julian_day(2024, 1, 25, 0, 30), DELHI.0, DELHI.1);
+        assert_eq!(before.vara.name, "Budhavara");
+        let after = calculate_panchanga(to_julian_day(2024, 1, 25, 3, 0), DELHI.0, DELHI.1);
+        assert_eq!(after.vara.name, "Guruvara");
+
+        // No sunrise in the Arctic winter: the civil day is used
+        let polar = calculate_panchanga(to_julian_day(2024, 12, 21, 12, 0), 80.0, 15.0);
+        assert!(polar.sunrise_jd.is_none());
+        assert_eq!(polar.vara.name, "Shanivara");
+    }
+
+    #[test]
+    fn limb_boundaries() {
+        assert_eq!(tithi(0.0).name, "Pratipada");
+        assert_eq!(tithi(359.9).name, "Amavasya");
+        assert_eq!(tithi(180.0).paksha, Paksha::Krishna);
+        assert_eq!(karana(0.0).name, "Kimstughna");
+        assert_eq!(karana(6.0).name, "Bava");
+        assert_eq!(karana(56.0 * 6.0).name, "Vishti");
+        assert_eq!(karana(359.0).name, "Naga");
+        assert_eq!(nakshatra(0.0).name, "Ashwini");
+        assert_eq!(nakshatra(359.9).pada, 4);
+        assert_eq!(yoga(200.0, 200.0).number, 4);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::types::AstrologicalAge;
+
+use super::{julian_centuries, norm_deg, J2000, SIGN_ORDER};
+
+// ---------------------------------------------------------------------------
+// Precession
+// ---------------------------------------------------------------------------
+
+/// Lahiri ayanamsa at J2000.0 (23°51'11").
+pub const LAHIRI_AYANAMSA_J2000: f64 = 23.853;
+
+/// Tropical longitude of the Galactic Center at J2000.0 (≈ 26°51' Sagittarius).
+pub const GALACTIC_CENTER_J2000: f64 = 266.85;
+
+/// Accumulated general precession in longitude since J2000.0, in degrees
+/// (IAU 2006, p_A = 5028.796195″ T + 1.1054348″ T²).
+pub fn general_precession(jd: f64) -> f64 {
+    let t = julian_centuries(jd);
+    (5028.796195 * t + 1.1054348 * t * t) / 3600.0
+}
+
+/// Move a tropical ecliptic longitude from the equinox of `from_jd` to the
+/// equinox of `to_jd`.
+pub fn precess_longitude(longitude: f64, from_jd: f64, to_jd: f64) -> f64 {
+    norm_deg(longitude + general_precession(to_jd) - general_precession(from_jd))
+}
+
+/// Lahiri (Chitrapaksha) ayanamsa at a Julian Day, in degrees.
+pub fn lahiri_ayanamsa(jd: f64) -> f64 {
+    LAHIRI_AYANAMSA_J2000 + general_precession(jd)
+}
+
+/// Convert a tropical longitude to the sidereal (Lahiri) zodiac.
+pub fn tropical_to_sidereal(longitude: f64, jd: f64) -> f64 {
+    norm_deg(longitude - lahiri_ayanamsa(jd))
+}
+
+/// Tropical longitude of the Galactic Center at a Julian Day.
+pub fn galactic_center_longitude(jd: f64) -> f64 {
+    precess_longitude(GALACTIC_CENTER_J2000, J2000, jd)
+}
+
+/// The astrological age at a Julian Day, using the Lahiri ayanamsa to place
+/// the vernal point in the sidereal zodiac. Ages run backwards through the
+/// signs (Pisces → Aquarius).
+pub fn astrological_age(jd: f64) -> AstrologicalAge {
+    let vernal_point = tropical_to_sidereal(0.0, jd);
+    let sign_index = (vernal_point / 30.0).floor() as usize % 12;
+    let degrees_remaining = vernal_point - sign_index as f64 * 30.0;
+
+    // Solve general_precession(jd) = target with Newton's method; the
+    // quadratic term is tiny so this converges in a few steps.
+    let target = general_precession(jd) + degrees_remaining;
+    let mut next_jd = jd + degrees_remaining / (5028.796195 / 3600.0) * 36525.0;
+    for _ in 0..5 {
+        let t = julian_centuries(next_jd);
+        let rate = (5028.796195 + 2.0 * 1.1054348 * t) / 3600.0 / 36525.0;
+        next_jd -= (general_precession(next_jd) - target) / rate;
+    }
+
+    AstrologicalAge {
+        sign: SIGN_ORDER[sign_index].to_string(),
+        vernal_point,
+        degrees_remaining,
+        next_sign: SIGN_ORDER[(sign_index + 11) % 12].to_string(),
+        next_age_jd: next_jd,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::to_julian_day;
+
+    #[test]
+    fn precession_is_about_fifty_arcseconds_per_year() {
+        let one_year = J2000 + 365.25;
+        let delta = precess_longitude(100.0, J2000, one_year) - 100.0;
+        assert!((delta * 3600.0 - 50.29).abs() < 0.01, "{}″", delta * 3600.0);
+        // Round trip back to the original epoch.
+        let back = precess_longitude(100.0 + delta, one_year, J2000);
+        assert!((back - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn current_age_is_pisces() {
+        let age = astrological_age(to_julian_day(2025, 1, 1, 0, 0));
+        assert_eq!(age.sign, "pisces");
+        assert_eq!(age.next_sign, "aquarius");
+        // With Lahiri the Age of Aquarius starts in the 25th century.
+        let years = (age.next_age_jd - J2000) / 365.25;
+        assert!(years > 400.0 && years < 500.0, "{} years", years);
+        assert_eq!(astrological_age(age.next_age_jd + 1.0).sign, "aquarius");
+    }
+
+    #[test]
+    fn galactic_center_is_late_sagittarius() {
+        let gc = galactic_center_longitude(to_julian_day(2025, 1, 1, 0, 0));
+        assert!(gc > 267.0 && gc < 267.3, "{}", gc);
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use crate::types::{BirthData, LifeEvent, NatalChart, RectificationCandidate, RectificationOptions};
+
+use super::{birth_julian_day, chart_at, to_julian_day, ASPECT_DEFS};
+
+// ---------------------------------------------------------------------------
+// Birth time rectification scanner
+// ---------------------------------------------------------------------------
+
+fn house_map(chart: &NatalChart) -> BTreeMap<String, usize> {
+    [
+        &chart.sun, &chart.moon, &chart.mercury, &chart.venus, &chart.mars,
+        &chart.jupiter, &chart.saturn, &chart.uranus, &chart.neptune, &chart.pluto,
+    ]
+    .iter()
+    .map(|p| (p.planet.clone(), p.house))
+    .collect()
+}
+
+fn describe_changes(previous: &RectificationCandidate, chart: &NatalChart) -> Vec<String> {
+    let mut changes = Vec::new();
+    if previous.ascendant.sign != chart.ascendant.sign {
+        changes.push(format!("ascendant enters {}", chart.ascendant.sign));
+    }
+    if previous.midheaven.sign != chart.midheaven.sign {
+        changes.push(format!("midheaven enters {}", chart.midheaven.sign));
+    }
+    for (planet, house) in house_map(chart) {
+        if previous.houses.get(&planet) != Some(&house) {
+            changes.push(format!("{} moves to house {}", planet, house));
+        }
+    }
+    changes
+}
+
+/// The transiting sky at noon UT on each event date.
+fn event_skies(events: &[LifeEvent]) -> Vec<(&LifeEvent, NatalChart)> {
+    events
+        .iter()
+        .map(|e| (e, chart_at(to_julian_day(e.year, e.month, e.day, 12, 0), 0.0, 0.0)))
+        .collect()
+}
+
+/// Score a candidate chart against the transits on each event date.
+fn score_events(
+    chart: &NatalChart,
+    skies: &[(&LifeEvent, NatalChart)],
+    orb: f64,
+) -> (f64, Vec<String>) {
+    let mut score = 0.0;
+    let mut hits = Vec::new();
+    let angles = [
+        ("ascendant", chart.ascendant.total_degrees),
+        ("midheaven", chart.midheaven.total_degrees),
+    ];
+
+    for (event, sky) in skies {
+        // The Moon and the fast inner planets aspect every angle too often to
+        // discriminate between candidate times.
+        let transits = [
+            &sky.mars, &sky.jupiter, &sky.saturn, &sky.uranus, &sky.neptune, &sky.pluto,
+        ];
+
+        for t in transits {
+            for (angle_name, angle) in angles {
+                let mut separation = (t.total_degrees - angle).abs();
+                if separation > 180.0 {
+                    separation = 360.0 - separation;
+                }
+                for def in ASPECT_DEFS.iter().filter(|d| d.nature != "harmonious") {
+                    let off = (separation - def.degrees).abs();
+                    if off <= orb {
+                        score += 1.0 - off / orb;
+                        hits.push(format!(
+                            "{}: {} {} {} ({:.2}°)",
+                            event.label,
+                            t.planet,
+                            def.name.to_lowercase(),
+                            angle_name,
+                            off
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    ((score * 1000.0).round() / 1000.0, hits)
+}
+
+/// Scan candidate birth times around the recorded one.
+///
+/// Each candidate reports its angles and house placements, what changed since
+/// the previous step, and (when `options.events` is non-empty) a score built
+/// from hard transits (conjunction, square, opposition) by Mars and the outer
+/// planets to the candidate Ascendant and Midheaven on each event date.
+///
+/// # Errors
+/// Returns an error string if `step_minutes` is zero, either window or
+/// step does not fit in an `i32` or `orb` is not a positive number of
+/// degrees.
+///
+/// # Panics
+/// Panics under the same conditions as
+/// [`calculate_natal_chart`](super::calculate_natal_chart).
+pub fn scan_birth_times(
+    birth_data: &BirthData,
+    options: &RectificationOptions,
+) -> Result<Vec<RectificationCandidate>, String> {
+    if options.step_minutes == 0 {
+        return Err("step_minutes must be greater than zero".to_string());
+    }
+    let window = i32::try_from(options.window_minutes)
+        .map_err(|_| format!("window_minutes is too large: {}", options.window_minutes))?;
+    let step = i32::try_from(options.step_minutes)
+        .map_err(|_| format!("step_minutes is too large: {}", options.step_minutes))?;
+    if !options.orb.is_finite() || options.orb <= 0.0 {
+        return Err(format!("orb must be a positive number of degrees, got {}", options.orb));
+    }
+    let latitude = birth_data.latitude.expect("latitude is required for rectification");
+    let longitude = birth_data.longitude.expect("longitude is required for rectification");
+    let base_jd = birth_julian_day(birth_data);
+    let skies = event_skies(&options.events);
+
+    let mut candidates: Vec<RectificationCandidate> = Vec::new();
+    let mut offset = -window;
+
+    while offset <= window {
+        let jd = base_jd + offset as f64 / 1440.0;
+        let chart = chart_at(jd, latitude, longitude);
+        let changes = candidates
+            .last()
+            .map(|prev| describe_changes(prev, &chart))
+            .unwrap_or_default();
+        let (score, hits) = score_events(&chart, &skies, options.orb);
+
+        candidates.push(RectificationCandidate {
+            offset_minutes: offset,
+            jd,
+            houses: house_map(&chart),
+            ascendant: chart.ascendant,
+            midheaven: chart.midheaven,
+            changes,
+            score,
+            hits,
+        });
+        match offset.checked_add(step) {
+            Some(next) => offset = next,
+            None => break,
+        }
+    }
+
+    Ok(candidates)
+}
+
+/// Candidates ordered from highest to lowest event score.
+pub fn rank_candidates(mut candidates: Vec<RectificationCandidate>) -> Vec<RectificationCandidate> {
+    candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::birth;
+
+    #[test]
+    fn scan_covers_window_and_reports_changes() {
+        let options = RectificationOptions {
+            window_minutes: 120,
+            step_minutes: 10,
+            ..Default::default()
+        };
+        let candidates = scan_birth_times(&birth(), &options).unwrap();
+        assert_eq!(candidates.len(), 25);
+        assert_eq!(candidates[0].offset_minutes, -120);
+        assert_eq!(candidates[12].offset_minutes, 0);
+        assert!(candidates[0].changes.is_empty());
+        // Over four hours the ascendant changes sign at least once.
+        assert!(candidates.iter().any(|c| c.changes.iter().any(|s| s.starts_with("ascendant"))));
+        assert!(candidates.iter().all(|c| c.score == 0.0));
+    }
+
+    #[test]
+    fn events_produce_scores() {
+        let options = RectificationOptions {
+            window_minutes: 180,
+            step_minutes: 15,
+            events: vec![
+                LifeEvent { label: "marriage".into(), year: 2015, month: 5, day: 2 },
+                LifeEvent { label: "relocation".into(), year: 2019, month: 9, day: 20 },
+            ],
+            orb: 3.0,
+        };
+        let ranked = rank_candidates(scan_birth_times(&birth(), &options).unwrap());
+        assert!(ranked[0].score > 0.0);
+        assert!(ranked[0].score >= ranked.last().unwrap().score);
+        assert!(!ranked[0].hits.is_empty());
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        let options = RectificationOptions { step_minutes: 0, ..Default::default() };
+        assert!(scan_birth_times(&birth(), &options).is_err());
+        let options = RectificationOptions { window_minutes: u32::MAX, ..Default::default() };
+        assert!(scan_birth_times(&birth(), &options).is_err());
+        let options = RectificationOptions { step_minutes: u32::MAX, ..Default::default() };
+        assert!(scan_birth_times(&birth(), &options).is_err());
+        for orb in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let options = RectificationOptions { orb, ..Default::default() };
+            assert!(scan_birth_times(&birth(), &options).is_err(), "orb {}", orb);
+        }
+    }
+}
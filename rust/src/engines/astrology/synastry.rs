@@ -0,0 +1,109 @@
+use crate::types::{ChartAspect, NatalChart, PlanetPosition, Synastry};
+
+use super::{calculate_aspects, ASPECT_DEFS};
+
+// ---------------------------------------------------------------------------
+// Synastry
+// ---------------------------------------------------------------------------
+//
+// Every planet of one chart is aspected to every planet of the other; the
+// angles are left out because they depend on an exact birth time. Each
+// contact weighs 0.5 plus up to 2 more as it nears exact, like the daily
+// horoscope, and double when both planets are personal (Sun, Moon,
+// Mercury, Venus or Mars). Conjunctions bind for better or worse and count
+// half their weight as harmonious.
+
+const PERSONAL_PLANETS: [&str; 5] = ["sun", "moon", "mercury", "venus", "mars"];
+
+fn planets(chart: &NatalChart) -> [&PlanetPosition; 10] {
+    [
+        &chart.sun, &chart.moon, &chart.mercury, &chart.venus, &chart.mars,
+        &chart.jupiter, &chart.saturn, &chart.uranus, &chart.neptune, &chart.pluto,
+    ]
+}
+
+fn contact_weight(aspect: &ChartAspect) -> f64 {
+    let max_orb = ASPECT_DEFS
+        .iter()
+        .find(|d| d.name == aspect.aspect_name)
+        .map_or(8.0, |d| d.orb);
+    let weight = 2.0 * (1.0 - aspect.orb / max_orb).max(0.0) + 0.5;
+    let personal = |p: &String| PERSONAL_PLANETS.contains(&p.as_str());
+    if personal(&aspect.planet1) && personal(&aspect.planet2) {
+        weight * 2.0
+    } else {
+        weight
+    }
+}
+
+/// Aspects between the planets of `first` and `second`, scored for how
+/// easily the two charts flow together.
+pub fn synastry(first: &NatalChart, second: &NatalChart) -> Synastry {
+    let mut aspects: Vec<ChartAspect> = planets(first)
+        .iter()
+        .flat_map(|a| {
+            planets(second)
+                .into_iter()
+                .flat_map(|b| calculate_aspects(&[(*a).clone(), b.clone()]))
+        })
+        .collect();
+    aspects.sort_by(|a, b| a.orb.total_cmp(&b.orb));
+
+    let (mut harmonious, mut challenging) = (0.0, 0.0);
+    for aspect in &aspects {
+        let weight = contact_weight(aspect);
+        match aspect.nature.as_str() {
+            "harmonious" => harmonious += weight,
+            "challenging" => challenging += weight,
+            _ => harmonious += weight / 2.0,
+        }
+    }
+    let total: f64 = harmonious + challenging;
+    let score = if total > 0.0 { (100.0 * harmonious / total).round() as u8 } else { 50 };
+
+    Synastry {
+        aspects,
+        harmonious: (harmonious * 100.0).round() / 100.0,
+        challenging: (challenging * 100.0).round() / 100.0,
+        score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::calculate_natal_chart;
+    use crate::types::BirthData;
+
+    fn chart(year: i32, month: u32, day: u32) -> NatalChart {
+        calculate_natal_chart(&BirthData {
+            year,
+            month,
+            day: Some(day),
+            hour: Some(12),
+            minute: Some(0),
+            latitude: Some(51.5),
+            longitude: Some(-0.12),
+            timezone: Some(0.0),
+        })
+    }
+
+    #[test]
+    fn contacts_run_between_the_charts() {
+        let (a, b) = (chart(1990, 6, 15), chart(1992, 11, 3));
+        let result = synastry(&a, &b);
+        assert!(!result.aspects.is_empty());
+        assert!(result.aspects.windows(2).all(|w| w[0].orb <= w[1].orb));
+        assert!(result.score <= 100);
+
+        // A chart meets itself in ten exact conjunctions
+        let own = synastry(&a, &a);
+        let conjunctions: Vec<&ChartAspect> = own
+            .aspects
+            .iter()
+            .filter(|x| x.planet1 == x.planet2)
+            .collect();
+        assert_eq!(conjunctions.len(), 10);
+        assert!(conjunctions.iter().all(|x| x.aspect_name == "Conjunction" && x.orb == 0.0));
+    }
+}
@@ -0,0 +1,356 @@
+mod error;
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::engines::astrology::to_julian_day;
+use crate::engines::calendar::{
+    cycle_parts, day_cycle, days_in_month, solar_month_branch, solar_year, year_cycle,
+};
+use crate::types::{
+    BaziChart, BirthData, BranchRelation, DayMasterStrength, EarthlyBranch, FivePhase, HeavenlyStem,
+    Pillar, ZodiacPairing,
+};
+
+pub use error::BaziError;
+
+// ---------------------------------------------------------------------------
+// Pillars
+// ---------------------------------------------------------------------------
+//
+// The year and month pillars follow the solar calendar: the year turns at
+// the start of spring and each month at its jie term, both judged at the
+// birth instant in UT. The day pillar counts the sixty-day cycle by the
+// local civil date, and the hour pillar takes the branch of the two-hour
+// period on the local clock (Zi spans 23:00–01:00; the day is not advanced
+// at 23:00). Month and hour stems follow from the year and day stems by the
+// "five tigers" and "five rats" rules.
+
+impl Pillar {
+    /// Position 0–59 in the sixty cycle, 0 being jia-zi.
+    pub fn cycle_index(self) -> usize {
+        let (s, b) = (self.stem as usize, self.branch as usize);
+        // The index is congruent to s mod 10 and b mod 12
+        (0..60).find(|n| n % 10 == s && n % 12 == b).unwrap_or(0)
+    }
+
+    /// Chinese characters, e.g. "甲子".
+    pub fn chinese(self) -> String {
+        format!("{}{}", self.stem.chinese(), self.branch.chinese())
+    }
+}
+
+impl fmt::Display for Pillar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.stem.as_str(), self.branch.as_str())
+    }
+}
+
+fn pillar(cycle: usize) -> Pillar {
+    let (stem, branch) = cycle_parts(cycle);
+    Pillar { stem, branch }
+}
+
+/// Month pillar from the year stem and the solar month's branch.
+fn month_pillar(year_stem: HeavenlyStem, branch: EarthlyBranch) -> Pillar {
+    // Jia and Ji years open with a Bing-yin month, Yi and Geng with Wu-yin…
+    let yin_stem = (year_stem as usize * 2 + 2) % 10;
+    let steps = (branch as usize + 12 - EarthlyBranch::Yin as usize) % 12;
+    Pillar {
+        stem: HeavenlyStem::ALL[(yin_stem + steps) % 10],
+        branch,
+    }
+}
+
+/// Hour pillar from the day stem and the local clock hour (0–23).
+fn hour_pillar(day_stem: HeavenlyStem, hour: u32) -> Pillar {
+    // Jia and Ji days open with a Jia-zi hour, Yi and Geng with Bing-zi…
+    let zi_stem = (day_stem as usize * 2) % 10;
+    let branch = (hour as usize).div_ceil(2) % 12;
+    Pillar {
+        stem: HeavenlyStem::ALL[(zi_stem + branch) % 10],
+        branch: EarthlyBranch::ALL[branch],
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Day master
+// ---------------------------------------------------------------------------
+//
+// Simplified strength count: each stem and branch other than the day stem
+// supports the day master when its phase is the day master's own or feeds
+// it. Branches count by their main phase only (hidden stems are not read)
+// and the month branch, which holds the season, counts double. More than
+// half the weight in support makes the day master strong. A strong day
+// master is balanced by the phases it feeds, controls and is controlled by;
+// a weak one by its own phase and the one that feeds it.
+
+fn supports(day_master: FivePhase, phase: FivePhase) -> bool {
+    phase == day_master || phase.generates() == day_master
+}
+
+/// The phase that feeds `phase` (water feeds wood).
+fn resource_of(phase: FivePhase) -> FivePhase {
+    FivePhase::ALL[(phase as usize + 4) % 5]
+}
+
+/// The phase that restrains `phase` (metal parts wood).
+fn controller_of(phase: FivePhase) -> FivePhase {
+    FivePhase::ALL[(phase as usize + 3) % 5]
+}
+
+fn favorable_phases(day_master: FivePhase, strength: DayMasterStrength) -> Vec<FivePhase> {
+    match strength {
+        DayMasterStrength::Strong => vec![
+            day_master.generates(),
+            day_master.overcomes(),
+            controller_of(day_master),
+        ],
+        DayMasterStrength::Weak => vec![day_master, resource_of(day_master)],
+        DayMasterStrength::Balanced => Vec::new(),
+    }
+}
+
+/// Four Pillars chart for a birth. The day and hour pillars use the local
+/// date and clock; the timezone (UT if unset) places the birth against the
+/// solar terms. Without an hour only three pillars are drawn.
+///
+/// # Errors
+/// Returns [`BaziError::MissingField`] without a day and
+/// [`BaziError::InvalidValue`] for a month, day, hour or minute out of
+/// range.
+pub fn calculate_bazi(birth: &BirthData) -> Result<BaziChart, BaziError> {
+    let invalid = |field, value: i64| BaziError::InvalidValue { field, value };
+    let day = birth.day.ok_or(BaziError::MissingField("day"))?;
+    if !(1..=12).contains(&birth.month) {
+        return Err(invalid("month", i64::from(birth.month)));
+    }
+    if day == 0 || day > days_in_month(birth.year, birth.month) {
+        return Err(invalid("day", i64::from(day)));
+    }
+    let hour = match birth.hour {
+        Some(h) if !(0..=23).contains(&h) => return Err(invalid("hour", i64::from(h))),
+        h => h.map(|h| h as u32),
+    };
+    let minute = birth.minute.unwrap_or(0);
+    if !(0..=59).contains(&minute) {
+        return Err(invalid("minute", i64::from(minute)));
+    }
+
+    // Noon stands in for an unknown hour when placing the solar terms
+    let local_hour = hour.map_or(12.0, f64::from) + f64::from(minute) / 60.0;
+    let ut_hours = local_hour - birth.timezone.unwrap_or(0.0);
+    let jd = to_julian_day(birth.year, birth.month, day, 0, 0) + ut_hours / 24.0;
+
+    let year = pillar(year_cycle(solar_year(birth.year, birth.month, jd)));
+    let month = month_pillar(year.stem, solar_month_branch(jd));
+    let day_pillar = pillar(day_cycle(birth.year, birth.month, day));
+    let hour = hour.map(|h| hour_pillar(day_pillar.stem, h));
+
+    let pillars: Vec<Pillar> = [Some(year), Some(month), Some(day_pillar), hour]
+        .into_iter()
+        .flatten()
+        .collect();
+    let mut phase_counts: BTreeMap<FivePhase, u32> = BTreeMap::new();
+    for p in &pillars {
+        *phase_counts.entry(p.stem.phase()).or_default() += 1;
+        *phase_counts.entry(p.branch.phase()).or_default() += 1;
+    }
+
+    let day_master = day_pillar.stem;
+    let dm_phase = day_master.phase();
+    let mut others: Vec<(FivePhase, u32)> = pillars
+        .iter()
+        .flat_map(|p| [(p.stem.phase(), 1), (p.branch.phase(), 1)])
+        .collect();
+    // Drop the day stem itself and double the month branch
+    others.remove(4);
+    others[3].1 = 2;
+    let total_weight: u32 = others.iter().map(|(_, w)| w).sum();
+    let support: u32 = others.iter().filter(|(p, _)| supports(dm_phase, *p)).map(|(_, w)| w).sum();
+    let strength = match (support * 2).cmp(&total_weight) {
+        std::cmp::Ordering::Greater => DayMasterStrength::Strong,
+        std::cmp::Ordering::Equal => DayMasterStrength::Balanced,
+        std::cmp::Ordering::Less => DayMasterStrength::Weak,
+    };
+
+    Ok(BaziChart {
+        year,
+        month,
+        day: day_pillar,
+        hour,
+        phase_counts,
+        day_master,
+        day_master_phase: dm_phase,
+        strength,
+        support,
+        total_weight,
+        favorable_phases: favorable_phases(dm_phase, strength),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Zodiac pairing
+// ---------------------------------------------------------------------------
+//
+// Two people's animals are the branches of their year pillars, so a birth
+// in January belongs to the previous year's animal. Branches 0–11 combine
+// in six harmony when they sum to 1 mod 12 and harm each other when they
+// sum to 7; the three harmony trines are the branches equal mod 4.
+
+/// How two branches relate as zodiac animals.
+pub fn branch_relation(first: EarthlyBranch, second: EarthlyBranch) -> BranchRelation {
+    let (a, b) = (first as usize, second as usize);
+    if a == b {
+        BranchRelation::Same
+    } else if first.offset(6) == second {
+        BranchRelation::Clash
+    } else if (a + b) % 12 == 1 {
+        BranchRelation::SixHarmony
+    } else if (a + b) % 12 == 7 {
+        BranchRelation::Harm
+    } else if a % 4 == b % 4 {
+        BranchRelation::ThreeHarmony
+    } else {
+        BranchRelation::Neutral
+    }
+}
+
+impl BranchRelation {
+    /// Compatibility from 0 to 100.
+    pub fn score(self) -> u8 {
+        match self {
+            Self::SixHarmony => 95,
+            Self::ThreeHarmony => 85,
+            Self::Same => 70,
+            Self::Neutral => 60,
+            Self::Harm => 35,
+            Self::Clash => 20,
+        }
+    }
+}
+
+/// The pairing of two zodiac animals, given as year branches.
+pub fn zodiac_pairing(first: EarthlyBranch, second: EarthlyBranch) -> ZodiacPairing {
+    let relation = branch_relation(first, second);
+    ZodiacPairing {
+        first,
+        second,
+        relation,
+        score: relation.score(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// BaziEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+pub struct BaziEngine;
+
+impl BaziEngine {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Four Pillars chart for a birth.
+    ///
+    /// # Errors
+    /// Returns an error if the day is missing or a field is out of range.
+    pub fn calculate(&self, birth: &BirthData) -> Result<BaziChart, BaziError> {
+        calculate_bazi(birth)
+    }
+
+    /// The zodiac pairing of two births by their year animals.
+    ///
+    /// # Errors
+    /// Returns an error if either birth cannot be charted.
+    pub fn zodiac_pairing(
+        &self,
+        first: &BirthData,
+        second: &BirthData,
+    ) -> Result<ZodiacPairing, BaziError> {
+        Ok(zodiac_pairing(calculate_bazi(first)?.year.branch, calculate_bazi(second)?.year.branch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::birth_on;
+
+    #[test]
+    fn pillars_for_a_known_date() {
+        let chart = calculate_bazi(&birth_on(2000, 1, 1, Some(12))).unwrap();
+        // Before the start of spring, so still the ji-mao year 1999
+        assert_eq!(chart.year.chinese(), "己卯");
+        assert_eq!(chart.month.chinese(), "丙子");
+        assert_eq!(chart.day.chinese(), "戊午");
+        assert_eq!(chart.hour.map(Pillar::chinese).as_deref(), Some("戊午"));
+        assert_eq!(chart.year.to_string(), "ji-mao");
+        assert_eq!(chart.day.cycle_index(), 54);
+
+        assert_eq!(chart.phase_counts[&FivePhase::Earth], 3);
+        assert_eq!(chart.phase_counts[&FivePhase::Fire], 3);
+        assert_eq!(chart.phase_counts.values().sum::<u32>(), 8);
+        assert_eq!((chart.support, chart.total_weight), (5, 8));
+        assert_eq!(chart.strength, DayMasterStrength::Strong);
+        use FivePhase::*;
+        assert_eq!(chart.favorable_phases, [Metal, Water, Wood]);
+    }
+
+    #[test]
+    fn year_and_month_turn_at_the_solar_terms() {
+        // Spring began on 4 February 1984, a jia-zi year
+        let before = calculate_bazi(&birth_on(1984, 2, 3, None)).unwrap();
+        let after = calculate_bazi(&birth_on(1984, 2, 5, None)).unwrap();
+        assert_eq!(before.year.chinese(), "癸亥");
+        assert_eq!(after.year.chinese(), "甲子");
+        assert_eq!(after.month.chinese(), "丙寅");
+        assert_eq!(before.month.branch, EarthlyBranch::Chou);
+        assert!(after.hour.is_none());
+        assert_eq!(after.total_weight, 6);
+    }
+
+    #[test]
+    fn hour_pillars_and_errors() {
+        // Zi hour runs from 23:00; on a jia day it is jia-zi
+        assert_eq!(hour_pillar(HeavenlyStem::Jia, 23).chinese(), "甲子");
+        assert_eq!(hour_pillar(HeavenlyStem::Jia, 0).chinese(), "甲子");
+        assert_eq!(hour_pillar(HeavenlyStem::Yi, 1).chinese(), "丁丑");
+        let engine = BaziEngine::new();
+        let mut bad = birth_on(2000, 1, 1, Some(24));
+        assert_eq!(
+            engine.calculate(&bad).unwrap_err(),
+            BaziError::InvalidValue { field: "hour", value: 24 }
+        );
+        let april_31 = birth_on(2000, 4, 31, None);
+        assert_eq!(
+            engine.calculate(&april_31).unwrap_err(),
+            BaziError::InvalidValue { field: "day", value: 31 }
+        );
+        bad.day = None;
+        assert_eq!(engine.calculate(&bad).unwrap_err(), BaziError::MissingField("day"));
+    }
+
+    #[test]
+    fn zodiac_pairings() {
+        use EarthlyBranch::*;
+        assert_eq!(branch_relation(Zi, Chou), BranchRelation::SixHarmony);
+        assert_eq!(branch_relation(Hai, Yin), BranchRelation::SixHarmony);
+        assert_eq!(branch_relation(Zi, Chen), BranchRelation::ThreeHarmony);
+        assert_eq!(branch_relation(Zi, Wu), BranchRelation::Clash);
+        assert_eq!(branch_relation(Zi, Wei), BranchRelation::Harm);
+        assert_eq!(branch_relation(You, Xu), BranchRelation::Harm);
+        assert_eq!(branch_relation(Zi, Yin), BranchRelation::Neutral);
+        assert_eq!(branch_relation(Si, Si), BranchRelation::Same);
+
+        // January 1985 is still the rat year; 1990 is the horse
+        let engine = BaziEngine::new();
+        let pairing = engine
+            .zodiac_pairing(&birth_on(1985, 1, 20, None), &birth_on(1990, 6, 15, None))
+            .unwrap();
+        assert_eq!((pairing.first.animal(), pairing.second.animal()), ("rat", "horse"));
+        assert_eq!((pairing.relation, pairing.score), (BranchRelation::Clash, 20));
+    }
+}
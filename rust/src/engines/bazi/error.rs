@@ -0,0 +1,25 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// BaziError — failures reported by the BaZi engine
+// ---------------------------------------------------------------------------
+
+/// Why a BaZi operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaziError {
+    /// A birth field the chart cannot be drawn without
+    MissingField(&'static str),
+    /// A birth field outside its range
+    InvalidValue { field: &'static str, value: i64 },
+}
+
+impl fmt::Display for BaziError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "birth {} is required", field),
+            Self::InvalidValue { field, value } => write!(f, "invalid birth {}: {}", field, value),
+        }
+    }
+}
+
+impl std::error::Error for BaziError {}
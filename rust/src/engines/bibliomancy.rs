@@ -0,0 +1,310 @@
+mod error;
+
+use rand::Rng;
+
+use crate::types::{BibliomancyDraw, Corpus};
+
+pub use crate::rng::seeded_rng;
+pub use error::BibliomancyError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const KJV_WISDOM_JSON: &str = include_str!("../../../data/bibliomancy/kjv_wisdom.json");
+
+/// Id of the embedded corpus.
+pub const DEFAULT_CORPUS_ID: &str = "kjv_wisdom";
+
+// ---------------------------------------------------------------------------
+// Corpora
+// ---------------------------------------------------------------------------
+
+/// Check that a corpus has an id and at least one passage, and that no
+/// passage is blank.
+///
+/// # Errors
+/// Returns [`BibliomancyError::InvalidCorpus`] listing every problem found.
+pub fn validate_corpus(corpus: &Corpus) -> Result<(), BibliomancyError> {
+    let mut problems = Vec::new();
+    if corpus.id.trim().is_empty() {
+        problems.push("the corpus has no id".to_string());
+    }
+    if passage_count(corpus) == 0 {
+        problems.push("the corpus has no passages".to_string());
+    }
+    for book in &corpus.books {
+        for section in &book.sections {
+            for passage in section.passages.iter().filter(|p| p.text.trim().is_empty()) {
+                problems.push(format!(
+                    "{} {} {} is blank",
+                    book.title, section.title, passage.reference
+                ));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(BibliomancyError::InvalidCorpus {
+            corpus: corpus.id.clone(),
+            problems,
+        })
+    }
+}
+
+/// Parse a corpus from JSON in the same shape as the embedded one.
+///
+/// # Errors
+/// Returns an error if the JSON does not parse or the corpus is invalid.
+pub fn parse_corpus(json: &str) -> Result<Corpus, BibliomancyError> {
+    let corpus: Corpus =
+        serde_json::from_str(json).map_err(|e| BibliomancyError::Json(e.to_string()))?;
+    validate_corpus(&corpus)?;
+    Ok(corpus)
+}
+
+pub fn passage_count(corpus: &Corpus) -> usize {
+    corpus
+        .books
+        .iter()
+        .flat_map(|b| &b.sections)
+        .map(|s| s.passages.len())
+        .sum()
+}
+
+// ---------------------------------------------------------------------------
+// Drawing
+// ---------------------------------------------------------------------------
+//
+// Every passage is equally likely, however the corpus is divided into books
+// and sections. Context is taken from the same section only, so a passage
+// at the start of a psalm is never shown with the end of the previous one.
+
+/// The passage at `index` counting through the whole corpus, with up to
+/// `context` passages either side. Returns `None` past the last passage.
+pub fn passage_at(corpus: &Corpus, index: usize, context: usize) -> Option<BibliomancyDraw> {
+    let mut remaining = index;
+    for book in &corpus.books {
+        for section in &book.sections {
+            let passages = &section.passages;
+            if remaining >= passages.len() {
+                remaining -= passages.len();
+                continue;
+            }
+            let end = remaining.saturating_add(1).saturating_add(context).min(passages.len());
+            return Some(BibliomancyDraw {
+                corpus_id: corpus.id.clone(),
+                book: book.title.clone(),
+                section: section.title.clone(),
+                passage: passages[remaining].clone(),
+                before: passages[remaining.saturating_sub(context)..remaining].to_vec(),
+                after: passages[remaining + 1..end].to_vec(),
+            });
+        }
+    }
+    None
+}
+
+/// Open `corpus` at a random passage.
+///
+/// # Errors
+/// Returns [`BibliomancyError::InvalidCorpus`] if the corpus is empty.
+pub fn draw_passage_with_rng<R: Rng + ?Sized>(
+    corpus: &Corpus,
+    context: usize,
+    rng: &mut R,
+) -> Result<BibliomancyDraw, BibliomancyError> {
+    let total = passage_count(corpus);
+    if total == 0 {
+        return Err(BibliomancyError::InvalidCorpus {
+            corpus: corpus.id.clone(),
+            problems: vec!["the corpus has no passages".to_string()],
+        });
+    }
+    let index = rng.gen_range(0..total);
+    passage_at(corpus, index, context).ok_or_else(|| BibliomancyError::InvalidCorpus {
+        corpus: corpus.id.clone(),
+        problems: vec![format!("passage {} is missing", index)],
+    })
+}
+
+// ---------------------------------------------------------------------------
+// BibliomancyEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct BibliomancyEngine {
+    corpora: Vec<Corpus>,
+}
+
+impl BibliomancyEngine {
+    /// Create an engine with the embedded corpus.
+    ///
+    /// # Panics
+    /// Panics if the embedded corpus is invalid; use [`Self::try_new`] to
+    /// handle that as an error.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded bibliomancy corpus: {}", e))
+    }
+
+    /// Create an engine with the embedded corpus.
+    ///
+    /// # Errors
+    /// Returns an error if the embedded corpus is invalid.
+    pub fn try_new() -> Result<Self, BibliomancyError> {
+        Ok(Self {
+            corpora: vec![parse_corpus(KJV_WISDOM_JSON)?],
+        })
+    }
+
+    pub fn corpora(&self) -> &[Corpus] {
+        &self.corpora
+    }
+
+    pub fn get_corpus(&self, id: &str) -> Option<&Corpus> {
+        self.corpora.iter().find(|c| c.id == id)
+    }
+
+    /// Add a user-supplied corpus alongside the embedded one.
+    ///
+    /// # Errors
+    /// Returns an error if the corpus is invalid or its id is taken.
+    pub fn add_corpus(&mut self, corpus: Corpus) -> Result<(), BibliomancyError> {
+        validate_corpus(&corpus)?;
+        if self.get_corpus(&corpus.id).is_some() {
+            return Err(BibliomancyError::DuplicateCorpus(corpus.id));
+        }
+        self.corpora.push(corpus);
+        Ok(())
+    }
+
+    /// Parse and add a corpus from JSON, returning its id.
+    ///
+    /// # Errors
+    /// Returns an error if the JSON does not parse, the corpus is invalid
+    /// or its id is taken.
+    pub fn add_corpus_json(&mut self, json: &str) -> Result<String, BibliomancyError> {
+        let corpus = parse_corpus(json)?;
+        let id = corpus.id.clone();
+        self.add_corpus(corpus)?;
+        Ok(id)
+    }
+
+    /// Remove a corpus by id; the embedded default can be removed too.
+    /// Returns whether it was loaded.
+    pub fn remove_corpus(&mut self, id: &str) -> bool {
+        let before = self.corpora.len();
+        self.corpora.retain(|c| c.id != id);
+        self.corpora.len() != before
+    }
+
+    /// Open the named corpus at a random passage, with up to `context`
+    /// passages either side.
+    ///
+    /// # Errors
+    /// Returns [`BibliomancyError::UnknownCorpus`] for an unknown id.
+    pub fn draw(
+        &self,
+        corpus_id: &str,
+        context: usize,
+    ) -> Result<BibliomancyDraw, BibliomancyError> {
+        self.draw_with_rng(corpus_id, context, &mut rand::thread_rng())
+    }
+
+    /// Open the named corpus at a random passage with the given RNG.
+    ///
+    /// # Errors
+    /// Returns [`BibliomancyError::UnknownCorpus`] for an unknown id.
+    pub fn draw_with_rng<R: Rng + ?Sized>(
+        &self,
+        corpus_id: &str,
+        context: usize,
+        rng: &mut R,
+    ) -> Result<BibliomancyDraw, BibliomancyError> {
+        let corpus = self
+            .get_corpus(corpus_id)
+            .ok_or_else(|| BibliomancyError::UnknownCorpus(corpus_id.to_string()))?;
+        draw_passage_with_rng(corpus, context, rng)
+    }
+}
+
+impl Default for BibliomancyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HAIKU: &str = r#"{
+        "id": "basho",
+        "title": "Three haiku",
+        "books": [{"title": "Basho", "sections": [{"title": "Spring", "passages": [
+            {"reference": "1", "text": "An old silent pond"},
+            {"reference": "2", "text": "A frog jumps into the pond"},
+            {"reference": "3", "text": "Splash! Silence again"}
+        ]}]}]
+    }"#;
+
+    #[test]
+    fn context_stays_within_the_section() {
+        let engine = BibliomancyEngine::new();
+        let corpus = engine.get_corpus(DEFAULT_CORPUS_ID).unwrap();
+        let first = passage_at(corpus, 0, 2).unwrap();
+        assert_eq!((first.book.as_str(), first.section.as_str()), ("Psalms", "Psalm 23"));
+        assert_eq!(first.passage.text, "The LORD is my shepherd; I shall not want.");
+        assert!(first.before.is_empty());
+        assert_eq!(first.after.len(), 2);
+
+        // The last verse of Psalm 23 is followed by nothing from Psalm 121
+        let last = passage_at(corpus, 5, 1).unwrap();
+        assert_eq!(last.passage.reference, "23:6");
+        assert_eq!(last.before[0].reference, "23:5");
+        assert!(last.after.is_empty());
+        assert!(passage_at(corpus, passage_count(corpus), 1).is_none());
+        let whole = passage_at(corpus, 2, usize::MAX).unwrap();
+        assert_eq!((whole.before.len(), whole.after.len()), (2, 3));
+    }
+
+    #[test]
+    fn seeded_draws_repeat() {
+        let engine = BibliomancyEngine::new();
+        let draw = |seed| {
+            engine
+                .draw_with_rng(DEFAULT_CORPUS_ID, 1, &mut seeded_rng(seed))
+                .unwrap()
+        };
+        assert_eq!(draw(8), draw(8));
+        assert!(!draw(8).passage.text.is_empty());
+        assert_eq!(
+            engine.draw("quran", 1),
+            Err(BibliomancyError::UnknownCorpus("quran".to_string()))
+        );
+    }
+
+    #[test]
+    fn user_corpora() {
+        let mut engine = BibliomancyEngine::new();
+        assert_eq!(engine.add_corpus_json(HAIKU).unwrap(), "basho");
+        assert_eq!(
+            engine.add_corpus_json(HAIKU),
+            Err(BibliomancyError::DuplicateCorpus("basho".to_string()))
+        );
+        let draw = engine.draw_with_rng("basho", 0, &mut seeded_rng(1)).unwrap();
+        assert_eq!(draw.book, "Basho");
+        assert!(draw.before.is_empty() && draw.after.is_empty());
+
+        let blank = HAIKU
+            .replace("\"id\": \"basho\"", "\"id\": \"empty\"")
+            .replace("An old silent pond", " ");
+        assert!(matches!(
+            engine.add_corpus_json(&blank),
+            Err(BibliomancyError::InvalidCorpus { problems, .. }) if problems.len() == 1
+        ));
+        assert!(matches!(engine.add_corpus_json("{"), Err(BibliomancyError::Json(_))));
+        assert!(engine.remove_corpus("basho"));
+        assert!(!engine.remove_corpus("basho"));
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// BibliomancyError — failures reported by the bibliomancy engine
+// ---------------------------------------------------------------------------
+
+/// Why a bibliomancy operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BibliomancyError {
+    UnknownCorpus(String),
+    /// A corpus with this id is already loaded
+    DuplicateCorpus(String),
+    /// Every problem found in a corpus
+    InvalidCorpus { corpus: String, problems: Vec<String> },
+    /// Corpus JSON that could not be parsed
+    Json(String),
+}
+
+impl fmt::Display for BibliomancyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCorpus(id) => write!(f, "unknown corpus: {}", id),
+            Self::DuplicateCorpus(id) => write!(f, "corpus '{}' is already loaded", id),
+            Self::InvalidCorpus { corpus, problems } => {
+                write!(f, "invalid corpus '{}': {}", corpus, problems.join("; "))
+            }
+            Self::Json(message) => write!(f, "invalid corpus JSON: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for BibliomancyError {}
@@ -0,0 +1,62 @@
+use crate::engines::astrology::{sun_longitude, to_julian_day};
+use crate::types::{EarthlyBranch, HeavenlyStem};
+
+// ---------------------------------------------------------------------------
+// Gregorian calendar
+// ---------------------------------------------------------------------------
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Chinese solar calendar
+// ---------------------------------------------------------------------------
+//
+// Shared by the I Ching (Liu Yao) and BaZi engines. Days count through the
+// sixty-day stem-branch cycle from midnight to midnight. Months and years
+// are solar: a month opens at each jie term, the Yin month and the year at
+// the start of spring when the sun reaches 315°.
+
+/// Position 0–59 in the sixty-day cycle of a Gregorian date, 0 being a
+/// jia-zi day.
+pub(crate) fn day_cycle(year: i32, month: u32, day: u32) -> usize {
+    let jd = to_julian_day(year, month, day, 12, 0);
+    (jd.round() as i64 + 49).rem_euclid(60) as usize
+}
+
+/// Stem and branch of a position in the sixty cycle.
+pub(crate) fn cycle_parts(cycle: usize) -> (HeavenlyStem, EarthlyBranch) {
+    (HeavenlyStem::ALL[cycle % 10], EarthlyBranch::ALL[cycle % 12])
+}
+
+/// Branch of the solar month containing Julian day `jd` (UT).
+pub(crate) fn solar_month_branch(jd: f64) -> EarthlyBranch {
+    let month_steps = ((sun_longitude(jd) - 315.0).rem_euclid(360.0) / 30.0) as i32;
+    EarthlyBranch::Yin.offset(month_steps)
+}
+
+/// The solar year an instant belongs to: dates in January and early
+/// February before the start of spring count toward the previous year.
+pub(crate) fn solar_year(year: i32, month: u32, jd: f64) -> i32 {
+    let before_spring = month <= 2 && (270.0..315.0).contains(&sun_longitude(jd));
+    if before_spring {
+        year - 1
+    } else {
+        year
+    }
+}
+
+/// Position 0–59 in the sixty-year cycle; 1984 was a jia-zi year.
+pub(crate) fn year_cycle(solar_year: i32) -> usize {
+    (solar_year - 1984).rem_euclid(60) as usize
+}
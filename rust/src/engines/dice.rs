@@ -0,0 +1,221 @@
+mod error;
+
+use rand::Rng;
+
+use crate::types::{DiceReading, DiceTotal};
+
+pub use crate::rng::seeded_rng;
+pub use error::DiceError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const TOTALS_JSON: &str = include_str!("../../../data/dice/totals.json");
+
+fn load_totals() -> Result<Vec<DiceTotal>, DiceError> {
+    serde_json::from_str(TOTALS_JSON).map_err(|e| DiceError::DataCorrupt(e.to_string()))
+}
+
+/// Check that every total two or three dice can show has exactly one
+/// meaning and that nothing else does.
+fn validate_totals(totals: &[DiceTotal]) -> Result<(), DiceError> {
+    let mut problems = Vec::new();
+    for (i, entry) in totals.iter().enumerate() {
+        if !DICE_COUNTS.contains(&usize::from(entry.dice)) {
+            problems.push(format!("a total is given for {} dice", entry.dice));
+        } else if !(entry.dice..=entry.dice * 6).contains(&entry.total) {
+            problems.push(format!("{} dice cannot total {}", entry.dice, entry.total));
+        }
+        if totals[..i].iter().any(|t| t.dice == entry.dice && t.total == entry.total) {
+            problems.push(format!("{} on {} dice has two meanings", entry.total, entry.dice));
+        }
+    }
+    for dice in DICE_COUNTS.map(|n| n as u8) {
+        for total in dice..=dice * 6 {
+            if totals.iter().all(|t| t.dice != dice || t.total != total) {
+                problems.push(format!("{} on {} dice has no meaning", total, dice));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(DiceError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Casting
+// ---------------------------------------------------------------------------
+//
+// Astragalomancy began with knucklebones; the modern form casts two or three
+// ordinary dice and reads the total. Two dice give eleven totals centred on
+// the lucky seven, three give sixteen. When every die shows the same face
+// the omen is read with extra weight.
+
+/// How many dice may be cast at once.
+pub const DICE_COUNTS: [usize; 2] = [2, 3];
+
+/// Roll `count` six-sided dice.
+///
+/// # Errors
+/// Returns [`DiceError::InvalidDiceCount`] unless `count` is 2 or 3.
+pub fn roll_dice_with_rng<R: Rng + ?Sized>(
+    count: usize,
+    rng: &mut R,
+) -> Result<Vec<u8>, DiceError> {
+    if !DICE_COUNTS.contains(&count) {
+        return Err(DiceError::InvalidDiceCount(count));
+    }
+    Ok((0..count).map(|_| rng.gen_range(1..=6)).collect())
+}
+
+/// Read `faces` against the table of totals.
+///
+/// # Errors
+/// Returns an error if there are not two or three faces or a face is
+/// outside 1–6.
+pub fn read_faces(totals: &[DiceTotal], faces: &[u8]) -> Result<DiceReading, DiceError> {
+    if !DICE_COUNTS.contains(&faces.len()) {
+        return Err(DiceError::InvalidDiceCount(faces.len()));
+    }
+    if let Some(&bad) = faces.iter().find(|f| !(1..=6).contains(*f)) {
+        return Err(DiceError::InvalidFace(bad));
+    }
+    let total: u8 = faces.iter().sum();
+    let meaning = totals
+        .iter()
+        .find(|t| usize::from(t.dice) == faces.len() && t.total == total)
+        .cloned()
+        .ok_or_else(|| {
+            DiceError::DataCorrupt(format!("{} on {} dice has no meaning", total, faces.len()))
+        })?;
+    let all_match = faces.windows(2).all(|w| w[0] == w[1]);
+
+    let sum: Vec<String> = faces.iter().map(u8::to_string).collect();
+    let mut summary = vec![format!("{} = {}: {}", sum.join(" + "), total, meaning.meaning)];
+    if all_match {
+        summary.push("Every die shows the same face, which strengthens the omen.".to_string());
+    }
+
+    Ok(DiceReading {
+        faces: faces.to_vec(),
+        total,
+        meaning,
+        all_match,
+        summary: summary.join(" "),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// DiceEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct DiceEngine {
+    totals: Vec<DiceTotal>,
+}
+
+impl DiceEngine {
+    /// Create an engine from the embedded table of totals.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded dice data: {}", e))
+    }
+
+    /// Load and validate the embedded table of totals.
+    ///
+    /// # Errors
+    /// Returns [`DiceError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, DiceError> {
+        let totals = load_totals()?;
+        validate_totals(&totals)?;
+        Ok(Self { totals })
+    }
+
+    /// Meanings for two dice (totals 2–12) then three (3–18).
+    pub fn totals(&self) -> &[DiceTotal] {
+        &self.totals
+    }
+
+    /// The meaning of `total` on `dice` dice.
+    pub fn meaning(&self, dice: usize, total: u8) -> Option<&DiceTotal> {
+        self.totals.iter().find(|t| usize::from(t.dice) == dice && t.total == total)
+    }
+
+    /// Cast and read `count` dice.
+    ///
+    /// # Errors
+    /// Returns [`DiceError::InvalidDiceCount`] unless `count` is 2 or 3.
+    pub fn cast(&self, count: usize) -> Result<DiceReading, DiceError> {
+        self.cast_with_rng(count, &mut rand::thread_rng())
+    }
+
+    /// Cast and read `count` dice with the given RNG.
+    ///
+    /// # Errors
+    /// Returns [`DiceError::InvalidDiceCount`] unless `count` is 2 or 3.
+    pub fn cast_with_rng<R: Rng + ?Sized>(
+        &self,
+        count: usize,
+        rng: &mut R,
+    ) -> Result<DiceReading, DiceError> {
+        read_faces(&self.totals, &roll_dice_with_rng(count, rng)?)
+    }
+
+    /// Read dice the querent cast themselves.
+    ///
+    /// # Errors
+    /// Returns an error if there are not two or three faces or a face is
+    /// outside 1–6.
+    pub fn read(&self, faces: &[u8]) -> Result<DiceReading, DiceError> {
+        read_faces(&self.totals, faces)
+    }
+}
+
+impl Default for DiceEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_totals() {
+        let engine = DiceEngine::new();
+        assert_eq!(engine.totals().len(), 11 + 16);
+        let reading = engine.read(&[6, 6, 6]).unwrap();
+        assert_eq!(reading.total, 18);
+        assert!(reading.all_match);
+        assert!(reading.summary.starts_with("6 + 6 + 6 = 18: "));
+        assert!(reading.summary.ends_with("strengthens the omen."));
+
+        let reading = engine.read(&[3, 4]).unwrap();
+        assert_eq!(reading.meaning, *engine.meaning(2, 7).unwrap());
+        assert!(!reading.all_match);
+
+        assert_eq!(engine.read(&[1]), Err(DiceError::InvalidDiceCount(1)));
+        assert_eq!(engine.read(&[1, 7]), Err(DiceError::InvalidFace(7)));
+        assert!(engine.meaning(3, 2).is_none());
+    }
+
+    #[test]
+    fn seeded_casts_repeat() {
+        let engine = DiceEngine::new();
+        let cast = |seed| engine.cast_with_rng(3, &mut seeded_rng(seed)).unwrap();
+        assert_eq!(cast(8), cast(8));
+        let mut rng = seeded_rng(1);
+        for _ in 0..100 {
+            let reading = engine.cast_with_rng(2, &mut rng).unwrap();
+            assert_eq!(reading.faces.len(), 2);
+            assert!((2..=12).contains(&reading.total));
+        }
+        assert_eq!(engine.cast(4), Err(DiceError::InvalidDiceCount(4)));
+    }
+}
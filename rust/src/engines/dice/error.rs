@@ -0,0 +1,28 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// DiceError — failures reported by the dice engine
+// ---------------------------------------------------------------------------
+
+/// Why a dice cast failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceError {
+    /// Dice are cast two or three at a time
+    InvalidDiceCount(usize),
+    /// A face outside 1–6
+    InvalidFace(u8),
+    /// The embedded table of totals is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDiceCount(n) => write!(f, "cannot cast {} dice; cast 2 or 3", n),
+            Self::InvalidFace(face) => write!(f, "invalid die face: {}", face),
+            Self::DataCorrupt(detail) => write!(f, "corrupt dice data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for DiceError {}
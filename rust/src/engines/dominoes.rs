@@ -0,0 +1,233 @@
+mod error;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::types::{Domino, DominoReading, DrawnDomino};
+
+pub use crate::rng::seeded_rng;
+pub use error::DominoError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const TILES_JSON: &str = include_str!("../../../data/dominoes/tiles.json");
+
+fn load_tiles() -> Result<Vec<Domino>, DominoError> {
+    serde_json::from_str(TILES_JSON).map_err(|e| DominoError::DataCorrupt(e.to_string()))
+}
+
+/// Check that the data holds each of the 28 double-six tiles once, larger
+/// end first.
+fn validate_tiles(tiles: &[Domino]) -> Result<(), DominoError> {
+    let mut problems = Vec::new();
+    if tiles.len() != 28 {
+        problems.push(format!("{} tiles instead of 28", tiles.len()));
+    }
+    for (i, tile) in tiles.iter().enumerate() {
+        if tile.high > 6 || tile.low > tile.high {
+            problems.push(format!("tile {} is not a double-six tile", tile.label()));
+        }
+        if tiles[..i].iter().any(|t| t.high == tile.high && t.low == tile.low) {
+            problems.push(format!("tile {} appears twice", tile.label()));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(DominoError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+impl Domino {
+    /// Pips as written, larger end first, e.g. `"6-4"`.
+    pub fn label(&self) -> String {
+        format!("{}-{}", self.high, self.low)
+    }
+
+    pub fn is_double(&self) -> bool {
+        self.high == self.low
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Drawing
+// ---------------------------------------------------------------------------
+//
+// The tiles are turned face down and shuffled, and no more than three are
+// drawn for one question. Each tile is read on its own; doubles carry extra
+// weight.
+
+/// Most tiles drawn for one question.
+pub const MAX_DRAW: usize = 3;
+
+/// Draw `count` tiles without replacement.
+///
+/// # Errors
+/// Returns [`DominoError::InvalidCount`] unless `count` is 1 to
+/// [`MAX_DRAW`].
+pub fn draw_tiles_with_rng<R: Rng + ?Sized>(
+    tiles: &[Domino],
+    count: usize,
+    rng: &mut R,
+) -> Result<Vec<Domino>, DominoError> {
+    if !(1..=MAX_DRAW).contains(&count) {
+        return Err(DominoError::InvalidCount(count));
+    }
+    Ok(tiles.choose_multiple(rng, count).cloned().collect())
+}
+
+/// Read drawn tiles in order, with a summary naming them and any doubles.
+pub fn read_tiles(tiles: Vec<Domino>) -> DominoReading {
+    let labels: Vec<String> = tiles.iter().map(Domino::label).collect();
+    let mut summary = vec![match labels.as_slice() {
+        [] => "No tiles were drawn.".to_string(),
+        [tile] => format!("The {} speaks alone.", tile),
+        [rest @ .., last] => format!("The {} and {} are read together.", rest.join(", "), last),
+    }];
+    match tiles.iter().filter(|t| t.is_double()).count() {
+        0 => {}
+        1 => summary.push("A double strengthens the reading.".to_string()),
+        n => summary.push(format!("{} doubles strengthen the reading.", n)),
+    }
+
+    let tiles = tiles
+        .into_iter()
+        .map(|tile| DrawnDomino {
+            text: format!("{} ({}): {}", tile.label(), tile.keywords.join(", "), tile.meaning),
+            tile,
+        })
+        .collect();
+    DominoReading {
+        tiles,
+        summary: summary.join(" "),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DominoEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct DominoEngine {
+    tiles: Vec<Domino>,
+}
+
+impl DominoEngine {
+    /// Create an engine from the embedded tiles.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded domino data: {}", e))
+    }
+
+    /// Load and validate the embedded tiles.
+    ///
+    /// # Errors
+    /// Returns [`DominoError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, DominoError> {
+        let tiles = load_tiles()?;
+        validate_tiles(&tiles)?;
+        Ok(Self { tiles })
+    }
+
+    /// All 28 tiles, from 6-6 down to 0-0.
+    pub fn tiles(&self) -> &[Domino] {
+        &self.tiles
+    }
+
+    /// The tile with these two ends, in either order.
+    pub fn get_tile(&self, a: u8, b: u8) -> Option<&Domino> {
+        let (high, low) = (a.max(b), a.min(b));
+        self.tiles.iter().find(|t| t.high == high && t.low == low)
+    }
+
+    /// Draw and read `count` tiles.
+    ///
+    /// # Errors
+    /// Returns [`DominoError::InvalidCount`] unless `count` is 1 to
+    /// [`MAX_DRAW`].
+    pub fn draw(&self, count: usize) -> Result<DominoReading, DominoError> {
+        self.draw_with_rng(count, &mut rand::thread_rng())
+    }
+
+    /// Draw and read `count` tiles with the given RNG.
+    ///
+    /// # Errors
+    /// Returns [`DominoError::InvalidCount`] unless `count` is 1 to
+    /// [`MAX_DRAW`].
+    pub fn draw_with_rng<R: Rng + ?Sized>(
+        &self,
+        count: usize,
+        rng: &mut R,
+    ) -> Result<DominoReading, DominoError> {
+        Ok(read_tiles(draw_tiles_with_rng(&self.tiles, count, rng)?))
+    }
+
+    /// Read tiles the querent drew from a physical set.
+    ///
+    /// # Errors
+    /// Returns an error if the count is not 1 to [`MAX_DRAW`], a tile has
+    /// pips outside 0–6 or a tile is given twice.
+    pub fn read(&self, ends: &[[u8; 2]]) -> Result<DominoReading, DominoError> {
+        if !(1..=MAX_DRAW).contains(&ends.len()) {
+            return Err(DominoError::InvalidCount(ends.len()));
+        }
+        let mut tiles: Vec<Domino> = Vec::with_capacity(ends.len());
+        for &[a, b] in ends {
+            let tile = self.get_tile(a, b).ok_or(DominoError::InvalidTile([a, b]))?;
+            if tiles.contains(tile) {
+                return Err(DominoError::DuplicateTile([a, b]));
+            }
+            tiles.push(tile.clone());
+        }
+        Ok(read_tiles(tiles))
+    }
+}
+
+impl Default for DominoEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_physical_tiles() {
+        let engine = DominoEngine::new();
+        assert_eq!(engine.tiles().len(), 28);
+        assert_eq!(engine.get_tile(1, 3), engine.get_tile(3, 1));
+
+        let reading = engine.read(&[[6, 6], [1, 3]]).unwrap();
+        assert_eq!(reading.tiles[1].tile.label(), "3-1");
+        assert!(reading.tiles[0].text.starts_with("6-6 (happiness"));
+        assert_eq!(
+            reading.summary,
+            "The 6-6 and 3-1 are read together. A double strengthens the reading."
+        );
+
+        assert_eq!(engine.read(&[]), Err(DominoError::InvalidCount(0)));
+        assert_eq!(engine.read(&[[7, 1]]), Err(DominoError::InvalidTile([7, 1])));
+        assert_eq!(engine.read(&[[2, 5], [5, 2]]), Err(DominoError::DuplicateTile([5, 2])));
+    }
+
+    #[test]
+    fn seeded_draws_repeat_without_replacement() {
+        let engine = DominoEngine::new();
+        let draw = |seed| engine.draw_with_rng(3, &mut seeded_rng(seed)).unwrap();
+        assert_eq!(draw(5), draw(5));
+        let mut rng = seeded_rng(9);
+        for _ in 0..50 {
+            let tiles = engine.draw_with_rng(3, &mut rng).unwrap().tiles;
+            assert_ne!(tiles[0].tile, tiles[1].tile);
+            assert_ne!(tiles[1].tile, tiles[2].tile);
+            assert_ne!(tiles[0].tile, tiles[2].tile);
+        }
+        assert_eq!(engine.draw(4), Err(DominoError::InvalidCount(4)));
+    }
+}
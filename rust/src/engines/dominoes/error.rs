@@ -0,0 +1,31 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// DominoError — failures reported by the domino engine
+// ---------------------------------------------------------------------------
+
+/// Why a domino draw failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DominoError {
+    /// Between one and three tiles are drawn at a time
+    InvalidCount(usize),
+    /// Pips outside 0–6
+    InvalidTile([u8; 2]),
+    /// The same tile given twice in one draw
+    DuplicateTile([u8; 2]),
+    /// The embedded tile data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for DominoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCount(n) => write!(f, "cannot draw {} tiles; draw 1 to 3", n),
+            Self::InvalidTile([a, b]) => write!(f, "invalid domino: {}-{}", a, b),
+            Self::DuplicateTile([a, b]) => write!(f, "domino {}-{} was drawn twice", a, b),
+            Self::DataCorrupt(detail) => write!(f, "corrupt domino data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for DominoError {}
@@ -0,0 +1,360 @@
+mod error;
+
+use crate::text::name_similarity;
+use crate::types::{DreamCombination, DreamInterpretation, DreamSymbol, SymbolMatch};
+
+pub use error::DreamError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const SYMBOLS_JSON: &str = include_str!("../../../data/dreams/symbols.json");
+const COMBINATIONS_JSON: &str = include_str!("../../../data/dreams/combinations.json");
+
+fn load_symbols() -> Result<Vec<DreamSymbol>, DreamError> {
+    serde_json::from_str(SYMBOLS_JSON).map_err(|e| DreamError::DataCorrupt(e.to_string()))
+}
+
+fn load_combinations() -> Result<Vec<DreamCombination>, DreamError> {
+    serde_json::from_str(COMBINATIONS_JSON).map_err(|e| DreamError::DataCorrupt(e.to_string()))
+}
+
+/// Check that ids are unique, that no word or phrase names two symbols and
+/// that every combination pairs two different known symbols.
+fn validate_data(
+    symbols: &[DreamSymbol],
+    combinations: &[DreamCombination],
+) -> Result<(), DreamError> {
+    let mut problems = Vec::new();
+    let mut terms: Vec<(String, &str)> = Vec::new();
+    for symbol in symbols {
+        for term in symbol_terms(symbol) {
+            match terms.iter().find(|(t, _)| *t == term) {
+                Some((_, other)) if *other != symbol.id => {
+                    problems.push(format!("'{}' names both {} and {}", term, other, symbol.id));
+                }
+                Some(_) => {}
+                None => terms.push((term, &symbol.id)),
+            }
+        }
+    }
+    for combo in combinations {
+        for id in &combo.symbols {
+            if symbols.iter().all(|s| &s.id != id) {
+                problems.push(format!("combination {} names unknown symbol {}", combo.name, id));
+            }
+        }
+        if combo.symbols[0] == combo.symbols[1] {
+            problems.push(format!("combination {} pairs a symbol with itself", combo.name));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(DreamError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lookup and search
+// ---------------------------------------------------------------------------
+//
+// A symbol is named by its id, its name and its aliases. Text is lowercased
+// and stripped of punctuation, and the last word of a phrase is also tried
+// in the singular, so "Snakes!" finds the snake and "wolves" the wolf.
+// Fuzzy search scores each term by normalised Levenshtein similarity.
+
+/// Lowest similarity (0.0–1.0) accepted by [`search_symbols`].
+pub const MIN_SYMBOL_SIMILARITY: f64 = 0.7;
+
+/// Longest alias, in words, that [`symbols_in_text`] looks for.
+const MAX_PHRASE_WORDS: usize = 3;
+
+fn words(text: &str) -> Vec<String> {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+fn symbol_terms(symbol: &DreamSymbol) -> Vec<String> {
+    let mut terms = vec![words(&symbol.id).join(" "), words(&symbol.name).join(" ")];
+    terms.extend(symbol.aliases.iter().map(|a| words(a).join(" ")));
+    terms.dedup();
+    terms
+}
+
+/// The phrase as written plus its plausible singulars.
+fn singular_forms(phrase: &str) -> Vec<String> {
+    let mut forms = vec![phrase.to_string()];
+    if let Some(stem) = phrase.strip_suffix("ies") {
+        forms.push(format!("{}y", stem));
+    }
+    if let Some(stem) = phrase.strip_suffix("ves") {
+        forms.push(format!("{}f", stem));
+    }
+    if let Some(stem) = phrase.strip_suffix("es") {
+        forms.push(stem.to_string());
+    }
+    if let Some(stem) = phrase.strip_suffix('s') {
+        forms.push(stem.to_string());
+    }
+    forms
+}
+
+fn matches_term(symbol: &DreamSymbol, phrase: &str) -> bool {
+    let forms = singular_forms(phrase);
+    symbol_terms(symbol).iter().any(|t| forms.contains(t))
+}
+
+/// The symbol named by `term` (id, name or alias), ignoring case,
+/// punctuation and a plural ending.
+pub fn lookup_symbol<'a>(symbols: &'a [DreamSymbol], term: &str) -> Option<&'a DreamSymbol> {
+    let phrase = words(term).join(" ");
+    symbols.iter().find(|s| matches_term(s, &phrase))
+}
+
+/// Symbols whose id, name or alias resembles `query`, best first. Returns
+/// nothing below [`MIN_SYMBOL_SIMILARITY`].
+pub fn search_symbols(symbols: &[DreamSymbol], query: &str) -> Vec<SymbolMatch> {
+    let forms = singular_forms(&words(query).join(" "));
+    let mut matches: Vec<SymbolMatch> = symbols
+        .iter()
+        .map(|s| {
+            let score = symbol_terms(s)
+                .iter()
+                .flat_map(|t| forms.iter().map(move |f| name_similarity(f, t)))
+                .fold(0.0, f64::max);
+            SymbolMatch {
+                symbol: s.clone(),
+                score,
+            }
+        })
+        .filter(|m| m.score >= MIN_SYMBOL_SIMILARITY)
+        .collect();
+    // Stable, so equal scores keep dictionary order
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches
+}
+
+/// Every symbol mentioned in `text`, in order of first mention. Longer
+/// phrases win over the words inside them, so "teeth falling out" is read
+/// once as teeth rather than also as falling.
+pub fn symbols_in_text(symbols: &[DreamSymbol], text: &str) -> Vec<DreamSymbol> {
+    let words = words(text);
+    let mut found: Vec<DreamSymbol> = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let hit = (1..=MAX_PHRASE_WORDS.min(words.len() - i)).rev().find_map(|n| {
+            let phrase = words[i..i + n].join(" ");
+            symbols.iter().find(|s| matches_term(s, &phrase)).map(|s| (n, s))
+        });
+        match hit {
+            Some((n, symbol)) => {
+                if found.iter().all(|f| f.id != symbol.id) {
+                    found.push(symbol.clone());
+                }
+                i += n;
+            }
+            None => i += 1,
+        }
+    }
+    found
+}
+
+// ---------------------------------------------------------------------------
+// Combination
+// ---------------------------------------------------------------------------
+
+/// What each category of image tends to speak about.
+const CATEGORY_THEMES: [(&str, &str); 7] = [
+    ("animals", "instinct and the untamed self"),
+    ("nature", "emotions and forces larger than you"),
+    ("body", "self-image, health and control"),
+    ("people", "relationships and unmet parts of the self"),
+    ("places", "the shape of your life and where it is heading"),
+    ("actions", "how you are meeting a situation"),
+    ("objects", "what you value and hold on to"),
+];
+
+/// Read `symbols` together: known pairs among them and the category most of
+/// them share, with a summary that lists each meaning in turn.
+pub fn combine_symbols(
+    combinations: &[DreamCombination],
+    symbols: Vec<DreamSymbol>,
+) -> DreamInterpretation {
+    let has = |id: &str| symbols.iter().any(|s| s.id == id);
+    let combinations: Vec<DreamCombination> = combinations
+        .iter()
+        .filter(|c| has(&c.symbols[0]) && has(&c.symbols[1]))
+        .cloned()
+        .collect();
+
+    let dominant = CATEGORY_THEMES.iter().find(|(category, _)| {
+        let count = symbols.iter().filter(|s| s.category == *category).count();
+        count >= 2 && count * 2 > symbols.len()
+    });
+
+    let mut summary: Vec<String> =
+        symbols.iter().map(|s| format!("{}: {}", s.name, s.meaning)).collect();
+    if summary.is_empty() {
+        summary.push("No known dream symbols were recognised.".to_string());
+    }
+    for c in &combinations {
+        summary.push(format!("Together, {}: {}", c.name.to_lowercase(), c.meaning));
+    }
+    if let Some((category, theme)) = dominant {
+        summary.push(format!("Most of the images are {}, pointing to {}.", category, theme));
+    }
+
+    DreamInterpretation {
+        symbols,
+        combinations,
+        dominant_category: dominant.map(|(category, _)| category.to_string()),
+        summary: summary.join(" "),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DreamEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct DreamEngine {
+    symbols: Vec<DreamSymbol>,
+    combinations: Vec<DreamCombination>,
+}
+
+impl DreamEngine {
+    /// Create an engine from the embedded dictionary.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded dream data: {}", e))
+    }
+
+    /// Load and validate the embedded symbols and combinations.
+    ///
+    /// # Errors
+    /// Returns [`DreamError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, DreamError> {
+        let symbols = load_symbols()?;
+        let combinations = load_combinations()?;
+        validate_data(&symbols, &combinations)?;
+        Ok(Self {
+            symbols,
+            combinations,
+        })
+    }
+
+    pub fn symbols(&self) -> &[DreamSymbol] {
+        &self.symbols
+    }
+
+    pub fn combinations(&self) -> &[DreamCombination] {
+        &self.combinations
+    }
+
+    /// Look up a symbol by id, name or alias.
+    pub fn lookup(&self, term: &str) -> Option<&DreamSymbol> {
+        lookup_symbol(&self.symbols, term)
+    }
+
+    /// Symbols resembling `query`, best first.
+    pub fn search(&self, query: &str) -> Vec<SymbolMatch> {
+        search_symbols(&self.symbols, query)
+    }
+
+    /// Find the symbols in a description of a dream and read them together.
+    pub fn interpret(&self, text: &str) -> DreamInterpretation {
+        combine_symbols(&self.combinations, symbols_in_text(&self.symbols, text))
+    }
+
+    /// Read the named symbols together.
+    ///
+    /// # Errors
+    /// Returns [`DreamError::UnknownSymbol`] if a term names no symbol.
+    pub fn combine(&self, terms: &[&str]) -> Result<DreamInterpretation, DreamError> {
+        let mut symbols: Vec<DreamSymbol> = Vec::new();
+        for term in terms {
+            let symbol = self
+                .lookup(term)
+                .ok_or_else(|| DreamError::UnknownSymbol(term.to_string()))?;
+            if symbols.iter().all(|s| s.id != symbol.id) {
+                symbols.push(symbol.clone());
+            }
+        }
+        Ok(combine_symbols(&self.combinations, symbols))
+    }
+}
+
+impl Default for DreamEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(symbols: &[DreamSymbol]) -> Vec<&str> {
+        symbols.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    #[test]
+    fn lookup_and_fuzzy_search() {
+        let engine = DreamEngine::new();
+        assert!(engine.symbols().len() >= 40);
+        assert_eq!(engine.lookup("Snakes").unwrap().id, "snake");
+        assert_eq!(engine.lookup("serpent").unwrap().id, "snake");
+        assert_eq!(engine.lookup("wolves").unwrap().id, "wolf");
+        assert_eq!(engine.lookup("being chased").unwrap().id, "chased");
+        assert!(engine.lookup("spaceship").is_none());
+
+        let results = engine.search("snak");
+        assert_eq!(results[0].symbol.id, "snake");
+        assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+        assert_eq!(engine.search("mirrors")[0].score, 1.0);
+        assert!(engine.search("xylophone").is_empty());
+    }
+
+    #[test]
+    fn finds_symbols_in_free_text() {
+        let engine = DreamEngine::new();
+        let found = symbols_in_text(engine.symbols(), "I dreamed of snakes and water.");
+        assert_eq!(ids(&found), ["snake", "water"]);
+        let found = symbols_in_text(
+            engine.symbols(),
+            "My teeth falling out, then a serpent by the river, then more snakes",
+        );
+        assert_eq!(ids(&found), ["teeth", "snake", "water"]);
+    }
+
+    #[test]
+    fn combines_pairs_and_categories() {
+        let engine = DreamEngine::new();
+        let reading = engine.interpret("I dreamed of snakes and water");
+        assert_eq!(reading.combinations.len(), 1);
+        assert_eq!(reading.combinations[0].name, "Feelings in motion");
+        assert!(reading.summary.starts_with("Snake: "));
+        assert!(reading.summary.contains("Together, feelings in motion"));
+        assert!(reading.dominant_category.is_none());
+
+        let reading = engine.combine(&["storm", "rain", "moon", "cat"]).unwrap();
+        assert_eq!(ids(&reading.symbols), ["storm", "water", "moon", "cat"]);
+        assert_eq!(reading.dominant_category.as_deref(), Some("nature"));
+        assert_eq!(reading.combinations[0].name, "Emotional upheaval");
+
+        assert_eq!(
+            engine.combine(&["snake", "unicorn"]).err(),
+            Some(DreamError::UnknownSymbol("unicorn".to_string()))
+        );
+        let empty = engine.interpret("nothing here");
+        assert_eq!(empty.summary, "No known dream symbols were recognised.");
+    }
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// DreamError — failures reported by the dream engine
+// ---------------------------------------------------------------------------
+
+/// Why a dream symbol operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DreamError {
+    UnknownSymbol(String),
+    /// The embedded symbol or combination data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for DreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol(name) => write!(f, "unknown dream symbol: {}", name),
+            Self::DataCorrupt(detail) => write!(f, "corrupt dream data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for DreamError {}
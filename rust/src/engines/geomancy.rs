@@ -0,0 +1,319 @@
+mod error;
+
+use rand::Rng;
+
+use crate::types::{GeomanticFigure, GeomanticHouse, HouseChart, ShieldChart};
+
+pub use crate::rng::seeded_rng;
+pub use error::GeomancyError;
+
+/// Points in the head, neck, body and feet rows of a figure.
+pub type FigureRows = [u8; 4];
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const FIGURES_JSON: &str = include_str!("../../../data/geomancy/figures.json");
+
+fn load_figures() -> Result<Vec<GeomanticFigure>, GeomancyError> {
+    serde_json::from_str(FIGURES_JSON).map_err(|e| GeomancyError::DataCorrupt(e.to_string()))
+}
+
+/// Check that the data holds each of the 16 possible row patterns once.
+fn validate_figures(figures: &[GeomanticFigure]) -> Result<(), GeomancyError> {
+    let mut problems = Vec::new();
+    if figures.len() != 16 {
+        problems.push(format!("{} figures instead of 16", figures.len()));
+    }
+    for (i, figure) in figures.iter().enumerate() {
+        if !valid_rows(figure.rows) {
+            problems.push(format!("figure {} has rows {:?}", figure.name, figure.rows));
+        }
+        if figures[..i].iter().any(|f| f.rows == figure.rows) {
+            problems.push(format!("figure {} repeats rows {:?}", figure.name, figure.rows));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(GeomancyError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+fn valid_rows(rows: FigureRows) -> bool {
+    rows.iter().all(|&r| r == 1 || r == 2)
+}
+
+// ---------------------------------------------------------------------------
+// Figure arithmetic
+// ---------------------------------------------------------------------------
+//
+// A row is odd (one point) or even (two). Adding two figures adds them row
+// by row and keeps only the parity, so matching rows give two points and
+// differing rows give one. The Daughters are the Mothers read sideways:
+// Daughter n takes row n of each Mother in turn.
+
+/// Add two figures row by row.
+pub fn add_rows(a: FigureRows, b: FigureRows) -> FigureRows {
+    [0, 1, 2, 3].map(|i| if a[i] == b[i] { 2 } else { 1 })
+}
+
+/// The four Daughters of `mothers`.
+pub fn daughters(mothers: &[FigureRows; 4]) -> [FigureRows; 4] {
+    [0, 1, 2, 3].map(|row| mothers.map(|m| m[row]))
+}
+
+/// Mothers from sixteen counts of points, as when marking lines of dots in
+/// sand or on paper: each count's parity gives one row, four rows to a
+/// Mother.
+pub fn mothers_from_counts(counts: &[u32; 16]) -> [FigureRows; 4] {
+    [0, 1, 2, 3].map(|m| [0, 1, 2, 3].map(|r| if counts[m * 4 + r] % 2 == 1 { 1 } else { 2 }))
+}
+
+/// Four random Mothers.
+pub fn random_mothers<R: Rng + ?Sized>(rng: &mut R) -> [FigureRows; 4] {
+    [(); 4].map(|_| [(); 4].map(|_| if rng.gen_bool(0.5) { 1 } else { 2 }))
+}
+
+fn figure_for(
+    figures: &[GeomanticFigure],
+    rows: FigureRows,
+) -> Result<GeomanticFigure, GeomancyError> {
+    figures
+        .iter()
+        .find(|f| f.rows == rows)
+        .cloned()
+        .ok_or(GeomancyError::InvalidRows(rows))
+}
+
+// ---------------------------------------------------------------------------
+// Shield and house charts
+// ---------------------------------------------------------------------------
+//
+// The Nieces add the Mothers and Daughters in pairs (1+2, 3+4), the Right
+// and Left Witnesses add the Nieces in pairs, and the Judge adds the
+// Witnesses. Because every figure of the shield comes from the Mothers an
+// even number of times over, the Judge always has an even number of points.
+
+/// Build the full shield from four Mothers.
+///
+/// # Errors
+/// Returns [`GeomancyError::InvalidRows`] if a Mother has a row other than 1
+/// or 2 points.
+pub fn shield_chart(
+    figures: &[GeomanticFigure],
+    mothers: [FigureRows; 4],
+) -> Result<ShieldChart, GeomancyError> {
+    if let Some(&bad) = mothers.iter().find(|m| !valid_rows(**m)) {
+        return Err(GeomancyError::InvalidRows(bad));
+    }
+    let daughters = daughters(&mothers);
+    let nieces = [
+        add_rows(mothers[0], mothers[1]),
+        add_rows(mothers[2], mothers[3]),
+        add_rows(daughters[0], daughters[1]),
+        add_rows(daughters[2], daughters[3]),
+    ];
+    let right_witness = add_rows(nieces[0], nieces[1]);
+    let left_witness = add_rows(nieces[2], nieces[3]);
+    let judge = add_rows(right_witness, left_witness);
+
+    let four = |rows: [FigureRows; 4]| -> Result<[GeomanticFigure; 4], GeomancyError> {
+        let [a, b, c, d] = rows;
+        Ok([
+            figure_for(figures, a)?,
+            figure_for(figures, b)?,
+            figure_for(figures, c)?,
+            figure_for(figures, d)?,
+        ])
+    };
+    Ok(ShieldChart {
+        mothers: four(mothers)?,
+        daughters: four(daughters)?,
+        nieces: four(nieces)?,
+        right_witness: figure_for(figures, right_witness)?,
+        left_witness: figure_for(figures, left_witness)?,
+        judge: figure_for(figures, judge)?,
+    })
+}
+
+/// What each of the twelve houses governs.
+pub const HOUSE_TOPICS: [&str; 12] = [
+    "the querent, their life and health",
+    "wealth and possessions",
+    "siblings, neighbours, news and short journeys",
+    "home, land, the father and the end of the matter",
+    "children, pleasure and messages",
+    "illness, servants and daily work",
+    "marriage, partners and open enemies",
+    "death, debts and inheritance",
+    "long journeys, learning and religion",
+    "career, honour and authority",
+    "friends, hopes and patrons",
+    "sorrows, secret enemies and confinement",
+];
+
+/// Place the Mothers, Daughters and Nieces of `shield` in houses 1–12 in
+/// that order, the traditional projection.
+pub fn house_chart(shield: &ShieldChart) -> HouseChart {
+    let figures = shield.mothers.iter().chain(&shield.daughters).chain(&shield.nieces);
+    let houses = (1..)
+        .zip(figures)
+        .zip(HOUSE_TOPICS)
+        .map(|((number, figure), topic)| GeomanticHouse {
+            number,
+            topic: topic.to_string(),
+            figure: figure.clone(),
+        })
+        .collect();
+    HouseChart { houses }
+}
+
+// ---------------------------------------------------------------------------
+// GeomancyEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct GeomancyEngine {
+    figures: Vec<GeomanticFigure>,
+}
+
+impl GeomancyEngine {
+    /// Create an engine from the embedded figures.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded geomancy data: {}", e))
+    }
+
+    /// Load and validate the embedded figures.
+    ///
+    /// # Errors
+    /// Returns [`GeomancyError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, GeomancyError> {
+        let figures = load_figures()?;
+        validate_figures(&figures)?;
+        Ok(Self { figures })
+    }
+
+    /// All 16 figures.
+    pub fn figures(&self) -> &[GeomanticFigure] {
+        &self.figures
+    }
+
+    /// Look up a figure by Latin name or English translation
+    /// (case-insensitive).
+    pub fn get_figure(&self, name: &str) -> Option<&GeomanticFigure> {
+        let name = name.trim();
+        self.figures
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(name) || f.translation.eq_ignore_ascii_case(name))
+    }
+
+    pub fn figure_for_rows(&self, rows: FigureRows) -> Option<&GeomanticFigure> {
+        self.figures.iter().find(|f| f.rows == rows)
+    }
+
+    /// Cast a shield chart from four random Mothers.
+    pub fn cast(&self) -> ShieldChart {
+        self.cast_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Cast a shield chart with the given RNG.
+    pub fn cast_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> ShieldChart {
+        shield_chart(&self.figures, random_mothers(rng))
+            .unwrap_or_else(|e| panic!("validated geomancy data: {}", e))
+    }
+
+    /// Cast a shield chart from sixteen counts of points.
+    pub fn cast_from_counts(&self, counts: &[u32; 16]) -> ShieldChart {
+        shield_chart(&self.figures, mothers_from_counts(counts))
+            .unwrap_or_else(|e| panic!("validated geomancy data: {}", e))
+    }
+
+    /// Build the shield from four named Mothers.
+    ///
+    /// # Errors
+    /// Returns [`GeomancyError::UnknownFigure`] if a name is not a figure.
+    pub fn shield_from_names(&self, mothers: [&str; 4]) -> Result<ShieldChart, GeomancyError> {
+        let mut rows = [[0; 4]; 4];
+        for (slot, name) in rows.iter_mut().zip(mothers) {
+            *slot = self
+                .get_figure(name)
+                .ok_or_else(|| GeomancyError::UnknownFigure(name.to_string()))?
+                .rows;
+        }
+        shield_chart(&self.figures, rows)
+    }
+
+    pub fn house_chart(&self, shield: &ShieldChart) -> HouseChart {
+        house_chart(shield)
+    }
+}
+
+impl Default for GeomancyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn figure_arithmetic() {
+        let engine = GeomancyEngine::new();
+        assert_eq!(engine.figures().len(), 16);
+        assert_eq!(engine.get_figure("greater fortune").unwrap().name, "Fortuna Major");
+        assert_eq!(engine.figure_for_rows([1, 1, 1, 1]).unwrap().name, "Via");
+        // Any figure added to itself gives Populus; adding Via inverts it
+        assert_eq!(add_rows([2, 1, 2, 1], [2, 1, 2, 1]), [2, 2, 2, 2]);
+        assert_eq!(add_rows([2, 1, 2, 1], [1, 1, 1, 1]), [1, 2, 1, 2]);
+        let mothers = [[1, 1, 2, 1], [2, 2, 2, 2], [1, 2, 1, 2], [2, 1, 1, 1]];
+        assert_eq!(daughters(&mothers)[0], [1, 2, 1, 2]);
+        assert_eq!(daughters(&daughters(&mothers)), mothers);
+        let counts = [3, 4, 8, 7, 2, 2, 2, 2, 5, 9, 1, 13, 6, 7, 9, 11];
+        assert_eq!(mothers_from_counts(&counts)[0], [1, 2, 2, 1]);
+        assert_eq!(mothers_from_counts(&counts)[2], [1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn shield_from_named_mothers() {
+        let engine = GeomancyEngine::new();
+        let shield = engine
+            .shield_from_names(["Puer", "Populus", "Amissio", "Caput Draconis"])
+            .unwrap();
+        let names = |figs: &[GeomanticFigure]| -> Vec<String> {
+            figs.iter().map(|f| f.name.clone()).collect()
+        };
+        assert_eq!(names(&shield.daughters), ["Amissio", "Carcer", "Fortuna Major", "Carcer"]);
+        assert_eq!(names(&shield.nieces), ["Puer", "Puer", "Fortuna Major", "Amissio"]);
+        assert_eq!(shield.right_witness.name, "Populus");
+        assert_eq!(shield.left_witness.name, "Carcer");
+        assert_eq!(shield.judge.name, "Carcer");
+        assert_eq!(
+            engine.shield_from_names(["Puer", "Nope", "Via", "Via"]),
+            Err(GeomancyError::UnknownFigure("Nope".to_string()))
+        );
+    }
+
+    #[test]
+    fn judges_are_even_and_houses_follow_the_shield() {
+        let engine = GeomancyEngine::new();
+        let mut rng = seeded_rng(4);
+        for _ in 0..50 {
+            let shield = engine.cast_with_rng(&mut rng);
+            let points: u8 = shield.judge.rows.iter().sum();
+            assert_eq!(points % 2, 0, "judge {} is odd", shield.judge.name);
+            let houses = engine.house_chart(&shield).houses;
+            assert_eq!(houses.len(), 12);
+            assert_eq!(houses[0].figure, shield.mothers[0]);
+            assert_eq!(houses[4].figure, shield.daughters[0]);
+            assert_eq!(houses[11].figure, shield.nieces[3]);
+        }
+        assert!(shield_chart(engine.figures(), [[1, 1, 1, 3]; 4]).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// GeomancyError — failures reported by the geomancy engine
+// ---------------------------------------------------------------------------
+
+/// Why a geomancy operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeomancyError {
+    UnknownFigure(String),
+    /// Rows that do not form a figure; each must hold 1 or 2 points
+    InvalidRows([u8; 4]),
+    /// The embedded figure data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for GeomancyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFigure(name) => write!(f, "unknown geomantic figure: {}", name),
+            Self::InvalidRows(rows) => write!(f, "invalid figure rows: {:?}", rows),
+            Self::DataCorrupt(detail) => write!(f, "corrupt geomancy data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for GeomancyError {}
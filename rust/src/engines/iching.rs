@@ -1,8 +1,60 @@
-use std::collections::HashMap;
+mod bagua;
+mod binary;
+mod chain;
+mod daily;
+mod error;
+mod glyphs;
+mod interpret;
+mod liuyao;
+mod methods;
+mod najia;
+mod plum;
+mod question;
+mod relations;
+mod rules;
+mod search;
+mod sovereign;
+mod stats;
+mod translations;
+
+use std::sync::OnceLock;
 
 use rand::Rng;
 
-use crate::types::{CastResult, Hexagram, Trigram};
+use crate::types::{
+    Arrangement, CastInterpretation, CastMethod, CastResult, ChangingLineReading, ChangingLineRule,
+    Direction, Hexagram, InterpretationProfile, LineDerivative, LineToss, LiuYaoContext,
+    LiuYaoReading, NajiaLine, PlumBlossomCast, ProfiledInterpretation, ReflectionPrompt,
+    ResolvedCast, SequencePair, SixRelative, SovereignHexagram, StrategyText, TranslationPack,
+    Trigram,
+};
+
+pub use crate::rng::seeded_rng;
+pub use bagua::{direction_of_trigram, trigram_number_for_direction};
+pub use binary::{
+    binary_to_mask, complement, differing_lines, hamming_distance, mask_to_binary, reverse, xor,
+};
+pub use chain::TransformationChain;
+pub use daily::reflection_prompt;
+pub use error::IChingError;
+pub use glyphs::{hexagram_glyph, trigram_glyph};
+pub use interpret::{interpret, resolve};
+pub use liuyao::liu_yao;
+pub use methods::line_probabilities;
+pub use najia::{najia, najia_for_binary};
+pub use plum::{plum_blossom_from_lunar_date, plum_blossom_from_numbers};
+pub use question::{question_lines, question_tosses, DEFAULT_QUESTION_BUCKET_MINUTES};
+pub use relations::{
+    binary_to_decimal, decimal_to_binary, fu_xi_to_king_wen, king_wen_to_fu_xi, line_derivatives,
+    next_in_sequence, previous_in_sequence, sequence_pair,
+};
+pub use rules::interpret_cast;
+pub use search::{hexagrams_by_english_name, hexagrams_by_name};
+pub use sovereign::{sovereign_hexagram_for, sovereign_hexagram_for_branch, sovereign_hexagrams};
+pub use stats::CastStatistics;
+pub use translations::{
+    apply_translation_pack, load_translation_pack, parse_translation_pack, DEFAULT_TRANSLATION_ID,
+};
 
 // ---------------------------------------------------------------------------
 // Static data loaded at compile time
@@ -10,13 +62,105 @@ use crate::types::{CastResult, Hexagram, Trigram};
 
 const HEXAGRAMS_JSON: &str = include_str!("../../../data/iching/hexagrams.json");
 const TRIGRAMS_JSON: &str = include_str!("../../../data/iching/trigrams.json");
+const STRATEGY_JSON: &str = include_str!("../../../data/iching/strategy.json");
+
+fn load_hexagrams() -> Result<Vec<Hexagram>, IChingError> {
+    serde_json::from_str(HEXAGRAMS_JSON).map_err(|e| corrupt("hexagrams.json", e))
+}
 
-fn load_hexagrams() -> Vec<Hexagram> {
-    serde_json::from_str(HEXAGRAMS_JSON).expect("Failed to parse hexagrams.json")
+fn load_trigrams() -> Result<Vec<Trigram>, IChingError> {
+    serde_json::from_str(TRIGRAMS_JSON).map_err(|e| corrupt("trigrams.json", e))
+}
+
+fn load_strategy() -> Result<Vec<StrategyText>, IChingError> {
+    serde_json::from_str(STRATEGY_JSON).map_err(|e| corrupt("strategy.json", e))
+}
+
+fn corrupt(file: &str, err: serde_json::Error) -> IChingError {
+    IChingError::DataCorrupt(format!("{}: {}", file, err))
+}
+
+/// King Wen numbers indexed by line bitmask (bit 0 = bottom line).
+type PatternTable = [u32; 64];
+
+/// Check that the hexagrams are numbered 1–64 once each, cover every
+/// six-line pattern once and point at trigrams that exist, and return the
+/// pattern lookup table.
+fn validate_data(
+    hexagrams: &[Hexagram],
+    trigrams: &[Trigram],
+    strategy: &[StrategyText],
+) -> Result<PatternTable, IChingError> {
+    let mut problems = Vec::new();
+    let mut table: PatternTable = [0; 64];
+    let mut numbers = [false; 65];
+    for h in hexagrams {
+        match usize::try_from(h.number).ok().filter(|n| (1..=64).contains(n)) {
+            Some(n) if numbers[n] => problems.push(format!("hexagram {} appears twice", n)),
+            Some(n) => numbers[n] = true,
+            None => problems.push(format!("hexagram number {} out of range", h.number)),
+        }
+        match binary_to_mask(&h.binary) {
+            Some(mask) if table[usize::from(mask)] != 0 => {
+                problems.push(format!("pattern {} appears twice", h.binary))
+            }
+            Some(mask) => table[usize::from(mask)] = h.number,
+            None => problems.push(format!("hexagram {} has pattern '{}'", h.number, h.binary)),
+        }
+        if h.lines.len() != 6 {
+            problems.push(format!("hexagram {} has {} lines", h.number, h.lines.len()));
+        }
+        for t in [h.top_trigram, h.bottom_trigram] {
+            if trigrams.iter().all(|tri| tri.number != t) {
+                problems.push(format!("hexagram {} refers to trigram {}", h.number, t));
+            }
+        }
+    }
+    if hexagrams.len() != 64 {
+        problems.push(format!("{} hexagrams instead of 64", hexagrams.len()));
+    }
+    if trigrams.len() != 8 {
+        problems.push(format!("{} trigrams instead of 8", trigrams.len()));
+    }
+    for t in strategy.iter().filter(|t| !(1..=64).contains(&t.number)) {
+        problems.push(format!("strategy text for hexagram {}", t.number));
+    }
+    if problems.is_empty() {
+        Ok(table)
+    } else {
+        Err(IChingError::DataCorrupt(problems.join("; ")))
+    }
 }
 
-fn load_trigrams() -> Vec<Trigram> {
-    serde_json::from_str(TRIGRAMS_JSON).expect("Failed to parse trigrams.json")
+/// The embedded texts with their pattern lookup table.
+struct EmbeddedData {
+    hexagrams: Vec<Hexagram>,
+    trigrams: Vec<Trigram>,
+    strategy: Vec<StrategyText>,
+    patterns: PatternTable,
+}
+
+/// The embedded texts, parsed and validated on first use.
+fn embedded() -> Result<&'static EmbeddedData, IChingError> {
+    static DATA: OnceLock<Result<EmbeddedData, IChingError>> = OnceLock::new();
+    let data = DATA.get_or_init(|| {
+        let hexagrams = load_hexagrams()?;
+        let trigrams = load_trigrams()?;
+        let strategy = load_strategy()?;
+        let patterns = validate_data(&hexagrams, &trigrams, &strategy)?;
+        Ok(EmbeddedData {
+            hexagrams,
+            trigrams,
+            strategy,
+            patterns,
+        })
+    });
+    data.as_ref().map_err(Clone::clone)
+}
+
+/// The validated lookup table for the embedded hexagrams.
+fn embedded_table() -> Result<&'static PatternTable, IChingError> {
+    Ok(&embedded()?.patterns)
 }
 
 // ---------------------------------------------------------------------------
@@ -29,6 +173,25 @@ struct CastLineResult {
     value: u8,
     /// Whether this line is a changing line
     changing: bool,
+    /// The coins, stalks or token behind the value, if the engine tossed it
+    toss: Option<LineToss>,
+}
+
+impl CastLineResult {
+    fn entered(value: u8) -> Self {
+        Self {
+            value,
+            changing: value == 6 || value == 9,
+            toss: None,
+        }
+    }
+
+    fn tossed(toss: LineToss) -> Self {
+        Self {
+            toss: Some(toss),
+            ..Self::entered(toss.value())
+        }
+    }
 }
 
 /// Three coins are tossed.  Heads = 3, Tails = 2.
@@ -37,20 +200,10 @@ struct CastLineResult {
 ///   7 (2+2+3) = Young Yang — stable solid line
 ///   8 (2+3+3) = Young Yin  — stable broken line
 ///   9 (3+3+3) = Old Yang   — changing solid line
-fn cast_line() -> CastLineResult {
-    let mut rng = rand::thread_rng();
-    let coin = |rng: &mut rand::rngs::ThreadRng| -> u8 {
-        if rng.gen_bool(0.5) { 3 } else { 2 }
-    };
-    let c1 = coin(&mut rng);
-    let c2 = coin(&mut rng);
-    let c3 = coin(&mut rng);
-    let value = c1 + c2 + c3;
-
-    CastLineResult {
-        value,
-        changing: value == 6 || value == 9,
-    }
+fn cast_line<R: Rng + ?Sized>(rng: &mut R) -> CastLineResult {
+    let mut coin = || rng.gen_bool(0.5);
+    let heads = [coin(), coin(), coin()];
+    CastLineResult::tossed(LineToss::ThreeCoins { heads })
 }
 
 /// Map a line value to its binary digit.
@@ -76,11 +229,16 @@ fn line_value_to_transformed_binary(value: u8) -> u8 {
 // Public free functions
 // ---------------------------------------------------------------------------
 
-/// Cast a full hexagram using the three-coin method.
-/// Lines are cast from bottom (position 1) to top (position 6).
-pub fn cast_hexagram() -> CastResult {
-    let cast_lines: Vec<CastLineResult> = (0..6).map(|_| cast_line()).collect();
+fn glyph_string(number: Option<u32>) -> Option<String> {
+    number.and_then(hexagram_glyph).map(String::from)
+}
 
+/// Build a cast from six line values, bottom first.
+fn build_cast(
+    cast_lines: &[CastLineResult],
+    method: CastMethod,
+    table: &PatternTable,
+) -> CastResult {
     let lines: Vec<u8> = cast_lines.iter().map(|cl| cl.value).collect();
     let changing_lines: Vec<usize> = cast_lines
         .iter()
@@ -94,23 +252,16 @@ pub fn cast_hexagram() -> CastResult {
         .map(|cl| line_value_to_binary(cl.value).to_string())
         .collect();
 
-    let hexagrams = load_hexagrams();
-    let binary_to_number: HashMap<String, u32> =
-        hexagrams.iter().map(|h| (h.binary.clone(), h.number)).collect();
-
-    let hexagram_number = *binary_to_number
-        .get(&binary)
-        .unwrap_or_else(|| panic!("Unknown hexagram binary pattern: {}", binary));
+    // The table covers all 64 patterns, so lookups cannot miss
+    let lookup = |bits: &str| binary_to_mask(bits).map_or(0, |m| table[usize::from(m)]);
+    let hexagram_number = lookup(&binary);
 
     let (transformed_hexagram_number, transformed_binary) = if !changing_lines.is_empty() {
         let tb: String = cast_lines
             .iter()
             .map(|cl| line_value_to_transformed_binary(cl.value).to_string())
             .collect();
-        let tn = *binary_to_number
-            .get(&tb)
-            .unwrap_or_else(|| panic!("Unknown hexagram binary pattern: {}", tb));
-        (Some(tn), Some(tb))
+        (Some(lookup(&tb)), Some(tb))
     } else {
         (None, None)
     };
@@ -122,44 +273,187 @@ pub fn cast_hexagram() -> CastResult {
         transformed_hexagram_number,
         binary,
         transformed_binary,
+        glyph: glyph_string(Some(hexagram_number)).unwrap_or_default(),
+        transformed_glyph: glyph_string(transformed_hexagram_number),
+        method,
+        tosses: cast_lines.iter().filter_map(|cl| cl.toss).collect(),
     }
 }
 
-/// Convert a binary string (e.g. "111111") to a hexagram number.
-pub fn binary_to_hexagram_number(binary: &str) -> Result<u32, String> {
-    let hexagrams = load_hexagrams();
-    hexagrams
+// ---------------------------------------------------------------------------
+// Public free functions
+// ---------------------------------------------------------------------------
+
+/// Cast a full hexagram using the three-coin method.
+/// Lines are cast from bottom (position 1) to top (position 6).
+///
+/// # Errors
+/// Returns [`IChingError::DataCorrupt`] if the embedded data is invalid.
+pub fn cast_hexagram() -> Result<CastResult, IChingError> {
+    cast_hexagram_with_rng(&mut rand::thread_rng())
+}
+
+/// Cast a full hexagram, tossing the coins with the given RNG.
+///
+/// # Errors
+/// Returns [`IChingError::DataCorrupt`] if the embedded data is invalid.
+pub fn cast_hexagram_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Result<CastResult, IChingError> {
+    let table = embedded_table()?;
+    let cast_lines: Vec<CastLineResult> = (0..6).map(|_| cast_line(rng)).collect();
+    Ok(build_cast(&cast_lines, CastMethod::Coins, table))
+}
+
+/// Cast a full hexagram by a random method, using the given RNG.
+///
+/// # Errors
+/// Returns [`IChingError::MethodNeedsInput`] for manual and Plum Blossom
+/// casts.
+pub fn cast_by_method_with_rng<R: Rng + ?Sized>(
+    method: CastMethod,
+    rng: &mut R,
+) -> Result<CastResult, IChingError> {
+    toss_lines(method, rng, embedded_table()?)
+}
+
+fn toss_lines<R: Rng + ?Sized>(
+    method: CastMethod,
+    rng: &mut R,
+    table: &PatternTable,
+) -> Result<CastResult, IChingError> {
+    let cast_lines = (0..6)
+        .map(|_| methods::toss(method, rng).map(CastLineResult::tossed))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(IChingError::MethodNeedsInput(method))?;
+    Ok(build_cast(&cast_lines, method, table))
+}
+
+/// Build a cast from six recorded tosses, bottom first.
+fn resolve_tosses(tosses: &[LineToss; 6], method: CastMethod, table: &PatternTable) -> CastResult {
+    let cast_lines: Vec<CastLineResult> =
+        tosses.iter().map(|&t| CastLineResult::tossed(t)).collect();
+    build_cast(&cast_lines, method, table)
+}
+
+/// Resolve a cast entered by hand, e.g. from physical coins: six line
+/// values, bottom line first, each 6, 7, 8 or 9.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidLine`] for the first value out of range.
+pub fn cast_from_lines(lines: &[u8; 6]) -> Result<CastResult, IChingError> {
+    resolve_entered_lines(lines, embedded_table()?)
+}
+
+/// Cast deterministically from a question and the time it was asked
+/// (milliseconds since the Unix epoch). The same question within the same
+/// `bucket_minutes` window always gives the same cast.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] if `bucket_minutes` is zero.
+pub fn cast_from_question(
+    question: &str,
+    timestamp: u64,
+    bucket_minutes: u32,
+) -> Result<CastResult, IChingError> {
+    let tosses = question_tosses(question, timestamp, bucket_minutes)?;
+    Ok(resolve_tosses(&tosses, CastMethod::QuestionHash, embedded_table()?))
+}
+
+/// The hexagram for a calendar date, optionally personalised by a user id.
+/// Asking again for the same date and user always gives the same cast.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] for a month outside 1–12 or a day
+/// the month does not have.
+pub fn hexagram_of_the_day(
+    year: i32,
+    month: u32,
+    day: u32,
+    user_seed: Option<&str>,
+) -> Result<CastResult, IChingError> {
+    let tosses = daily::daily_tosses(year, month, day, user_seed)?;
+    Ok(resolve_tosses(&tosses, CastMethod::QuestionHash, embedded_table()?))
+}
+
+fn resolve_entered_lines(
+    lines: &[u8; 6],
+    table: &PatternTable,
+) -> Result<CastResult, IChingError> {
+    let cast_lines = lines
         .iter()
-        .find(|h| h.binary == binary)
-        .map(|h| h.number)
-        .ok_or_else(|| format!("Unknown hexagram binary pattern: {}", binary))
+        .enumerate()
+        .map(|(i, &value)| match value {
+            6..=9 => Ok(CastLineResult::entered(value)),
+            _ => Err(IChingError::InvalidLine {
+                position: i + 1,
+                value,
+            }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(build_cast(&cast_lines, CastMethod::Manual, table))
+}
+
+/// Convert a binary string (e.g. "111111") to a hexagram number.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidBinary`] unless `binary` is six '0'/'1'
+/// characters.
+pub fn binary_to_hexagram_number(binary: &str) -> Result<u32, IChingError> {
+    let mask = binary_to_mask(binary).ok_or_else(|| IChingError::InvalidBinary(binary.into()))?;
+    Ok(embedded_table()?[usize::from(mask)])
 }
 
 /// Get a hexagram by its King Wen sequence number (1–64).
-pub fn get_hexagram(number: u32) -> Result<Hexagram, String> {
-    let hexagrams = load_hexagrams();
-    hexagrams
-        .into_iter()
+///
+/// # Errors
+/// Returns [`IChingError::InvalidNumber`] for a number outside 1–64.
+pub fn get_hexagram(number: u32) -> Result<Hexagram, IChingError> {
+    embedded()?
+        .hexagrams
+        .iter()
         .find(|h| h.number == number)
-        .ok_or_else(|| format!("Hexagram number {} not found (valid range: 1-64)", number))
+        .cloned()
+        .ok_or(IChingError::InvalidNumber { kind: "hexagram", number, max: 64 })
 }
 
 /// Get a trigram by its number (1–8).
-pub fn get_trigram(number: u32) -> Result<Trigram, String> {
-    let trigrams = load_trigrams();
-    trigrams
-        .into_iter()
+///
+/// # Errors
+/// Returns [`IChingError::InvalidNumber`] for a number outside 1–8.
+pub fn get_trigram(number: u32) -> Result<Trigram, IChingError> {
+    embedded()?
+        .trigrams
+        .iter()
         .find(|t| t.number == number)
-        .ok_or_else(|| format!("Trigram number {} not found (valid range: 1-8)", number))
+        .cloned()
+        .ok_or(IChingError::InvalidNumber { kind: "trigram", number, max: 8 })
+}
+
+/// Get the strategy-profile text for a hexagram (1–64).
+///
+/// # Errors
+/// Returns [`IChingError::InvalidNumber`] for a number outside 1–64.
+pub fn get_strategy_text(number: u32) -> Result<StrategyText, IChingError> {
+    embedded()?
+        .strategy
+        .iter()
+        .find(|t| t.number == number)
+        .cloned()
+        .ok_or(IChingError::InvalidNumber { kind: "hexagram", number, max: 64 })
 }
 
 /// Get the lower (bottom) trigram of a hexagram.
-pub fn get_lower_trigram(hexagram: &Hexagram) -> Result<Trigram, String> {
+///
+/// # Errors
+/// Returns an error if the hexagram names a trigram that does not exist.
+pub fn get_lower_trigram(hexagram: &Hexagram) -> Result<Trigram, IChingError> {
     get_trigram(hexagram.bottom_trigram)
 }
 
 /// Get the upper (top) trigram of a hexagram.
-pub fn get_upper_trigram(hexagram: &Hexagram) -> Result<Trigram, String> {
+///
+/// # Errors
+/// Returns an error if the hexagram names a trigram that does not exist.
+pub fn get_upper_trigram(hexagram: &Hexagram) -> Result<Trigram, IChingError> {
     get_trigram(hexagram.top_trigram)
 }
 
@@ -170,66 +464,156 @@ pub fn get_upper_trigram(hexagram: &Hexagram) -> Result<Trigram, String> {
 pub struct IChingEngine {
     hexagrams: Vec<Hexagram>,
     trigrams: Vec<Trigram>,
-    binary_to_number: HashMap<String, u32>,
+    strategy: Vec<StrategyText>,
+    patterns: PatternTable,
+    /// Id of the translation the hexagram texts come from
+    translation: String,
 }
 
 impl IChingEngine {
+    /// Load the embedded texts.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid, which the crate's tests rule
+    /// out; use [`try_new`](Self::try_new) to handle it instead.
     pub fn new() -> Self {
-        let hexagrams = load_hexagrams();
-        let trigrams = load_trigrams();
-        let binary_to_number: HashMap<String, u32> =
-            hexagrams.iter().map(|h| (h.binary.clone(), h.number)).collect();
+        Self::try_new().unwrap_or_else(|e| panic!("embedded I Ching data: {}", e))
+    }
 
-        Self {
-            hexagrams,
-            trigrams,
-            binary_to_number,
-        }
+    /// Load and validate the embedded texts.
+    ///
+    /// # Errors
+    /// Returns [`IChingError::DataCorrupt`] listing every problem found.
+    pub fn try_new() -> Result<Self, IChingError> {
+        let data = embedded()?;
+        Ok(Self {
+            hexagrams: data.hexagrams.clone(),
+            trigrams: data.trigrams.clone(),
+            strategy: data.strategy.clone(),
+            patterns: data.patterns,
+            translation: DEFAULT_TRANSLATION_ID.to_string(),
+        })
+    }
+
+    /// An engine reading from an alternative translation, with the embedded
+    /// texts filling any gaps.
+    ///
+    /// # Errors
+    /// Returns an error if the pack names a hexagram or line that does not
+    /// exist.
+    pub fn with_translation(pack: &TranslationPack) -> Result<Self, IChingError> {
+        let mut engine = Self::try_new()?;
+        engine.hexagrams = apply_translation_pack(&engine.hexagrams, pack)?;
+        engine.translation = pack.id.clone();
+        Ok(engine)
+    }
+
+    /// Id of the translation in use; [`DEFAULT_TRANSLATION_ID`] for the
+    /// embedded texts.
+    pub fn translation(&self) -> &str {
+        &self.translation
     }
 
     /// Cast a full hexagram using the three-coin method.
     pub fn cast_hexagram(&self) -> CastResult {
-        let cast_lines: Vec<CastLineResult> = (0..6).map(|_| cast_line()).collect();
+        self.cast_hexagram_with_rng(&mut rand::thread_rng())
+    }
 
-        let lines: Vec<u8> = cast_lines.iter().map(|cl| cl.value).collect();
-        let changing_lines: Vec<usize> = cast_lines
-            .iter()
-            .enumerate()
-            .filter_map(|(i, cl)| if cl.changing { Some(i + 1) } else { None })
-            .collect();
+    /// Cast a full hexagram, tossing the coins with the given RNG.
+    pub fn cast_hexagram_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> CastResult {
+        let cast_lines: Vec<CastLineResult> = (0..6).map(|_| cast_line(rng)).collect();
+        build_cast(&cast_lines, CastMethod::Coins, &self.patterns)
+    }
 
-        let binary: String = cast_lines
-            .iter()
-            .map(|cl| line_value_to_binary(cl.value).to_string())
-            .collect();
+    /// Cast reproducibly from `seed`, e.g. the id of the message that asked.
+    pub fn cast_hexagram_seeded(&self, seed: u64) -> CastResult {
+        self.cast_hexagram_with_rng(&mut seeded_rng(seed))
+    }
 
-        let hexagram_number = *self
-            .binary_to_number
-            .get(&binary)
-            .unwrap_or_else(|| panic!("Unknown hexagram binary pattern: {}", binary));
-
-        let (transformed_hexagram_number, transformed_binary) = if !changing_lines.is_empty() {
-            let tb: String = cast_lines
-                .iter()
-                .map(|cl| line_value_to_transformed_binary(cl.value).to_string())
-                .collect();
-            let tn = *self
-                .binary_to_number
-                .get(&tb)
-                .unwrap_or_else(|| panic!("Unknown hexagram binary pattern: {}", tb));
-            (Some(tn), Some(tb))
-        } else {
-            (None, None)
-        };
+    /// Cast a full hexagram by a random method.
+    ///
+    /// # Errors
+    /// Returns an error for manual and Plum Blossom casts.
+    pub fn cast_by_method(&self, method: CastMethod) -> Result<CastResult, IChingError> {
+        self.cast_by_method_with_rng(method, &mut rand::thread_rng())
+    }
 
-        CastResult {
-            lines,
-            changing_lines,
-            hexagram_number,
-            transformed_hexagram_number,
-            binary,
-            transformed_binary,
+    /// Cast a full hexagram by a random method, using the given RNG.
+    ///
+    /// # Errors
+    /// Returns an error for manual and Plum Blossom casts.
+    pub fn cast_by_method_with_rng<R: Rng + ?Sized>(
+        &self,
+        method: CastMethod,
+        rng: &mut R,
+    ) -> Result<CastResult, IChingError> {
+        toss_lines(method, rng, &self.patterns)
+    }
+
+    /// Cast `n` times by `method` and tally the hexagrams and line values.
+    ///
+    /// # Errors
+    /// Returns an error for methods that take their lines from the querent.
+    pub fn cast_many(&self, n: usize, method: CastMethod) -> Result<CastStatistics, IChingError> {
+        self.cast_many_with_rng(n, method, &mut rand::thread_rng())
+    }
+
+    /// Cast `n` times by `method` with the given RNG and tally the results.
+    ///
+    /// # Errors
+    /// Returns an error for methods that take their lines from the querent.
+    pub fn cast_many_with_rng<R: Rng + ?Sized>(
+        &self,
+        n: usize,
+        method: CastMethod,
+        rng: &mut R,
+    ) -> Result<CastStatistics, IChingError> {
+        if line_probabilities(method).is_none() {
+            return Err(IChingError::MethodNeedsInput(method));
+        }
+        let mut stats = CastStatistics::new(method);
+        for _ in 0..n {
+            stats.record(&toss_lines(method, rng, &self.patterns)?);
         }
+        Ok(stats)
+    }
+
+    /// Follow `first` through its transformations, casting again by
+    /// `method` from each resulting hexagram until a cast has no changing
+    /// lines.
+    ///
+    /// # Errors
+    /// Returns an error for manual and Plum Blossom methods.
+    pub fn transformation_chain<'a, R: Rng + ?Sized>(
+        &'a self,
+        first: CastResult,
+        method: CastMethod,
+        rng: &'a mut R,
+    ) -> Result<TransformationChain<'a, R>, IChingError> {
+        TransformationChain::new(&self.patterns, method, first, rng)
+    }
+
+    /// Cast deterministically from a question and the time it was asked,
+    /// so repeating the question within one time bucket repeats the cast.
+    ///
+    /// # Errors
+    /// Returns an error if `bucket_minutes` is zero.
+    pub fn cast_from_question(
+        &self,
+        question: &str,
+        timestamp: u64,
+        bucket_minutes: u32,
+    ) -> Result<CastResult, IChingError> {
+        let tosses = question_tosses(question, timestamp, bucket_minutes)?;
+        Ok(resolve_tosses(&tosses, CastMethod::QuestionHash, &self.patterns))
+    }
+
+    /// Resolve a cast entered by hand, bottom line first.
+    ///
+    /// # Errors
+    /// Returns [`IChingError::InvalidLine`] for the first value out of range.
+    pub fn cast_from_lines(&self, lines: &[u8; 6]) -> Result<CastResult, IChingError> {
+        resolve_entered_lines(lines, &self.patterns)
     }
 
     /// Look up a hexagram by King Wen number.
@@ -237,14 +621,172 @@ impl IChingEngine {
         self.hexagrams.iter().find(|h| h.number == number)
     }
 
+    /// The hexagram whose pinyin name best matches `query`, e.g. `"qian"`
+    /// or `"xiao chu"`. Shared names resolve to the lower number.
+    pub fn get_hexagram_by_name(&self, query: &str) -> Option<&Hexagram> {
+        self.find_hexagrams_by_name(query).into_iter().next()
+    }
+
+    /// Every hexagram whose pinyin name matches `query`, best first.
+    pub fn find_hexagrams_by_name(&self, query: &str) -> Vec<&Hexagram> {
+        hexagrams_by_name(&self.hexagrams, query)
+    }
+
+    /// The hexagram whose English name best matches `query`, e.g.
+    /// `"The Creative"` or `"creative"`.
+    pub fn get_hexagram_by_english_name(&self, query: &str) -> Option<&Hexagram> {
+        hexagrams_by_english_name(&self.hexagrams, query).into_iter().next()
+    }
+
     /// Look up a trigram by number.
     pub fn get_trigram(&self, number: u32) -> Option<&Trigram> {
         self.trigrams.iter().find(|t| t.number == number)
     }
 
+    /// The trigram placed at `direction` in the given bagua arrangement.
+    pub fn trigram_for_direction(
+        &self,
+        direction: Direction,
+        arrangement: Arrangement,
+    ) -> Option<&Trigram> {
+        self.get_trigram(trigram_number_for_direction(direction, arrangement))
+    }
+
+    /// The hexagram with every line of `number` changed.
+    pub fn complement_of(&self, number: u32) -> Option<u32> {
+        self.binary_to_hexagram_number(&complement(&self.get_hexagram(number)?.binary)?)
+    }
+
+    /// Hexagram `number` turned upside down.
+    pub fn reverse_of(&self, number: u32) -> Option<u32> {
+        self.binary_to_hexagram_number(&reverse(&self.get_hexagram(number)?.binary)?)
+    }
+
+    /// How many lines differ between hexagrams `a` and `b`.
+    pub fn hamming_distance(&self, a: u32, b: u32) -> Option<u32> {
+        hamming_distance(&self.get_hexagram(a)?.binary, &self.get_hexagram(b)?.binary)
+    }
+
+    /// What hexagram `number` becomes if each single line changes, bottom
+    /// line first. `None` if there is no such hexagram.
+    pub fn line_derivatives(&self, number: u32) -> Option<Vec<LineDerivative>> {
+        let hexagram = self.get_hexagram(number)?;
+        Some(line_derivatives(&self.hexagrams, hexagram))
+    }
+
+    /// The hexagram after `number` in the King Wen order.
+    pub fn next_in_sequence(&self, number: u32) -> Option<u32> {
+        next_in_sequence(number)
+    }
+
+    /// The hexagram before `number` in the King Wen order.
+    pub fn previous_in_sequence(&self, number: u32) -> Option<u32> {
+        previous_in_sequence(number)
+    }
+
+    /// The King Wen pair `number` belongs to.
+    pub fn sequence_pair(&self, number: u32) -> Option<SequencePair> {
+        sequence_pair(&self.hexagrams, number)
+    }
+
+    /// The Fu Xi (binary) value, 0–63, of King Wen hexagram `number`.
+    pub fn king_wen_to_fu_xi(&self, number: u32) -> Option<u32> {
+        king_wen_to_fu_xi(&self.hexagrams, number)
+    }
+
+    /// The King Wen number of the hexagram with Fu Xi value `decimal`.
+    pub fn fu_xi_to_king_wen(&self, decimal: u32) -> Option<u32> {
+        fu_xi_to_king_wen(&self.hexagrams, decimal)
+    }
+
+    /// Look up a hexagram by its Fu Xi value (0–63).
+    pub fn hexagram_from_decimal(&self, decimal: u32) -> Option<&Hexagram> {
+        self.get_hexagram(self.fu_xi_to_king_wen(decimal)?)
+    }
+
+    /// Plum Blossom cast from two numbers.
+    ///
+    /// # Errors
+    /// Returns an error if either number is zero.
+    pub fn plum_blossom_from_numbers(
+        &self,
+        upper: u32,
+        lower: u32,
+    ) -> Result<PlumBlossomCast, IChingError> {
+        plum_blossom_from_numbers(&self.hexagrams, upper, lower)
+    }
+
+    /// Plum Blossom cast from a lunar date and clock hour.
+    ///
+    /// # Errors
+    /// Returns an error if the month, day or hour is out of range.
+    pub fn plum_blossom_from_lunar_date(
+        &self,
+        lunar_year: i32,
+        lunar_month: u32,
+        lunar_day: u32,
+        hour: u32,
+    ) -> Result<PlumBlossomCast, IChingError> {
+        plum_blossom_from_lunar_date(&self.hexagrams, lunar_year, lunar_month, lunar_day, hour)
+    }
+
+    /// The sovereign hexagram of the solar month holding a Gregorian date,
+    /// for framing a reading in its season.
+    ///
+    /// # Errors
+    /// Returns an error if the month or day is out of range.
+    pub fn sovereign_hexagram_for(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<SovereignHexagram, IChingError> {
+        sovereign_hexagram_for(year, month, day)
+    }
+
+    /// Stems and branches of hexagram `number`'s lines, bottom first.
+    pub fn najia(&self, number: u32) -> Option<[NajiaLine; 6]> {
+        najia(self.get_hexagram(number)?)
+    }
+
+    /// Lay out a cast as a Wen Wang Gua (Liu Yao) chart and judge the
+    /// line standing for `use_god` against the day and month.
+    ///
+    /// # Errors
+    /// Returns an error if the cast's binary patterns are not hexagrams.
+    pub fn liu_yao(
+        &self,
+        cast: &CastResult,
+        context: LiuYaoContext,
+        use_god: SixRelative,
+    ) -> Result<LiuYaoReading, IChingError> {
+        liu_yao(cast, context, use_god)
+    }
+
     /// Convert a binary pattern to a hexagram number.
     pub fn binary_to_hexagram_number(&self, binary: &str) -> Option<u32> {
-        self.binary_to_number.get(binary).copied()
+        binary_to_mask(binary).map(|m| self.patterns[usize::from(m)])
+    }
+
+    /// The hexagram formed by trigram `upper` above trigram `lower`, e.g.
+    /// thunder (3) over lake (8) for Gui Mei.
+    ///
+    /// # Errors
+    /// Returns [`IChingError::InvalidValue`] if either is not a trigram
+    /// number (1–8).
+    pub fn hexagram_from_trigrams(&self, upper: u32, lower: u32) -> Result<&Hexagram, IChingError> {
+        for (field, value) in [("upper trigram", upper), ("lower trigram", lower)] {
+            if self.get_trigram(value).is_none() {
+                return Err(IChingError::InvalidValue { field, value });
+            }
+        }
+        self.hexagrams
+            .iter()
+            .find(|h| h.top_trigram == upper && h.bottom_trigram == lower)
+            .ok_or_else(|| {
+                let detail = format!("no hexagram for trigrams {} over {}", upper, lower);
+                IChingError::DataCorrupt(detail)
+            })
     }
 
     /// Get the lower (bottom) trigram of a hexagram.
@@ -256,6 +798,119 @@ impl IChingEngine {
     pub fn get_upper_trigram(&self, hexagram: &Hexagram) -> Option<&Trigram> {
         self.get_trigram(hexagram.top_trigram)
     }
+
+    /// Look up the strategy-profile text for a hexagram.
+    pub fn get_strategy_text(&self, number: u32) -> Option<&StrategyText> {
+        self.strategy.iter().find(|t| t.number == number)
+    }
+
+    /// Attach full hexagram data and changing-line texts to a cast.
+    /// Returns `None` if the cast refers to missing data.
+    pub fn resolve(&self, cast: &CastResult) -> Option<ResolvedCast> {
+        resolve(&self.hexagrams, cast)
+    }
+
+    /// Resolve a hand-entered cast in one step.
+    ///
+    /// # Errors
+    /// Returns [`IChingError::InvalidLine`] for the first value out of range.
+    pub fn cast_resolved_from_lines(&self, lines: &[u8; 6]) -> Result<ResolvedCast, IChingError> {
+        let cast = self.cast_from_lines(lines)?;
+        self.resolve(&cast)
+            .ok_or_else(|| IChingError::DataCorrupt(format!("cannot resolve {}", cast.binary)))
+    }
+
+    /// Gather a cast's judgment, image, changing-line texts and resulting
+    /// judgment for display. Returns `None` if the cast refers to missing
+    /// data.
+    pub fn interpret(&self, cast: &CastResult) -> Option<CastInterpretation> {
+        interpret(&self.hexagrams, cast)
+    }
+
+    /// A journal prompt built from `cast`, dated `date`. Returns `None` if
+    /// the cast refers to missing data.
+    pub fn reflection_prompt(&self, cast: &CastResult, date: &str) -> Option<ReflectionPrompt> {
+        reflection_prompt(&self.hexagrams, cast, date)
+    }
+
+    /// A journal prompt for the hexagram of a calendar date, optionally
+    /// personalised by a user id.
+    ///
+    /// # Errors
+    /// Returns an error if the month or day is out of range.
+    pub fn daily_reflection_prompt(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        user_seed: Option<&str>,
+    ) -> Result<ReflectionPrompt, IChingError> {
+        let tosses = daily::daily_tosses(year, month, day, user_seed)?;
+        let cast = resolve_tosses(&tosses, CastMethod::QuestionHash, &self.patterns);
+        let date = format!("{:04}-{:02}-{:02}", year, month, day);
+        reflection_prompt(&self.hexagrams, &cast, &date).ok_or_else(|| {
+            IChingError::DataCorrupt(format!("no text for hexagram {}", cast.hexagram_number))
+        })
+    }
+
+    /// Select the judgment and line texts that apply to `cast` under
+    /// `rule`. Returns `None` if the cast refers to missing data.
+    pub fn interpret_cast(
+        &self,
+        cast: &CastResult,
+        rule: ChangingLineRule,
+    ) -> Option<ChangingLineReading> {
+        interpret_cast(&self.hexagrams, cast, rule)
+    }
+
+    /// Read a cast through the chosen interpretation profile.
+    ///
+    /// Returns `None` if the cast refers to a hexagram missing from the
+    /// loaded data.
+    pub fn interpret_with_profile(
+        &self,
+        cast: &CastResult,
+        profile: InterpretationProfile,
+    ) -> Option<ProfiledInterpretation> {
+        let transformed_number = cast.transformed_hexagram_number;
+
+        match profile {
+            InterpretationProfile::Traditional => {
+                let hex = self.get_hexagram(cast.hexagram_number)?;
+                let transformed_headline = match transformed_number {
+                    Some(n) => Some(self.get_hexagram(n)?.judgment.clone()),
+                    None => None,
+                };
+                Some(ProfiledInterpretation {
+                    profile,
+                    hexagram_number: hex.number,
+                    headline: hex.judgment.clone(),
+                    guidance: hex.image.clone(),
+                    timing: None,
+                    risk_posture: None,
+                    transformed_hexagram_number: transformed_number,
+                    transformed_headline,
+                })
+            }
+            InterpretationProfile::Strategy => {
+                let text = self.get_strategy_text(cast.hexagram_number)?;
+                let transformed_headline = match transformed_number {
+                    Some(n) => Some(self.get_strategy_text(n)?.situation.clone()),
+                    None => None,
+                };
+                Some(ProfiledInterpretation {
+                    profile,
+                    hexagram_number: text.number,
+                    headline: text.situation.clone(),
+                    guidance: text.guidance.clone(),
+                    timing: Some(text.timing.clone()),
+                    risk_posture: Some(text.risk_posture.clone()),
+                    transformed_hexagram_number: transformed_number,
+                    transformed_headline,
+                })
+            }
+        }
+    }
 }
 
 impl Default for IChingEngine {
@@ -270,19 +925,19 @@ mod tests {
 
     #[test]
     fn loads_64_hexagrams() {
-        let hexagrams = load_hexagrams();
+        let hexagrams = load_hexagrams().unwrap();
         assert_eq!(hexagrams.len(), 64);
     }
 
     #[test]
     fn loads_8_trigrams() {
-        let trigrams = load_trigrams();
+        let trigrams = load_trigrams().unwrap();
         assert_eq!(trigrams.len(), 8);
     }
 
     #[test]
     fn cast_hexagram_produces_valid_result() {
-        let result = cast_hexagram();
+        let result = cast_hexagram().unwrap();
         assert_eq!(result.lines.len(), 6);
         for &line in &result.lines {
             assert!(
@@ -318,6 +973,117 @@ mod tests {
         assert_eq!(n, 2); // Kun / The Receptive
     }
 
+    #[test]
+    fn strategy_pack_covers_all_hexagrams() {
+        let strategy = load_strategy().unwrap();
+        assert_eq!(strategy.len(), 64);
+        assert_eq!(get_strategy_text(33).unwrap().risk_posture, "defensive");
+    }
+
+    #[test]
+    fn interpret_with_strategy_profile() {
+        let engine = IChingEngine::new();
+        let cast = CastResult {
+            lines: vec![9, 7, 7, 7, 7, 7],
+            changing_lines: vec![1],
+            hexagram_number: 1,
+            transformed_hexagram_number: Some(44),
+            binary: "111111".to_string(),
+            transformed_binary: Some("011111".to_string()),
+            glyph: "䷀".to_string(),
+            transformed_glyph: Some("䷫".to_string()),
+            method: CastMethod::Manual,
+            tosses: Vec::new(),
+        };
+
+        let strategy = engine
+            .interpret_with_profile(&cast, InterpretationProfile::Strategy)
+            .unwrap();
+        assert_eq!(strategy.risk_posture.as_deref(), Some("assertive"));
+        assert_eq!(strategy.transformed_hexagram_number, Some(44));
+        assert!(strategy.transformed_headline.is_some());
+
+        let traditional = engine
+            .interpret_with_profile(&cast, InterpretationProfile::Traditional)
+            .unwrap();
+        assert!(traditional.timing.is_none());
+        assert!(traditional.headline.starts_with("The Creative"));
+    }
+
+    #[test]
+    fn seeded_casts_replay() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.cast_hexagram_seeded(7), engine.cast_hexagram_seeded(7));
+        let free = cast_hexagram_with_rng(&mut seeded_rng(7)).unwrap();
+        assert_eq!(free, engine.cast_hexagram_seeded(7));
+        let casts: Vec<CastResult> = (0..10).map(|s| engine.cast_hexagram_seeded(s)).collect();
+        assert!(casts.iter().any(|c| c != &casts[0]));
+    }
+
+    #[test]
+    fn manual_cast_entry() {
+        let cast = cast_from_lines(&[9, 7, 7, 7, 7, 7]).unwrap();
+        assert_eq!(cast.hexagram_number, 1);
+        assert_eq!(cast.changing_lines, vec![1]);
+        assert_eq!(cast.transformed_hexagram_number, Some(44));
+
+        let engine = IChingEngine::new();
+        let still = engine.cast_from_lines(&[8; 6]).unwrap();
+        assert_eq!(still.hexagram_number, 2);
+        assert!(still.transformed_hexagram_number.is_none());
+
+        assert_eq!(
+            engine.cast_from_lines(&[7, 7, 5, 7, 10, 7]).unwrap_err(),
+            IChingError::InvalidLine { position: 3, value: 5 }
+        );
+    }
+
+    #[test]
+    fn hexagram_from_trigram_pair() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.hexagram_from_trigrams(3, 8).unwrap().number, 54);
+        assert_eq!(engine.hexagram_from_trigrams(2, 1).unwrap().number, 11);
+        for upper in 1..=8 {
+            for lower in 1..=8 {
+                let hex = engine.hexagram_from_trigrams(upper, lower).unwrap();
+                assert_eq!((hex.top_trigram, hex.bottom_trigram), (upper, lower));
+            }
+        }
+        assert_eq!(
+            engine.hexagram_from_trigrams(9, 1).unwrap_err(),
+            IChingError::InvalidValue { field: "upper trigram", value: 9 }
+        );
+    }
+
+    #[test]
+    fn embedded_data_validates() {
+        assert!(IChingEngine::try_new().is_ok());
+        let mut hexagrams = load_hexagrams().unwrap();
+        let trigrams = load_trigrams().unwrap();
+        hexagrams[1].binary = hexagrams[0].binary.clone();
+        hexagrams[2].top_trigram = 9;
+        match validate_data(&hexagrams, &trigrams, &[]) {
+            Err(IChingError::DataCorrupt(detail)) => {
+                assert!(detail.contains("pattern 111111 appears twice"));
+                assert!(detail.contains("trigram 9"));
+            }
+            other => panic!("expected DataCorrupt, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn typed_lookup_errors() {
+        assert_eq!(
+            get_hexagram(65).unwrap_err(),
+            IChingError::InvalidNumber { kind: "hexagram", number: 65, max: 64 }
+        );
+        assert!(matches!(get_trigram(0), Err(IChingError::InvalidNumber { kind: "trigram", .. })));
+        assert_eq!(
+            binary_to_hexagram_number("11x111"),
+            Err(IChingError::InvalidBinary("11x111".to_string()))
+        );
+    }
+
     #[test]
     fn engine_cast_hexagram() {
         let engine = IChingEngine::new();
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use crate::types::{Arrangement, Direction};
+
+// ---------------------------------------------------------------------------
+// Bagua arrangements
+// ---------------------------------------------------------------------------
+//
+// The Earlier Heaven (Fu Xi) bagua sets Qian in the south facing Kun in the
+// north, so every trigram sits across the circle from its complement. The
+// Later Heaven (King Wen) bagua follows the seasons round from Zhen in the
+// east; it is the arrangement feng shui reads, with Li in the south.
+// Trigrams are numbered as in `trigrams.json`: Qian 1, Kun 2, Zhen 3,
+// Xun 4, Kan 5, Li 6, Gen 7, Dui 8.
+
+/// Trigram numbers by direction, in the order of [`Direction::ALL`].
+const EARLIER_HEAVEN: [u32; 8] = [2, 3, 6, 8, 1, 4, 5, 7];
+const LATER_HEAVEN: [u32; 8] = [5, 7, 3, 4, 6, 2, 8, 1];
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Self::North,
+        Self::Northeast,
+        Self::East,
+        Self::Southeast,
+        Self::South,
+        Self::Southwest,
+        Self::West,
+        Self::Northwest,
+    ];
+
+    /// Lowercase name, as in `trigrams.json`.
+    pub fn as_str(self) -> &'static str {
+        [
+            "north",
+            "northeast",
+            "east",
+            "southeast",
+            "south",
+            "southwest",
+            "west",
+            "northwest",
+        ][self as usize]
+    }
+
+    /// Compass bearing in degrees, north being 0 and east 90.
+    pub fn bearing(self) -> u32 {
+        self as u32 * 45
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn layout(arrangement: Arrangement) -> &'static [u32; 8] {
+    match arrangement {
+        Arrangement::EarlierHeaven => &EARLIER_HEAVEN,
+        Arrangement::LaterHeaven => &LATER_HEAVEN,
+    }
+}
+
+/// Number of the trigram placed at `direction`.
+pub fn trigram_number_for_direction(direction: Direction, arrangement: Arrangement) -> u32 {
+    layout(arrangement)[direction as usize]
+}
+
+/// Where trigram `number` sits; `None` for a number outside 1–8.
+pub fn direction_of_trigram(number: u32, arrangement: Arrangement) -> Option<Direction> {
+    let index = layout(arrangement).iter().position(|&n| n == number)?;
+    Some(Direction::ALL[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{complement, IChingEngine};
+
+    #[test]
+    fn later_heaven_matches_trigram_data() {
+        let engine = IChingEngine::new();
+        for number in 1..=8 {
+            let trigram = engine.get_trigram(number).unwrap();
+            let direction = direction_of_trigram(number, Arrangement::LaterHeaven).unwrap();
+            assert_eq!(trigram.direction, direction.as_str());
+        }
+        let southeast = engine.trigram_for_direction(Direction::Southeast, Arrangement::default());
+        assert_eq!(southeast.map(|t| t.name.as_str()), Some("Xun"));
+        assert_eq!(direction_of_trigram(9, Arrangement::LaterHeaven), None);
+    }
+
+    #[test]
+    fn earlier_heaven_faces_complements() {
+        let engine = IChingEngine::new();
+        let at = |d| engine.trigram_for_direction(d, Arrangement::EarlierHeaven).unwrap();
+        assert_eq!(at(Direction::South).name, "Qian");
+        for d in Direction::ALL {
+            // Complementing the six-line doubled trigram flips all three lines
+            let doubled = at(d).binary.repeat(2);
+            assert_eq!(complement(&doubled), Some(at(d.opposite()).binary.repeat(2)));
+        }
+        assert_eq!(Direction::Southwest.bearing(), 225);
+        assert_eq!(serde_json::to_value(Direction::Northwest).unwrap(), "northwest");
+    }
+}
@@ -0,0 +1,89 @@
+// ---------------------------------------------------------------------------
+// Binary pattern utilities
+// ---------------------------------------------------------------------------
+//
+// Patterns are six characters, bottom line first, '1' for a solid line. As
+// a bitmask, bit 0 is the bottom line, so line N is bit N-1. (The Fu Xi
+// value in `relations` reads the other way, bottom line most significant.)
+
+/// Bitmask of a six-line pattern; `None` unless it is six '0'/'1' characters.
+pub fn binary_to_mask(binary: &str) -> Option<u8> {
+    if binary.len() != 6 {
+        return None;
+    }
+    binary.bytes().enumerate().try_fold(0u8, |mask, (i, b)| match b {
+        b'1' => Some(mask | 1 << i),
+        b'0' => Some(mask),
+        _ => None,
+    })
+}
+
+/// Six-line pattern of a bitmask; `None` if bits above the sixth are set.
+pub fn mask_to_binary(mask: u8) -> Option<String> {
+    (mask < 64).then(|| (0..6).map(|i| if mask & 1 << i != 0 { '1' } else { '0' }).collect())
+}
+
+/// Every line changed: yin for yang and yang for yin.
+pub fn complement(binary: &str) -> Option<String> {
+    mask_to_binary(!binary_to_mask(binary)? & 0b11_1111)
+}
+
+/// The figure turned upside down.
+pub fn reverse(binary: &str) -> Option<String> {
+    binary_to_mask(binary)?;
+    Some(binary.chars().rev().collect())
+}
+
+/// Lines that differ between two patterns, as a pattern.
+pub fn xor(a: &str, b: &str) -> Option<String> {
+    mask_to_binary(binary_to_mask(a)? ^ binary_to_mask(b)?)
+}
+
+/// How many lines differ between two patterns (0–6).
+pub fn hamming_distance(a: &str, b: &str) -> Option<u32> {
+    Some((binary_to_mask(a)? ^ binary_to_mask(b)?).count_ones())
+}
+
+/// 1-based positions of the lines that differ, bottom first: the changing
+/// lines that turn `a` into `b`.
+pub fn differing_lines(a: &str, b: &str) -> Option<Vec<usize>> {
+    let diff = binary_to_mask(a)? ^ binary_to_mask(b)?;
+    Some((0..6).filter(|i| diff & 1 << i != 0).map(|i| i + 1).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn masks_round_trip() {
+        assert_eq!(binary_to_mask("100000"), Some(1));
+        assert_eq!(binary_to_mask("000001"), Some(32));
+        assert_eq!(binary_to_mask("10000"), None);
+        assert_eq!(binary_to_mask("10000x"), None);
+        assert!((0..64).all(|m| binary_to_mask(&mask_to_binary(m).unwrap()) == Some(m)));
+        assert_eq!(mask_to_binary(64), None);
+    }
+
+    #[test]
+    fn pattern_operations() {
+        assert_eq!(complement("111000").as_deref(), Some("000111"));
+        assert_eq!(reverse("100010").as_deref(), Some("010001"));
+        assert_eq!(xor("111111", "011111").as_deref(), Some("100000"));
+        assert_eq!(hamming_distance("111111", "000000"), Some(6));
+        assert_eq!(differing_lines("111111", "011110"), Some(vec![1, 6]));
+    }
+
+    #[test]
+    fn engine_relations_by_number() {
+        let engine = IChingEngine::new();
+        // Tai (11) and Pi (12) are each other's complement and reverse
+        assert_eq!(engine.complement_of(11), Some(12));
+        assert_eq!(engine.reverse_of(11), Some(12));
+        // Zhun (3) reversed is Meng (4)
+        assert_eq!(engine.reverse_of(3), Some(4));
+        assert_eq!(engine.hamming_distance(1, 44), Some(1));
+        assert_eq!(engine.complement_of(65), None);
+    }
+}
@@ -0,0 +1,111 @@
+use rand::Rng;
+
+use crate::types::{CastMethod, CastResult, LineToss};
+
+use super::{methods, resolve_tosses, IChingError, PatternTable};
+
+// ---------------------------------------------------------------------------
+// Transformation chains
+// ---------------------------------------------------------------------------
+//
+// A chain starts from a cast and, while the latest cast has changing lines,
+// casts again from the hexagram it became. Each new line keeps the
+// polarity of the line it continues; only whether it moves is tossed
+// again, by re-tossing until the toss has the right polarity. That keeps
+// each method's odds: with three coins a line moves one time in four,
+// with yarrow odds a yang line three times in eight and a yin line one
+// time in eight.
+
+/// Iterator over a chain of casts, ending with the first cast that has no
+/// changing lines. Created by
+/// [`IChingEngine::transformation_chain`](super::IChingEngine::transformation_chain).
+pub struct TransformationChain<'a, R: Rng + ?Sized> {
+    table: &'a PatternTable,
+    method: CastMethod,
+    rng: &'a mut R,
+    next: Option<CastResult>,
+}
+
+impl<'a, R: Rng + ?Sized> TransformationChain<'a, R> {
+    /// # Errors
+    /// Returns [`IChingError::MethodNeedsInput`] for manual and Plum Blossom
+    /// methods, which cannot continue a chain on their own.
+    pub(crate) fn new(
+        table: &'a PatternTable,
+        method: CastMethod,
+        first: CastResult,
+        rng: &'a mut R,
+    ) -> Result<Self, IChingError> {
+        if methods::line_probabilities(method).is_none() {
+            return Err(IChingError::MethodNeedsInput(method));
+        }
+        Ok(Self {
+            table,
+            method,
+            rng,
+            next: Some(first),
+        })
+    }
+
+    fn continue_from(&mut self, binary: &str) -> Option<CastResult> {
+        let mut tosses = [LineToss::Token { number: 0 }; 6];
+        for (toss, bit) in tosses.iter_mut().zip(binary.bytes()) {
+            let yang = bit == b'1';
+            *toss = loop {
+                let t = methods::toss(self.method, self.rng)?;
+                if (t.value() % 2 == 1) == yang {
+                    break t;
+                }
+            };
+        }
+        Some(resolve_tosses(&tosses, self.method, self.table))
+    }
+}
+
+impl<R: Rng + ?Sized> Iterator for TransformationChain<'_, R> {
+    type Item = CastResult;
+
+    fn next(&mut self) -> Option<CastResult> {
+        let current = self.next.take()?;
+        if let Some(binary) = &current.transformed_binary {
+            self.next = self.continue_from(binary);
+        }
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{seeded_rng, IChingEngine};
+
+    #[test]
+    fn chains_follow_each_transformation_and_end_still() {
+        let engine = IChingEngine::new();
+        let mut rng = seeded_rng(5);
+        for _ in 0..20 {
+            let first = engine.cast_from_lines(&[9, 6, 7, 8, 7, 8]).unwrap();
+            let chain: Vec<CastResult> = engine
+                .transformation_chain(first, CastMethod::Coins, &mut rng)
+                .unwrap()
+                .take(200)
+                .collect();
+            assert!(chain.len() >= 2);
+            for pair in chain.windows(2) {
+                assert_eq!(pair[0].transformed_binary.as_ref(), Some(&pair[1].binary));
+            }
+            assert!(chain.last().unwrap().changing_lines.is_empty());
+        }
+    }
+
+    #[test]
+    fn still_casts_end_at_once() {
+        let engine = IChingEngine::new();
+        let first = engine.cast_from_lines(&[7; 6]).unwrap();
+        let mut rng = seeded_rng(1);
+        let chain = engine.transformation_chain(first, CastMethod::TwoCoin, &mut rng).unwrap();
+        assert_eq!(chain.count(), 1);
+        let first = engine.cast_from_lines(&[7; 6]).unwrap();
+        assert!(engine.transformation_chain(first, CastMethod::Manual, &mut rng).is_err());
+    }
+}
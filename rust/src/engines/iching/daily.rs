@@ -0,0 +1,141 @@
+use crate::engines::calendar::days_in_month;
+use crate::identity;
+use crate::types::{CastResult, ChangingLineRule, Hexagram, LineToss, ReflectionPrompt};
+
+use super::question::hashed_coins;
+use super::rules::interpret_cast;
+use super::IChingError;
+
+// ---------------------------------------------------------------------------
+// Hexagram of the day and journal prompts
+// ---------------------------------------------------------------------------
+//
+// Like the tarot card of the day, the hexagram is hashed from the date and
+// an optional user seed rather than drawn from an RNG, so every
+// implementation gives the same cast. The coins are read from the hash as
+// in question casting. The prompt pairs the hexagram's keywords with the
+// text Zhu Xi's rules weigh most and asks a question about each.
+
+/// The coins for a calendar date, optionally personalised by a user id.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] for a month outside 1–12 or a day
+/// the month does not have.
+pub(crate) fn daily_tosses(
+    year: i32,
+    month: u32,
+    day: u32,
+    user_seed: Option<&str>,
+) -> Result<[LineToss; 6], IChingError> {
+    if !(1..=12).contains(&month) {
+        return Err(IChingError::InvalidValue { field: "month", value: month });
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(IChingError::InvalidValue { field: "day", value: day });
+    }
+    // Prefixed so the hexagram does not track the tarot card of the day
+    let key = format!("iching\0{:04}-{:02}-{:02}\0{}", year, month, day, user_seed.unwrap_or(""));
+    Ok(hashed_coins(identity::fnv1a_64(key.as_bytes())))
+}
+
+/// Build a reflection prompt for `cast` on `date`. Returns `None` if the
+/// cast refers to a hexagram missing from `hexagrams`.
+pub fn reflection_prompt(
+    hexagrams: &[Hexagram],
+    cast: &CastResult,
+    date: &str,
+) -> Option<ReflectionPrompt> {
+    let find = |n: u32| hexagrams.iter().find(|h| h.number == n);
+    let hexagram = find(cast.hexagram_number)?;
+    let transformed = match cast.transformed_hexagram_number {
+        Some(n) => Some(find(n)?),
+        None => None,
+    };
+    let reading = interpret_cast(hexagrams, cast, ChangingLineRule::ZhuXi)?;
+    let focus = reading.texts.into_iter().find(|t| t.principal)?;
+
+    let theme = format!(
+        "Today's hexagram is {} {} ({}){}.",
+        hexagram.number,
+        hexagram.name,
+        hexagram.english_name,
+        transformed.map_or(String::new(), |t| format!(", moving toward {}", t.english_name))
+    );
+    let mut questions = Vec::with_capacity(3);
+    match hexagram.keywords.as_slice() {
+        [] => {}
+        [only] => questions.push(format!("Where does {} ask for your attention today?", only)),
+        [first, second, ..] => questions.push(format!(
+            "Where do {} and {} ask for your attention today?",
+            first, second
+        )),
+    }
+    questions.push(match focus.line {
+        Some(line) => format!(
+            "Line {} reads: \"{}\" What would acting on it look like today?",
+            line, focus.text
+        ),
+        None => format!("The judgment reads: \"{}\" What does it ask of you today?", focus.text),
+    });
+    questions.push(match transformed {
+        Some(t) => format!(
+            "The situation is becoming {}. What are you ready to let change?",
+            t.english_name
+        ),
+        None => "No lines are changing. What deserves steady attention rather than action?"
+            .to_string(),
+    });
+
+    Some(ReflectionPrompt {
+        date: date.to_string(),
+        cast: cast.clone(),
+        hexagram_number: hexagram.number,
+        name: hexagram.name.clone(),
+        english_name: hexagram.english_name.clone(),
+        glyph: cast.glyph.clone(),
+        keywords: hexagram.keywords.clone(),
+        focus,
+        transformed_name: transformed.map(|t| t.name.clone()),
+        theme,
+        questions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{hexagram_of_the_day, IChingEngine};
+    use crate::types::CastMethod;
+
+    #[test]
+    fn hexagram_of_the_day_is_stable_per_date_and_user() {
+        let first = hexagram_of_the_day(2024, 3, 5, None).unwrap();
+        assert_eq!(first, hexagram_of_the_day(2024, 3, 5, None).unwrap());
+        assert_eq!(first.method, CastMethod::QuestionHash);
+        let days: Vec<u32> = (1..=10)
+            .map(|d| hexagram_of_the_day(2024, 3, d, Some("user-1")).unwrap().hexagram_number)
+            .collect();
+        assert!(days.iter().any(|&n| n != days[0]));
+        assert!(hexagram_of_the_day(2024, 0, 5, None).is_err());
+        assert!(hexagram_of_the_day(2023, 2, 29, None).is_err());
+    }
+
+    #[test]
+    fn prompt_follows_the_changing_line() {
+        let engine = IChingEngine::new();
+        // Fu with its first line changing
+        let cast = engine.cast_from_lines(&[9, 8, 8, 8, 8, 8]).unwrap();
+        let prompt = engine.reflection_prompt(&cast, "2024-12-21").unwrap();
+        assert_eq!(prompt.hexagram_number, 24);
+        assert_eq!(prompt.focus.line, Some(1));
+        assert!(prompt.transformed_name.is_some());
+        assert_eq!(prompt.questions.len(), 3);
+        assert!(prompt.questions[1].starts_with("Line 1 reads"));
+        assert!(reflection_prompt(&[], &cast, "2024-12-21").is_none());
+
+        let daily = engine.daily_reflection_prompt(2024, 12, 21, Some("user-1")).unwrap();
+        assert_eq!(daily.date, "2024-12-21");
+        assert!(daily.theme.contains(&daily.english_name));
+        assert!(!daily.keywords.is_empty());
+    }
+}
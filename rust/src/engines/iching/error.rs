@@ -0,0 +1,66 @@
+use std::fmt;
+
+use crate::types::CastMethod;
+
+// ---------------------------------------------------------------------------
+// IChingError — failures reported by the I Ching engine
+// ---------------------------------------------------------------------------
+
+/// Why an I Ching operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IChingError {
+    /// A line value other than 6, 7, 8 or 9 at a 1-based position
+    InvalidLine { position: usize, value: u8 },
+    /// An input number outside the range a method accepts
+    InvalidValue { field: &'static str, value: u32 },
+    /// Manual and Plum Blossom casts take their lines from the querent
+    MethodNeedsInput(CastMethod),
+    /// A pattern that is not six '0'/'1' characters
+    InvalidBinary(String),
+    /// A hexagram or trigram number outside `1..=max`
+    InvalidNumber { kind: &'static str, number: u32, max: u32 },
+    /// The embedded or loaded text data is inconsistent
+    DataCorrupt(String),
+    /// JSON that could not be parsed or produced
+    Json { context: String, message: String },
+    Io { path: String, message: String },
+}
+
+impl IChingError {
+    pub(crate) fn json(context: &str, err: serde_json::Error) -> Self {
+        Self::Json {
+            context: context.to_string(),
+            message: err.to_string(),
+        }
+    }
+
+    pub(crate) fn io(path: &std::path::Path, err: std::io::Error) -> Self {
+        Self::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for IChingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLine { position, value } => {
+                write!(f, "line {} has value {}; expected 6, 7, 8 or 9", position, value)
+            }
+            Self::InvalidValue { field, value } => write!(f, "invalid {}: {}", field, value),
+            Self::MethodNeedsInput(method) => {
+                write!(f, "the {:?} method needs lines or numbers from the querent", method)
+            }
+            Self::InvalidBinary(binary) => write!(f, "invalid hexagram pattern: '{}'", binary),
+            Self::InvalidNumber { kind, number, max } => {
+                write!(f, "{} number {} not found (valid range: 1-{})", kind, number, max)
+            }
+            Self::DataCorrupt(detail) => write!(f, "corrupt I Ching data: {}", detail),
+            Self::Json { context, message } => write!(f, "invalid {}: {}", context, message),
+            Self::Io { path, message } => write!(f, "failed to read {}: {}", path, message),
+        }
+    }
+}
+
+impl std::error::Error for IChingError {}
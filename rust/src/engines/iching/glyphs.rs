@@ -0,0 +1,67 @@
+use crate::types::{Hexagram, Trigram};
+
+// ---------------------------------------------------------------------------
+// Unicode glyphs
+// ---------------------------------------------------------------------------
+//
+// The Yijing Hexagram Symbols block (U+4DC0–U+4DFF) follows the King Wen
+// order. The trigram block (U+2630–U+2637) runs ☰ ☱ ☲ ☳ ☴ ☵ ☶ ☷, which is
+// 7 minus the trigram's bottom-first binary value.
+
+/// The Unicode symbol for King Wen hexagram `number` (1–64).
+pub fn hexagram_glyph(number: u32) -> Option<char> {
+    if !(1..=64).contains(&number) {
+        return None;
+    }
+    char::from_u32(0x4DC0 + number - 1)
+}
+
+/// The Unicode symbol for a three-line binary pattern, bottom line first.
+pub fn trigram_glyph(binary: &str) -> Option<char> {
+    if binary.len() != 3 || !binary.bytes().all(|b| b == b'0' || b == b'1') {
+        return None;
+    }
+    let value = u32::from_str_radix(binary, 2).ok()?;
+    char::from_u32(0x2630 + 7 - value)
+}
+
+impl Hexagram {
+    pub fn unicode_char(&self) -> char {
+        hexagram_glyph(self.number).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+}
+
+impl Trigram {
+    pub fn unicode_char(&self) -> char {
+        trigram_glyph(&self.binary).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{cast_from_lines, IChingEngine};
+
+    #[test]
+    fn glyphs_match_the_embedded_characters() {
+        let engine = IChingEngine::new();
+        for n in 1..=64 {
+            let hex = engine.get_hexagram(n).unwrap();
+            assert_eq!(hex.unicode_char().to_string(), hex.character);
+        }
+        for n in 1..=8 {
+            let tri = engine.get_trigram(n).unwrap();
+            assert_eq!(tri.unicode_char().to_string(), tri.character);
+        }
+        assert_eq!(hexagram_glyph(0), None);
+        assert_eq!(trigram_glyph("1111"), None);
+    }
+
+    #[test]
+    fn casts_serialize_their_glyphs() {
+        let cast = cast_from_lines(&[9, 7, 7, 7, 7, 7]).unwrap();
+        let json = serde_json::to_value(&cast).unwrap();
+        assert_eq!(json["glyph"], "䷀");
+        assert_eq!(json["transformed_glyph"], "䷫");
+    }
+}
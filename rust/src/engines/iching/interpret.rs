@@ -0,0 +1,97 @@
+use crate::types::{CastInterpretation, CastResult, Hexagram, HexagramLine, ResolvedCast};
+
+// ---------------------------------------------------------------------------
+// Cast interpretation
+// ---------------------------------------------------------------------------
+
+fn changing_line_texts(hexagram: &Hexagram, cast: &CastResult) -> Option<Vec<HexagramLine>> {
+    cast.changing_lines
+        .iter()
+        .map(|&p| hexagram.lines.iter().find(|l| l.position as usize == p).cloned())
+        .collect()
+}
+
+/// Attach the primary hexagram, its changing-line texts and the resulting
+/// hexagram to `cast`. Returns `None` if the cast refers to a hexagram or
+/// line missing from `hexagrams`.
+pub fn resolve(hexagrams: &[Hexagram], cast: &CastResult) -> Option<ResolvedCast> {
+    let find = |n: u32| hexagrams.iter().find(|h| h.number == n).cloned();
+    let hexagram = find(cast.hexagram_number)?;
+    let transformed = match cast.transformed_hexagram_number {
+        Some(n) => Some(find(n)?),
+        None => None,
+    };
+    Some(ResolvedCast {
+        cast: cast.clone(),
+        changing_lines: changing_line_texts(&hexagram, cast)?,
+        hexagram,
+        transformed,
+    })
+}
+
+/// Gather the primary hexagram's judgment and image, the text of each
+/// changing line and the resulting hexagram's judgment into one object.
+/// Returns `None` if the cast refers to a hexagram or line missing from
+/// `hexagrams`.
+pub fn interpret(hexagrams: &[Hexagram], cast: &CastResult) -> Option<CastInterpretation> {
+    let find = |n: u32| hexagrams.iter().find(|h| h.number == n);
+    let primary = find(cast.hexagram_number)?;
+    let transformed = match cast.transformed_hexagram_number {
+        Some(n) => Some(find(n)?),
+        None => None,
+    };
+    let changing_lines = changing_line_texts(primary, cast)?;
+
+    Some(CastInterpretation {
+        hexagram_number: primary.number,
+        name: primary.name.clone(),
+        english_name: primary.english_name.clone(),
+        glyph: primary.character.clone(),
+        judgment: primary.judgment.clone(),
+        image: primary.image.clone(),
+        changing_lines,
+        transformed_hexagram_number: transformed.map(|h| h.number),
+        transformed_name: transformed.map(|h| h.english_name.clone()),
+        transformed_judgment: transformed.map(|h| h.judgment.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn composes_primary_lines_and_result() {
+        let engine = IChingEngine::new();
+        let cast = engine.cast_from_lines(&[9, 7, 7, 7, 7, 9]).unwrap();
+        let reading = engine.interpret(&cast).unwrap();
+        assert_eq!(reading.english_name, "The Creative");
+        assert_eq!(reading.glyph, "䷀");
+        let positions: Vec<u32> = reading.changing_lines.iter().map(|l| l.position).collect();
+        assert_eq!(positions, [1, 6]);
+        assert_eq!(reading.changing_lines[0].text, "Hidden dragon. Do not act.");
+        assert_eq!(reading.transformed_hexagram_number, cast.transformed_hexagram_number);
+        assert!(reading.transformed_judgment.is_some());
+        assert!(interpret(&[], &cast).is_none());
+
+        let still = engine.interpret(&engine.cast_from_lines(&[7; 6]).unwrap()).unwrap();
+        assert!(still.changing_lines.is_empty());
+        assert!(still.transformed_name.is_none());
+    }
+
+    #[test]
+    fn resolved_casts_carry_full_hexagrams() {
+        let engine = IChingEngine::new();
+        let resolved = engine.cast_resolved_from_lines(&[7, 8, 7, 7, 7, 9]).unwrap();
+        assert_eq!(resolved.hexagram.number, resolved.cast.hexagram_number);
+        assert_eq!(resolved.changing_lines.len(), 1);
+        assert_eq!(resolved.changing_lines[0].position, 6);
+        let transformed = resolved.transformed.unwrap();
+        assert_eq!(Some(transformed.number), resolved.cast.transformed_hexagram_number);
+        assert_eq!(transformed.lines.len(), 6);
+
+        let random = engine.resolve(&engine.cast_hexagram()).unwrap();
+        assert_eq!(random.changing_lines.len(), random.cast.changing_lines.len());
+    }
+}
@@ -0,0 +1,316 @@
+use crate::engines::astrology::to_julian_day;
+use crate::engines::calendar::{cycle_parts, day_cycle, solar_month_branch};
+use crate::types::{
+    CastResult, EarthlyBranch, FivePhase, HeavenlyStem, Indication, LiuYaoContext, LiuYaoLine,
+    LiuYaoReading, MonthStrength, SixRelative, SixSpirit,
+};
+
+use super::najia::najia_for_binary;
+use super::relations::flip_line;
+use super::IChingError;
+
+// ---------------------------------------------------------------------------
+// Wen Wang Gua (Liu Yao)
+// ---------------------------------------------------------------------------
+//
+// Each hexagram belongs to one of eight palaces, headed by a doubled
+// trigram. Starting from the head, changing lines 1 to 5 in turn gives the
+// first to fifth generations; changing line 4 back gives the "wandering
+// soul" and restoring the lower trigram the "returning soul". The
+// generation fixes the self and response lines, and the palace's phase
+// fixes each line's relative.
+//
+// Judgement is simplified to the month and day: a use-god line gains from
+// a month of its own or a producing phase and from a supporting day, and
+// loses from a clashing month, a harming day or being void.
+
+const PALACE_HEADS: [&str; 8] = ["111", "000", "100", "011", "010", "101", "001", "110"];
+
+/// Self and response lines by generation: head, 1st–5th, wandering,
+/// returning.
+const SELF_RESPONSE: [(u32, u32); 8] =
+    [(6, 3), (1, 4), (2, 5), (3, 6), (4, 1), (5, 2), (4, 1), (3, 6)];
+
+/// The eight hexagram binaries of the palace headed by trigram `head`.
+fn palace_members(head: &str) -> [String; 8] {
+    let mut members: [String; 8] = Default::default();
+    let mut binary = format!("{}{}", head, head);
+    members[0] = binary.clone();
+    for (line, member) in (1..=5).zip(members[1..6].iter_mut()) {
+        binary = flip_line(&binary, line);
+        *member = binary.clone();
+    }
+    binary = flip_line(&binary, 4);
+    members[6] = binary.clone();
+    members[7] = format!("{}{}", head, &binary[3..]);
+    members
+}
+
+/// Palace (trigram number, 1–8 as in `trigrams.json`) and generation
+/// (0–7) of a binary pattern.
+pub(crate) fn palace_of(binary: &str) -> Option<(u32, usize)> {
+    PALACE_HEADS.iter().enumerate().find_map(|(i, head)| {
+        let generation = palace_members(head).iter().position(|m| m == binary)?;
+        Some((i as u32 + 1, generation))
+    })
+}
+
+fn trigram_phase(trigram: u32) -> FivePhase {
+    use FivePhase::*;
+    [Metal, Earth, Wood, Wood, Water, Fire, Earth, Metal][(trigram - 1) as usize]
+}
+
+fn relative(palace: FivePhase, line: FivePhase) -> SixRelative {
+    if line == palace {
+        SixRelative::Brothers
+    } else if palace.generates() == line {
+        SixRelative::Offspring
+    } else if palace.overcomes() == line {
+        SixRelative::Wealth
+    } else if line.overcomes() == palace {
+        SixRelative::Officer
+    } else {
+        SixRelative::Parents
+    }
+}
+
+fn first_spirit(stem: HeavenlyStem) -> usize {
+    use HeavenlyStem::*;
+    match stem {
+        Jia | Yi => 0,
+        Bing | Ding => 1,
+        Wu => 2,
+        Ji => 3,
+        Geng | Xin => 4,
+        Ren | Gui => 5,
+    }
+}
+
+const SPIRITS: [SixSpirit; 6] = [
+    SixSpirit::GreenDragon,
+    SixSpirit::VermilionBird,
+    SixSpirit::HookedArray,
+    SixSpirit::FlyingSerpent,
+    SixSpirit::WhiteTiger,
+    SixSpirit::BlackTortoise,
+];
+
+/// The two branches left over when the ten stems are paired with branches
+/// for the ten-day week containing this day.
+fn void_branches(stem: HeavenlyStem, branch: EarthlyBranch) -> [EarthlyBranch; 2] {
+    let week_start = branch.offset(-(stem as i32));
+    [week_start.offset(10), week_start.offset(11)]
+}
+
+fn month_strength(month: EarthlyBranch, branch: EarthlyBranch) -> MonthStrength {
+    let (month_phase, phase) = (month.phase(), branch.phase());
+    if month.offset(6) == branch {
+        MonthStrength::Broken
+    } else if month_phase == phase {
+        MonthStrength::Prosperous
+    } else if month_phase.generates() == phase {
+        MonthStrength::Supported
+    } else {
+        MonthStrength::Weak
+    }
+}
+
+impl LiuYaoContext {
+    /// The day's stem and branch and the solar month's branch for a
+    /// Gregorian date. Days run midnight to midnight; months change at the
+    /// solar terms, Yin month beginning when the sun reaches 315°.
+    pub fn from_date(year: i32, month: u32, day: u32) -> Self {
+        let (day_stem, day_branch) = cycle_parts(day_cycle(year, month, day));
+        Self {
+            day_stem,
+            day_branch,
+            month_branch: solar_month_branch(to_julian_day(year, month, day, 12, 0)),
+        }
+    }
+}
+
+/// Lay out `cast` as a Liu Yao chart and judge the line standing for
+/// `use_god` against the day and month.
+///
+/// # Errors
+/// Returns [`IChingError::DataCorrupt`] if the cast's binary patterns are
+/// not valid hexagrams.
+pub fn liu_yao(
+    cast: &CastResult,
+    context: LiuYaoContext,
+    use_god: SixRelative,
+) -> Result<LiuYaoReading, IChingError> {
+    let corrupt = || IChingError::DataCorrupt(format!("not a hexagram: {}", cast.binary));
+    let najia = najia_for_binary(&cast.binary).ok_or_else(corrupt)?;
+    let changed = match &cast.transformed_binary {
+        Some(b) => Some(najia_for_binary(b).ok_or_else(corrupt)?),
+        None => None,
+    };
+    let (palace, generation) = palace_of(&cast.binary).ok_or_else(corrupt)?;
+    let palace_phase = trigram_phase(palace);
+    let (self_line, response_line) = SELF_RESPONSE[generation];
+    let void = void_branches(context.day_stem, context.day_branch);
+    let spirit_start = first_spirit(context.day_stem);
+    let day_phase = context.day_branch.phase();
+
+    let lines: Vec<LiuYaoLine> = najia
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let moving = cast.changing_lines.contains(&(i + 1));
+            let phase = line.phase;
+            LiuYaoLine {
+                najia: *line,
+                relative: relative(palace_phase, phase),
+                spirit: SPIRITS[(spirit_start + i) % 6],
+                moving,
+                is_self: line.position == self_line,
+                is_response: line.position == response_line,
+                month_strength: month_strength(context.month_branch, line.branch),
+                day_supports: day_phase == phase || day_phase.generates() == phase,
+                day_harms: day_phase.overcomes() == phase,
+                void: void.contains(&line.branch),
+                changes_to: changed
+                    .filter(|_| moving)
+                    .map(|c| (c[i], relative(palace_phase, c[i].phase))),
+            }
+        })
+        .collect();
+
+    // A moving use god speaks loudest; otherwise the one nearest the self
+    let use_god_line = lines
+        .iter()
+        .filter(|l| l.relative == use_god)
+        .min_by_key(|l| (!l.moving, l.najia.position.abs_diff(self_line)))
+        .map(|l| l.najia.position);
+    let (indication, notes) = judge(&lines, use_god, use_god_line);
+
+    Ok(LiuYaoReading {
+        hexagram_number: cast.hexagram_number,
+        transformed_hexagram_number: cast.transformed_hexagram_number,
+        palace,
+        palace_phase,
+        self_line,
+        response_line,
+        context,
+        lines,
+        use_god,
+        use_god_line,
+        indication,
+        notes,
+    })
+}
+
+fn judge(
+    lines: &[LiuYaoLine],
+    use_god: SixRelative,
+    use_god_line: Option<u32>,
+) -> (Indication, Vec<String>) {
+    let Some(position) = use_god_line else {
+        let note = format!("{:?} does not appear in the hexagram; the matter is hidden", use_god);
+        return (Indication::Unfavorable, vec![note]);
+    };
+    let line = &lines[position as usize - 1];
+    let mut notes = Vec::new();
+    let mut score = 0;
+    let branch = line.najia.branch;
+    match line.month_strength {
+        MonthStrength::Prosperous => {
+            score += 2;
+            notes.push(format!("{} shares the month's phase", branch));
+        }
+        MonthStrength::Supported => {
+            score += 1;
+            notes.push(format!("the month feeds {}", branch));
+        }
+        MonthStrength::Weak => {
+            score -= 1;
+            notes.push(format!("{} is out of season", branch));
+        }
+        MonthStrength::Broken => {
+            score -= 2;
+            notes.push(format!("the month clashes with {}", branch));
+        }
+    }
+    if line.day_supports {
+        score += 1;
+        notes.push("the day supports it".to_string());
+    }
+    if line.day_harms {
+        score -= 1;
+        notes.push("the day overcomes it".to_string());
+    }
+    if line.void {
+        score -= 1;
+        notes.push(format!("{} is void this week", branch));
+    }
+    if line.moving {
+        notes.push("it is moving, so the matter is in motion".to_string());
+    }
+    let indication = match score {
+        s if s >= 2 => Indication::Favorable,
+        s if s <= -1 => Indication::Unfavorable,
+        _ => Indication::Mixed,
+    };
+    (indication, notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn palaces_hold_eight_hexagrams_each() {
+        let engine = IChingEngine::new();
+        let mut seen = std::collections::HashSet::new();
+        for head in PALACE_HEADS {
+            for member in palace_members(head) {
+                assert!(engine.binary_to_hexagram_number(&member).is_some());
+                assert!(seen.insert(member));
+            }
+        }
+        assert_eq!(seen.len(), 64);
+        // Gou (44) is the first generation of Qian; Da You (14) its returning soul
+        assert_eq!(palace_of("011111"), Some((1, 1)));
+        assert_eq!(palace_of("111101"), Some((1, 7)));
+    }
+
+    #[test]
+    fn calendar_context() {
+        // 1 Jan 2000 was a wu-wu day in the zi month
+        let ctx = LiuYaoContext::from_date(2000, 1, 1);
+        assert_eq!((ctx.day_stem, ctx.day_branch), (HeavenlyStem::Wu, EarthlyBranch::Wu));
+        assert_eq!(ctx.month_branch, EarthlyBranch::Zi);
+        // Jia-zi week: xu and hai are void
+        let void = void_branches(HeavenlyStem::Jia, EarthlyBranch::Zi);
+        assert_eq!(void, [EarthlyBranch::Xu, EarthlyBranch::Hai]);
+    }
+
+    #[test]
+    fn qian_chart() {
+        let engine = IChingEngine::new();
+        let cast = engine.cast_from_lines(&[7, 7, 7, 7, 9, 7]).unwrap();
+        let context = LiuYaoContext {
+            day_stem: HeavenlyStem::Jia,
+            day_branch: EarthlyBranch::Zi,
+            month_branch: EarthlyBranch::Shen,
+        };
+        let reading = engine.liu_yao(&cast, context, SixRelative::Wealth).unwrap();
+        assert_eq!((reading.self_line, reading.response_line), (6, 3));
+        assert_eq!(reading.palace_phase, FivePhase::Metal);
+
+        let relatives: Vec<SixRelative> = reading.lines.iter().map(|l| l.relative).collect();
+        use SixRelative::*;
+        assert_eq!(relatives, [Offspring, Wealth, Parents, Officer, Brothers, Parents]);
+        assert_eq!(reading.lines[0].spirit, SixSpirit::GreenDragon);
+        assert!(reading.lines[5].void);
+        assert!(reading.lines[4].changes_to.is_some());
+        assert!(reading.lines[3].changes_to.is_none());
+
+        // Wealth is the yin (wood) line; a metal month overcomes it
+        assert_eq!(reading.use_god_line, Some(2));
+        assert_eq!(reading.indication, Indication::Unfavorable);
+        assert!(!reading.notes.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use rand::Rng;
+
+use crate::types::{CastMethod, LineToss};
+
+// ---------------------------------------------------------------------------
+// Casting methods and their line probabilities
+// ---------------------------------------------------------------------------
+//
+// Chance of each line value (6, 7, 8, 9) in sixteenths:
+//
+//   three coins   2  6  6  2   each coin heads = 3, tails = 2, summed
+//   two coins     1  5  7  3   two coins tossed twice, read as 0–15
+//   16 tokens     1  5  7  3   one token drawn from a bag of 16
+//
+// The last two reproduce the yarrow-stalk odds, where old yang is three
+// times as likely as old yin. Coins and tokens are equivalent here; the
+// methods differ in what the querent handles.
+
+/// Chance of each line value 6, 7, 8 and 9 under `method`. `None` for
+/// methods that take their lines from the querent.
+pub fn line_probabilities(method: CastMethod) -> Option<[f64; 4]> {
+    let sixteenths = match method {
+        CastMethod::Coins => [2.0, 6.0, 6.0, 2.0],
+        CastMethod::TwoCoin | CastMethod::SixteenToken => [1.0, 5.0, 7.0, 3.0],
+        CastMethod::Manual | CastMethod::PlumBlossom | CastMethod::QuestionHash => return None,
+    };
+    Some(sixteenths.map(|n: f64| n / 16.0))
+}
+
+/// Yarrow-odds line for a number 0–15.
+fn yarrow_line(n: u8) -> u8 {
+    match n {
+        0 => 6,
+        1..=5 => 7,
+        6..=12 => 8,
+        _ => 9,
+    }
+}
+
+impl LineToss {
+    /// The line value, 6–9, the toss gives.
+    pub fn value(self) -> u8 {
+        match self {
+            Self::ThreeCoins { heads } => heads.iter().map(|&h| if h { 3 } else { 2 }).sum(),
+            // Heads = 1; the four faces of two tosses form a 4-bit number
+            Self::TwoCoins { heads } => {
+                yarrow_line(heads.iter().fold(0, |n, &h| n << 1 | u8::from(h)))
+            }
+            Self::Token { number } => yarrow_line(number),
+        }
+    }
+}
+
+/// Toss one line. `None` for methods that take their lines from the
+/// querent.
+pub(crate) fn toss<R: Rng + ?Sized>(method: CastMethod, rng: &mut R) -> Option<LineToss> {
+    match method {
+        CastMethod::Coins => Some(LineToss::ThreeCoins {
+            heads: [(); 3].map(|_| rng.gen_bool(0.5)),
+        }),
+        CastMethod::TwoCoin => Some(LineToss::TwoCoins {
+            heads: [(); 4].map(|_| rng.gen_bool(0.5)),
+        }),
+        CastMethod::SixteenToken => Some(LineToss::Token { number: rng.gen_range(0..16) }),
+        CastMethod::Manual | CastMethod::PlumBlossom | CastMethod::QuestionHash => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{seeded_rng, IChingEngine, IChingError};
+
+    fn frequencies(method: CastMethod) -> [f64; 4] {
+        const TRIALS: usize = 40_000;
+        let mut rng = seeded_rng(11);
+        let mut counts = [0usize; 4];
+        for _ in 0..TRIALS {
+            counts[usize::from(toss(method, &mut rng).unwrap().value() - 6)] += 1;
+        }
+        counts.map(|c| c as f64 / TRIALS as f64)
+    }
+
+    #[test]
+    fn distributions_match_the_documented_odds() {
+        for method in [CastMethod::Coins, CastMethod::TwoCoin, CastMethod::SixteenToken] {
+            let expected = line_probabilities(method).unwrap();
+            assert!((expected.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            let observed = frequencies(method);
+            for (e, o) in expected.iter().zip(observed) {
+                // ~5 standard errors at 40 000 trials
+                assert!((e - o).abs() < 0.012, "{:?}: expected {}, got {}", method, e, o);
+            }
+        }
+        assert!(line_probabilities(CastMethod::Manual).is_none());
+    }
+
+    #[test]
+    fn engine_casts_by_method() {
+        let engine = IChingEngine::new();
+        let mut rng = seeded_rng(2);
+        let cast = engine.cast_by_method_with_rng(CastMethod::SixteenToken, &mut rng).unwrap();
+        assert_eq!(cast.lines.len(), 6);
+        assert_eq!(
+            engine.cast_by_method(CastMethod::PlumBlossom).unwrap_err(),
+            IChingError::MethodNeedsInput(CastMethod::PlumBlossom)
+        );
+    }
+
+    #[test]
+    fn casts_record_their_method_and_tosses() {
+        let engine = IChingEngine::new();
+        let mut rng = seeded_rng(8);
+        for method in [CastMethod::Coins, CastMethod::TwoCoin, CastMethod::SixteenToken] {
+            let cast = engine.cast_by_method_with_rng(method, &mut rng).unwrap();
+            assert_eq!(cast.method, method);
+            let values: Vec<u8> = cast.tosses.iter().map(|t| t.value()).collect();
+            assert_eq!(values, cast.lines);
+        }
+        let coins = engine.cast_hexagram_seeded(4);
+        assert!(matches!(coins.tosses[0], LineToss::ThreeCoins { .. }));
+
+        let manual = engine.cast_from_lines(&[6, 7, 8, 9, 7, 8]).unwrap();
+        assert_eq!(manual.method, CastMethod::Manual);
+        assert!(manual.tosses.is_empty());
+
+        let json = serde_json::to_value(LineToss::TwoCoins { heads: [true, false, false, true] });
+        assert_eq!(json.unwrap()["kind"], "two_coins");
+        assert_eq!(LineToss::TwoCoins { heads: [true; 4] }.value(), 9);
+        assert_eq!(LineToss::Token { number: 0 }.value(), 6);
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+
+use crate::types::{EarthlyBranch, FivePhase, HeavenlyStem, Hexagram, NajiaLine};
+
+// ---------------------------------------------------------------------------
+// Stems, branches and phases
+// ---------------------------------------------------------------------------
+
+impl FivePhase {
+    pub const ALL: [FivePhase; 5] = [Self::Wood, Self::Fire, Self::Earth, Self::Metal, Self::Water];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Wood => "wood",
+            Self::Fire => "fire",
+            Self::Earth => "earth",
+            Self::Metal => "metal",
+            Self::Water => "water",
+        }
+    }
+
+    /// The phase this one feeds in the generating cycle (wood feeds fire).
+    pub fn generates(self) -> Self {
+        Self::ALL[(self as usize + 1) % 5]
+    }
+
+    /// The phase this one restrains in the overcoming cycle (wood parts
+    /// earth).
+    pub fn overcomes(self) -> Self {
+        Self::ALL[(self as usize + 2) % 5]
+    }
+}
+
+impl fmt::Display for FivePhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl HeavenlyStem {
+    pub const ALL: [HeavenlyStem; 10] = [
+        Self::Jia,
+        Self::Yi,
+        Self::Bing,
+        Self::Ding,
+        Self::Wu,
+        Self::Ji,
+        Self::Geng,
+        Self::Xin,
+        Self::Ren,
+        Self::Gui,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        ["jia", "yi", "bing", "ding", "wu", "ji", "geng", "xin", "ren", "gui"][self as usize]
+    }
+
+    pub fn chinese(self) -> char {
+        ['甲', '乙', '丙', '丁', '戊', '己', '庚', '辛', '壬', '癸'][self as usize]
+    }
+
+    /// Stems pair off by phase: jia and yi are wood, bing and ding fire, …
+    pub fn phase(self) -> FivePhase {
+        FivePhase::ALL[self as usize / 2]
+    }
+
+    /// Odd-numbered stems (jia, bing, …) are yang.
+    pub fn is_yang(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+}
+
+impl fmt::Display for HeavenlyStem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl EarthlyBranch {
+    pub const ALL: [EarthlyBranch; 12] = [
+        Self::Zi,
+        Self::Chou,
+        Self::Yin,
+        Self::Mao,
+        Self::Chen,
+        Self::Si,
+        Self::Wu,
+        Self::Wei,
+        Self::Shen,
+        Self::You,
+        Self::Xu,
+        Self::Hai,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        [
+            "zi", "chou", "yin", "mao", "chen", "si", "wu", "wei", "shen", "you", "xu", "hai",
+        ][self as usize]
+    }
+
+    pub fn chinese(self) -> char {
+        ['子', '丑', '寅', '卯', '辰', '巳', '午', '未', '申', '酉', '戌', '亥'][self as usize]
+    }
+
+    pub fn animal(self) -> &'static str {
+        [
+            "rat", "ox", "tiger", "rabbit", "dragon", "snake", "horse", "goat", "monkey",
+            "rooster", "dog", "pig",
+        ][self as usize]
+    }
+
+    pub fn phase(self) -> FivePhase {
+        use FivePhase::*;
+        [
+            Water, Earth, Wood, Wood, Earth, Fire, Fire, Earth, Metal, Metal, Earth, Water,
+        ][self as usize]
+    }
+
+    /// Odd-numbered branches (zi, yin, …) are yang.
+    pub fn is_yang(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    /// The branch `steps` places further round the cycle.
+    pub fn offset(self, steps: i32) -> Self {
+        Self::ALL[(self as i32 + steps).rem_euclid(12) as usize]
+    }
+}
+
+impl fmt::Display for EarthlyBranch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Najia (na jia) attribution
+// ---------------------------------------------------------------------------
+//
+// Each trigram carries a stem and three branches; a hexagram takes the
+// lower trigram's "inner" set for lines 1–3 and the upper trigram's "outer"
+// set for lines 4–6. Qian and Kun change stem between inner and outer.
+
+/// Inner stem, outer stem and the six branches, keyed by trigram binary
+/// (bottom line first).
+fn trigram_najia(binary: &str) -> Option<(HeavenlyStem, HeavenlyStem, [EarthlyBranch; 6])> {
+    use EarthlyBranch::*;
+    use HeavenlyStem as S;
+    Some(match binary {
+        "111" => (S::Jia, S::Ren, [Zi, Yin, Chen, Wu, Shen, Xu]),
+        "000" => (S::Yi, S::Gui, [Wei, Si, Mao, Chou, Hai, You]),
+        "100" => (S::Geng, S::Geng, [Zi, Yin, Chen, Wu, Shen, Xu]),
+        "011" => (S::Xin, S::Xin, [Chou, Hai, You, Wei, Si, Mao]),
+        "010" => (S::Wu, S::Wu, [Yin, Chen, Wu, Shen, Xu, Zi]),
+        "101" => (S::Ji, S::Ji, [Mao, Chou, Hai, You, Wei, Si]),
+        "001" => (S::Bing, S::Bing, [Chen, Wu, Shen, Xu, Zi, Yin]),
+        "110" => (S::Ding, S::Ding, [Si, Mao, Chou, Hai, You, Wei]),
+        _ => return None,
+    })
+}
+
+/// Stems and branches for the six lines of a binary pattern, bottom first.
+pub fn najia_for_binary(binary: &str) -> Option<[NajiaLine; 6]> {
+    if binary.len() != 6 {
+        return None;
+    }
+    let (lower_stem, _, lower) = trigram_najia(binary.get(..3)?)?;
+    let (_, upper_stem, upper) = trigram_najia(binary.get(3..)?)?;
+    Some(std::array::from_fn(|i| {
+        let (stem, branch) = if i < 3 { (lower_stem, lower[i]) } else { (upper_stem, upper[i]) };
+        NajiaLine {
+            position: i as u32 + 1,
+            stem,
+            branch,
+            phase: branch.phase(),
+        }
+    }))
+}
+
+/// Stems and branches for the six lines of `hexagram`, bottom first.
+pub fn najia(hexagram: &Hexagram) -> Option<[NajiaLine; 6]> {
+    najia_for_binary(&hexagram.binary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    fn branches(lines: &[NajiaLine]) -> Vec<EarthlyBranch> {
+        lines.iter().map(|l| l.branch).collect()
+    }
+
+    #[test]
+    fn pure_and_mixed_hexagrams() {
+        use EarthlyBranch::*;
+        let engine = IChingEngine::new();
+        let qian = engine.najia(1).unwrap();
+        assert_eq!(branches(&qian), [Zi, Yin, Chen, Wu, Shen, Xu]);
+        assert_eq!(qian[0].stem, HeavenlyStem::Jia);
+        assert_eq!(qian[5].stem, HeavenlyStem::Ren);
+
+        // Tai: Qian inside, Kun outside
+        let tai = engine.najia(11).unwrap();
+        assert_eq!(branches(&tai), [Zi, Yin, Chen, Chou, Hai, You]);
+        assert_eq!(tai[3].stem, HeavenlyStem::Gui);
+        assert_eq!(tai[3].phase, FivePhase::Earth);
+
+        assert!(engine.najia(0).is_none());
+        assert!(najia_for_binary("1111").is_none());
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(FivePhase::Water.generates(), FivePhase::Wood);
+        assert_eq!(FivePhase::Metal.overcomes(), FivePhase::Wood);
+        assert_eq!(HeavenlyStem::Geng.phase(), FivePhase::Metal);
+        assert!(HeavenlyStem::Jia.is_yang() && !HeavenlyStem::Gui.is_yang());
+        assert_eq!(EarthlyBranch::Hai.offset(1), EarthlyBranch::Zi);
+        assert_eq!(EarthlyBranch::Zi.chinese(), '子');
+        let json = serde_json::to_value(EarthlyBranch::Shen).unwrap();
+        assert_eq!(json, "shen");
+    }
+}
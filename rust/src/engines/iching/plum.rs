@@ -0,0 +1,169 @@
+use crate::types::{CastMethod, CastResult, Hexagram, PlumBlossomCast};
+
+use super::{hexagram_glyph, IChingError};
+
+// ---------------------------------------------------------------------------
+// Plum Blossom (Mei Hua Yi Shu) numerology
+// ---------------------------------------------------------------------------
+//
+// Two numbers pick the upper and lower trigrams by their remainder mod 8 in
+// the Earlier Heaven order (Qian 1, Dui 2, Li 3, Zhen 4, Xun 5, Kan 6, Gen 7,
+// Kun 8); their sum mod 6 picks the moving line. A remainder of 0 counts as
+// 8 or 6. The date method uses the lunar year's earthly branch plus the lunar
+// month and day for the upper trigram, and adds the hour's branch for the
+// lower. Converting a Gregorian date to the lunar calendar is left to the
+// caller.
+
+/// Earlier Heaven trigram binaries (bottom line first), indexed by number - 1.
+const EARLIER_HEAVEN: [&str; 8] = ["111", "110", "101", "100", "011", "010", "001", "000"];
+
+fn earlier_heaven_binary(n: u32) -> &'static str {
+    EARLIER_HEAVEN[((n + 7) % 8) as usize]
+}
+
+fn number_for(hexagrams: &[Hexagram], binary: &str) -> Option<u32> {
+    hexagrams.iter().find(|h| h.binary == binary).map(|h| h.number)
+}
+
+fn trigram_number(trigram_binary: &str) -> u32 {
+    // trigrams.json numbers the eight figures in this binary order
+    const DATA_ORDER: [&str; 8] = ["111", "000", "100", "011", "010", "101", "001", "110"];
+    DATA_ORDER.iter().position(|b| *b == trigram_binary).map_or(0, |i| i as u32 + 1)
+}
+
+fn positive(field: &'static str, value: u32) -> Result<u32, IChingError> {
+    if value == 0 {
+        Err(IChingError::InvalidValue { field, value })
+    } else {
+        Ok(value)
+    }
+}
+
+/// Cast from two numbers, e.g. ones a querent names or counts from their
+/// surroundings.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] if either number is zero.
+pub fn plum_blossom_from_numbers(
+    hexagrams: &[Hexagram],
+    upper: u32,
+    lower: u32,
+) -> Result<PlumBlossomCast, IChingError> {
+    let upper = positive("upper number", upper)?;
+    let lower = positive("lower number", lower)?;
+    let moving_line = ((upper + lower + 5) % 6) as usize + 1;
+    build(hexagrams, upper, lower, moving_line)
+}
+
+/// Cast from a lunar date and the clock hour (0–23).
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] for a lunar month outside 1–12, a
+/// lunar day outside 1–30 or an hour past 23.
+pub fn plum_blossom_from_lunar_date(
+    hexagrams: &[Hexagram],
+    lunar_year: i32,
+    lunar_month: u32,
+    lunar_day: u32,
+    hour: u32,
+) -> Result<PlumBlossomCast, IChingError> {
+    if !(1..=12).contains(&lunar_month) {
+        return Err(IChingError::InvalidValue { field: "lunar month", value: lunar_month });
+    }
+    if !(1..=30).contains(&lunar_day) {
+        return Err(IChingError::InvalidValue { field: "lunar day", value: lunar_day });
+    }
+    if hour > 23 {
+        return Err(IChingError::InvalidValue { field: "hour", value: hour });
+    }
+    // Zi = 1; 4 CE was a Zi (rat) year, and the Zi hour starts at 23:00
+    let year_branch = (lunar_year - 4).rem_euclid(12) as u32 + 1;
+    let hour_branch = hour.div_ceil(2) % 12 + 1;
+    let upper = year_branch + lunar_month + lunar_day;
+    let lower = upper + hour_branch;
+    let moving_line = ((lower + 5) % 6) as usize + 1;
+    build(hexagrams, upper, lower, moving_line)
+}
+
+fn build(
+    hexagrams: &[Hexagram],
+    upper: u32,
+    lower: u32,
+    moving_line: usize,
+) -> Result<PlumBlossomCast, IChingError> {
+    let (upper_bin, lower_bin) = (earlier_heaven_binary(upper), earlier_heaven_binary(lower));
+    let binary = format!("{}{}", lower_bin, upper_bin);
+    let transformed = super::relations::flip_line(&binary, moving_line);
+    let mutual = format!("{}{}", &binary[1..4], &binary[2..5]);
+    let lines = binary
+        .bytes()
+        .enumerate()
+        .map(|(i, b)| match (i + 1 == moving_line, b == b'1') {
+            (true, true) => 9,
+            (true, false) => 6,
+            (false, true) => 7,
+            (false, false) => 8,
+        })
+        .collect();
+
+    let lookup = |b: &str| {
+        number_for(hexagrams, b)
+            .ok_or_else(|| IChingError::DataCorrupt(format!("no hexagram has pattern {}", b)))
+    };
+    let (number, transformed_number) = (lookup(&binary)?, lookup(&transformed)?);
+    let glyph = |n: u32| hexagram_glyph(n).map(String::from);
+    Ok(PlumBlossomCast {
+        upper_trigram: trigram_number(upper_bin),
+        lower_trigram: trigram_number(lower_bin),
+        moving_line,
+        mutual_hexagram_number: lookup(&mutual)?,
+        cast: CastResult {
+            lines,
+            changing_lines: vec![moving_line],
+            hexagram_number: number,
+            transformed_hexagram_number: Some(transformed_number),
+            binary,
+            transformed_binary: Some(transformed),
+            glyph: glyph(number).unwrap_or_default(),
+            transformed_glyph: glyph(transformed_number),
+            method: CastMethod::PlumBlossom,
+            tosses: Vec::new(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn numbers_pick_trigrams_and_moving_line() {
+        let engine = IChingEngine::new();
+        // 8 -> Kun above, 1 -> Qian below: Tai (11); 9 mod 6 moves line 3
+        let cast = engine.plum_blossom_from_numbers(8, 1).unwrap();
+        assert_eq!(cast.cast.hexagram_number, 11);
+        assert_eq!((cast.upper_trigram, cast.lower_trigram), (2, 1));
+        assert_eq!(cast.moving_line, 3);
+        assert_eq!(cast.cast.lines, vec![7, 7, 9, 8, 8, 8]);
+        assert_eq!(cast.cast.transformed_hexagram_number, Some(19));
+        // Lines 2-4 (Dui) below lines 3-5 (Zhen): Gui Mei (54)
+        assert_eq!(cast.mutual_hexagram_number, 54);
+
+        // Remainders of zero wrap to 8 and 6
+        assert_eq!(engine.plum_blossom_from_numbers(16, 8).unwrap().moving_line, 6);
+        assert!(engine.plum_blossom_from_numbers(0, 3).is_err());
+    }
+
+    #[test]
+    fn lunar_date_method() {
+        let engine = IChingEngine::new();
+        // Chen (5) year, month 11, day 17, Shen hour (15:00, branch 9)
+        let cast = engine.plum_blossom_from_lunar_date(2024, 11, 17, 15).unwrap();
+        assert_eq!(cast.upper_trigram, trigram_number(earlier_heaven_binary(33)));
+        assert_eq!(cast.lower_trigram, trigram_number(earlier_heaven_binary(42)));
+        assert_eq!(cast.moving_line, 6);
+        assert!(engine.plum_blossom_from_lunar_date(2024, 13, 1, 0).is_err());
+        assert!(engine.plum_blossom_from_lunar_date(2024, 1, 1, 24).is_err());
+    }
+}
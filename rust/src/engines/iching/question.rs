@@ -0,0 +1,104 @@
+use crate::identity;
+use crate::types::LineToss;
+
+use super::IChingError;
+
+// ---------------------------------------------------------------------------
+// Question-hash casting
+// ---------------------------------------------------------------------------
+//
+// Some products want the same question asked twice in one sitting to give
+// the same answer. The question is trimmed, lowercased and its whitespace
+// collapsed, then hashed with FNV-1a together with the index of the time
+// bucket the timestamp falls in. Hashing rather than seeding an RNG keeps
+// the cast identical in every implementation and across `rand` upgrades.
+// The low 18 bits are read as three coins per line, bottom line first, so
+// the lines have the three-coin odds.
+
+/// Bucket length used when a product has no session of its own: questions
+/// repeated within the same hour agree.
+pub const DEFAULT_QUESTION_BUCKET_MINUTES: u32 = 60;
+
+fn normalize(question: &str) -> String {
+    question.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// The coins, bottom line first, read from the hash of `question` asked at
+/// `timestamp` (milliseconds since the Unix epoch).
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] if `bucket_minutes` is zero.
+pub fn question_tosses(
+    question: &str,
+    timestamp: u64,
+    bucket_minutes: u32,
+) -> Result<[LineToss; 6], IChingError> {
+    if bucket_minutes == 0 {
+        return Err(IChingError::InvalidValue { field: "bucket minutes", value: 0 });
+    }
+    let bucket = timestamp / (u64::from(bucket_minutes) * 60_000);
+    let key = format!("{}\0{}", normalize(question), bucket);
+    Ok(hashed_coins(identity::fnv1a_64(key.as_bytes())))
+}
+
+/// Three coins per line, bottom first, read from the low 18 bits of `hash`.
+pub(crate) fn hashed_coins(hash: u64) -> [LineToss; 6] {
+    let mut tosses = [LineToss::ThreeCoins { heads: [false; 3] }; 6];
+    for (i, toss) in tosses.iter_mut().enumerate() {
+        let mut heads = [false; 3];
+        for (coin, head) in heads.iter_mut().enumerate() {
+            *head = (hash >> (3 * i + coin)) & 1 == 1;
+        }
+        *toss = LineToss::ThreeCoins { heads };
+    }
+    tosses
+}
+
+/// Six line values, bottom first, for `question` asked at `timestamp`.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] if `bucket_minutes` is zero.
+pub fn question_lines(
+    question: &str,
+    timestamp: u64,
+    bucket_minutes: u32,
+) -> Result<[u8; 6], IChingError> {
+    Ok(question_tosses(question, timestamp, bucket_minutes)?.map(LineToss::value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{line_probabilities, IChingEngine};
+    use crate::types::CastMethod;
+
+    const HOUR: u64 = 3_600_000;
+
+    #[test]
+    fn same_question_same_bucket_same_cast() {
+        let engine = IChingEngine::new();
+        let t = 1_700_000_000_000 - 1_700_000_000_000 % HOUR;
+        let first = engine.cast_from_question("Should I move?", t, 60).unwrap();
+        let again = engine.cast_from_question("  should i   MOVE? ", t + HOUR - 1, 60).unwrap();
+        assert_eq!(first, again);
+        assert_eq!(first.method, CastMethod::QuestionHash);
+        assert_eq!(first.tosses.len(), 6);
+        assert!(line_probabilities(CastMethod::QuestionHash).is_none());
+
+        let questions = ["Should I move?", "Will it rain?", "What now?", "Why?"];
+        let next_hour: Vec<[u8; 6]> =
+            questions.iter().map(|q| question_lines(q, t + HOUR, 60).unwrap()).collect();
+        let this_hour: Vec<[u8; 6]> =
+            questions.iter().map(|q| question_lines(q, t, 60).unwrap()).collect();
+        assert_ne!(this_hour, next_hour);
+        assert!(this_hour.iter().flatten().all(|v| (6..=9).contains(v)));
+    }
+
+    #[test]
+    fn zero_bucket_is_rejected() {
+        assert_eq!(
+            question_lines("anything", 0, 0),
+            Err(IChingError::InvalidValue { field: "bucket minutes", value: 0 })
+        );
+    }
+}
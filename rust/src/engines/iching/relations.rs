@@ -0,0 +1,166 @@
+use crate::types::{Hexagram, LineDerivative, PairRelation, SequencePair};
+
+// ---------------------------------------------------------------------------
+// Relationships between hexagrams
+// ---------------------------------------------------------------------------
+//
+// Binary patterns are written bottom line first, so line N is character N-1.
+
+/// Flip line `line` (1–6) of a binary pattern.
+pub(crate) fn flip_line(binary: &str, line: usize) -> String {
+    binary
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match (i + 1 == line, c) {
+            (true, '1') => '0',
+            (true, _) => '1',
+            (false, c) => c,
+        })
+        .collect()
+}
+
+/// The six hexagrams `hexagram` becomes when exactly one of its lines
+/// changes, bottom line first.
+pub fn line_derivatives(hexagrams: &[Hexagram], hexagram: &Hexagram) -> Vec<LineDerivative> {
+    (1..=6)
+        .filter_map(|line| {
+            let binary = flip_line(&hexagram.binary, line);
+            let number = hexagrams.iter().find(|h| h.binary == binary)?.number;
+            Some(LineDerivative {
+                line,
+                becomes_yang: binary.as_bytes()[line - 1] == b'1',
+                binary,
+                hexagram_number: number,
+            })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// King Wen sequence
+// ---------------------------------------------------------------------------
+
+/// The hexagram after `number` in the King Wen order; `None` after 64.
+pub fn next_in_sequence(number: u32) -> Option<u32> {
+    (1..64).contains(&number).then_some(number + 1)
+}
+
+/// The hexagram before `number` in the King Wen order; `None` before 1.
+pub fn previous_in_sequence(number: u32) -> Option<u32> {
+    (2..=64).contains(&number).then_some(number - 1)
+}
+
+/// The pair `number` belongs to, and whether its partner is the figure
+/// inverted or, for the eight figures that read the same upside down,
+/// complemented. `None` if either hexagram is missing.
+pub fn sequence_pair(hexagrams: &[Hexagram], number: u32) -> Option<SequencePair> {
+    let first = if number % 2 == 1 { number } else { number.checked_sub(1)? };
+    let binary = |n: u32| hexagrams.iter().find(|h| h.number == n).map(|h| h.binary.as_str());
+    let (a, b) = (binary(first)?, binary(first + 1)?);
+    let inverted: String = a.chars().rev().collect();
+    let relation = if inverted != a && inverted == b {
+        PairRelation::Inverted
+    } else {
+        PairRelation::Complemented
+    };
+    Some(SequencePair {
+        first,
+        second: first + 1,
+        relation,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Fu Xi (binary) ordering
+// ---------------------------------------------------------------------------
+//
+// Shao Yong's arrangement reads the lines as a binary number with the bottom
+// line most significant: Kun is 0, Bo 1, Bi 2, … Qian 63. Our bottom-first
+// binary strings are therefore already the number's digits.
+
+/// The Fu Xi (binary) value, 0–63, of a binary pattern.
+pub fn binary_to_decimal(binary: &str) -> Option<u32> {
+    if binary.len() != 6 || !binary.bytes().all(|b| b == b'0' || b == b'1') {
+        return None;
+    }
+    u32::from_str_radix(binary, 2).ok()
+}
+
+/// The binary pattern with Fu Xi value `decimal` (0–63).
+pub fn decimal_to_binary(decimal: u32) -> Option<String> {
+    (decimal < 64).then(|| format!("{:06b}", decimal))
+}
+
+/// The Fu Xi value of King Wen hexagram `number`.
+pub fn king_wen_to_fu_xi(hexagrams: &[Hexagram], number: u32) -> Option<u32> {
+    binary_to_decimal(&hexagrams.iter().find(|h| h.number == number)?.binary)
+}
+
+/// The King Wen number of the hexagram with Fu Xi value `decimal`.
+pub fn fu_xi_to_king_wen(hexagrams: &[Hexagram], decimal: u32) -> Option<u32> {
+    let binary = decimal_to_binary(decimal)?;
+    hexagrams.iter().find(|h| h.binary == binary).map(|h| h.number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn qian_changes_into_the_yin_one_line_hexagrams() {
+        let engine = IChingEngine::new();
+        let derived = engine.line_derivatives(1).unwrap();
+        let numbers: Vec<u32> = derived.iter().map(|d| d.hexagram_number).collect();
+        assert_eq!(numbers, [44, 13, 10, 9, 14, 43]);
+        assert!(derived.iter().all(|d| !d.becomes_yang));
+        assert_eq!(derived[0].binary, "011111");
+
+        // Changing the same line back returns to the start
+        for d in engine.line_derivatives(2).unwrap() {
+            let back = engine.line_derivatives(d.hexagram_number).unwrap();
+            assert_eq!(back[d.line - 1].hexagram_number, 2);
+        }
+        assert!(engine.line_derivatives(65).is_none());
+        assert_eq!(flip_line("000000", 6), "000001");
+    }
+
+    #[test]
+    fn king_wen_pairs() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.next_in_sequence(63), Some(64));
+        assert_eq!(engine.next_in_sequence(64), None);
+        assert_eq!(engine.previous_in_sequence(1), None);
+        assert_eq!(engine.previous_in_sequence(30), Some(29));
+
+        let pair = engine.sequence_pair(4).unwrap();
+        assert_eq!((pair.first, pair.second), (3, 4));
+        assert_eq!(pair.relation, PairRelation::Inverted);
+        assert_eq!(engine.sequence_pair(1).unwrap().relation, PairRelation::Complemented);
+        assert_eq!(engine.sequence_pair(29).unwrap().relation, PairRelation::Complemented);
+
+        let complemented = (1..=64)
+            .step_by(2)
+            .filter(|&n| engine.sequence_pair(n).unwrap().relation == PairRelation::Complemented)
+            .count();
+        assert_eq!(complemented, 4);
+        assert!(engine.sequence_pair(65).is_none());
+    }
+
+    #[test]
+    fn fu_xi_ordering() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.hexagram_from_decimal(0).unwrap().number, 2);
+        assert_eq!(engine.hexagram_from_decimal(63).unwrap().number, 1);
+        // Bo (23) has only its top line solid, Fu (24) only its bottom
+        assert_eq!(engine.king_wen_to_fu_xi(23), Some(1));
+        assert_eq!(engine.king_wen_to_fu_xi(24), Some(32));
+        assert!(engine.hexagram_from_decimal(64).is_none());
+
+        for decimal in 0..64 {
+            let number = engine.fu_xi_to_king_wen(decimal).unwrap();
+            assert_eq!(engine.king_wen_to_fu_xi(number), Some(decimal));
+        }
+        assert_eq!(binary_to_decimal("11"), None);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::types::{CastResult, ChangingLineReading, ChangingLineRule, Hexagram, SelectedText};
+
+// ---------------------------------------------------------------------------
+// Changing-line rules
+// ---------------------------------------------------------------------------
+//
+// Zhu Xi's rules by number of changing lines:
+//   0  the primary judgment
+//   1  that line of the primary hexagram
+//   2  both changing lines, the upper one principal
+//   3  the primary and resulting judgments, the primary principal
+//   4  the two unchanging lines of the resulting hexagram, the lower principal
+//   5  the one unchanging line of the resulting hexagram
+//   6  the resulting judgment (the extra texts Qian and Kun carry for this
+//      case are not in the embedded data)
+
+fn judgment(hexagram: &Hexagram, principal: bool) -> SelectedText {
+    SelectedText {
+        hexagram_number: hexagram.number,
+        line: None,
+        text: hexagram.judgment.clone(),
+        meaning: None,
+        principal,
+    }
+}
+
+fn line(hexagram: &Hexagram, position: usize, principal: bool) -> Option<SelectedText> {
+    let line = hexagram.lines.iter().find(|l| l.position as usize == position)?;
+    Some(SelectedText {
+        hexagram_number: hexagram.number,
+        line: Some(line.position),
+        text: line.text.clone(),
+        meaning: Some(line.meaning.clone()),
+        principal,
+    })
+}
+
+/// Select the judgment and line texts that apply to `cast` under `rule`.
+/// Returns `None` if the cast refers to a hexagram or line missing from
+/// `hexagrams`.
+pub fn interpret_cast(
+    hexagrams: &[Hexagram],
+    cast: &CastResult,
+    rule: ChangingLineRule,
+) -> Option<ChangingLineReading> {
+    let find = |n: u32| hexagrams.iter().find(|h| h.number == n);
+    let primary = find(cast.hexagram_number)?;
+    let resulting = match cast.transformed_hexagram_number {
+        Some(n) => Some(find(n)?),
+        None => None,
+    };
+    let changing = &cast.changing_lines;
+    let stable: Vec<usize> = (1..=6).filter(|p| !changing.contains(p)).collect();
+
+    let texts = match (rule, resulting) {
+        (_, None) => vec![judgment(primary, true)],
+        (ChangingLineRule::ResultingOnly, Some(res)) => vec![judgment(res, true)],
+        (ChangingLineRule::AllLines, Some(res)) => {
+            let mut texts = vec![judgment(primary, true)];
+            for &p in changing {
+                texts.push(line(primary, p, false)?);
+            }
+            texts.push(judgment(res, false));
+            texts
+        }
+        (ChangingLineRule::ZhuXi, Some(res)) => match changing.len() {
+            1 => vec![line(primary, changing[0], true)?],
+            2 => vec![line(primary, changing[0], false)?, line(primary, changing[1], true)?],
+            3 => vec![judgment(primary, true), judgment(res, false)],
+            4 => vec![line(res, stable[0], true)?, line(res, stable[1], false)?],
+            5 => vec![line(res, stable[0], true)?],
+            _ => vec![judgment(res, true)],
+        },
+    };
+
+    Some(ChangingLineReading {
+        rule,
+        hexagram_number: primary.number,
+        transformed_hexagram_number: resulting.map(|h| h.number),
+        texts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    fn read(lines: [u8; 6], rule: ChangingLineRule) -> Vec<(u32, Option<u32>, bool)> {
+        let engine = IChingEngine::new();
+        let cast = engine.cast_from_lines(&lines).unwrap();
+        engine
+            .interpret_cast(&cast, rule)
+            .unwrap()
+            .texts
+            .iter()
+            .map(|t| (t.hexagram_number, t.line, t.principal))
+            .collect()
+    }
+
+    #[test]
+    fn zhu_xi_rules_by_changing_count() {
+        use ChangingLineRule::ZhuXi;
+        assert_eq!(read([7; 6], ZhuXi), [(1, None, true)]);
+        assert_eq!(read([9, 7, 7, 7, 7, 7], ZhuXi), [(1, Some(1), true)]);
+        assert_eq!(read([9, 7, 9, 7, 7, 7], ZhuXi), [(1, Some(1), false), (1, Some(3), true)]);
+        // Lines 1-3 of Qian turn yin, giving Pi (12)
+        assert_eq!(read([9, 9, 9, 7, 7, 7], ZhuXi), [(1, None, true), (12, None, false)]);
+        // Four changing leaves lines 5 and 6 stable in the result
+        let four = read([9, 9, 9, 9, 7, 7], ZhuXi);
+        assert_eq!(four[0].1, Some(5));
+        assert!(four[0].2);
+        assert_eq!(four[1].1, Some(6));
+        assert_eq!(read([9, 9, 9, 9, 9, 7], ZhuXi)[0].1, Some(6));
+        assert_eq!(read([9; 6], ZhuXi), [(2, None, true)]);
+    }
+
+    #[test]
+    fn other_rules() {
+        let all = read([9, 7, 9, 7, 7, 7], ChangingLineRule::AllLines);
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0], (1, None, true));
+        assert_eq!(all[3].1, None);
+        assert_eq!(read([9, 7, 7, 7, 7, 7], ChangingLineRule::ResultingOnly), [(44, None, true)]);
+        assert_eq!(read([8; 6], ChangingLineRule::ResultingOnly), [(2, None, true)]);
+    }
+}
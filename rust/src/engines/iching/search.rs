@@ -0,0 +1,130 @@
+use crate::types::Hexagram;
+
+// ---------------------------------------------------------------------------
+// Hexagram lookup by name
+// ---------------------------------------------------------------------------
+//
+// Names are compared after lowercasing, folding pinyin tone marks and
+// dropping spaces and punctuation, so "Xiao Chu", "xiaochu" and "Xiǎo Chù"
+// agree. English names also lose a leading "the". Several pinyin names are
+// shared (Qian is both 1 and 15), so matches are ranked: exact, then
+// prefix, then anywhere in the name, with ties in King Wen order.
+
+const TONE_MARKS: [(char, char); 24] = [
+    ('ā', 'a'),
+    ('á', 'a'),
+    ('ǎ', 'a'),
+    ('à', 'a'),
+    ('ē', 'e'),
+    ('é', 'e'),
+    ('ě', 'e'),
+    ('è', 'e'),
+    ('ī', 'i'),
+    ('í', 'i'),
+    ('ǐ', 'i'),
+    ('ì', 'i'),
+    ('ō', 'o'),
+    ('ó', 'o'),
+    ('ǒ', 'o'),
+    ('ò', 'o'),
+    ('ū', 'u'),
+    ('ú', 'u'),
+    ('ǔ', 'u'),
+    ('ù', 'u'),
+    ('ǖ', 'u'),
+    ('ǘ', 'u'),
+    ('ǚ', 'u'),
+    ('ǜ', 'u'),
+];
+
+fn normalize(name: &str) -> String {
+    let lowered = name.to_lowercase();
+    let words: Vec<&str> = lowered.split_whitespace().collect();
+    let words = match words.split_first() {
+        Some((&"the", rest)) if !rest.is_empty() => rest,
+        _ => &words[..],
+    };
+    words
+        .concat()
+        .chars()
+        .map(|c| TONE_MARKS.iter().find(|(marked, _)| *marked == c).map_or(c, |(_, plain)| *plain))
+        .map(|c| if c == 'ü' { 'u' } else { c })
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// 0 for an exact match, 1 for a prefix, 2 for anywhere in the name.
+fn match_rank(query: &str, name: &str) -> Option<u8> {
+    let name = normalize(name);
+    if name == query {
+        Some(0)
+    } else if name.starts_with(query) {
+        Some(1)
+    } else if name.contains(query) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn ranked<'a>(
+    hexagrams: &'a [Hexagram],
+    query: &str,
+    name: impl Fn(&Hexagram) -> &str,
+) -> Vec<&'a Hexagram> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<(u8, &Hexagram)> = hexagrams
+        .iter()
+        .filter_map(|h| Some((match_rank(&query, name(h))?, h)))
+        .collect();
+    matches.sort_by_key(|(rank, h)| (*rank, h.number));
+    matches.into_iter().map(|(_, h)| h).collect()
+}
+
+/// Hexagrams whose pinyin name matches `query`, best first. Case, tone
+/// marks and spacing are ignored and partial names match.
+pub fn hexagrams_by_name<'a>(hexagrams: &'a [Hexagram], query: &str) -> Vec<&'a Hexagram> {
+    ranked(hexagrams, query, |h| &h.name)
+}
+
+/// Hexagrams whose English name matches `query`, best first. Case and a
+/// leading "the" are ignored and partial names match.
+pub fn hexagrams_by_english_name<'a>(hexagrams: &'a [Hexagram], query: &str) -> Vec<&'a Hexagram> {
+    ranked(hexagrams, query, |h| &h.english_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{load_hexagrams, IChingEngine};
+
+    fn numbers(found: Vec<&Hexagram>) -> Vec<u32> {
+        found.iter().map(|h| h.number).collect()
+    }
+
+    #[test]
+    fn pinyin_names_ignore_case_tones_and_spacing() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.get_hexagram_by_name("qian").map(|h| h.number), Some(1));
+        assert_eq!(numbers(engine.find_hexagrams_by_name("QIAN")), [1, 15]);
+        assert_eq!(engine.get_hexagram_by_name("xiaochu").map(|h| h.number), Some(9));
+        assert_eq!(engine.get_hexagram_by_name("Xiǎo Chù").map(|h| h.number), Some(9));
+        assert_eq!(engine.get_hexagram_by_name("gui m").map(|h| h.number), Some(54));
+        assert!(engine.get_hexagram_by_name("zzz").is_none());
+        assert!(engine.get_hexagram_by_name("  ").is_none());
+    }
+
+    #[test]
+    fn english_names_match_partially() {
+        let engine = IChingEngine::new();
+        assert_eq!(engine.get_hexagram_by_english_name("The Creative").map(|h| h.number), Some(1));
+        assert_eq!(engine.get_hexagram_by_english_name("creative").map(|h| h.number), Some(1));
+        assert_eq!(engine.get_hexagram_by_english_name("well").map(|h| h.number), Some(48));
+        // A prefix match outranks the names ending in "the Great"
+        let hexagrams = load_hexagrams().unwrap();
+        assert_eq!(numbers(hexagrams_by_english_name(&hexagrams, "great")), [14, 26, 28, 34]);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::engines::astrology::to_julian_day;
+use crate::engines::calendar::{days_in_month, solar_month_branch};
+use crate::types::{EarthlyBranch, SovereignHexagram};
+
+use super::IChingError;
+
+// ---------------------------------------------------------------------------
+// The twelve sovereign hexagrams
+// ---------------------------------------------------------------------------
+//
+// Fu, one yang line under five yin, rules the Zi month holding the winter
+// solstice. Each month adds a yang line from the bottom up to Qian in the
+// Si month; Gou then brings yin in from below until Kun closes the year in
+// the Hai month. Months are solar, opening at the jie terms, so the
+// mapping follows the sun rather than the lunar calendar.
+
+/// King Wen numbers from the Zi month onward: Fu, Lin, Tai, Da Zhuang,
+/// Guai, Qian, Gou, Dun, Pi, Guan, Bo, Kun.
+const SOVEREIGNS: [u32; 12] = [24, 19, 11, 34, 43, 1, 44, 33, 12, 20, 23, 2];
+
+/// The jie term opening each month from the Zi month onward.
+const SOLAR_TERMS: [&str; 12] = [
+    "Daxue (Major Snow)",
+    "Xiaohan (Minor Cold)",
+    "Lichun (Start of Spring)",
+    "Jingzhe (Awakening of Insects)",
+    "Qingming (Pure Brightness)",
+    "Lixia (Start of Summer)",
+    "Mangzhong (Grain in Ear)",
+    "Xiaoshu (Minor Heat)",
+    "Liqiu (Start of Autumn)",
+    "Bailu (White Dew)",
+    "Hanlu (Cold Dew)",
+    "Lidong (Start of Winter)",
+];
+
+/// The sovereign hexagram of the solar month with branch `branch`.
+pub fn sovereign_hexagram_for_branch(branch: EarthlyBranch) -> SovereignHexagram {
+    let i = branch as usize;
+    let yang_lines = if i < 6 { i + 1 } else { 11 - i };
+    SovereignHexagram {
+        hexagram_number: SOVEREIGNS[i],
+        month_branch: branch,
+        month: ((i + 10) % 12 + 1) as u32,
+        solar_term: SOLAR_TERMS[i].to_string(),
+        solar_term_longitude: ((255 + 30 * i) % 360) as u32,
+        yang_lines: yang_lines as u32,
+        waxing: i < 6,
+    }
+}
+
+/// All twelve sovereign hexagrams, from Fu in the Zi month to Kun in the Hai
+/// month.
+pub fn sovereign_hexagrams() -> Vec<SovereignHexagram> {
+    EarthlyBranch::ALL.into_iter().map(sovereign_hexagram_for_branch).collect()
+}
+
+/// The sovereign hexagram ruling a Gregorian date, found from the sun's
+/// longitude at noon UT.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] for a month outside 1–12 or a day
+/// the month does not have.
+pub fn sovereign_hexagram_for(
+    year: i32,
+    month: u32,
+    day: u32,
+) -> Result<SovereignHexagram, IChingError> {
+    if !(1..=12).contains(&month) {
+        return Err(IChingError::InvalidValue { field: "month", value: month });
+    }
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(IChingError::InvalidValue { field: "day", value: day });
+    }
+    let jd = to_julian_day(year, month, day, 12, 0);
+    Ok(sovereign_hexagram_for_branch(solar_month_branch(jd)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    #[test]
+    fn sovereigns_wax_and_wane_line_by_line() {
+        let engine = IChingEngine::new();
+        for s in sovereign_hexagrams() {
+            let binary = &engine.get_hexagram(s.hexagram_number).unwrap().binary;
+            let n = s.yang_lines as usize;
+            let expected = if s.waxing {
+                format!("{}{}", "1".repeat(n), "0".repeat(6 - n))
+            } else {
+                format!("{}{}", "0".repeat(6 - n), "1".repeat(n))
+            };
+            assert_eq!(binary, &expected, "hexagram {}", s.hexagram_number);
+        }
+    }
+
+    #[test]
+    fn dates_follow_the_solar_terms() {
+        let solstice = sovereign_hexagram_for(2024, 12, 21).unwrap();
+        assert_eq!(
+            (solstice.hexagram_number, solstice.month_branch),
+            (24, EarthlyBranch::Zi)
+        );
+        assert_eq!(solstice.month, 11);
+
+        let spring = sovereign_hexagram_for(2024, 2, 10).unwrap();
+        assert_eq!((spring.hexagram_number, spring.month), (11, 1));
+        // Lichun falls on 4 February; the day before is still the Chou month
+        assert_eq!(
+            sovereign_hexagram_for(2024, 2, 3).unwrap().hexagram_number,
+            19
+        );
+
+        assert_eq!(
+            sovereign_hexagram_for(2024, 6, 21).unwrap().hexagram_number,
+            44
+        );
+        assert!(sovereign_hexagram_for(2024, 13, 1).is_err());
+        assert!(sovereign_hexagram_for(2024, 6, 31).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CastMethod, CastResult};
+
+use super::methods::line_probabilities;
+
+// ---------------------------------------------------------------------------
+// Cast statistics
+// ---------------------------------------------------------------------------
+//
+// Tallies over many casts, mainly to check that each method produces the
+// line odds documented in `methods` and that an RNG shows no obvious bias.
+
+/// Running tallies of hexagrams and line values over a batch of casts.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CastStatistics {
+    method: CastMethod,
+    casts: u64,
+    /// Casts by primary hexagram number
+    hexagrams: BTreeMap<u32, u64>,
+    /// Lines of value 6, 7, 8 and 9
+    line_values: [u64; 4],
+    /// Casts with no changing lines
+    still_casts: u64,
+}
+
+impl CastStatistics {
+    pub fn new(method: CastMethod) -> Self {
+        Self {
+            method,
+            ..Self::default()
+        }
+    }
+
+    /// Tally one cast.
+    pub fn record(&mut self, cast: &CastResult) {
+        self.casts += 1;
+        *self.hexagrams.entry(cast.hexagram_number).or_default() += 1;
+        for &value in &cast.lines {
+            if let 6..=9 = value {
+                self.line_values[usize::from(value - 6)] += 1;
+            }
+        }
+        self.still_casts += u64::from(cast.changing_lines.is_empty());
+    }
+
+    pub fn method(&self) -> CastMethod {
+        self.method
+    }
+
+    pub fn casts(&self) -> u64 {
+        self.casts
+    }
+
+    /// How many casts gave hexagram `number`.
+    pub fn hexagram_count(&self, number: u32) -> u64 {
+        self.hexagrams.get(&number).copied().unwrap_or(0)
+    }
+
+    /// Casts by hexagram number, for the hexagrams that came up.
+    pub fn hexagram_counts(&self) -> &BTreeMap<u32, u64> {
+        &self.hexagrams
+    }
+
+    /// Lines of value 6, 7, 8 and 9.
+    pub fn line_value_counts(&self) -> [u64; 4] {
+        self.line_values
+    }
+
+    /// Share of lines of value 6, 7, 8 and 9; all zero before any cast.
+    pub fn line_frequencies(&self) -> [f64; 4] {
+        let total: u64 = self.line_values.iter().sum();
+        if total == 0 {
+            return [0.0; 4];
+        }
+        self.line_values.map(|n| n as f64 / total as f64)
+    }
+
+    pub fn still_casts(&self) -> u64 {
+        self.still_casts
+    }
+
+    /// Largest gap between an observed line frequency and the method's
+    /// documented odds. `None` for methods without fixed odds.
+    pub fn max_line_deviation(&self) -> Option<f64> {
+        let expected = line_probabilities(self.method)?;
+        let observed = self.line_frequencies();
+        Some(expected.iter().zip(observed).map(|(e, o)| (e - o).abs()).fold(0.0, f64::max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::{seeded_rng, IChingEngine, IChingError};
+
+    #[test]
+    fn methods_produce_their_documented_odds() {
+        let engine = IChingEngine::new();
+        for method in [CastMethod::Coins, CastMethod::TwoCoin, CastMethod::SixteenToken] {
+            let stats = engine.cast_many_with_rng(5_000, method, &mut seeded_rng(17)).unwrap();
+            assert_eq!(stats.casts(), 5_000);
+            assert_eq!(stats.line_value_counts().iter().sum::<u64>(), 30_000);
+            assert_eq!(stats.hexagram_counts().values().sum::<u64>(), 5_000);
+            assert_eq!(stats.hexagram_counts().len(), 64);
+            // ~5 standard errors at 30 000 lines
+            assert!(stats.max_line_deviation().unwrap() < 0.015, "{:?}", method);
+        }
+        let mut rng = seeded_rng(3);
+        let coins = engine.cast_many_with_rng(2_000, CastMethod::Coins, &mut rng).unwrap();
+        // (3/4)^6 of coin casts have no changing lines
+        let still = coins.still_casts() as f64 / 2_000.0;
+        assert!((still - 0.178).abs() < 0.05);
+    }
+
+    #[test]
+    fn methods_without_odds_are_rejected() {
+        let engine = IChingEngine::new();
+        assert_eq!(
+            engine.cast_many(0, CastMethod::Manual).err(),
+            Some(IChingError::MethodNeedsInput(CastMethod::Manual))
+        );
+        assert_eq!(CastStatistics::new(CastMethod::Manual).max_line_deviation(), None);
+        assert_eq!(CastStatistics::default().line_frequencies(), [0.0; 4]);
+    }
+}
@@ -0,0 +1,122 @@
+use std::fs;
+use std::path::Path;
+
+use crate::types::{Hexagram, TranslationPack};
+
+use super::IChingError;
+
+// ---------------------------------------------------------------------------
+// Alternative translations
+// ---------------------------------------------------------------------------
+//
+// The embedded texts are the default. Other translations are loaded at run
+// time and overlaid field by field, so a pack may cover only the judgments
+// or only some hexagrams.
+
+/// Id reported by an engine using the embedded texts.
+pub const DEFAULT_TRANSLATION_ID: &str = "default";
+
+/// Parse a translation pack from JSON.
+///
+/// # Errors
+/// Returns an error if the JSON is malformed.
+pub fn parse_translation_pack(json: &str) -> Result<TranslationPack, IChingError> {
+    serde_json::from_str(json).map_err(|e| IChingError::json("translation pack JSON", e))
+}
+
+/// Read and parse a translation pack file.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or is malformed.
+pub fn load_translation_pack(path: &Path) -> Result<TranslationPack, IChingError> {
+    let json = fs::read_to_string(path).map_err(|e| IChingError::io(path, e))?;
+    parse_translation_pack(&json)
+}
+
+/// Overlay a pack's texts on `hexagrams`. Nothing is applied if the pack
+/// names a hexagram or line that does not exist.
+///
+/// # Errors
+/// Returns [`IChingError::InvalidValue`] for the first unknown hexagram
+/// number or line position.
+pub fn apply_translation_pack(
+    hexagrams: &[Hexagram],
+    pack: &TranslationPack,
+) -> Result<Vec<Hexagram>, IChingError> {
+    let mut hexagrams = hexagrams.to_vec();
+    for t in &pack.hexagrams {
+        let hex = hexagrams
+            .iter_mut()
+            .find(|h| h.number == t.number)
+            .ok_or(IChingError::InvalidValue { field: "hexagram", value: t.number })?;
+        if let Some(name) = &t.english_name {
+            hex.english_name = name.clone();
+        }
+        if let Some(judgment) = &t.judgment {
+            hex.judgment = judgment.clone();
+        }
+        if let Some(image) = &t.image {
+            hex.image = image.clone();
+        }
+        for lt in &t.lines {
+            let line = hex
+                .lines
+                .iter_mut()
+                .find(|l| l.position == lt.position)
+                .ok_or(IChingError::InvalidValue { field: "line position", value: lt.position })?;
+            line.text = lt.text.clone();
+            if let Some(meaning) = &lt.meaning {
+                line.meaning = meaning.clone();
+            }
+        }
+    }
+    Ok(hexagrams)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+
+    const LEGGE_SAMPLE: &str = r#"{
+        "id": "legge",
+        "name": "Legge (1882)",
+        "translator": "James Legge",
+        "hexagrams": [{
+            "number": 1,
+            "judgment": "Khien (represents) what is great and originating.",
+            "lines": [{ "position": 1, "text": "The dragon lying hid in the deep." }]
+        }]
+    }"#;
+
+    #[test]
+    fn engines_choose_their_translation() {
+        let pack = parse_translation_pack(LEGGE_SAMPLE).unwrap();
+        let legge = IChingEngine::with_translation(&pack).unwrap();
+        let default = IChingEngine::new();
+        assert_eq!(legge.translation(), "legge");
+        assert_eq!(default.translation(), DEFAULT_TRANSLATION_ID);
+
+        let qian = legge.get_hexagram(1).unwrap();
+        assert!(qian.judgment.starts_with("Khien"));
+        assert!(qian.lines[0].text.contains("dragon lying hid"));
+        // Untranslated fields keep the embedded text
+        let embedded = default.get_hexagram(1).unwrap();
+        assert_eq!(qian.image, embedded.image);
+        assert_eq!(qian.lines[0].meaning, embedded.lines[0].meaning);
+        let kun = (legge.get_hexagram(2).unwrap(), default.get_hexagram(2).unwrap());
+        assert_eq!(kun.0.judgment, kun.1.judgment);
+    }
+
+    #[test]
+    fn unknown_targets_are_rejected() {
+        let mut pack = parse_translation_pack(LEGGE_SAMPLE).unwrap();
+        pack.hexagrams[0].lines[0].position = 7;
+        assert_eq!(
+            IChingEngine::with_translation(&pack).err(),
+            Some(IChingError::InvalidValue { field: "line position", value: 7 })
+        );
+        assert!(matches!(parse_translation_pack("{"), Err(IChingError::Json { .. })));
+        assert!(load_translation_pack(Path::new("/nonexistent/legge.json")).is_err());
+    }
+}
@@ -0,0 +1,398 @@
+mod error;
+
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
+use crate::types::{
+    CorrespondenceKind, LetterClass, Pathworking, PathworkingStep, Sephirah, TreePath,
+};
+
+pub use error::KabbalahError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const SEPHIROTH_JSON: &str = include_str!("../../../data/kabbalah/sephiroth.json");
+const PATHS_JSON: &str = include_str!("../../../data/kabbalah/paths.json");
+
+/// Number of the sephirah every pathworking traditionally starts from.
+pub const MALKUTH: u8 = 10;
+
+fn load_sephiroth() -> Result<Vec<Sephirah>, KabbalahError> {
+    serde_json::from_str(SEPHIROTH_JSON).map_err(|e| KabbalahError::DataCorrupt(e.to_string()))
+}
+
+fn load_paths() -> Result<Vec<TreePath>, KabbalahError> {
+    serde_json::from_str(PATHS_JSON).map_err(|e| KabbalahError::DataCorrupt(e.to_string()))
+}
+
+/// Check that there are ten sephiroth and 22 paths numbered in order, that
+/// each path joins two sephiroth top to bottom, that mother, double and
+/// simple letters go to elements, planets and signs, that no letter,
+/// correspondence or card appears twice and that every sephirah is reached.
+fn validate_tree(sephiroth: &[Sephirah], paths: &[TreePath]) -> Result<(), KabbalahError> {
+    let mut problems = Vec::new();
+    if sephiroth.len() != 10 {
+        problems.push(format!("{} sephiroth instead of 10", sephiroth.len()));
+    }
+    for (expected, sephirah) in (1..).zip(sephiroth) {
+        if sephirah.number != expected {
+            problems.push(format!("sephirah {} is numbered {}", sephirah.name, sephirah.number));
+        }
+    }
+    if paths.len() != 22 {
+        problems.push(format!("{} paths instead of 22", paths.len()));
+    }
+    for (i, path) in paths.iter().enumerate() {
+        if usize::from(path.number) != i + 11 {
+            problems.push(format!("path {} is numbered {}", path.letter, path.number));
+        }
+        let [upper, lower] = path.connects;
+        if upper == 0 || lower as usize > sephiroth.len() || upper >= lower {
+            problems.push(format!("path {} joins {} and {}", path.number, upper, lower));
+        }
+        let expected = match path.letter_class {
+            LetterClass::Mother => CorrespondenceKind::Element,
+            LetterClass::Double => CorrespondenceKind::Planet,
+            LetterClass::Simple => CorrespondenceKind::Sign,
+        };
+        if path.correspondence_kind != expected {
+            problems.push(format!("path {} has a {:?} letter", path.number, path.letter_class));
+        }
+        let earlier = &paths[..i];
+        if earlier.iter().any(|p| p.letter.eq_ignore_ascii_case(&path.letter)) {
+            problems.push(format!("letter {} is used twice", path.letter));
+        }
+        if earlier.iter().any(|p| p.correspondence == path.correspondence) {
+            problems.push(format!("{} is attributed twice", path.correspondence));
+        }
+        if earlier.iter().any(|p| p.tarot_card == path.tarot_card) {
+            problems.push(format!("card {} is on two paths", path.tarot_card));
+        }
+    }
+    if problems.is_empty() {
+        for sephirah in &sephiroth[1..] {
+            if find_route(paths, 1, sephirah.number).is_none() {
+                problems.push(format!("{} is not joined to the Tree", sephirah.name));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(KabbalahError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+/// The embedded sephiroth and paths.
+pub(crate) struct Tree {
+    pub(crate) sephiroth: Vec<Sephirah>,
+    pub(crate) paths: Vec<TreePath>,
+}
+
+/// The embedded Tree, parsed and validated on first use. The tarot
+/// attributions read from it too.
+pub(crate) fn embedded_tree() -> Result<&'static Tree, KabbalahError> {
+    static TREE: OnceLock<Result<Tree, KabbalahError>> = OnceLock::new();
+    let tree = TREE.get_or_init(|| {
+        let sephiroth = load_sephiroth()?;
+        let paths = load_paths()?;
+        validate_tree(&sephiroth, &paths)?;
+        Ok(Tree { sephiroth, paths })
+    });
+    tree.as_ref().map_err(Clone::clone)
+}
+
+// ---------------------------------------------------------------------------
+// Pathworking routes
+// ---------------------------------------------------------------------------
+//
+// A breadth-first search over the paths, so a route uses as few paths as
+// possible. Neighbours are tried from the highest-numbered path down; the
+// high numbers sit at the foot of the Tree, so a climb from Malkuth to
+// Kether takes the Middle Pillar (32, 25, 13) rather than a side route.
+
+/// The fewest paths from sephirah `from` to `to`, in travelling order.
+/// Returns an empty route when they are the same and `None` if `to` cannot
+/// be reached.
+pub fn find_route(paths: &[TreePath], from: u8, to: u8) -> Option<Vec<&TreePath>> {
+    let mut ordered: Vec<&TreePath> = paths.iter().collect();
+    ordered.sort_by_key(|p| std::cmp::Reverse(p.number));
+
+    // came_by[n] is the path that first reached sephirah n
+    let mut came_by: [Option<&TreePath>; 11] = [None; 11];
+    let mut seen = [false; 11];
+    let mut queue = VecDeque::from([from]);
+    *seen.get_mut(usize::from(from))? = true;
+    while let Some(current) = queue.pop_front() {
+        if current == to {
+            break;
+        }
+        for path in &ordered {
+            let next = match path.connects {
+                [a, b] if a == current => b,
+                [a, b] if b == current => a,
+                _ => continue,
+            };
+            let Some(slot) = seen.get_mut(usize::from(next)) else { continue };
+            if !*slot {
+                *slot = true;
+                came_by[usize::from(next)] = Some(path);
+                queue.push_back(next);
+            }
+        }
+    }
+    if !*seen.get(usize::from(to))? {
+        return None;
+    }
+
+    let mut route = Vec::new();
+    let mut at = to;
+    while at != from {
+        let path = came_by[usize::from(at)]?;
+        at = if path.connects[0] == at { path.connects[1] } else { path.connects[0] };
+        route.push(path);
+    }
+    route.reverse();
+    Some(route)
+}
+
+// ---------------------------------------------------------------------------
+// KabbalahEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct KabbalahEngine {
+    sephiroth: Vec<Sephirah>,
+    paths: Vec<TreePath>,
+}
+
+impl KabbalahEngine {
+    /// Create an engine from the embedded sephiroth and paths.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded kabbalah data: {}", e))
+    }
+
+    /// Load and validate the embedded sephiroth and paths.
+    ///
+    /// # Errors
+    /// Returns [`KabbalahError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, KabbalahError> {
+        let tree = embedded_tree()?;
+        Ok(Self {
+            sephiroth: tree.sephiroth.clone(),
+            paths: tree.paths.clone(),
+        })
+    }
+
+    /// The ten sephiroth, Kether first.
+    pub fn sephiroth(&self) -> &[Sephirah] {
+        &self.sephiroth
+    }
+
+    /// The 22 paths, 11 (Aleph) first.
+    pub fn paths(&self) -> &[TreePath] {
+        &self.paths
+    }
+
+    /// Look up a sephirah by name or English title (case-insensitive), its
+    /// Hebrew name or its number.
+    pub fn sephirah(&self, name: &str) -> Option<&Sephirah> {
+        let name = name.trim();
+        self.sephiroth.iter().find(|s| {
+            s.name.eq_ignore_ascii_case(name)
+                || s.title.eq_ignore_ascii_case(name)
+                || s.hebrew == name
+                || name.parse() == Ok(s.number)
+        })
+    }
+
+    /// The path numbered 11–32.
+    pub fn path(&self, number: u8) -> Option<&TreePath> {
+        self.paths.iter().find(|p| p.number == number)
+    }
+
+    /// The path of a Hebrew letter, by transliteration or glyph.
+    pub fn path_for_letter(&self, letter: &str) -> Option<&TreePath> {
+        let letter = letter.trim();
+        self.paths.iter().find(|p| p.letter.eq_ignore_ascii_case(letter) || p.glyph == letter)
+    }
+
+    /// The path a major arcana card sits on.
+    pub fn path_for_card(&self, card_id: &str) -> Option<&TreePath> {
+        self.paths.iter().find(|p| p.tarot_card == card_id)
+    }
+
+    fn path_for(&self, kind: CorrespondenceKind, key: &str) -> Option<&TreePath> {
+        let key = key.trim();
+        self.paths
+            .iter()
+            .find(|p| p.correspondence_kind == kind && p.correspondence.eq_ignore_ascii_case(key))
+    }
+
+    /// The path of a planet's double letter, e.g. `"mars"` → 27 (Peh).
+    pub fn path_for_planet(&self, planet: &str) -> Option<&TreePath> {
+        self.path_for(CorrespondenceKind::Planet, planet)
+    }
+
+    /// The path of a sign's simple letter, e.g. `"leo"` → 19 (Teth).
+    pub fn path_for_sign(&self, sign: &str) -> Option<&TreePath> {
+        self.path_for(CorrespondenceKind::Sign, sign)
+    }
+
+    /// The path of an element's mother letter: air, water or fire.
+    pub fn path_for_element(&self, element: &str) -> Option<&TreePath> {
+        self.path_for(CorrespondenceKind::Element, element)
+    }
+
+    /// The sephirah whose sphere is a planet, e.g. `"mars"` → Geburah.
+    pub fn sephirah_for_planet(&self, planet: &str) -> Option<&Sephirah> {
+        let planet = planet.trim();
+        self.sephiroth
+            .iter()
+            .find(|s| s.planet.as_deref().is_some_and(|p| p.eq_ignore_ascii_case(planet)))
+    }
+
+    /// Paths leading out of a sephirah, lowest number first.
+    ///
+    /// # Errors
+    /// Returns [`KabbalahError::UnknownSephirah`] if `sephirah` is unknown.
+    pub fn paths_from(&self, sephirah: &str) -> Result<Vec<&TreePath>, KabbalahError> {
+        let number = self.lookup(sephirah)?.number;
+        Ok(self.paths.iter().filter(|p| p.connects.contains(&number)).collect())
+    }
+
+    fn lookup(&self, sephirah: &str) -> Result<&Sephirah, KabbalahError> {
+        self.sephirah(sephirah)
+            .ok_or_else(|| KabbalahError::UnknownSephirah(sephirah.to_string()))
+    }
+
+    fn name_of(&self, number: u8) -> String {
+        self.sephiroth
+            .iter()
+            .find(|s| s.number == number)
+            .map_or_else(|| number.to_string(), |s| s.name.clone())
+    }
+
+    fn steps_from(&self, mut at: u8, route: Vec<&TreePath>) -> Vec<PathworkingStep> {
+        route
+            .into_iter()
+            .map(|path| {
+                let next = if path.connects[0] == at { path.connects[1] } else { path.connects[0] };
+                let step = PathworkingStep {
+                    from: self.name_of(at),
+                    to: self.name_of(next),
+                    path: path.clone(),
+                };
+                at = next;
+                step
+            })
+            .collect()
+    }
+
+    /// Suggest a pathworking from one sephirah to another over the fewest
+    /// paths. Each step carries its path's letter, card and guided theme.
+    ///
+    /// # Errors
+    /// Returns [`KabbalahError::UnknownSephirah`] if either sephirah is
+    /// unknown.
+    pub fn pathworking(&self, from: &str, to: &str) -> Result<Pathworking, KabbalahError> {
+        let (start, end) = (self.lookup(from)?, self.lookup(to)?);
+        let route = find_route(&self.paths, start.number, end.number)
+            .ok_or_else(|| KabbalahError::DataCorrupt(format!("{} is unreachable", end.name)))?;
+        Ok(Pathworking {
+            from: start.name.clone(),
+            to: end.name.clone(),
+            steps: self.steps_from(start.number, route),
+        })
+    }
+
+    /// Suggest a pathworking for a major arcana card: climb from Malkuth to
+    /// the foot of the card's path, then travel the path itself.
+    ///
+    /// # Errors
+    /// Returns [`KabbalahError::UnknownCard`] if no path carries the card.
+    pub fn pathworking_for_card(&self, card_id: &str) -> Result<Pathworking, KabbalahError> {
+        let path = self
+            .path_for_card(card_id)
+            .ok_or_else(|| KabbalahError::UnknownCard(card_id.to_string()))?;
+        let [upper, lower] = path.connects;
+        let mut route = find_route(&self.paths, MALKUTH, lower).ok_or_else(|| {
+            KabbalahError::DataCorrupt(format!("{} is unreachable", self.name_of(lower)))
+        })?;
+        route.push(path);
+        Ok(Pathworking {
+            from: self.name_of(MALKUTH),
+            to: self.name_of(upper),
+            steps: self.steps_from(MALKUTH, route),
+        })
+    }
+}
+
+impl Default for KabbalahEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, kabbalah};
+
+    #[test]
+    fn correspondence_lookups() {
+        let engine = KabbalahEngine::new();
+        assert_eq!(engine.sephirah("crown").unwrap().name, "Kether");
+        assert_eq!(engine.sephirah("6").unwrap().name, "Tiphareth");
+        assert_eq!(engine.sephirah_for_planet("Mars").unwrap().name, "Geburah");
+        assert!(engine.sephirah_for_planet("pluto").is_none());
+
+        assert_eq!(engine.path_for_planet("mars").unwrap().letter, "Peh");
+        assert_eq!(engine.path_for_sign("leo").unwrap().number, 19);
+        assert_eq!(engine.path_for_element("water").unwrap().tarot_card, "major_12_hanged_man");
+        assert_eq!(engine.path_for_letter("ת").unwrap().number, 32);
+        assert!(engine.path_for_sign("mars").is_none());
+        assert_eq!(engine.paths_from("Malkuth").unwrap().len(), 3);
+        assert!(engine.paths_from("Daath").is_err());
+    }
+
+    #[test]
+    fn paths_agree_with_tarot_attributions() {
+        let engine = KabbalahEngine::new();
+        for card in create_deck().iter().filter(|c| c.is_major()) {
+            let tarot = kabbalah::kabbalah_for_card(card).unwrap();
+            let path = engine.path_for_card(&card.id).unwrap();
+            assert_eq!(tarot.path, Some(path.number));
+            assert_eq!(tarot.hebrew_letter.as_deref(), Some(path.letter.as_str()));
+            assert_eq!(tarot.hebrew_glyph.as_deref(), Some(path.glyph.as_str()));
+            let connects = path.connects.map(|n| engine.name_of(n));
+            assert_eq!(tarot.connects, Some(connects));
+        }
+    }
+
+    #[test]
+    fn pathworking_routes() {
+        let engine = KabbalahEngine::new();
+        let climb = engine.pathworking("Malkuth", "Kether").unwrap();
+        let numbers: Vec<u8> = climb.steps.iter().map(|s| s.path.number).collect();
+        assert_eq!(numbers, [32, 25, 13]);
+        assert_eq!(climb.steps[1].from, "Yesod");
+        assert_eq!(climb.steps[1].to, "Tiphareth");
+        assert!(engine.pathworking("hod", "Hod").unwrap().steps.is_empty());
+
+        let strength = engine.pathworking_for_card("major_08_strength").unwrap();
+        assert_eq!(strength.to, "Chesed");
+        let last = strength.steps.last().unwrap();
+        assert_eq!((last.from.as_str(), last.path.number), ("Geburah", 19));
+        assert_eq!(engine.pathworking_for_card("major_21_world").unwrap().steps.len(), 1);
+        assert_eq!(
+            engine.pathworking_for_card("cups_02").unwrap_err(),
+            KabbalahError::UnknownCard("cups_02".to_string())
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// KabbalahError — failures reported by the Kabbalah engine
+// ---------------------------------------------------------------------------
+
+/// Why a Tree of Life operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KabbalahError {
+    UnknownSephirah(String),
+    /// A card id that no path is attributed to
+    UnknownCard(String),
+    /// The embedded sephiroth or path data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for KabbalahError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSephirah(name) => write!(f, "unknown sephirah: {}", name),
+            Self::UnknownCard(id) => write!(f, "no path is attributed to card: {}", id),
+            Self::DataCorrupt(detail) => write!(f, "corrupt kabbalah data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for KabbalahError {}
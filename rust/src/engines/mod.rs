@@ -1,3 +1,17 @@
 pub mod tarot;
 pub mod iching;
 pub mod astrology;
+pub mod bazi;
+pub(crate) mod calendar;
+pub mod numerology;
+pub mod runes;
+pub mod geomancy;
+pub mod ogham;
+pub mod dreams;
+pub mod tzolkin;
+pub mod pendulum;
+pub mod bibliomancy;
+pub mod kabbalah;
+pub mod tasseography;
+pub mod dice;
+pub mod dominoes;
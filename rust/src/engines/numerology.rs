@@ -0,0 +1,421 @@
+mod error;
+mod patterns;
+
+use crate::engines::calendar::days_in_month;
+use crate::types::{
+    LifePathCompatibility, LifePathRelation, NameNumber, NameNumerology, NumberPattern,
+    NumerologySystem, YVowelRule,
+};
+
+use patterns::{find_patterns_in, pattern_data, time_patterns_in, PatternData};
+
+pub use error::NumerologyError;
+pub use patterns::{classify_digits, find_number_patterns, time_patterns, timestamp_patterns};
+
+// ---------------------------------------------------------------------------
+// Letter tables
+// ---------------------------------------------------------------------------
+//
+// Pythagorean values run 1–9 across the alphabet in three rows. The
+// Chaldean table assigns 1–8 by sound; 9 is held sacred and given to no
+// letter. Both read the 26 letters of the Latin alphabet, so names are
+// first folded to A–Z: accents are dropped and ligatures spelled out.
+
+/// Chaldean values for A–Z.
+const CHALDEAN: [u32; 26] = [
+    1, 2, 3, 4, 5, 8, 3, 5, 1, 1, 2, 3, 4, 5, 7, 8, 1, 2, 3, 4, 6, 6, 6, 5, 1, 7,
+];
+
+const MASTER_NUMBERS: [u32; 3] = [11, 22, 33];
+
+/// Value of an uppercase ASCII letter; `None` for anything else.
+pub fn letter_value(letter: char, system: NumerologySystem) -> Option<u32> {
+    if !letter.is_ascii_uppercase() {
+        return None;
+    }
+    let index = letter as u32 - 'A' as u32;
+    Some(match system {
+        NumerologySystem::Pythagorean => index % 9 + 1,
+        NumerologySystem::Chaldean => CHALDEAN[index as usize],
+    })
+}
+
+/// Sum of the decimal digits of `n`.
+pub fn digit_sum(mut n: u32) -> u32 {
+    let mut sum = 0;
+    while n > 0 {
+        sum += n % 10;
+        n /= 10;
+    }
+    sum
+}
+
+/// Sum digits until one digit remains.
+pub fn reduce_number(mut n: u32) -> u32 {
+    while n > 9 {
+        n = digit_sum(n);
+    }
+    n
+}
+
+/// Sum digits until one digit remains, stopping at 11, 22 or 33.
+pub fn reduce(mut n: u32) -> u32 {
+    while n > 9 && !MASTER_NUMBERS.contains(&n) {
+        n = digit_sum(n);
+    }
+    n
+}
+
+// ---------------------------------------------------------------------------
+// Name normalisation
+// ---------------------------------------------------------------------------
+
+/// Plain spelling of a lowercase letter with diacritics, or of a ligature.
+fn fold(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' | 'ĺ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' | 'ŕ' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    })
+}
+
+/// Uppercase A–Z words of `name`, split on spaces, hyphens and other
+/// separators. Apostrophes join ("O'Brien" is one word) and characters
+/// with no Latin spelling are dropped.
+pub fn normalize_name(name: &str) -> String {
+    let mut plain = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_lowercase() {
+            plain.push(c);
+        } else if let Some(folded) = fold(c) {
+            plain.push_str(folded);
+        } else if c.is_whitespace() || c == '-' || c == '.' || c == ',' {
+            plain.push(' ');
+        }
+    }
+    plain.split_whitespace().collect::<Vec<_>>().join(" ").to_ascii_uppercase()
+}
+
+fn is_plain_vowel(c: u8) -> bool {
+    matches!(c, b'A' | b'E' | b'I' | b'O' | b'U')
+}
+
+/// Whether the letter at `i` of an uppercase word counts as a vowel.
+fn is_vowel(word: &[u8], i: usize, y_rule: YVowelRule) -> bool {
+    match (word[i], y_rule) {
+        (b'Y', YVowelRule::Always) => true,
+        (b'Y', YVowelRule::Never) => false,
+        (b'Y', YVowelRule::Contextual) => {
+            let before = i.checked_sub(1).map(|j| word[j]);
+            let after = word.get(i + 1).copied();
+            !before.is_some_and(is_plain_vowel) && !after.is_some_and(is_plain_vowel)
+        }
+        (c, _) => is_plain_vowel(c),
+    }
+}
+
+fn name_number(total: u32) -> NameNumber {
+    NameNumber {
+        total,
+        value: reduce(total),
+    }
+}
+
+/// Expression, soul urge and personality numbers of `name`.
+///
+/// # Errors
+/// Returns [`NumerologyError::NoLetters`] if nothing in `name` can be read
+/// as a letter.
+pub fn name_numerology(
+    name: &str,
+    system: NumerologySystem,
+    y_rule: YVowelRule,
+) -> Result<NameNumerology, NumerologyError> {
+    let normalized = normalize_name(name);
+    if normalized.is_empty() {
+        return Err(NumerologyError::NoLetters(name.to_string()));
+    }
+    let (mut vowels, mut consonants) = (0, 0);
+    for word in normalized.split(' ').map(str::as_bytes) {
+        for (i, &c) in word.iter().enumerate() {
+            let value = letter_value(char::from(c), system).unwrap_or(0);
+            if is_vowel(word, i, y_rule) {
+                vowels += value;
+            } else {
+                consonants += value;
+            }
+        }
+    }
+    Ok(NameNumerology {
+        system,
+        normalized,
+        expression: name_number(vowels + consonants),
+        soul_urge: name_number(vowels),
+        personality: name_number(consonants),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Life path
+// ---------------------------------------------------------------------------
+//
+// The month, day and year are each reduced before they are added, so a
+// master number hidden in one part survives. Compatibility follows the
+// three triads of numbers that share a temperament; master numbers are read
+// by their root (11 as 2, 22 as 4, 33 as 6) for pairing.
+
+const LIFE_PATH_TRIADS: [[u32; 3]; 3] = [[1, 5, 7], [2, 4, 8], [3, 6, 9]];
+
+/// Pairs across triads that still sit easily together.
+const COMPATIBLE_LIFE_PATHS: [(u32, u32); 10] = [
+    (1, 3), (1, 9), (2, 3), (2, 6), (2, 9), (3, 5), (4, 6), (4, 7), (5, 9), (6, 8),
+];
+
+/// Life path number of a birth date.
+///
+/// # Errors
+/// Returns [`NumerologyError::InvalidDate`] for a month outside 1–12 or a
+/// day the month does not have.
+pub fn life_path(year: i32, month: u32, day: u32) -> Result<NameNumber, NumerologyError> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(NumerologyError::InvalidDate { month, day });
+    }
+    Ok(name_number(reduce(month) + reduce(day) + reduce(year.unsigned_abs())))
+}
+
+fn life_path_root(n: u32) -> u32 {
+    if n > 9 { reduce(n / 10 + n % 10) } else { n }
+}
+
+/// How two life path numbers relate.
+pub fn life_path_relation(first: u32, second: u32) -> LifePathRelation {
+    let (a, b) = (life_path_root(first), life_path_root(second));
+    if LIFE_PATH_TRIADS.iter().any(|t| t.contains(&a) && t.contains(&b)) {
+        LifePathRelation::Natural
+    } else if COMPATIBLE_LIFE_PATHS.contains(&(a.min(b), a.max(b))) {
+        LifePathRelation::Compatible
+    } else {
+        LifePathRelation::Challenging
+    }
+}
+
+/// Life path compatibility of two life path numbers.
+pub fn life_path_compatibility(first: NameNumber, second: NameNumber) -> LifePathCompatibility {
+    let relation = life_path_relation(first.value, second.value);
+    LifePathCompatibility {
+        first,
+        second,
+        relation,
+        score: match relation {
+            LifePathRelation::Natural => 90,
+            LifePathRelation::Compatible => 70,
+            LifePathRelation::Challenging => 40,
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// NumerologyEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct NumerologyEngine {
+    system: NumerologySystem,
+    y_rule: YVowelRule,
+    patterns: &'static PatternData,
+}
+
+impl NumerologyEngine {
+    /// Pythagorean values with Y read by context.
+    ///
+    /// # Panics
+    /// Panics if the embedded pattern data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded numerology data: {}", e))
+    }
+
+    /// Load and validate the embedded pattern data.
+    ///
+    /// # Errors
+    /// Returns [`NumerologyError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, NumerologyError> {
+        Ok(Self {
+            system: NumerologySystem::default(),
+            y_rule: YVowelRule::default(),
+            patterns: pattern_data()?,
+        })
+    }
+
+    pub fn with_system(system: NumerologySystem) -> Self {
+        Self {
+            system,
+            ..Self::new()
+        }
+    }
+
+    pub fn system(&self) -> NumerologySystem {
+        self.system
+    }
+
+    /// Choose when Y counts as a vowel.
+    pub fn set_y_rule(&mut self, y_rule: YVowelRule) {
+        self.y_rule = y_rule;
+    }
+
+    /// Expression, soul urge and personality numbers of `name`.
+    ///
+    /// # Errors
+    /// Returns an error if `name` has no letters.
+    pub fn name_numerology(&self, name: &str) -> Result<NameNumerology, NumerologyError> {
+        name_numerology(name, self.system, self.y_rule)
+    }
+
+    /// Repeating, mirrored and sequential numbers in `text`, such as 444,
+    /// 1212 or 12:21.
+    pub fn number_patterns(&self, text: &str) -> Vec<NumberPattern> {
+        find_patterns_in(self.patterns, text)
+    }
+
+    /// Life path number of a birth date.
+    ///
+    /// # Errors
+    /// Returns an error for a month or day out of range.
+    pub fn life_path(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<NameNumber, NumerologyError> {
+        life_path(year, month, day)
+    }
+
+    /// Life path compatibility of two birth dates, given as
+    /// `(year, month, day)`.
+    ///
+    /// # Errors
+    /// Returns an error for a month or day out of range.
+    pub fn life_path_compatibility(
+        &self,
+        first: (i32, u32, u32),
+        second: (i32, u32, u32),
+    ) -> Result<LifePathCompatibility, NumerologyError> {
+        Ok(life_path_compatibility(
+            life_path(first.0, first.1, first.2)?,
+            life_path(second.0, second.1, second.2)?,
+        ))
+    }
+
+    /// Significant numbers in a clock time.
+    pub fn time_patterns(&self, hour: u32, minute: u32) -> Vec<NumberPattern> {
+        time_patterns_in(self.patterns, hour, minute)
+    }
+}
+
+impl Default for NumerologyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letter_tables() {
+        let values = |system| -> Vec<u32> {
+            "AIJRSZ".chars().map(|c| letter_value(c, system).unwrap()).collect()
+        };
+        assert_eq!(values(NumerologySystem::Pythagorean), [1, 9, 1, 9, 1, 8]);
+        assert_eq!(values(NumerologySystem::Chaldean), [1, 1, 1, 2, 3, 7]);
+        assert!(CHALDEAN.iter().all(|&v| (1..=8).contains(&v)));
+        assert_eq!(letter_value('a', NumerologySystem::Pythagorean), None);
+        assert_eq!((reduce(38), reduce(29), reduce(0)), (11, 11, 0));
+        assert_eq!(reduce(1987), 7);
+    }
+
+    #[test]
+    fn names_in_both_systems() {
+        let engine = NumerologyEngine::new();
+        // J1 O6 H8 N5 / S1 M4 I9 T2 H8
+        let john = engine.name_numerology("John Smith").unwrap();
+        assert_eq!(john.expression, NameNumber { total: 44, value: 8 });
+        assert_eq!(john.soul_urge, NameNumber { total: 15, value: 6 });
+        assert_eq!(john.personality, NameNumber { total: 29, value: 11 });
+
+        // J1 O7 H5 N5 / S3 M4 I1 T4 H5
+        let chaldean = NumerologyEngine::with_system(NumerologySystem::Chaldean);
+        let john = chaldean.name_numerology("John Smith").unwrap();
+        assert_eq!((john.expression.total, john.expression.value), (35, 8));
+        assert_eq!(john.system, NumerologySystem::Chaldean);
+    }
+
+    #[test]
+    fn diacritics_and_separators_are_normalised() {
+        assert_eq!(normalize_name("  Zoë  O'Brien-Ångström "), "ZOE OBRIEN ANGSTROM");
+        assert_eq!(normalize_name("Jürgen Straß"), "JURGEN STRASS");
+        let engine = NumerologyEngine::new();
+        assert_eq!(
+            engine.name_numerology("José").unwrap().expression,
+            engine.name_numerology("jose").unwrap().expression
+        );
+        assert_eq!(
+            engine.name_numerology("张伟 ?").unwrap_err(),
+            NumerologyError::NoLetters("张伟 ?".to_string())
+        );
+    }
+
+    #[test]
+    fn y_follows_the_chosen_rule() {
+        let mut engine = NumerologyEngine::new();
+        // Y (7) sounds as a vowel in Mary and Lynn but not in Maya
+        assert_eq!(engine.name_numerology("Mary").unwrap().soul_urge.total, 8);
+        assert_eq!(engine.name_numerology("Lynn").unwrap().soul_urge.total, 7);
+        assert_eq!(engine.name_numerology("Maya").unwrap().soul_urge.total, 2);
+        engine.set_y_rule(YVowelRule::Never);
+        assert_eq!(engine.name_numerology("Lynn").unwrap().soul_urge.total, 0);
+        engine.set_y_rule(YVowelRule::Always);
+        assert_eq!(engine.name_numerology("Maya").unwrap().soul_urge.total, 9);
+    }
+
+    #[test]
+    fn life_paths() {
+        // 6 + (1+5) + (1+9+9+0 → 19 → 1) = 13 → 4
+        assert_eq!(life_path(1990, 6, 15).unwrap(), NameNumber { total: 13, value: 4 });
+        // 11 + 2 + 9 (1989 → 27 → 9) = 22, a master number
+        assert_eq!(life_path(1989, 11, 2).unwrap().value, 22);
+        assert_eq!(
+            life_path(1990, 13, 1),
+            Err(NumerologyError::InvalidDate { month: 13, day: 1 })
+        );
+        assert!(life_path(2024, 2, 29).is_ok());
+        assert!(life_path(2023, 2, 29).is_err());
+
+        assert_eq!(life_path_relation(1, 7), LifePathRelation::Natural);
+        assert_eq!(life_path_relation(22, 8), LifePathRelation::Natural);
+        assert_eq!(life_path_relation(9, 1), LifePathRelation::Compatible);
+        assert_eq!(life_path_relation(4, 5), LifePathRelation::Challenging);
+        let pair = NumerologyEngine::new()
+            .life_path_compatibility((1990, 6, 15), (1989, 11, 2))
+            .unwrap();
+        assert_eq!((pair.relation, pair.score), (LifePathRelation::Natural, 90));
+    }
+}
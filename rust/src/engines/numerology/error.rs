@@ -0,0 +1,28 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// NumerologyError — failures reported by the numerology engine
+// ---------------------------------------------------------------------------
+
+/// Why a numerology operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumerologyError {
+    /// A name with no letters left after normalisation
+    NoLetters(String),
+    /// A calendar month or day out of range
+    InvalidDate { month: u32, day: u32 },
+    /// The embedded pattern data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for NumerologyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoLetters(name) => write!(f, "'{}' has no letters to count", name),
+            Self::InvalidDate { month, day } => write!(f, "invalid date: {}/{}", month, day),
+            Self::DataCorrupt(detail) => write!(f, "corrupt numerology data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for NumerologyError {}
@@ -0,0 +1,318 @@
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::types::{NumberPattern, NumberPatternKind};
+
+use super::NumerologyError;
+
+// ---------------------------------------------------------------------------
+// Repeating-number patterns ("angel numbers")
+// ---------------------------------------------------------------------------
+//
+// Numbers are read from text as runs of digits, which may be split by a
+// colon or a point between digits, so "11:11" and "$4.44" are found as 1111
+// and 444. A colon marks the number as a clock time. Only runs of three to
+// eight digits are considered: shorter ones are too common to mean anything
+// and longer ones are phone or account numbers.
+//
+// Meanings come from the embedded data: the exact digits if listed, then
+// the first three digits of a longer repeat (4444 reads as 444), then the
+// general meaning of the pattern's kind.
+
+const PATTERNS_JSON: &str = include_str!("../../../../data/numerology/patterns.json");
+
+const MIN_DIGITS: usize = 3;
+const MAX_DIGITS: usize = 8;
+
+const KINDS: [NumberPatternKind; 6] = [
+    NumberPatternKind::Repeating,
+    NumberPatternKind::RepeatingPair,
+    NumberPatternKind::MirroredTime,
+    NumberPatternKind::Palindrome,
+    NumberPatternKind::Ascending,
+    NumberPatternKind::Descending,
+];
+
+/// Meanings of listed numbers and of each pattern kind.
+#[derive(Debug, Deserialize)]
+pub struct PatternData {
+    numbers: Vec<NumberMeaning>,
+    kinds: Vec<KindMeaning>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NumberMeaning {
+    digits: String,
+    keywords: Vec<String>,
+    meaning: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KindMeaning {
+    kind: NumberPatternKind,
+    keywords: Vec<String>,
+    meaning: String,
+}
+
+fn load_pattern_data() -> Result<PatternData, NumerologyError> {
+    serde_json::from_str(PATTERNS_JSON).map_err(|e| NumerologyError::DataCorrupt(e.to_string()))
+}
+
+/// Check that every listed number has a pattern and is listed once, and
+/// that every kind has a meaning.
+fn validate_pattern_data(data: &PatternData) -> Result<(), NumerologyError> {
+    let mut problems = Vec::new();
+    for (i, n) in data.numbers.iter().enumerate() {
+        if classify_digits(&n.digits, true).is_none() || n.digits.len() > MAX_DIGITS {
+            problems.push(format!("{} is not a significant number", n.digits));
+        }
+        if data.numbers[..i].iter().any(|m| m.digits == n.digits) {
+            problems.push(format!("{} is listed twice", n.digits));
+        }
+    }
+    for kind in KINDS {
+        if data.kinds.iter().all(|k| k.kind != kind) {
+            problems.push(format!("no meaning for {:?}", kind));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(NumerologyError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+/// The embedded pattern meanings, parsed and validated on first use.
+pub fn pattern_data() -> Result<&'static PatternData, NumerologyError> {
+    static DATA: OnceLock<Result<PatternData, NumerologyError>> = OnceLock::new();
+    let data = DATA.get_or_init(|| {
+        let data = load_pattern_data()?;
+        validate_pattern_data(&data)?;
+        Ok(data)
+    });
+    data.as_ref().map_err(Clone::clone)
+}
+
+/// The shape of `digits`, if it has one. Repeats are checked first, so
+/// 11:11 is a repeating number rather than a mirrored time.
+pub fn classify_digits(digits: &str, is_time: bool) -> Option<NumberPatternKind> {
+    let d: Vec<u8> = digits.bytes().filter(u8::is_ascii_digit).map(|b| b - b'0').collect();
+    if d.len() < MIN_DIGITS || d.len() != digits.len() {
+        return None;
+    }
+    let steps_by = |step: i8| d.windows(2).all(|w| w[1] as i8 - w[0] as i8 == step);
+    if d.iter().all(|&x| x == d[0]) {
+        Some(NumberPatternKind::Repeating)
+    } else if d.len().is_multiple_of(2) && d.iter().enumerate().all(|(i, &x)| x == d[i % 2]) {
+        Some(NumberPatternKind::RepeatingPair)
+    } else if d.iter().eq(d.iter().rev()) {
+        Some(if is_time {
+            NumberPatternKind::MirroredTime
+        } else {
+            NumberPatternKind::Palindrome
+        })
+    } else if steps_by(1) {
+        Some(NumberPatternKind::Ascending)
+    } else if steps_by(-1) {
+        Some(NumberPatternKind::Descending)
+    } else {
+        None
+    }
+}
+
+/// Runs of digits in `text` as (as written, digits, is a time). A colon or
+/// point only joins a run when digits follow it.
+fn number_tokens(text: &str) -> Vec<(String, String, bool)> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut is_time = false;
+        while i < chars.len() {
+            if chars[i].is_ascii_digit() {
+                i += 1;
+            } else if matches!(chars[i], ':' | '.')
+                && chars.get(i + 1).is_some_and(char::is_ascii_digit)
+            {
+                is_time |= chars[i] == ':';
+                i += 1;
+            } else {
+                break;
+            }
+        }
+        let written: String = chars[start..i].iter().collect();
+        let digits: String = written.chars().filter(char::is_ascii_digit).collect();
+        tokens.push((written, digits, is_time));
+    }
+    tokens
+}
+
+fn describe(
+    data: &PatternData,
+    text: String,
+    digits: String,
+    kind: NumberPatternKind,
+) -> NumberPattern {
+    let listed = |d: &str| data.numbers.iter().find(|n| n.digits == d);
+    let shortened = match kind {
+        NumberPatternKind::Repeating => listed(&digits[..MIN_DIGITS]),
+        _ => None,
+    };
+    let (keywords, meaning) = match listed(&digits).or(shortened) {
+        Some(n) => (n.keywords.clone(), n.meaning.clone()),
+        None => data
+            .kinds
+            .iter()
+            .find(|k| k.kind == kind)
+            .map(|k| (k.keywords.clone(), k.meaning.clone()))
+            .unwrap_or_default(),
+    };
+    NumberPattern {
+        text,
+        digits,
+        kind,
+        keywords,
+        meaning,
+    }
+}
+
+/// Every significant number in `text`, in the order written.
+///
+/// # Errors
+/// Returns [`NumerologyError::DataCorrupt`] if the embedded data is invalid.
+pub fn find_number_patterns(text: &str) -> Result<Vec<NumberPattern>, NumerologyError> {
+    Ok(find_patterns_in(pattern_data()?, text))
+}
+
+pub(super) fn find_patterns_in(data: &PatternData, text: &str) -> Vec<NumberPattern> {
+    number_tokens(text)
+        .into_iter()
+        .filter(|(_, digits, _)| digits.len() <= MAX_DIGITS)
+        .filter_map(|(written, digits, is_time)| {
+            let kind = classify_digits(&digits, is_time)?;
+            Some(describe(data, written, digits, kind))
+        })
+        .collect()
+}
+
+/// Patterns in a clock time, read on a 24-hour clock ("13:31") and, in the
+/// afternoon, also on a 12-hour clock ("1:31"). Returns nothing for an
+/// hour or minute out of range.
+///
+/// # Errors
+/// Returns [`NumerologyError::DataCorrupt`] if the embedded data is invalid.
+pub fn time_patterns(hour: u32, minute: u32) -> Result<Vec<NumberPattern>, NumerologyError> {
+    Ok(time_patterns_in(pattern_data()?, hour, minute))
+}
+
+pub(super) fn time_patterns_in(data: &PatternData, hour: u32, minute: u32) -> Vec<NumberPattern> {
+    if hour > 23 || minute > 59 {
+        return Vec::new();
+    }
+    let mut times = vec![format!("{:02}:{:02}", hour, minute)];
+    let twelve = match hour % 12 {
+        0 => 12,
+        h => h,
+    };
+    if twelve != hour {
+        times.push(format!("{}:{:02}", twelve, minute));
+    }
+    // "09:09" reads as 909 on a clock without a leading zero
+    if hour < 10 {
+        times.push(format!("{}:{:02}", hour, minute));
+    }
+    let mut patterns: Vec<NumberPattern> = Vec::new();
+    for time in times {
+        for p in find_patterns_in(data, &time) {
+            if patterns.iter().all(|q| q.digits != p.digits) {
+                patterns.push(p);
+            }
+        }
+    }
+    patterns
+}
+
+/// Patterns in the local clock time of a timestamp in milliseconds since
+/// the Unix epoch, `utc_offset_minutes` east of UTC.
+///
+/// # Errors
+/// Returns [`NumerologyError::DataCorrupt`] if the embedded data is invalid.
+pub fn timestamp_patterns(
+    timestamp_ms: u64,
+    utc_offset_minutes: i32,
+) -> Result<Vec<NumberPattern>, NumerologyError> {
+    let minutes = (timestamp_ms / 60_000) as i64 + i64::from(utc_offset_minutes);
+    let of_day = minutes.rem_euclid(24 * 60) as u32;
+    time_patterns(of_day / 60, of_day % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(text: &str) -> Vec<NumberPattern> {
+        find_number_patterns(text).unwrap()
+    }
+
+    fn kinds(text: &str) -> Vec<(String, NumberPatternKind)> {
+        patterns(text).into_iter().map(|p| (p.digits, p.kind)).collect()
+    }
+
+    #[test]
+    fn classifies_digit_shapes() {
+        use NumberPatternKind::*;
+        assert_eq!(classify_digits("444", false), Some(Repeating));
+        assert_eq!(classify_digits("1111", true), Some(Repeating));
+        assert_eq!(classify_digits("1212", true), Some(RepeatingPair));
+        assert_eq!(classify_digits("1221", true), Some(MirroredTime));
+        assert_eq!(classify_digits("1221", false), Some(Palindrome));
+        assert_eq!(classify_digits("3456", false), Some(Ascending));
+        assert_eq!(classify_digits("321", false), Some(Descending));
+        assert_eq!(classify_digits("1213", false), None);
+        assert_eq!(classify_digits("11", false), None);
+    }
+
+    #[test]
+    fn finds_numbers_in_text() {
+        use NumberPatternKind::*;
+        let found = kinds("Woke at 11:11, paid $4.44, then saw 12:21 and 1234 on a plate. 3.14159");
+        assert_eq!(
+            found,
+            [
+                ("1111".to_string(), Repeating),
+                ("444".to_string(), Repeating),
+                ("1221".to_string(), MirroredTime),
+                ("1234".to_string(), Ascending),
+            ]
+        );
+        let p = &patterns("room 4444")[0];
+        assert_eq!(p.text, "4444");
+        assert_eq!(p.meaning, patterns("444")[0].meaning);
+        // Kinds without a listed number fall back to the kind's meaning
+        assert!(patterns("404")[0].meaning.contains("same both ways"));
+        assert!(patterns("call 5551212333 or 12").is_empty());
+        let train = &patterns("the 4:44 train")[0];
+        assert!(train.keywords.contains(&"protection".to_string()));
+    }
+
+    #[test]
+    fn clock_times() {
+        let digits = |ps: Result<Vec<NumberPattern>, NumerologyError>| {
+            ps.unwrap().into_iter().map(|p| p.digits).collect::<Vec<_>>()
+        };
+        assert_eq!(digits(time_patterns(13, 31)), ["1331", "131"]);
+        assert_eq!(digits(time_patterns(11, 11)), ["1111"]);
+        assert_eq!(digits(time_patterns(9, 9)), ["0909", "909"]);
+        assert!(digits(time_patterns(10, 37)).is_empty());
+        assert!(digits(time_patterns(24, 0)).is_empty());
+        // 2024-01-01T02:33:00Z is 03:33 at UTC+1
+        assert_eq!(digits(timestamp_patterns(1_704_076_380_000, 60)), ["333"]);
+        assert!(digits(timestamp_patterns(1_704_076_380_000, 0)).is_empty());
+    }
+}
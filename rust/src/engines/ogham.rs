@@ -0,0 +1,407 @@
+mod error;
+mod tree_signs;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::types::{DrawnFid, FidReading, OghamDraw, OghamFid, OghamReading, OghamSpread, TreeSign};
+
+pub use crate::rng::seeded_rng;
+pub use error::OghamError;
+pub use tree_signs::tree_sign_for;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const FEDA_JSON: &str = include_str!("../../../data/ogham/feda.json");
+const SPREADS_JSON: &str = include_str!("../../../data/ogham/spreads.json");
+
+fn load_feda() -> Result<Vec<OghamFid>, OghamError> {
+    serde_json::from_str(FEDA_JSON).map_err(|e| OghamError::DataCorrupt(e.to_string()))
+}
+
+fn load_spreads() -> Result<Vec<OghamSpread>, OghamError> {
+    serde_json::from_str(SPREADS_JSON).map_err(|e| OghamError::DataCorrupt(e.to_string()))
+}
+
+/// Check that there are 20 feda numbered in order, five to each aicme, that
+/// each spread's positions match its fid count and that the tree signs
+/// cover the year.
+fn validate_data(
+    feda: &[OghamFid],
+    spreads: &[OghamSpread],
+    tree_signs: &[TreeSign],
+) -> Result<(), OghamError> {
+    let mut problems = tree_signs::validate_tree_signs(tree_signs, feda);
+    if feda.len() != 20 {
+        problems.push(format!("{} feda instead of 20", feda.len()));
+    }
+    for (expected, fid) in (1..).zip(feda) {
+        if fid.number != expected {
+            problems.push(format!("fid {} is numbered {}", fid.name, fid.number));
+        }
+    }
+    for aicme in AICMI.map(|(key, _, _)| key) {
+        let count = feda.iter().filter(|f| f.aicme == aicme).count();
+        if count != 5 {
+            problems.push(format!("aicme {} has {} feda", aicme, count));
+        }
+    }
+    for spread in spreads {
+        if spread.positions.len() != spread.fid_count {
+            problems.push(format!("spread {} has {} positions", spread.id, spread.positions.len()));
+        }
+        if spread.fid_count > feda.len() {
+            problems.push(format!("spread {} needs {} feda", spread.id, spread.fid_count));
+        }
+        if spread.positions.iter().enumerate().any(|(i, p)| p.index != i) {
+            problems.push(format!("spread {} positions are out of order", spread.id));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(OghamError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Drawing
+// ---------------------------------------------------------------------------
+//
+// Feda are drawn without replacement, like staves pulled from a bag. Ogham
+// staves are not read reversed.
+
+/// Draw one fid into each position of `spread`.
+///
+/// # Errors
+/// Returns [`OghamError::DataCorrupt`] if the spread needs more feda than
+/// `feda` holds.
+pub fn draw_feda_with_rng<R: Rng + ?Sized>(
+    feda: &[OghamFid],
+    spread: &OghamSpread,
+    rng: &mut R,
+) -> Result<OghamDraw, OghamError> {
+    if spread.fid_count > feda.len() {
+        return Err(OghamError::DataCorrupt(format!(
+            "spread {} needs {} feda but only {} are available",
+            spread.id,
+            spread.fid_count,
+            feda.len()
+        )));
+    }
+    let drawn = feda
+        .choose_multiple(rng, spread.fid_count)
+        .enumerate()
+        .map(|(position_index, fid)| DrawnFid {
+            fid: fid.clone(),
+            position_index,
+        })
+        .collect();
+    Ok(OghamDraw {
+        spread_id: spread.id.clone(),
+        feda: drawn,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Interpretation
+// ---------------------------------------------------------------------------
+
+/// Each aicme's key, the fid it is named after and the theme it brings to a
+/// reading.
+const AICMI: [(&str, &str, &str); 4] = [
+    ("beithe", "Beith", "beginnings, protection and the self"),
+    ("huatha", "Uath", "obstacles, strength and decisions"),
+    ("muine", "Muin", "the inner life, growth and hard-won knowledge"),
+    ("ailme", "Ailm", "spirit, endurance and renewal"),
+];
+
+fn fid_text(position_name: &str, position_description: &str, fid: &OghamFid) -> String {
+    format!(
+        "{}: {} ({}, {}). {} Keywords: {}. {} {}",
+        position_name,
+        fid.name,
+        fid.tree,
+        fid.character,
+        position_description,
+        fid.keywords.join(", "),
+        fid.meaning,
+        fid.advice
+    )
+}
+
+/// Compose a reading of `draw`: each position's description merged with its
+/// fid's meaning and advice, and a summary naming the trees drawn and any
+/// aicme that holds most of the draw.
+///
+/// # Errors
+/// Returns [`OghamError::DataCorrupt`] if a fid was drawn into a position
+/// the spread does not have.
+pub fn interpret_draw(spread: &OghamSpread, draw: &OghamDraw) -> Result<OghamReading, OghamError> {
+    let mut drawn: Vec<&DrawnFid> = draw.feda.iter().collect();
+    drawn.sort_by_key(|d| d.position_index);
+
+    let mut positions = Vec::with_capacity(drawn.len());
+    for d in &drawn {
+        let position = spread.positions.get(d.position_index).ok_or_else(|| {
+            OghamError::DataCorrupt(format!(
+                "spread {} has no position {}",
+                spread.id, d.position_index
+            ))
+        })?;
+        positions.push(FidReading {
+            position_index: d.position_index,
+            position_name: position.name.clone(),
+            fid_name: d.fid.name.clone(),
+            tree: d.fid.tree.clone(),
+            text: fid_text(&position.name, &position.description, &d.fid),
+        });
+    }
+
+    // A single fid is never said to dominate
+    let dominant_aicme = AICMI.iter().find(|(key, _, _)| {
+        let count = drawn.iter().filter(|d| d.fid.aicme == *key).count();
+        drawn.len() > 1 && count * 2 > drawn.len()
+    });
+    let trees: Vec<&str> = drawn.iter().map(|d| d.fid.tree.as_str()).collect();
+    let mut summary = vec![match trees.as_slice() {
+        [] => "No feda were drawn.".to_string(),
+        [tree] => format!("The {} speaks alone.", tree),
+        [rest @ .., last] => format!("The {} and {} speak together.", rest.join(", "), last),
+    }];
+    if let Some((_, name, theme)) = dominant_aicme {
+        summary.push(format!(
+            "Most of the draw comes from the aicme of {}, pointing to {}.",
+            name, theme
+        ));
+    }
+
+    Ok(OghamReading {
+        spread_id: spread.id.clone(),
+        spread_name: spread.name.clone(),
+        positions,
+        dominant_aicme: dominant_aicme.map(|(key, _, _)| key.to_string()),
+        summary: summary.join(" "),
+    })
+}
+
+// ---------------------------------------------------------------------------
+// OghamEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct OghamEngine {
+    feda: Vec<OghamFid>,
+    spreads: Vec<OghamSpread>,
+    tree_signs: Vec<TreeSign>,
+}
+
+impl OghamEngine {
+    /// Create an engine from the embedded feda, spreads and tree signs.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded ogham data: {}", e))
+    }
+
+    /// Load and validate the embedded feda, spreads and tree signs.
+    ///
+    /// # Errors
+    /// Returns [`OghamError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, OghamError> {
+        let feda = load_feda()?;
+        let spreads = load_spreads()?;
+        let tree_signs = tree_signs::load_tree_signs()?;
+        validate_data(&feda, &spreads, &tree_signs)?;
+        Ok(Self {
+            feda,
+            spreads,
+            tree_signs,
+        })
+    }
+
+    /// All 20 feda in alphabet order.
+    pub fn feda(&self) -> &[OghamFid] {
+        &self.feda
+    }
+
+    /// Look up a fid by name, tree (case-insensitive) or its character.
+    pub fn get_fid(&self, name: &str) -> Option<&OghamFid> {
+        let name = name.trim();
+        let lower = name.to_lowercase();
+        self.feda.iter().find(|f| {
+            f.name.to_lowercase() == lower || f.tree.to_lowercase() == lower || f.character == name
+        })
+    }
+
+    pub fn spreads(&self) -> &[OghamSpread] {
+        &self.spreads
+    }
+
+    pub fn get_spread(&self, id: &str) -> Option<&OghamSpread> {
+        self.spreads.iter().find(|s| s.id == id)
+    }
+
+    /// Draw feda into the named spread.
+    ///
+    /// # Errors
+    /// Returns [`OghamError::UnknownSpread`] for an unknown spread id.
+    pub fn draw(&self, spread_id: &str) -> Result<OghamDraw, OghamError> {
+        self.draw_with_rng(spread_id, &mut rand::thread_rng())
+    }
+
+    /// Draw feda into the named spread with the given RNG.
+    ///
+    /// # Errors
+    /// Returns [`OghamError::UnknownSpread`] for an unknown spread id.
+    pub fn draw_with_rng<R: Rng + ?Sized>(
+        &self,
+        spread_id: &str,
+        rng: &mut R,
+    ) -> Result<OghamDraw, OghamError> {
+        draw_feda_with_rng(&self.feda, self.spread(spread_id)?, rng)
+    }
+
+    /// Record feda the querent drew themselves, in position order.
+    ///
+    /// # Errors
+    /// Returns [`OghamError::UnknownSpread`] or [`OghamError::UnknownFid`]
+    /// for an unknown spread or fid, [`OghamError::WrongCount`] if the
+    /// number of feda does not match the spread and
+    /// [`OghamError::DuplicateFid`] if a fid is named twice, since a fid
+    /// leaves the bag once drawn.
+    pub fn draw_from_names(
+        &self,
+        spread_id: &str,
+        names: &[&str],
+    ) -> Result<OghamDraw, OghamError> {
+        let spread = self.spread(spread_id)?;
+        if names.len() != spread.fid_count {
+            return Err(OghamError::WrongCount {
+                expected: spread.fid_count,
+                got: names.len(),
+            });
+        }
+        let mut feda: Vec<DrawnFid> = Vec::with_capacity(names.len());
+        for (position_index, name) in names.iter().enumerate() {
+            let fid = self
+                .get_fid(name)
+                .ok_or_else(|| OghamError::UnknownFid(name.to_string()))?;
+            if feda.iter().any(|d| d.fid.name == fid.name) {
+                return Err(OghamError::DuplicateFid(fid.name.clone()));
+            }
+            feda.push(DrawnFid {
+                fid: fid.clone(),
+                position_index,
+            });
+        }
+        Ok(OghamDraw {
+            spread_id: spread.id.clone(),
+            feda,
+        })
+    }
+
+    /// Compose a reading of `draw`.
+    ///
+    /// # Errors
+    /// Returns an error if the draw's spread is unknown or a fid lies in a
+    /// position the spread does not have.
+    pub fn interpret(&self, draw: &OghamDraw) -> Result<OghamReading, OghamError> {
+        interpret_draw(self.spread(&draw.spread_id)?, draw)
+    }
+
+    /// The 13 trees of the Celtic tree zodiac, birch first.
+    pub fn tree_signs(&self) -> &[TreeSign] {
+        &self.tree_signs
+    }
+
+    /// The Celtic tree sign for a birthday.
+    ///
+    /// # Errors
+    /// Returns [`OghamError::InvalidDate`] if the month or day is out of
+    /// range.
+    pub fn tree_sign(&self, month: u32, day: u32) -> Result<&TreeSign, OghamError> {
+        tree_sign_for(&self.tree_signs, month, day)
+    }
+
+    fn spread(&self, spread_id: &str) -> Result<&OghamSpread, OghamError> {
+        self.get_spread(spread_id)
+            .ok_or_else(|| OghamError::UnknownSpread(spread_id.to_string()))
+    }
+}
+
+impl Default for OghamEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_data_is_the_ogham_alphabet() {
+        let engine = OghamEngine::new();
+        assert_eq!(engine.feda().len(), 20);
+        assert_eq!(engine.get_fid("beith").unwrap().character, "ᚁ");
+        assert_eq!(engine.get_fid("Oak").unwrap().name, "Dair");
+        assert_eq!(engine.get_fid("ᚔ").unwrap().tree, "yew");
+        assert_eq!(engine.get_fid("úr").unwrap().name, "Úr");
+        let counts: Vec<usize> = engine.spreads().iter().map(|s| s.fid_count).collect();
+        assert_eq!(counts, [1, 3]);
+    }
+
+    #[test]
+    fn draws_are_distinct_and_in_position_order() {
+        let engine = OghamEngine::new();
+        let mut rng = seeded_rng(5);
+        for _ in 0..20 {
+            let draw = engine.draw_with_rng("three_feda", &mut rng).unwrap();
+            assert_eq!(draw.feda.len(), 3);
+            for (i, d) in draw.feda.iter().enumerate() {
+                assert_eq!(d.position_index, i);
+                assert!(!draw.feda[..i].iter().any(|o| o.fid.number == d.fid.number));
+            }
+        }
+        assert_eq!(engine.draw("single").unwrap().feda.len(), 1);
+        assert_eq!(
+            engine.draw("celtic_cross").err(),
+            Some(OghamError::UnknownSpread("celtic_cross".to_string()))
+        );
+    }
+
+    #[test]
+    fn readings_compose_positions_and_summary() {
+        let engine = OghamEngine::new();
+        let draw = engine.draw_from_names("three_feda", &["Beith", "rowan", "Coll"]).unwrap();
+        let reading = engine.interpret(&draw).unwrap();
+        assert_eq!(reading.positions.len(), 3);
+        assert!(reading.positions[0].text.starts_with("Root: Beith (birch, ᚁ)."));
+        assert_eq!(reading.positions[1].fid_name, "Luis");
+        assert_eq!(reading.dominant_aicme.as_deref(), Some("beithe"));
+        assert!(reading.summary.starts_with("The birch, rowan and hazel speak together."));
+        assert!(reading.summary.contains("aicme of Beith"));
+
+        let single = engine.draw_from_names("single", &["Ailm"]).unwrap();
+        let reading = engine.interpret(&single).unwrap();
+        assert_eq!(reading.summary, "The pine speaks alone.");
+        assert!(reading.dominant_aicme.is_none());
+
+        assert_eq!(
+            engine.draw_from_names("single", &["oak tree"]).err(),
+            Some(OghamError::UnknownFid("oak tree".to_string()))
+        );
+        assert_eq!(
+            engine.draw_from_names("three_feda", &["Beith"]).err(),
+            Some(OghamError::WrongCount { expected: 3, got: 1 })
+        );
+        assert_eq!(
+            engine.draw_from_names("three_feda", &["Beith", "Coll", "birch"]).err(),
+            Some(OghamError::DuplicateFid("Beith".to_string()))
+        );
+    }
+}
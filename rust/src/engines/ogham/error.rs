@@ -0,0 +1,37 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// OghamError — failures reported by the Ogham engine
+// ---------------------------------------------------------------------------
+
+/// Why an Ogham operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OghamError {
+    UnknownSpread(String),
+    UnknownFid(String),
+    /// A hand-drawn reading names the wrong number of feda for its spread
+    WrongCount { expected: usize, got: usize },
+    /// A hand-drawn reading names the same fid twice
+    DuplicateFid(String),
+    /// A calendar month or day out of range
+    InvalidDate { month: u32, day: u32 },
+    /// The embedded feda or spread data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for OghamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSpread(id) => write!(f, "unknown ogham spread: {}", id),
+            Self::UnknownFid(name) => write!(f, "unknown fid: {}", name),
+            Self::WrongCount { expected, got } => {
+                write!(f, "expected {} feda, got {}", expected, got)
+            }
+            Self::DuplicateFid(name) => write!(f, "fid drawn twice: {}", name),
+            Self::InvalidDate { month, day } => write!(f, "invalid date: {}/{}", month, day),
+            Self::DataCorrupt(detail) => write!(f, "corrupt ogham data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for OghamError {}
@@ -0,0 +1,102 @@
+use crate::engines::calendar::days_in_month;
+use crate::types::{OghamFid, TreeSign};
+
+use super::OghamError;
+
+// ---------------------------------------------------------------------------
+// Celtic tree zodiac
+// ---------------------------------------------------------------------------
+//
+// The modern tree calendar after Robert Graves: thirteen 28-day months,
+// each ruled by a tree of the Ogham, running from the birch month that
+// opens on 24 December. Every day of the year, 29 February included, falls
+// in exactly one month.
+
+const TREE_SIGNS_JSON: &str = include_str!("../../../../data/ogham/tree_signs.json");
+
+pub(super) fn load_tree_signs() -> Result<Vec<TreeSign>, OghamError> {
+    serde_json::from_str(TREE_SIGNS_JSON).map_err(|e| OghamError::DataCorrupt(e.to_string()))
+}
+
+/// Days in `month` of a leap year, so 29 February is covered.
+fn month_days(month: u32) -> u32 {
+    days_in_month(2000, month)
+}
+
+fn valid_date(month: u32, day: u32) -> bool {
+    (1..=12).contains(&month) && (1..=month_days(month)).contains(&day)
+}
+
+fn contains(sign: &TreeSign, month: u32, day: u32) -> bool {
+    let date = (month, day);
+    let (start, end) = ((sign.start[0], sign.start[1]), (sign.end[0], sign.end[1]));
+    if start <= end {
+        start <= date && date <= end
+    } else {
+        // The birch month runs over the new year
+        date >= start || date <= end
+    }
+}
+
+/// Check that there are 13 signs, that each names a fid and that every day
+/// of the year falls in exactly one sign.
+pub(super) fn validate_tree_signs(signs: &[TreeSign], feda: &[OghamFid]) -> Vec<String> {
+    let mut problems = Vec::new();
+    if signs.len() != 13 {
+        problems.push(format!("{} tree signs instead of 13", signs.len()));
+    }
+    for sign in signs {
+        if feda.iter().all(|f| f.name != sign.ogham) {
+            problems.push(format!("tree sign {} names unknown fid {}", sign.tree, sign.ogham));
+        }
+        if !valid_date(sign.start[0], sign.start[1]) || !valid_date(sign.end[0], sign.end[1]) {
+            problems.push(format!("tree sign {} has an invalid date", sign.tree));
+        }
+    }
+    for month in 1..=12 {
+        for day in 1..=month_days(month) {
+            let count = signs.iter().filter(|s| contains(s, month, day)).count();
+            if count != 1 {
+                problems.push(format!("{}/{} falls in {} tree signs", month, day, count));
+            }
+        }
+    }
+    problems
+}
+
+/// The tree sign for a birthday.
+///
+/// # Errors
+/// Returns [`OghamError::InvalidDate`] if the month or day is out of range.
+pub fn tree_sign_for(signs: &[TreeSign], month: u32, day: u32) -> Result<&TreeSign, OghamError> {
+    if !valid_date(month, day) {
+        return Err(OghamError::InvalidDate { month, day });
+    }
+    signs
+        .iter()
+        .find(|s| contains(s, month, day))
+        .ok_or_else(|| OghamError::DataCorrupt(format!("no tree sign for {}/{}", month, day)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::ogham::OghamEngine;
+
+    #[test]
+    fn birthdays_map_to_trees() {
+        let engine = OghamEngine::new();
+        let tree = |m, d| engine.tree_sign(m, d).unwrap().tree.clone();
+        assert_eq!(tree(12, 24), "Birch");
+        assert_eq!(tree(1, 1), "Birch");
+        assert_eq!(tree(1, 21), "Rowan");
+        assert_eq!(tree(2, 29), "Ash");
+        assert_eq!(tree(7, 7), "Oak");
+        assert_eq!(tree(12, 23), "Elder");
+        let oak = engine.tree_sign(6, 20).unwrap();
+        assert_eq!(oak.animal, "White Horse");
+        assert_eq!(engine.get_fid(&oak.ogham).unwrap().tree, "oak");
+        assert_eq!(engine.tree_sign(2, 30), Err(OghamError::InvalidDate { month: 2, day: 30 }));
+        assert_eq!(engine.tree_signs().len(), 13);
+    }
+}
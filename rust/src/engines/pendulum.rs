@@ -0,0 +1,246 @@
+mod error;
+
+use rand::Rng;
+
+use crate::types::{AskAgainPolicy, PendulumAnswer, PendulumBias, PendulumOptions, PendulumReading};
+
+pub use crate::rng::seeded_rng;
+pub use error::PendulumError;
+
+// ---------------------------------------------------------------------------
+// Swinging
+// ---------------------------------------------------------------------------
+
+impl PendulumAnswer {
+    /// How the pendulum moves to give this answer.
+    pub fn swing(self) -> &'static str {
+        match self {
+            Self::Yes => "swings forward and back",
+            Self::No => "swings from side to side",
+            Self::Unclear => "circles without settling",
+        }
+    }
+}
+
+/// Check that the weights are finite, non-negative and not all zero.
+///
+/// # Errors
+/// Returns [`PendulumError::InvalidBias`] otherwise.
+pub fn validate_bias(bias: PendulumBias) -> Result<(), PendulumError> {
+    let weights = [bias.yes, bias.no, bias.unclear];
+    if weights.iter().all(|w| w.is_finite() && *w >= 0.0) && weights.iter().sum::<f64>() > 0.0 {
+        Ok(())
+    } else {
+        Err(PendulumError::InvalidBias {
+            yes: bias.yes,
+            no: bias.no,
+            unclear: bias.unclear,
+        })
+    }
+}
+
+/// Pick an answer in proportion to `bias`, which must be valid.
+pub fn swing_with_rng<R: Rng + ?Sized>(bias: PendulumBias, rng: &mut R) -> PendulumAnswer {
+    let total = bias.yes + bias.no + bias.unclear;
+    let roll = rng.gen::<f64>() * total;
+    if roll < bias.yes {
+        PendulumAnswer::Yes
+    } else if roll < bias.yes + bias.no || bias.unclear == 0.0 {
+        PendulumAnswer::No
+    } else {
+        PendulumAnswer::Unclear
+    }
+}
+
+/// Questions are the same if they match ignoring case, spacing and
+/// punctuation.
+fn question_key(question: &str) -> String {
+    question
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// PendulumEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+//
+// The engine remembers each question's first answer until its cooldown
+// ends, so asking again straight away cannot be used to fish for a
+// different answer unless the policy allows it.
+
+#[derive(Debug, Clone, Default)]
+pub struct PendulumEngine {
+    options: PendulumOptions,
+    /// First answers whose cooldown may still be running, oldest first
+    recent: Vec<PendulumReading>,
+}
+
+impl PendulumEngine {
+    /// Even odds of yes and no with an occasional unclear answer, repeating
+    /// answers for ten minutes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Errors
+    /// Returns [`PendulumError::InvalidBias`] if the bias is invalid.
+    pub fn with_options(options: PendulumOptions) -> Result<Self, PendulumError> {
+        validate_bias(options.bias)?;
+        Ok(Self {
+            options,
+            recent: Vec::new(),
+        })
+    }
+
+    pub fn options(&self) -> PendulumOptions {
+        self.options
+    }
+
+    /// Answers still within their cooldown, oldest first.
+    pub fn recent(&self) -> &[PendulumReading] {
+        &self.recent
+    }
+
+    /// Forget every earlier answer.
+    pub fn clear(&mut self) {
+        self.recent.clear();
+    }
+
+    /// Ask a yes/no question at `timestamp` (milliseconds since the Unix
+    /// epoch).
+    ///
+    /// # Errors
+    /// Returns an error if the question is empty, or it was asked within
+    /// the cooldown and the policy refuses repeats.
+    pub fn ask(
+        &mut self,
+        question: &str,
+        timestamp: u64,
+    ) -> Result<PendulumReading, PendulumError> {
+        self.ask_with_rng(question, timestamp, &mut rand::thread_rng())
+    }
+
+    /// Ask a yes/no question with the given RNG.
+    ///
+    /// # Errors
+    /// Returns an error if the question is empty, or it was asked within
+    /// the cooldown and the policy refuses repeats.
+    pub fn ask_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        question: &str,
+        timestamp: u64,
+        rng: &mut R,
+    ) -> Result<PendulumReading, PendulumError> {
+        let key = question_key(question);
+        if key.is_empty() {
+            return Err(PendulumError::EmptyQuestion);
+        }
+        let cooldown = self.options.cooldown_ms;
+        self.recent.retain(|r| r.timestamp.saturating_add(cooldown) > timestamp);
+
+        let earlier = self.recent.iter().find(|r| question_key(&r.question) == key);
+        match (earlier, self.options.ask_again) {
+            (Some(first), AskAgainPolicy::RepeatAnswer) => {
+                return Ok(PendulumReading {
+                    question: question.to_string(),
+                    timestamp,
+                    repeated: true,
+                    ..first.clone()
+                });
+            }
+            (Some(first), AskAgainPolicy::Refuse) => {
+                return Err(PendulumError::AskedTooSoon {
+                    question: question.to_string(),
+                    retry_at: first.timestamp.saturating_add(cooldown),
+                });
+            }
+            _ => {}
+        }
+
+        let answer = swing_with_rng(self.options.bias, rng);
+        let reading = PendulumReading {
+            question: question.to_string(),
+            answer,
+            swing: answer.swing().to_string(),
+            timestamp,
+            repeated: false,
+        };
+        self.recent.retain(|r| question_key(&r.question) != key);
+        self.recent.push(reading.clone());
+        Ok(reading)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: u64 = 60 * 1000;
+
+    #[test]
+    fn bias_shapes_the_answers() {
+        let mut rng = seeded_rng(11);
+        let bias = PendulumBias {
+            yes: 3.0,
+            no: 1.0,
+            unclear: 0.0,
+        };
+        let answers: Vec<PendulumAnswer> =
+            (0..400).map(|_| swing_with_rng(bias, &mut rng)).collect();
+        let yes = answers.iter().filter(|a| **a == PendulumAnswer::Yes).count();
+        assert!((260..340).contains(&yes), "{} yes", yes);
+        assert!(!answers.contains(&PendulumAnswer::Unclear));
+
+        let bad = PendulumOptions {
+            bias: PendulumBias {
+                yes: -1.0,
+                ..bias
+            },
+            ..PendulumOptions::default()
+        };
+        assert!(matches!(
+            PendulumEngine::with_options(bad),
+            Err(PendulumError::InvalidBias { .. })
+        ));
+        let seeded = |seed| PendulumEngine::new().ask_with_rng("Go?", 0, &mut seeded_rng(seed));
+        assert_eq!(seeded(5).unwrap().answer, seeded(5).unwrap().answer);
+    }
+
+    #[test]
+    fn repeated_questions_follow_the_policy() {
+        let mut engine = PendulumEngine::new();
+        let mut rng = seeded_rng(2);
+        let first = engine.ask_with_rng("Should I take the job?", 0, &mut rng).unwrap();
+        assert!(!first.repeated);
+        for minute in 1..10 {
+            let again = engine
+                .ask_with_rng("should i take the JOB", minute * MINUTE, &mut rng)
+                .unwrap();
+            assert!(again.repeated);
+            assert_eq!(again.answer, first.answer);
+        }
+        let later = engine.ask_with_rng("Should I take the job?", 10 * MINUTE, &mut rng).unwrap();
+        assert!(!later.repeated);
+        assert_eq!(engine.recent().len(), 1);
+
+        let mut strict = PendulumEngine::with_options(PendulumOptions {
+            ask_again: AskAgainPolicy::Refuse,
+            cooldown_ms: 5 * MINUTE,
+            ..PendulumOptions::default()
+        })
+        .unwrap();
+        strict.ask_with_rng("Move house?", 0, &mut rng).unwrap();
+        assert_eq!(
+            strict.ask_with_rng("move house", MINUTE, &mut rng),
+            Err(PendulumError::AskedTooSoon {
+                question: "move house".to_string(),
+                retry_at: 5 * MINUTE
+            })
+        );
+        assert!(strict.ask_with_rng("Stay put?", MINUTE, &mut rng).is_ok());
+        assert_eq!(strict.ask(" ?! ", MINUTE), Err(PendulumError::EmptyQuestion));
+    }
+}
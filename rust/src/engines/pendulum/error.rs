@@ -0,0 +1,32 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// PendulumError — failures reported by the pendulum engine
+// ---------------------------------------------------------------------------
+
+/// Why a pendulum question was not answered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendulumError {
+    /// A question with no words in it
+    EmptyQuestion,
+    /// Weights that are negative, not finite or all zero
+    InvalidBias { yes: f64, no: f64, unclear: f64 },
+    /// The same question was asked again before its cooldown ended
+    AskedTooSoon { question: String, retry_at: u64 },
+}
+
+impl fmt::Display for PendulumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyQuestion => write!(f, "the question is empty"),
+            Self::InvalidBias { yes, no, unclear } => {
+                write!(f, "invalid pendulum bias: yes {}, no {}, unclear {}", yes, no, unclear)
+            }
+            Self::AskedTooSoon { question, retry_at } => {
+                write!(f, "'{}' was asked too recently; ask again at {}", question, retry_at)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PendulumError {}
@@ -0,0 +1,269 @@
+mod error;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::types::{CastRune, Rune, RuneCast, RuneCastOptions, RuneOrientation, RuneSpread};
+
+pub use crate::rng::seeded_rng;
+pub use error::RuneError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const RUNES_JSON: &str = include_str!("../../../data/runes/runes.json");
+const SPREADS_JSON: &str = include_str!("../../../data/runes/spreads.json");
+
+fn load_runes() -> Result<Vec<Rune>, RuneError> {
+    serde_json::from_str(RUNES_JSON).map_err(|e| RuneError::DataCorrupt(e.to_string()))
+}
+
+fn load_spreads() -> Result<Vec<RuneSpread>, RuneError> {
+    serde_json::from_str(SPREADS_JSON).map_err(|e| RuneError::DataCorrupt(e.to_string()))
+}
+
+/// Check that the runes are the 24 of the futhark, numbered in order, and
+/// that each spread's positions match its rune count.
+fn validate_data(runes: &[Rune], spreads: &[RuneSpread]) -> Result<(), RuneError> {
+    let mut problems = Vec::new();
+    if runes.len() != 24 {
+        problems.push(format!("{} runes instead of 24", runes.len()));
+    }
+    for (expected, rune) in (1..).zip(runes) {
+        if rune.number != expected {
+            problems.push(format!("rune {} is numbered {}", rune.name, rune.number));
+        }
+    }
+    for spread in spreads {
+        if spread.positions.len() != spread.rune_count {
+            problems.push(format!("spread {} has {} positions", spread.id, spread.positions.len()));
+        }
+        if spread.rune_count > runes.len() {
+            problems.push(format!("spread {} needs {} runes", spread.id, spread.rune_count));
+        }
+        if spread.positions.iter().enumerate().any(|(i, p)| p.index != i) {
+            problems.push(format!("spread {} positions are out of order", spread.id));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(RuneError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Casting
+// ---------------------------------------------------------------------------
+//
+// Runes are drawn without replacement. When reversals are allowed each one
+// lands upside down half the time: a reversible rune is then read
+// reversed. Nine runes (Gebo, Hagalaz, Nauthiz, Isa, Jera, Eihwaz, Sowilo,
+// Ingwaz and Dagaz) look the same either way up, so instead they are read
+// merkstave when that is allowed and upright otherwise.
+
+impl CastRune {
+    /// The reading for the way the rune landed.
+    pub fn meaning(&self) -> &str {
+        match self.orientation {
+            RuneOrientation::Upright => &self.rune.meaning,
+            RuneOrientation::Reversed | RuneOrientation::Merkstave => &self.rune.reversed_meaning,
+        }
+    }
+}
+
+/// How a rune that landed inverted (or not) is read under `options`.
+pub fn orientation_for(rune: &Rune, inverted: bool, options: RuneCastOptions) -> RuneOrientation {
+    match (inverted, rune.reversible) {
+        (false, _) => RuneOrientation::Upright,
+        (true, true) if options.allow_reversals => RuneOrientation::Reversed,
+        (true, false) if options.allow_merkstave => RuneOrientation::Merkstave,
+        _ => RuneOrientation::Upright,
+    }
+}
+
+/// Cast one rune into each position of `spread`.
+///
+/// # Errors
+/// Returns [`RuneError::DataCorrupt`] if the spread needs more runes than
+/// `runes` holds.
+pub fn cast_runes_with_rng<R: Rng + ?Sized>(
+    runes: &[Rune],
+    spread: &RuneSpread,
+    options: RuneCastOptions,
+    rng: &mut R,
+) -> Result<RuneCast, RuneError> {
+    if spread.rune_count > runes.len() {
+        return Err(RuneError::DataCorrupt(format!(
+            "spread {} needs {} runes but only {} are available",
+            spread.id,
+            spread.rune_count,
+            runes.len()
+        )));
+    }
+    let can_invert = options.allow_reversals || options.allow_merkstave;
+    let drawn: Vec<Rune> = runes.choose_multiple(rng, spread.rune_count).cloned().collect();
+    let cast = drawn
+        .into_iter()
+        .enumerate()
+        .map(|(position_index, rune)| {
+            let inverted = can_invert && rng.gen_bool(0.5);
+            CastRune {
+                orientation: orientation_for(&rune, inverted, options),
+                rune,
+                position_index,
+            }
+        })
+        .collect();
+    Ok(RuneCast {
+        spread_id: spread.id.clone(),
+        runes: cast,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// RuneEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct RuneEngine {
+    runes: Vec<Rune>,
+    spreads: Vec<RuneSpread>,
+}
+
+impl RuneEngine {
+    /// Create an engine from the embedded runes and spreads.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded rune data: {}", e))
+    }
+
+    /// Load and validate the embedded runes and spreads.
+    ///
+    /// # Errors
+    /// Returns [`RuneError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, RuneError> {
+        let runes = load_runes()?;
+        let spreads = load_spreads()?;
+        validate_data(&runes, &spreads)?;
+        Ok(Self { runes, spreads })
+    }
+
+    /// All 24 runes in futhark order.
+    pub fn runes(&self) -> &[Rune] {
+        &self.runes
+    }
+
+    /// Look up a rune by name (case-insensitive) or by its character.
+    pub fn get_rune(&self, name: &str) -> Option<&Rune> {
+        let name = name.trim();
+        self.runes
+            .iter()
+            .find(|r| r.name.eq_ignore_ascii_case(name) || r.character == name)
+    }
+
+    pub fn spreads(&self) -> &[RuneSpread] {
+        &self.spreads
+    }
+
+    pub fn get_spread(&self, id: &str) -> Option<&RuneSpread> {
+        self.spreads.iter().find(|s| s.id == id)
+    }
+
+    /// Cast runes into the named spread.
+    ///
+    /// # Errors
+    /// Returns [`RuneError::UnknownSpread`] for an unknown spread id.
+    pub fn cast(&self, spread_id: &str, options: RuneCastOptions) -> Result<RuneCast, RuneError> {
+        self.cast_with_rng(spread_id, options, &mut rand::thread_rng())
+    }
+
+    /// Cast runes into the named spread with the given RNG.
+    ///
+    /// # Errors
+    /// Returns [`RuneError::UnknownSpread`] for an unknown spread id.
+    pub fn cast_with_rng<R: Rng + ?Sized>(
+        &self,
+        spread_id: &str,
+        options: RuneCastOptions,
+        rng: &mut R,
+    ) -> Result<RuneCast, RuneError> {
+        let spread = self
+            .get_spread(spread_id)
+            .ok_or_else(|| RuneError::UnknownSpread(spread_id.to_string()))?;
+        cast_runes_with_rng(&self.runes, spread, options, rng)
+    }
+}
+
+impl Default for RuneEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_data_is_the_elder_futhark() {
+        let engine = RuneEngine::new();
+        assert_eq!(engine.runes().len(), 24);
+        assert_eq!(engine.get_rune("fehu").unwrap().character, "ᚠ");
+        assert_eq!(engine.get_rune("ᛟ").unwrap().name, "Othala");
+        assert_eq!(engine.runes().iter().filter(|r| !r.reversible).count(), 9);
+        let counts: Vec<usize> = engine.spreads().iter().map(|s| s.rune_count).collect();
+        assert_eq!(counts, [1, 3, 5]);
+        for aett in ["freyr", "heimdall", "tyr"] {
+            assert_eq!(engine.runes().iter().filter(|r| r.aett == aett).count(), 8);
+        }
+    }
+
+    #[test]
+    fn casts_draw_distinct_runes_in_position_order() {
+        let engine = RuneEngine::new();
+        let mut rng = seeded_rng(9);
+        for _ in 0..20 {
+            let cast = engine
+                .cast_with_rng("five_rune_cross", RuneCastOptions::default(), &mut rng)
+                .unwrap();
+            assert_eq!(cast.runes.len(), 5);
+            for (i, r) in cast.runes.iter().enumerate() {
+                assert_eq!(r.position_index, i);
+                assert!(!cast.runes[..i].iter().any(|o| o.rune.number == r.rune.number));
+                let expected = match r.orientation {
+                    RuneOrientation::Upright => true,
+                    RuneOrientation::Reversed => r.rune.reversible,
+                    RuneOrientation::Merkstave => !r.rune.reversible,
+                };
+                assert!(expected, "{} landed {:?}", r.rune.name, r.orientation);
+            }
+        }
+        assert_eq!(
+            engine.cast("celtic_cross", RuneCastOptions::default()).err(),
+            Some(RuneError::UnknownSpread("celtic_cross".to_string()))
+        );
+    }
+
+    #[test]
+    fn orientation_follows_the_options() {
+        let engine = RuneEngine::new();
+        let (fehu, isa) = (engine.get_rune("Fehu").unwrap(), engine.get_rune("Isa").unwrap());
+        let all = RuneCastOptions::default();
+        let none = RuneCastOptions {
+            allow_reversals: false,
+            allow_merkstave: false,
+        };
+        assert_eq!(orientation_for(fehu, true, all), RuneOrientation::Reversed);
+        assert_eq!(orientation_for(isa, true, all), RuneOrientation::Merkstave);
+        assert_eq!(orientation_for(fehu, true, none), RuneOrientation::Upright);
+        assert_eq!(orientation_for(isa, false, all), RuneOrientation::Upright);
+
+        let cast = engine.cast_with_rng("norns", none, &mut seeded_rng(1)).unwrap();
+        assert!(cast.runes.iter().all(|r| r.orientation == RuneOrientation::Upright));
+        assert_eq!(cast.runes[0].meaning(), cast.runes[0].rune.meaning);
+    }
+}
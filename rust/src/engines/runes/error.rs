@@ -0,0 +1,24 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// RuneError — failures reported by the rune engine
+// ---------------------------------------------------------------------------
+
+/// Why a rune operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuneError {
+    UnknownSpread(String),
+    /// The embedded rune or spread data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for RuneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSpread(id) => write!(f, "unknown rune spread: {}", id),
+            Self::DataCorrupt(detail) => write!(f, "corrupt rune data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for RuneError {}
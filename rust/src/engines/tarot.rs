@@ -1,7 +1,51 @@
+pub mod builder;
+pub mod cards;
+pub mod combinations;
+pub mod decans;
+pub mod deck_state;
+pub mod decks;
+pub mod dignities;
+pub mod error;
+pub mod export;
+pub mod interpret;
+pub mod kabbalah;
+pub mod layout;
+pub mod locale;
+pub mod numerology;
+pub mod search;
+pub mod session;
+pub mod shuffle;
+pub mod significator;
+pub mod stacked;
+pub mod stats;
+pub mod story;
+pub mod timing;
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::sync::Mutex;
+
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
 
-use crate::types::{DrawnCard, SpreadDefinition, TarotCard};
+use crate::identity;
+use crate::types::{
+    Arcana, CardCombination, CardFilter, CombinationMatch, Decan, DeckIssue, DeckStack, DrawOptions,
+    DrawStatsSummary, DrawnCard, KabbalahAttribution, NatalChart, NatalDecan, OrientedCard,
+    PersonalYearCard, PositionDignity, Reading, ReadingInterpretation, ReadingKind, ReadingRequest,
+    ReversalRule, ShuffleOptions, ShuffledReading, SignificatorReading, SpreadDefinition,
+    SpreadNumerology, Suit, TarotCard,
+};
+
+pub use crate::rng::seeded_rng;
+pub use builder::{SpreadBuildError, SpreadBuilder};
+pub use deck_state::DeckState;
+pub use decks::DeckSource;
+pub use error::TarotError;
+pub use session::ReadingSession;
+pub use stats::DrawStatistics;
 
 // ---------------------------------------------------------------------------
 // Static data loaded at compile time
@@ -11,11 +55,7 @@ const CARDS_JSON: &str = include_str!("../../../data/tarot/cards.json");
 const SPREADS_JSON: &str = include_str!("../../../data/tarot/spreads.json");
 
 fn load_cards() -> Vec<TarotCard> {
-    serde_json::from_str(CARDS_JSON).expect("Failed to parse cards.json")
-}
-
-fn load_spreads() -> Vec<SpreadDefinition> {
-    serde_json::from_str(SPREADS_JSON).expect("Failed to parse spreads.json")
+    parse_cards(CARDS_JSON).expect("embedded cards.json is valid")
 }
 
 // ---------------------------------------------------------------------------
@@ -28,9 +68,55 @@ pub fn create_deck() -> Vec<TarotCard> {
 }
 
 /// Fisher-Yates shuffle using `rand::thread_rng()` (OsRng-backed).
-pub fn shuffle_deck(cards: &mut Vec<TarotCard>) {
-    let mut rng = rand::thread_rng();
-    cards.shuffle(&mut rng);
+pub fn shuffle_deck(cards: &mut [TarotCard]) {
+    shuffle_deck_with_rng(cards, &mut rand::thread_rng());
+}
+
+/// Fisher-Yates shuffle driven by a caller-supplied RNG.
+pub fn shuffle_deck_with_rng<R: Rng + ?Sized>(cards: &mut [TarotCard], rng: &mut R) {
+    cards.shuffle(rng);
+}
+
+/// Cut the deck: the top `position` cards go to the bottom.
+///
+/// # Errors
+/// Returns an error unless `0 < position < deck.len()`.
+pub fn cut_deck<T>(deck: &mut [T], position: usize) -> Result<(), TarotError> {
+    if position == 0 || position >= deck.len() {
+        return Err(TarotError::InvalidCut {
+            position,
+            deck_len: deck.len(),
+        });
+    }
+    deck.rotate_left(position);
+    Ok(())
+}
+
+/// Cut at a random position and return the index used.
+///
+/// # Errors
+/// Returns an error if the deck has fewer than two cards.
+pub fn cut_deck_random<T>(deck: &mut [T]) -> Result<usize, TarotError> {
+    cut_deck_random_with_rng(deck, &mut rand::thread_rng())
+}
+
+/// Cut at a position chosen by `rng` and return the index used.
+///
+/// # Errors
+/// Returns an error if the deck has fewer than two cards.
+pub fn cut_deck_random_with_rng<T, R: Rng + ?Sized>(
+    deck: &mut [T],
+    rng: &mut R,
+) -> Result<usize, TarotError> {
+    if deck.len() < 2 {
+        return Err(TarotError::NotEnoughCards {
+            needed: 2,
+            available: deck.len(),
+        });
+    }
+    let position = rng.gen_range(1..deck.len());
+    cut_deck(deck, position)?;
+    Ok(position)
 }
 
 /// Draw `count` cards from the top of the deck.
@@ -38,33 +124,46 @@ pub fn shuffle_deck(cards: &mut Vec<TarotCard>) {
 /// If `allow_reversals` is true, each card has a 50 % chance of being reversed.
 ///
 /// # Errors
-/// Returns an error string if `count` exceeds the deck size.
+/// Returns an error if `count` exceeds the deck size.
 pub fn draw_cards(
     deck: &[TarotCard],
     count: usize,
     allow_reversals: bool,
-) -> Result<Vec<DrawnCard>, String> {
+) -> Result<Vec<DrawnCard>, TarotError> {
+    draw_cards_with_rng(deck, count, allow_reversals, &mut rand::thread_rng())
+}
+
+/// Draw `count` cards from the top of the deck, deciding reversals with a
+/// caller-supplied RNG.
+///
+/// # Errors
+/// Returns an error if `count` exceeds the deck size.
+pub fn draw_cards_with_rng<R: Rng + ?Sized>(
+    deck: &[TarotCard],
+    count: usize,
+    allow_reversals: bool,
+    rng: &mut R,
+) -> Result<Vec<DrawnCard>, TarotError> {
     if count > deck.len() {
-        return Err(format!(
-            "Cannot draw {} cards from a deck of {}",
-            count,
-            deck.len()
-        ));
+        return Err(TarotError::NotEnoughCards {
+            needed: count,
+            available: deck.len(),
+        });
     }
 
-    let mut rng = rand::thread_rng();
     let mut drawn = Vec::with_capacity(count);
 
-    for i in 0..count {
+    for (i, card) in deck.iter().take(count).enumerate() {
         let reversed = if allow_reversals {
             rng.gen_bool(0.5)
         } else {
             false
         };
         drawn.push(DrawnCard {
-            card: deck[i].clone(),
+            card: card.clone(),
             reversed,
             position_index: i,
+            clarifies: None,
         });
     }
 
@@ -79,25 +178,378 @@ pub fn get_card(deck: &[TarotCard], id: &str) -> Option<TarotCard> {
 /// Filter cards by arcana and/or suit.
 pub fn filter_cards(
     deck: &[TarotCard],
-    arcana: Option<&str>,
-    suit: Option<&str>,
+    arcana: Option<Arcana>,
+    suit: Option<Suit>,
 ) -> Vec<TarotCard> {
     deck.iter()
-        .filter(|c| {
-            if let Some(a) = arcana {
-                if c.arcana != a {
-                    return false;
-                }
+        .filter(|c| arcana.is_none_or(|a| c.arcana == a))
+        .filter(|c| suit.is_none_or(|s| c.suit == Some(s)))
+        .cloned()
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Position reversal rules
+// ---------------------------------------------------------------------------
+
+impl ReversalRule {
+    pub fn is_random(&self) -> bool {
+        *self == Self::Random
+    }
+
+    /// Orientation under this rule; `random` is only consulted for
+    /// [`ReversalRule::Random`].
+    pub fn resolve(self, random: impl FnOnce() -> bool) -> bool {
+        match self {
+            Self::Random => random(),
+            Self::Never => false,
+            Self::Always => true,
+        }
+    }
+}
+
+/// Force the orientation of dealt cards whose spread position has a fixed
+/// [`ReversalRule`]. Clarifiers keep their drawn orientation.
+pub fn apply_reversal_rules(spread: &SpreadDefinition, drawn: &mut [DrawnCard]) {
+    for d in drawn.iter_mut().filter(|d| d.clarifies.is_none()) {
+        if let Some(position) = spread.positions.get(d.position_index) {
+            d.reversed = position.reversal.resolve(|| d.reversed);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Filtered draws
+// ---------------------------------------------------------------------------
+
+impl CardFilter {
+    /// Whether a card belongs to the filtered sub-deck.
+    pub fn matches(&self, card: &TarotCard) -> bool {
+        match self {
+            Self::MajorsOnly => card.is_major(),
+            Self::MinorsOnly => !card.is_major(),
+            Self::Suit(suit) => card.suit == Some(*suit),
+            Self::CourtsOnly => card.is_court(),
+            Self::PipsOnly => card.rank().is_some_and(|r| !r.is_court()),
+        }
+    }
+}
+
+/// Shuffle the cards allowed by `options.filter`, less any in
+/// `options.exclude`, and draw from them. Excluded cards are removed before
+/// the shuffle, so every remaining card is equally likely.
+///
+/// # Errors
+/// Returns an error if an excluded id is not in the deck or the remaining
+/// sub-deck is smaller than `options.count`.
+pub fn draw_with_options(
+    deck: &[TarotCard],
+    options: &DrawOptions,
+) -> Result<Vec<DrawnCard>, TarotError> {
+    draw_with_options_with_rng(deck, options, &mut rand::thread_rng())
+}
+
+/// Like [`draw_with_options`] with a caller-supplied RNG.
+///
+/// # Errors
+/// Returns an error if an excluded id is not in the deck or the remaining
+/// sub-deck is smaller than `options.count`.
+pub fn draw_with_options_with_rng<R: Rng + ?Sized>(
+    deck: &[TarotCard],
+    options: &DrawOptions,
+    rng: &mut R,
+) -> Result<Vec<DrawnCard>, TarotError> {
+    let mut pool = options_pool(deck, options)?;
+    shuffle_deck_with_rng(&mut pool, rng);
+    draw_cards_with_rng(&pool, options.count, options.allow_reversals, rng)
+}
+
+/// The cards `options` allows, in deck order.
+fn options_pool(deck: &[TarotCard], options: &DrawOptions) -> Result<Vec<TarotCard>, TarotError> {
+    if let Some(unknown) = options.exclude.iter().find(|id| deck.iter().all(|c| &c.id != *id)) {
+        return Err(TarotError::UnknownCard(unknown.clone()));
+    }
+    Ok(deck
+        .iter()
+        .filter(|c| options.filter.as_ref().is_none_or(|f| f.matches(c)))
+        .filter(|c| !options.exclude.contains(&c.id))
+        .cloned()
+        .collect())
+}
+
+// ---------------------------------------------------------------------------
+// Correspondence lookups
+// ---------------------------------------------------------------------------
+
+fn matches(value: Option<&str>, wanted: &str) -> bool {
+    value.is_some_and(|v| v.eq_ignore_ascii_case(wanted))
+}
+
+/// Cards ruled by a planet (case-insensitive, e.g. `"venus"`).
+pub fn cards_by_planet(deck: &[TarotCard], planet: &str) -> Vec<TarotCard> {
+    deck.iter()
+        .filter(|c| matches(c.planet.as_deref(), planet))
+        .cloned()
+        .collect()
+}
+
+/// Cards attributed to a zodiac sign (case-insensitive, e.g. `"scorpio"`).
+pub fn cards_by_zodiac(deck: &[TarotCard], sign: &str) -> Vec<TarotCard> {
+    deck.iter()
+        .filter(|c| matches(c.zodiac.as_deref(), sign))
+        .cloned()
+        .collect()
+}
+
+/// Cards of an element (case-insensitive, e.g. `"water"`).
+pub fn cards_by_element(deck: &[TarotCard], element: &str) -> Vec<TarotCard> {
+    deck.iter()
+        .filter(|c| matches(Some(&c.element), element))
+        .cloned()
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Clarifiers
+// ---------------------------------------------------------------------------
+
+/// Draw a clarifier for an already dealt position: the next card in `deck`
+/// that is not already in `drawn`, linked back via `clarifies`.
+///
+/// # Errors
+/// Returns an error if no card was dealt at `position_index` or every
+/// card in the deck has been drawn.
+pub fn draw_clarifier(
+    deck: &[TarotCard],
+    drawn: &[DrawnCard],
+    position_index: usize,
+    allow_reversals: bool,
+) -> Result<DrawnCard, TarotError> {
+    draw_clarifier_with_rng(deck, drawn, position_index, allow_reversals, &mut rand::thread_rng())
+}
+
+/// Like [`draw_clarifier`] with a caller-supplied RNG.
+///
+/// # Errors
+/// Returns an error if no card was dealt at `position_index` or every
+/// card in the deck has been drawn.
+pub fn draw_clarifier_with_rng<R: Rng + ?Sized>(
+    deck: &[TarotCard],
+    drawn: &[DrawnCard],
+    position_index: usize,
+    allow_reversals: bool,
+    rng: &mut R,
+) -> Result<DrawnCard, TarotError> {
+    if !drawn.iter().any(|d| d.clarifies.is_none() && d.position_index == position_index) {
+        return Err(TarotError::PositionNotDealt(position_index));
+    }
+    let card = deck
+        .iter()
+        .find(|c| drawn.iter().all(|d| d.card.id != c.id))
+        .ok_or(TarotError::NotEnoughCards {
+            needed: drawn.len() + 1,
+            available: deck.len(),
+        })?;
+    Ok(DrawnCard {
+        card: card.clone(),
+        reversed: allow_reversals && rng.gen_bool(0.5),
+        position_index,
+        clarifies: Some(position_index),
+    })
+}
+
+/// Clarifiers drawn for a position, in draw order.
+pub fn clarifiers_for(drawn: &[DrawnCard], position_index: usize) -> Vec<&DrawnCard> {
+    drawn.iter().filter(|d| d.clarifies == Some(position_index)).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Card of the day
+// ---------------------------------------------------------------------------
+
+fn daily_card_from(
+    deck: &[TarotCard],
+    year: i32,
+    month: u32,
+    day: u32,
+    user_seed: Option<&str>,
+) -> DrawnCard {
+    // Hash rather than seed an RNG so the choice is identical in every
+    // implementation and across `rand` upgrades.
+    let key = format!("{:04}-{:02}-{:02}\0{}", year, month, day, user_seed.unwrap_or(""));
+    let hash = identity::fnv1a_64(key.as_bytes());
+    DrawnCard {
+        card: deck[(hash % deck.len() as u64) as usize].clone(),
+        reversed: (hash >> 32) & 1 == 1,
+        position_index: 0,
+        clarifies: None,
+    }
+}
+
+/// The card for a calendar date, optionally personalised by a user id.
+/// Asking again for the same date and user always returns the same card
+/// and orientation.
+pub fn card_of_the_day(year: i32, month: u32, day: u32, user_seed: Option<&str>) -> DrawnCard {
+    daily_card_from(&create_deck(), year, month, day, user_seed)
+}
+
+// ---------------------------------------------------------------------------
+// Custom spreads
+// ---------------------------------------------------------------------------
+
+impl SpreadDefinition {
+    /// Check that the spread is usable: it has an id, at least one position,
+    /// `card_count` matches the number of positions and position indices run
+    /// contiguously from 0.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::InvalidSpread`] listing every problem found.
+    pub fn validate(&self) -> Result<(), TarotError> {
+        let mut problems = Vec::new();
+        if self.id.trim().is_empty() {
+            problems.push("id must not be empty".to_string());
+        }
+        if self.positions.is_empty() {
+            problems.push("no positions".to_string());
+        }
+        if self.card_count != self.positions.len() {
+            problems.push(format!(
+                "declares {} cards but has {} positions",
+                self.card_count,
+                self.positions.len()
+            ));
+        }
+        for (expected, position) in self.positions.iter().enumerate() {
+            if position.index != expected {
+                problems.push(format!(
+                    "position '{}' has index {}, expected {}",
+                    position.name, position.index, expected
+                ));
             }
-            if let Some(s) = suit {
-                match &c.suit {
-                    Some(cs) if cs == s => {}
-                    _ => return false,
-                }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(TarotError::InvalidSpread {
+                spread: self.id.clone(),
+                problems,
+            })
+        }
+    }
+}
+
+/// Check that a spread is usable; see [`SpreadDefinition::validate`].
+///
+/// # Errors
+/// Returns [`TarotError::InvalidSpread`] listing every problem found.
+pub fn validate_spread(spread: &SpreadDefinition) -> Result<(), TarotError> {
+    spread.validate()
+}
+
+/// Parse and validate a JSON array of spread definitions in the same format
+/// as the embedded `spreads.json`.
+///
+/// # Errors
+/// Returns an error if the JSON is malformed or any spread is invalid.
+pub fn parse_spreads(json: &str) -> Result<Vec<SpreadDefinition>, TarotError> {
+    let spreads: Vec<SpreadDefinition> =
+        serde_json::from_str(json).map_err(|e| TarotError::json("spreads JSON", e))?;
+    for spread in &spreads {
+        spread.validate()?;
+    }
+    Ok(spreads)
+}
+
+// ---------------------------------------------------------------------------
+// Deck data
+// ---------------------------------------------------------------------------
+
+/// Parse a JSON array of cards in the same format as the embedded
+/// `cards.json`.
+///
+/// # Errors
+/// Returns [`TarotError::Json`] if the JSON is malformed.
+pub fn parse_cards(json: &str) -> Result<Vec<TarotCard>, TarotError> {
+    serde_json::from_str(json).map_err(|e| TarotError::json("cards JSON", e))
+}
+
+/// Check a deck's integrity: 78 cards, unique ids, suits on exactly the
+/// minor cards, numbers in range for their arcana and numerology matching
+/// the number. Suitable for vetting third-party deck packs.
+///
+/// # Errors
+/// Returns every issue found, in deck order after the card count.
+pub fn validate_deck(cards: &[TarotCard]) -> Result<(), Vec<DeckIssue>> {
+    let mut issues = Vec::new();
+    if cards.len() != 78 {
+        issues.push(DeckIssue::WrongCardCount { found: cards.len() });
+    }
+    issues.extend(duplicate_ids(cards));
+    for c in cards {
+        let id = c.id.clone();
+        match (c.arcana, c.suit) {
+            (Arcana::Minor, None) => issues.push(DeckIssue::MissingSuit { id: id.clone() }),
+            (Arcana::Major, Some(_)) => issues.push(DeckIssue::UnexpectedSuit { id: id.clone() }),
+            _ => {}
+        }
+        let range = if c.is_major() { 0..=21 } else { 1..=14 };
+        if !range.contains(&c.number) {
+            issues.push(DeckIssue::NumberOutOfRange {
+                id: id.clone(),
+                number: c.number,
+            });
+        }
+        if c.numerology != c.number {
+            issues.push(DeckIssue::NumerologyMismatch {
+                id,
+                number: c.number,
+                numerology: c.numerology,
+            });
+        }
+    }
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(issues)
+    }
+}
+
+impl std::fmt::Display for DeckIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::WrongCardCount { found } => write!(f, "has {} cards, expected 78", found),
+            Self::DuplicateId { id } => write!(f, "card '{}' appears more than once", id),
+            Self::MissingSuit { id } => write!(f, "minor card '{}' has no suit", id),
+            Self::UnexpectedSuit { id } => write!(f, "major card '{}' has a suit", id),
+            Self::NumberOutOfRange { id, number } => {
+                write!(f, "card '{}' has out-of-range number {}", id, number)
             }
-            true
-        })
-        .cloned()
+            Self::NumerologyMismatch { id, number, numerology } => write!(
+                f,
+                "card '{}' has numerology {} but number {}",
+                id, numerology, number
+            ),
+        }
+    }
+}
+
+/// [`validate_deck`] as a [`TarotError`] naming the deck.
+///
+/// # Errors
+/// Returns [`TarotError::InvalidDeck`] listing every issue found.
+pub fn check_deck(deck_id: &str, cards: &[TarotCard]) -> Result<(), TarotError> {
+    validate_deck(cards).map_err(|issues| TarotError::InvalidDeck {
+        deck: deck_id.to_string(),
+        problems: issues.iter().map(ToString::to_string).collect(),
+    })
+}
+
+fn duplicate_ids(cards: &[TarotCard]) -> Vec<DeckIssue> {
+    let mut seen = HashSet::new();
+    cards
+        .iter()
+        .filter(|c| !seen.insert(c.id.as_str()))
+        .map(|c| DeckIssue::DuplicateId { id: c.id.clone() })
         .collect()
 }
 
@@ -105,17 +557,76 @@ pub fn filter_cards(
 // TarotEngine — stateful wrapper
 // ---------------------------------------------------------------------------
 
+/// Id under which [`TarotEngine`] exposes its primary deck.
+pub const DEFAULT_DECK_ID: &str = "default";
+
 pub struct TarotEngine {
     deck: Vec<TarotCard>,
     spreads: Vec<SpreadDefinition>,
+    combinations: Vec<CardCombination>,
+    /// Additional named decks, keyed by id
+    decks: BTreeMap<String, Vec<TarotCard>>,
+    /// Draw tallies, when statistics are enabled
+    stats: Option<Mutex<DrawStatistics>>,
+    /// Rigged order used in place of a fair shuffle, for tests and demos
+    stack: Option<DeckStack>,
 }
 
 impl TarotEngine {
+    /// Create an engine from the embedded data.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; see [`try_new`](Self::try_new).
     pub fn new() -> Self {
-        Self {
-            deck: load_cards(),
-            spreads: load_spreads(),
+        Self::try_new().unwrap_or_else(|e| panic!("embedded tarot data is invalid: {}", e))
+    }
+
+    /// Create an engine from the embedded data, validating the deck and
+    /// every spread.
+    ///
+    /// # Errors
+    /// Returns the first deck or spread that fails validation, with every
+    /// problem found in it.
+    pub fn try_new() -> Result<Self, TarotError> {
+        Self::from_parts(parse_cards(CARDS_JSON)?)
+    }
+
+    fn from_parts(deck: Vec<TarotCard>) -> Result<Self, TarotError> {
+        check_deck(DEFAULT_DECK_ID, &deck)?;
+        Ok(Self {
+            deck,
+            spreads: parse_spreads(SPREADS_JSON)?,
+            combinations: combinations::load_combinations(),
+            decks: BTreeMap::new(),
+            stats: None,
+            stack: None,
+        })
+    }
+
+    /// Create an engine whose deck comes from `source` instead of the
+    /// embedded Rider-Waite-Smith cards.
+    ///
+    /// # Errors
+    /// Returns an error if the deck pack cannot be loaded or the resulting
+    /// deck fails validation.
+    pub fn with_deck(source: DeckSource) -> Result<Self, TarotError> {
+        Self::from_parts(decks::load_deck(&source)?)
+    }
+
+    /// Create an engine whose card texts are in `locale` (e.g. `"es"`),
+    /// falling back to English for any field a pack does not translate.
+    /// `"en"` gives the plain English deck.
+    ///
+    /// # Errors
+    /// Returns an error if the locale is not supported or the embedded data
+    /// is invalid.
+    pub fn with_locale(locale: &str) -> Result<Self, TarotError> {
+        let mut engine = Self::try_new()?;
+        if !locale.eq_ignore_ascii_case("en") && !locale.to_lowercase().starts_with("en-") {
+            let pack = locale::locale_pack(locale)?;
+            engine.deck = locale::apply_locale_pack(&engine.deck, &pack)?;
         }
+        Ok(engine)
     }
 
     /// Return a copy of the full 78-card deck.
@@ -123,9 +634,72 @@ impl TarotEngine {
         self.deck.clone()
     }
 
-    /// Shuffle a deck in-place using Fisher-Yates.
-    pub fn shuffle_deck(&self, cards: &mut Vec<TarotCard>) {
-        shuffle_deck(cards);
+    /// Shuffle a deck in-place using Fisher-Yates, or arrange it by the
+    /// stack set with [`stack_deck`](Self::stack_deck).
+    pub fn shuffle_deck(&self, cards: &mut [TarotCard]) {
+        self.shuffle_deck_with_rng(cards, &mut rand::thread_rng());
+    }
+
+    /// Like [`shuffle_deck`](Self::shuffle_deck) with a caller-supplied RNG.
+    pub fn shuffle_deck_with_rng<R: Rng + ?Sized>(&self, cards: &mut [TarotCard], rng: &mut R) {
+        match &self.stack {
+            Some(stack) => stacked::arrange_with_rng(cards, stack, rng),
+            None => shuffle_deck_with_rng(cards, rng),
+        }
+    }
+
+    /// Rig every later engine shuffle (draws, named-deck draws and sessions)
+    /// with `stack` so tests and demos get known cards. Hand-shuffle
+    /// simulations and significator draws are unaffected. Reversals are still
+    /// random unless disabled.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::InvalidDeck`] if the stack names unknown cards,
+    /// repeats a card or has a negative weight.
+    pub fn stack_deck(&mut self, stack: DeckStack) -> Result<(), TarotError> {
+        stacked::validate_stack(&self.deck, &stack)?;
+        self.stack = Some(stack);
+        Ok(())
+    }
+
+    /// Return to fair shuffles.
+    pub fn unstack_deck(&mut self) {
+        self.stack = None;
+    }
+
+    /// The primary deck after a simulated hand shuffle, with reversals
+    /// arising from how the cards were turned. Jumpers are left out; use
+    /// [`shuffled_reading`](Self::shuffled_reading) to keep them.
+    pub fn hand_shuffle(&self, options: &ShuffleOptions) -> Vec<OrientedCard> {
+        let mut deck = shuffle::orient(self.create_deck());
+        shuffle::simulate_shuffle(&mut deck, options, &mut rand::thread_rng());
+        deck
+    }
+
+    /// Hand-shuffle the primary deck and deal `count` cards, with reversals
+    /// arising from how the cards were turned and any jumpers set aside.
+    ///
+    /// # Errors
+    /// Returns an error if too few cards remain after jumpers leave.
+    pub fn shuffled_reading(
+        &self,
+        count: usize,
+        options: &ShuffleOptions,
+    ) -> Result<ShuffledReading, TarotError> {
+        shuffle::shuffled_reading(self.create_deck(), count, options, &mut rand::thread_rng())
+    }
+
+    /// Shuffle the primary deck, restricted by `options.filter`, and draw.
+    ///
+    /// # Errors
+    /// Returns an error if the filtered deck is too small.
+    pub fn draw(&self, options: &DrawOptions) -> Result<Vec<DrawnCard>, TarotError> {
+        let mut rng = rand::thread_rng();
+        let mut pool = options_pool(&self.deck, options)?;
+        self.shuffle_deck_with_rng(&mut pool, &mut rng);
+        let drawn = draw_cards_with_rng(&pool, options.count, options.allow_reversals, &mut rng)?;
+        self.record_draws(&drawn);
+        Ok(drawn)
     }
 
     /// Draw `count` cards from the given deck.
@@ -134,8 +708,181 @@ impl TarotEngine {
         deck: &[TarotCard],
         count: usize,
         allow_reversals: bool,
-    ) -> Result<Vec<DrawnCard>, String> {
-        draw_cards(deck, count, allow_reversals)
+    ) -> Result<Vec<DrawnCard>, TarotError> {
+        let drawn = draw_cards(deck, count, allow_reversals)?;
+        self.record_draws(&drawn);
+        Ok(drawn)
+    }
+
+    /// Draw `count` cards from the given deck with a caller-supplied RNG.
+    pub fn draw_cards_with_rng<R: Rng + ?Sized>(
+        &self,
+        deck: &[TarotCard],
+        count: usize,
+        allow_reversals: bool,
+        rng: &mut R,
+    ) -> Result<Vec<DrawnCard>, TarotError> {
+        let drawn = draw_cards_with_rng(deck, count, allow_reversals, rng)?;
+        self.record_draws(&drawn);
+        Ok(drawn)
+    }
+
+    /// Draw a clarifier for a dealt position from the given deck.
+    pub fn draw_clarifier(
+        &self,
+        deck: &[TarotCard],
+        drawn: &[DrawnCard],
+        position_index: usize,
+        allow_reversals: bool,
+    ) -> Result<DrawnCard, TarotError> {
+        draw_clarifier(deck, drawn, position_index, allow_reversals)
+    }
+
+    /// Traditional court-card significator for a sun sign.
+    pub fn choose_significator_by_zodiac(&self, sign: &str) -> Option<TarotCard> {
+        significator::choose_significator_by_zodiac(&self.deck, sign)
+    }
+
+    /// Court-card significator for a querent's age and element.
+    pub fn choose_significator_by_profile(&self, age: u32, element: &str) -> Option<TarotCard> {
+        significator::choose_significator_by_profile(&self.deck, age, element)
+    }
+
+    /// Deal a spread from the primary deck, setting aside its significator.
+    pub fn draw_with_significator(
+        &self,
+        spread: &SpreadDefinition,
+        chosen_id: Option<&str>,
+        allow_reversals: bool,
+    ) -> Result<SignificatorReading, TarotError> {
+        significator::draw_with_significator(&self.deck, spread, chosen_id, allow_reversals)
+    }
+
+    /// Shuffle, deal and interpret one of the everyday spreads in one call.
+    ///
+    /// # Errors
+    /// Returns an error if the built-in spread has been replaced by one the
+    /// deck cannot fill.
+    pub fn quick_reading(&self, kind: ReadingKind) -> Result<ReadingInterpretation, TarotError> {
+        let spread_id = match kind {
+            ReadingKind::Single => "single",
+            ReadingKind::ThreeCard => "three_card",
+        };
+        let request = ReadingRequest {
+            spread_id: spread_id.to_string(),
+            ..Default::default()
+        };
+        let reading = self.read(&request, 0)?;
+        let spread = self
+            .get_spread(spread_id)
+            .ok_or_else(|| TarotError::UnknownSpread(spread_id.to_string()))?;
+        self.interpret_reading(spread, &reading.cards)
+    }
+
+    /// Interpret a dealt spread position by position with a synthesis.
+    ///
+    /// # Errors
+    /// Returns an error if a card sits outside the spread's positions.
+    pub fn interpret_reading(
+        &self,
+        spread: &SpreadDefinition,
+        drawn: &[DrawnCard],
+    ) -> Result<ReadingInterpretation, TarotError> {
+        interpret::interpret_with(&self.combinations, spread, drawn)
+    }
+
+    /// Known two-card combinations present in a dealt spread.
+    pub fn find_combinations(&self, drawn: &[DrawnCard]) -> Vec<CombinationMatch> {
+        combinations::find_combinations(&self.combinations, drawn)
+    }
+
+    /// Elemental dignity of each dealt card against its neighbours.
+    pub fn elemental_dignities(&self, drawn: &[DrawnCard]) -> Vec<PositionDignity> {
+        dignities::elemental_dignities(drawn)
+    }
+
+    /// Start recording draws made through this engine. Keeps existing
+    /// tallies if statistics were already enabled.
+    pub fn enable_statistics(&mut self) {
+        if self.stats.is_none() {
+            self.stats = Some(Mutex::new(DrawStatistics::new()));
+        }
+    }
+
+    /// Resume recording on top of previously saved tallies.
+    pub fn restore_statistics(&mut self, stats: DrawStatistics) {
+        self.stats = Some(Mutex::new(stats));
+    }
+
+    /// Stop recording and return the tallies collected so far.
+    pub fn disable_statistics(&mut self) -> Option<DrawStatistics> {
+        self.stats
+            .take()
+            .map(|m| m.into_inner().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Tally draws made elsewhere (e.g. a [`ReadingSession`]). Does nothing
+    /// when statistics are disabled.
+    pub fn record_draws(&self, drawn: &[DrawnCard]) {
+        if let Some(stats) = &self.stats {
+            stats.lock().unwrap_or_else(|e| e.into_inner()).record(drawn);
+        }
+    }
+
+    /// Summary of recorded draws, or `None` when statistics are disabled.
+    pub fn statistics(&self) -> Option<DrawStatsSummary> {
+        self.stats
+            .as_ref()
+            .map(|m| m.lock().unwrap_or_else(|e| e.into_inner()).summary())
+    }
+
+    /// Golden Dawn decan ruled by a pip card.
+    pub fn decan_for_card(&self, card_id: &str) -> Option<Decan> {
+        decans::decan_for_card(card_id)
+    }
+
+    /// Decan cards for the planets and angles of a natal chart.
+    pub fn natal_decans(&self, chart: &NatalChart) -> Vec<NatalDecan> {
+        decans::natal_decans(chart)
+    }
+
+    /// Tree of Life attribution for a card in the master deck.
+    pub fn kabbalah_for_card(&self, card_id: &str) -> Option<KabbalahAttribution> {
+        kabbalah::kabbalah_for_card(&self.get_card(card_id)?)
+    }
+
+    /// Numerological profile of a dealt spread.
+    pub fn analyze_spread_numerology(&self, drawn: &[DrawnCard]) -> SpreadNumerology {
+        numerology::analyze_spread_numerology(drawn)
+    }
+
+    /// Major arcana card for the year beginning on the querent's birthday
+    /// in `year`.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::InvalidDate`] if the month or day is out of range.
+    pub fn personal_year_card(
+        &self,
+        birth_month: u32,
+        birth_day: u32,
+        year: i32,
+    ) -> Result<PersonalYearCard, TarotError> {
+        numerology::personal_year_card(&self.deck, birth_month, birth_day, year)
+    }
+
+    /// ASCII drawing of a dealt spread in its traditional shape.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::UnknownSpread`] if the spread id is unknown.
+    pub fn render_layout(
+        &self,
+        spread_id: &str,
+        drawn: &[DrawnCard],
+    ) -> Result<String, TarotError> {
+        let spread = self
+            .get_spread(spread_id)
+            .ok_or_else(|| TarotError::UnknownSpread(spread_id.to_string()))?;
+        Ok(layout::render_layout(spread, drawn))
     }
 
     /// Look up a card by id in the master deck.
@@ -143,11 +890,31 @@ impl TarotEngine {
         get_card(&self.deck, id)
     }
 
+    /// Look up a card by a user-typed name, tolerating case and typos.
+    pub fn find_card_by_name(&self, query: &str) -> Option<TarotCard> {
+        search::find_card_by_name(&self.deck, query)
+    }
+
     /// Filter the master deck by arcana / suit.
-    pub fn filter_cards(&self, arcana: Option<&str>, suit: Option<&str>) -> Vec<TarotCard> {
+    pub fn filter_cards(&self, arcana: Option<Arcana>, suit: Option<Suit>) -> Vec<TarotCard> {
         filter_cards(&self.deck, arcana, suit)
     }
 
+    /// Cards in the master deck ruled by a planet.
+    pub fn cards_by_planet(&self, planet: &str) -> Vec<TarotCard> {
+        cards_by_planet(&self.deck, planet)
+    }
+
+    /// Cards in the master deck attributed to a zodiac sign.
+    pub fn cards_by_zodiac(&self, sign: &str) -> Vec<TarotCard> {
+        cards_by_zodiac(&self.deck, sign)
+    }
+
+    /// Cards in the master deck of an element.
+    pub fn cards_by_element(&self, element: &str) -> Vec<TarotCard> {
+        cards_by_element(&self.deck, element)
+    }
+
     /// Return all available spread definitions.
     pub fn get_spreads(&self) -> &[SpreadDefinition] {
         &self.spreads
@@ -157,6 +924,244 @@ impl TarotEngine {
     pub fn get_spread(&self, id: &str) -> Option<&SpreadDefinition> {
         self.spreads.iter().find(|s| s.id == id)
     }
+
+    /// The card of the day from this engine's primary deck.
+    pub fn card_of_the_day(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+        user_seed: Option<&str>,
+    ) -> DrawnCard {
+        daily_card_from(&self.deck, year, month, day, user_seed)
+    }
+
+    /// Register a named deck alongside the primary one, replacing any deck
+    /// already registered under `id`.
+    ///
+    /// # Errors
+    /// Returns an error if `id` is empty or [`DEFAULT_DECK_ID`], the
+    /// deck is empty, or it contains duplicate card ids.
+    pub fn register_deck(&mut self, id: &str, cards: Vec<TarotCard>) -> Result<(), TarotError> {
+        if id.trim().is_empty() || id == DEFAULT_DECK_ID {
+            return Err(TarotError::InvalidDeckId(id.to_string()));
+        }
+        let mut problems: Vec<String> =
+            duplicate_ids(&cards).iter().map(ToString::to_string).collect();
+        if cards.is_empty() {
+            problems.push("has no cards".to_string());
+        }
+        if !problems.is_empty() {
+            return Err(TarotError::InvalidDeck {
+                deck: id.to_string(),
+                problems,
+            });
+        }
+        self.decks.insert(id.to_string(), cards);
+        Ok(())
+    }
+
+    /// Load a deck from `source` and register it under `id`.
+    ///
+    /// # Errors
+    /// Returns an error if the deck cannot be loaded or registered.
+    pub fn register_deck_source(
+        &mut self,
+        id: &str,
+        source: &DeckSource,
+    ) -> Result<(), TarotError> {
+        let cards = decks::load_deck(source)?;
+        self.register_deck(id, cards)
+    }
+
+    /// Ids of every available deck, starting with [`DEFAULT_DECK_ID`].
+    pub fn deck_ids(&self) -> Vec<&str> {
+        std::iter::once(DEFAULT_DECK_ID)
+            .chain(self.decks.keys().map(String::as_str))
+            .collect()
+    }
+
+    /// Cards of a deck by id; [`DEFAULT_DECK_ID`] is the primary deck.
+    pub fn get_deck(&self, id: &str) -> Option<&[TarotCard]> {
+        if id == DEFAULT_DECK_ID {
+            return Some(&self.deck);
+        }
+        self.decks.get(id).map(Vec::as_slice)
+    }
+
+    /// Shuffle a copy of the named deck and draw `count` cards from it.
+    ///
+    /// # Errors
+    /// Returns an error if the deck is unknown or too small.
+    pub fn draw_from(
+        &self,
+        deck_id: &str,
+        count: usize,
+        allow_reversals: bool,
+    ) -> Result<Vec<DrawnCard>, TarotError> {
+        self.draw_from_with_rng(deck_id, count, allow_reversals, &mut rand::thread_rng())
+    }
+
+    /// Like [`draw_from`](Self::draw_from) with a caller-supplied RNG.
+    ///
+    /// # Errors
+    /// Returns an error if the deck is unknown or too small.
+    pub fn draw_from_with_rng<R: Rng + ?Sized>(
+        &self,
+        deck_id: &str,
+        count: usize,
+        allow_reversals: bool,
+        rng: &mut R,
+    ) -> Result<Vec<DrawnCard>, TarotError> {
+        let mut deck = self.deck_for(deck_id)?.to_vec();
+        self.shuffle_deck_with_rng(&mut deck, rng);
+        let drawn = draw_cards_with_rng(&deck, count, allow_reversals, rng)?;
+        self.record_draws(&drawn);
+        Ok(drawn)
+    }
+
+    /// Register a spread, replacing any existing spread with the same id.
+    ///
+    /// # Errors
+    /// Returns an error if the spread fails [`validate_spread`].
+    pub fn add_spread(&mut self, spread: SpreadDefinition) -> Result<(), TarotError> {
+        spread.validate()?;
+        match self.spreads.iter_mut().find(|s| s.id == spread.id) {
+            Some(existing) => *existing = spread,
+            None => self.spreads.push(spread),
+        }
+        Ok(())
+    }
+
+    /// Register every spread in a JSON array. Nothing is added unless all of
+    /// them validate. Returns the number of spreads loaded.
+    ///
+    /// # Errors
+    /// Returns an error if the JSON is malformed or any spread is invalid.
+    pub fn load_spreads_from_str(&mut self, json: &str) -> Result<usize, TarotError> {
+        let spreads = parse_spreads(json)?;
+        let count = spreads.len();
+        for spread in spreads {
+            self.add_spread(spread)?;
+        }
+        Ok(count)
+    }
+
+    /// Register every spread in a JSON file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or its contents are
+    /// invalid.
+    pub fn load_spreads_from_path<P: AsRef<Path>>(&mut self, path: P) -> Result<usize, TarotError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|e| TarotError::io(path, e))?;
+        self.load_spreads_from_str(&json)
+    }
+
+    /// Shuffle, optionally cut, and deal a full spread, returning it with the
+    /// request's context attached. A seeded request always yields the same
+    /// cards, cut and reversals.
+    ///
+    /// # Errors
+    /// Returns an error if the spread or deck id is unknown or the deck is
+    /// too small for the spread.
+    pub fn read(&self, request: &ReadingRequest, timestamp: u64) -> Result<Reading, TarotError> {
+        let spread = self
+            .get_spread(&request.spread_id)
+            .ok_or_else(|| TarotError::UnknownSpread(request.spread_id.clone()))?;
+        let deck_id = request.deck_id.as_deref().unwrap_or(DEFAULT_DECK_ID);
+        let mut deck = self.deck_for(deck_id)?.to_vec();
+
+        let mut rng = match request.seed {
+            Some(seed) => seeded_rng(seed),
+            None => StdRng::from_entropy(),
+        };
+        self.shuffle_deck_with_rng(&mut deck, &mut rng);
+        let mut session = ReadingSession::from_deck(spread.clone(), deck, request.allow_reversals);
+        if request.cut {
+            session.cut_random_with_rng(&mut rng)?;
+        }
+        while !session.is_complete() {
+            session.draw_next_with_rng(&mut rng)?;
+        }
+        self.record_draws(session.drawn());
+
+        Ok(Reading {
+            question: request.question.clone(),
+            querent_id: request.querent_id.clone(),
+            seed: request.seed,
+            tags: request.tags.clone(),
+            ..session.into_reading(deck_id, timestamp)
+        })
+    }
+
+    /// Shuffle a copy of the named deck and return its order for drawing in
+    /// a later message.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::UnknownDeck`] if the deck id is unknown.
+    pub fn shuffle_for_later(&self, deck_id: &str) -> Result<DeckState, TarotError> {
+        let mut deck = self.deck_for(deck_id)?.to_vec();
+        self.shuffle_deck(&mut deck);
+        Ok(DeckState::new(deck_id, &deck))
+    }
+
+    /// Draw `count` cards off the top of a saved deck, removing them from it.
+    ///
+    /// # Errors
+    /// Returns an error if the deck is unknown, a saved card is no longer in
+    /// it, or too few cards remain.
+    pub fn draw_from_state(
+        &self,
+        state: &mut DeckState,
+        count: usize,
+        allow_reversals: bool,
+    ) -> Result<Vec<DrawnCard>, TarotError> {
+        let cards = state.take(self.deck_for(state.deck_id())?, count)?;
+        let drawn = draw_cards(&cards, count, allow_reversals)?;
+        self.record_draws(&drawn);
+        Ok(drawn)
+    }
+
+    /// Continue a saved deck as a reading session, without re-shuffling.
+    ///
+    /// # Errors
+    /// Returns an error if the spread or deck is unknown or a saved card is
+    /// no longer in the deck.
+    pub fn session_from_state(
+        &self,
+        state: DeckState,
+        spread_id: &str,
+        allow_reversals: bool,
+    ) -> Result<ReadingSession, TarotError> {
+        let spread = self
+            .get_spread(spread_id)
+            .ok_or_else(|| TarotError::UnknownSpread(spread_id.to_string()))?;
+        let deck = state.resolve(self.deck_for(state.deck_id())?)?;
+        Ok(ReadingSession::from_deck(spread.clone(), deck, allow_reversals))
+    }
+
+    fn deck_for(&self, deck_id: &str) -> Result<&[TarotCard], TarotError> {
+        self.get_deck(deck_id)
+            .ok_or_else(|| TarotError::UnknownDeck(deck_id.to_string()))
+    }
+
+    /// Start a reading session for a spread with a freshly shuffled deck.
+    ///
+    /// # Errors
+    /// Returns an error if the spread id is unknown.
+    pub fn start_session(
+        &self,
+        spread_id: &str,
+        allow_reversals: bool,
+    ) -> Result<ReadingSession, TarotError> {
+        let spread = self
+            .get_spread(spread_id)
+            .ok_or_else(|| TarotError::UnknownSpread(spread_id.to_string()))?;
+        let mut deck = self.create_deck();
+        self.shuffle_deck(&mut deck);
+        Ok(ReadingSession::from_deck(spread.clone(), deck, allow_reversals))
+    }
 }
 
 impl Default for TarotEngine {
@@ -165,6 +1170,26 @@ impl Default for TarotEngine {
     }
 }
 
+/// Test fixture: the cards `ids` dealt in order, reversed where `reversed`
+/// says so; cards past the end of `reversed` are upright.
+#[cfg(test)]
+pub(crate) fn drawn_cards(ids: &[&str], reversed: &[bool]) -> Vec<DrawnCard> {
+    let deck = create_deck();
+    ids.iter()
+        .enumerate()
+        .map(|(i, id)| DrawnCard {
+            card: deck
+                .iter()
+                .find(|c| c.id == *id)
+                .unwrap_or_else(|| panic!("unknown card {}", id))
+                .clone(),
+            reversed: reversed.get(i).copied().unwrap_or(false),
+            position_index: i,
+            clarifies: None,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,20 +1230,300 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn seeded_shuffle_and_draw_are_reproducible() {
+        let draw = |seed| {
+            let mut rng = seeded_rng(seed);
+            let mut deck = create_deck();
+            shuffle_deck_with_rng(&mut deck, &mut rng);
+            draw_cards_with_rng(&deck, 10, true, &mut rng)
+                .unwrap()
+                .into_iter()
+                .map(|d| (d.card.id, d.reversed))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(42), draw(42));
+        assert_ne!(draw(42), draw(43));
+    }
+
+    #[test]
+    fn cut_moves_top_cards_to_bottom() {
+        let mut deck = create_deck();
+        let top = deck[0].id.clone();
+        cut_deck(&mut deck, 10).unwrap();
+        assert_eq!(deck[68].id, top);
+        assert!(cut_deck(&mut deck, 0).is_err());
+        assert!(cut_deck(&mut deck, 78).is_err());
+
+        let at = cut_deck_random_with_rng(&mut deck, &mut seeded_rng(1)).unwrap();
+        assert!((1..78).contains(&at));
+        assert!(cut_deck_random(&mut deck[..1]).is_err());
+    }
+
     #[test]
     fn filter_major_arcana() {
         let deck = create_deck();
-        let major = filter_cards(&deck, Some("major"), None);
+        let major = filter_cards(&deck, Some(Arcana::Major), None);
         assert_eq!(major.len(), 22);
     }
 
     #[test]
     fn filter_by_suit() {
         let deck = create_deck();
-        let wands = filter_cards(&deck, Some("minor"), Some("wands"));
+        let wands = filter_cards(&deck, Some(Arcana::Minor), Some(Suit::Wands));
         assert_eq!(wands.len(), 14);
     }
 
+    #[test]
+    fn filtered_draws_stay_in_the_sub_deck() {
+        let engine = TarotEngine::new();
+        let majors = DrawOptions {
+            count: 22,
+            filter: Some(CardFilter::MajorsOnly),
+            ..Default::default()
+        };
+        assert!(engine.draw(&majors).unwrap().iter().all(|d| d.card.is_major()));
+        assert!(engine.draw(&DrawOptions { count: 23, ..majors }).is_err());
+
+        let courts = DrawOptions {
+            count: 16,
+            allow_reversals: false,
+            filter: Some(CardFilter::CourtsOnly),
+            ..Default::default()
+        };
+        let drawn =
+            draw_with_options_with_rng(&create_deck(), &courts, &mut seeded_rng(8)).unwrap();
+        assert!(drawn.iter().all(|d| d.card.number > 10 && !d.reversed));
+
+        let cups = DrawOptions {
+            count: 14,
+            filter: Some(CardFilter::Suit(Suit::Cups)),
+            ..Default::default()
+        };
+        assert!(engine.draw(&cups).unwrap().iter().all(|d| d.card.element == "Water"));
+    }
+
+    #[test]
+    fn quick_readings() {
+        let engine = TarotEngine::new();
+        let three = engine.quick_reading(ReadingKind::ThreeCard).unwrap();
+        let names: Vec<&str> = three.positions.iter().map(|p| p.position_name.as_str()).collect();
+        assert_eq!(names, ["Past", "Present", "Future"]);
+        assert!(!three.synthesis.summary.is_empty());
+
+        let single = engine.quick_reading(ReadingKind::Single).unwrap();
+        assert_eq!(single.positions.len(), 1);
+    }
+
+    #[test]
+    fn seeded_readings_replay() {
+        let engine = TarotEngine::new();
+        let request = ReadingRequest {
+            spread_id: "celtic_cross".to_string(),
+            question: Some("What should I focus on?".to_string()),
+            querent_id: Some("user-1".to_string()),
+            seed: Some(11),
+            cut: true,
+            tags: vec!["weekly".to_string()],
+            ..Default::default()
+        };
+        let reading = engine.read(&request, 1_700_000_000_000).unwrap();
+        assert_eq!(reading.cards.len(), 10);
+        assert_eq!(reading.deck_id, DEFAULT_DECK_ID);
+        assert!(reading.cut_index.is_some());
+
+        let again = engine.read(&request, 0).unwrap();
+        let ids = |r: &Reading| -> Vec<(String, bool)> {
+            r.cards.iter().map(|d| (d.card.id.clone(), d.reversed)).collect()
+        };
+        assert_eq!(ids(&reading), ids(&again));
+        assert_eq!(reading.cut_index, again.cut_index);
+
+        let json = serde_json::to_string(&reading).unwrap();
+        let restored: Reading = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.question, request.question);
+        assert_eq!(restored.seed, Some(11));
+
+        let unknown = ReadingRequest {
+            deck_id: Some("nope".to_string()),
+            ..request
+        };
+        assert_eq!(engine.read(&unknown, 0).unwrap_err(), TarotError::UnknownDeck("nope".into()));
+    }
+
+    #[test]
+    fn excluded_cards_are_never_drawn() {
+        let engine = TarotEngine::new();
+        let options = DrawOptions {
+            count: 20,
+            filter: Some(CardFilter::MajorsOnly),
+            exclude: vec!["major_13_death".to_string(), "major_16_tower".to_string()],
+            ..Default::default()
+        };
+        let drawn = engine.draw(&options).unwrap();
+        assert!(drawn.iter().all(|d| !options.exclude.contains(&d.card.id)));
+        assert!(engine.draw(&DrawOptions { count: 21, ..options.clone() }).is_err());
+
+        let typo = DrawOptions {
+            exclude: vec!["major_99".to_string()],
+            ..options
+        };
+        assert_eq!(engine.draw(&typo).unwrap_err(), TarotError::UnknownCard("major_99".into()));
+    }
+
+    #[test]
+    fn correspondence_lookups() {
+        let engine = TarotEngine::new();
+        let venus = engine.cards_by_planet("venus");
+        assert!(venus.iter().any(|c| c.id == "major_03_empress"));
+        assert!(venus.iter().all(|c| c.planet.as_deref() == Some("Venus")));
+
+        let scorpio = engine.cards_by_zodiac("Scorpio");
+        assert!(scorpio.iter().any(|c| c.id == "major_13_death"));
+
+        let water = engine.cards_by_element("water");
+        assert!(water.iter().filter(|c| c.suit == Some(Suit::Cups)).count() == 14);
+        assert!(engine.cards_by_planet("vulcan").is_empty());
+    }
+
+    fn custom_spread_json(second_index: usize) -> String {
+        format!(
+            r#"[{{"id": "crossroads", "name": "Crossroads", "description": "Two paths",
+                "cardCount": 2, "positions": [
+                  {{"index": 0, "name": "Left", "description": "One way"}},
+                  {{"index": {}, "name": "Right", "description": "The other"}}
+                ]}}]"#,
+            second_index
+        )
+    }
+
+    #[test]
+    fn load_custom_spreads() {
+        let mut engine = TarotEngine::new();
+        let builtin = engine.get_spreads().len();
+        assert_eq!(engine.load_spreads_from_str(&custom_spread_json(1)).unwrap(), 1);
+        assert_eq!(engine.get_spreads().len(), builtin + 1);
+        assert_eq!(engine.get_spread("crossroads").unwrap().card_count, 2);
+
+        // Re-loading replaces rather than duplicates
+        engine.load_spreads_from_str(&custom_spread_json(1)).unwrap();
+        assert_eq!(engine.get_spreads().len(), builtin + 1);
+    }
+
+    #[test]
+    fn invalid_spreads_are_rejected() {
+        let mut engine = TarotEngine::new();
+        let builtin = engine.get_spreads().len();
+        assert!(engine.load_spreads_from_str(&custom_spread_json(2)).is_err());
+        assert!(engine.load_spreads_from_str("not json").is_err());
+        assert_eq!(engine.get_spreads().len(), builtin);
+
+        let mut spread = engine.get_spread("three_card").unwrap().clone();
+        spread.card_count = 4;
+        assert!(validate_spread(&spread).is_err());
+        assert!(engine.get_spreads().iter().all(|s| validate_spread(s).is_ok()));
+    }
+
+    #[test]
+    fn validation_reports_every_problem() {
+        let mut spread = TarotEngine::new().get_spread("three_card").unwrap().clone();
+        spread.card_count = 4;
+        spread.positions[2].index = 5;
+        match spread.validate() {
+            Err(TarotError::InvalidSpread { spread, problems }) => {
+                assert_eq!(spread, "three_card");
+                assert_eq!(problems.len(), 2);
+            }
+            other => panic!("expected InvalidSpread, got {:?}", other),
+        }
+
+        let mut deck = create_deck();
+        assert!(check_deck("default", &deck).is_ok());
+        deck[1] = deck[0].clone();
+        deck.pop();
+        let err = check_deck("broken", &deck).unwrap_err();
+        assert!(matches!(&err, TarotError::InvalidDeck { problems, .. } if problems.len() == 2));
+        assert!(err.to_string().contains("major_00_fool"));
+
+        assert!(matches!(parse_cards("[{"), Err(TarotError::Json { .. })));
+
+        let mut odd = create_deck();
+        odd[0].numerology = 5;
+        odd[30].suit = None;
+        assert_eq!(
+            validate_deck(&odd).unwrap_err(),
+            vec![
+                DeckIssue::NumerologyMismatch {
+                    id: "major_00_fool".to_string(),
+                    number: 0,
+                    numerology: 5
+                },
+                DeckIssue::MissingSuit { id: odd[30].id.clone() },
+            ]
+        );
+        for source in [DeckSource::Thoth, DeckSource::Marseille] {
+            assert!(validate_deck(&decks::load_deck(&source).unwrap()).is_ok());
+        }
+        assert!(TarotEngine::try_new().is_ok());
+        assert_eq!(
+            draw_cards(&deck[..2], 3, false).unwrap_err(),
+            TarotError::NotEnoughCards {
+                needed: 3,
+                available: 2
+            }
+        );
+    }
+
+    #[test]
+    fn draw_from_named_decks() {
+        let mut engine = TarotEngine::new();
+        engine.register_deck_source("thoth", &DeckSource::Thoth).unwrap();
+        assert_eq!(engine.deck_ids(), vec![DEFAULT_DECK_ID, "thoth"]);
+
+        let drawn = engine.draw_from("thoth", 78, false).unwrap();
+        assert!(drawn.iter().any(|d| d.card.name == "Lust"));
+        assert!(engine.draw_from("default", 3, true).is_ok());
+        assert!(engine.draw_from("marseille", 3, true).is_err());
+
+        let mut dup = engine.create_deck();
+        dup.push(dup[0].clone());
+        assert!(engine.register_deck("broken", dup).is_err());
+        assert!(engine.register_deck(DEFAULT_DECK_ID, engine.create_deck()).is_err());
+    }
+
+    #[test]
+    fn clarifier_links_to_its_position() {
+        let mut rng = seeded_rng(3);
+        let mut deck = create_deck();
+        shuffle_deck_with_rng(&mut deck, &mut rng);
+        let mut drawn = draw_cards_with_rng(&deck, 3, true, &mut rng).unwrap();
+
+        let clarifier = draw_clarifier_with_rng(&deck, &drawn, 1, true, &mut rng).unwrap();
+        assert_eq!(clarifier.card.id, deck[3].id);
+        assert_eq!(clarifier.clarifies, Some(1));
+        drawn.push(clarifier);
+
+        let second = draw_clarifier(&deck, &drawn, 1, false).unwrap();
+        assert_eq!(second.card.id, deck[4].id);
+        drawn.push(second);
+        assert_eq!(clarifiers_for(&drawn, 1).len(), 2);
+        assert!(clarifiers_for(&drawn, 0).is_empty());
+
+        assert!(draw_clarifier(&deck, &drawn, 5, false).is_err());
+    }
+
+    #[test]
+    fn card_of_the_day_is_stable() {
+        let a = card_of_the_day(2024, 3, 15, Some("user-1"));
+        let b = card_of_the_day(2024, 3, 15, Some("user-1"));
+        assert_eq!((a.card.id, a.reversed), (b.card.id, b.reversed));
+
+        let year: std::collections::HashSet<String> = (1..=28)
+            .flat_map(|d| (1..=12).map(move |m| card_of_the_day(2024, m, d, None).card.id))
+            .collect();
+        assert!(year.len() > 50);
+    }
+
     #[test]
     fn engine_get_spread() {
         let engine = TarotEngine::new();
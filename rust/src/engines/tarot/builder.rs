@@ -0,0 +1,211 @@
+use std::fmt;
+
+use crate::types::{ReversalRule, SignificatorMode, SpreadDefinition, SpreadPosition};
+
+// ---------------------------------------------------------------------------
+// SpreadBuilder — programmatic spread creation
+// ---------------------------------------------------------------------------
+
+/// Why a [`SpreadBuilder`] could not produce a spread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpreadBuildError {
+    /// Neither a name nor an id was given
+    MissingName,
+    /// No positions were added
+    EmptySpread,
+    /// Two positions were given the same index
+    DuplicateIndex(usize),
+    /// Indices do not run contiguously from 0
+    MissingIndex(usize),
+}
+
+impl fmt::Display for SpreadBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingName => write!(f, "spread needs a name or id"),
+            Self::EmptySpread => write!(f, "spread has no positions"),
+            Self::DuplicateIndex(i) => write!(f, "position index {} is used more than once", i),
+            Self::MissingIndex(i) => write!(f, "no position has index {}", i),
+        }
+    }
+}
+
+impl std::error::Error for SpreadBuildError {}
+
+/// Fluent builder for [`SpreadDefinition`].
+///
+/// Positions added with [`position`](Self::position) are numbered in order;
+/// [`position_at`](Self::position_at) places one at an explicit index. When no
+/// id is given it is derived from the name (`"Two Paths"` → `"two_paths"`).
+#[derive(Debug, Clone, Default)]
+pub struct SpreadBuilder {
+    id: Option<String>,
+    name: String,
+    description: String,
+    positions: Vec<SpreadPosition>,
+    significator: Option<SignificatorMode>,
+}
+
+impl SpreadDefinition {
+    /// Start building a spread programmatically.
+    pub fn builder() -> SpreadBuilder {
+        SpreadBuilder::default()
+    }
+}
+
+impl SpreadBuilder {
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Reserve a significator card for the spread.
+    pub fn significator(mut self, mode: SignificatorMode) -> Self {
+        self.significator = Some(mode);
+        self
+    }
+
+    /// Append a position after the highest index used so far.
+    pub fn position(self, name: impl Into<String>, description: impl Into<String>) -> Self {
+        let index = self.positions.iter().map(|p| p.index + 1).max().unwrap_or(0);
+        self.position_at(index, name, description)
+    }
+
+    /// Add a position at an explicit index.
+    pub fn position_at(
+        mut self,
+        index: usize,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        self.positions.push(SpreadPosition {
+            index,
+            name: name.into(),
+            description: description.into(),
+            reversal: ReversalRule::Random,
+        });
+        self
+    }
+
+    /// Set the reversal rule of the most recently added position.
+    pub fn reversal(mut self, rule: ReversalRule) -> Self {
+        if let Some(last) = self.positions.last_mut() {
+            last.reversal = rule;
+        }
+        self
+    }
+
+    /// Validate and produce the spread, with positions sorted by index.
+    ///
+    /// # Errors
+    /// Returns a [`SpreadBuildError`] if the spread is unnamed, empty, or its
+    /// indices are duplicated or non-contiguous.
+    pub fn build(self) -> Result<SpreadDefinition, SpreadBuildError> {
+        let id = match self.id {
+            Some(id) if !id.trim().is_empty() => id,
+            _ => slugify(&self.name),
+        };
+        if id.is_empty() {
+            return Err(SpreadBuildError::MissingName);
+        }
+        if self.positions.is_empty() {
+            return Err(SpreadBuildError::EmptySpread);
+        }
+
+        let mut positions = self.positions;
+        positions.sort_by_key(|p| p.index);
+        for (expected, pair) in positions.windows(2).enumerate() {
+            if pair[0].index == pair[1].index {
+                return Err(SpreadBuildError::DuplicateIndex(pair[0].index));
+            }
+            if pair[0].index != expected {
+                return Err(SpreadBuildError::MissingIndex(expected));
+            }
+        }
+        let last = positions.len() - 1;
+        if positions[last].index != last {
+            return Err(SpreadBuildError::MissingIndex(last));
+        }
+
+        let name = if self.name.is_empty() { id.clone() } else { self.name };
+        Ok(SpreadDefinition {
+            id,
+            name,
+            description: self.description,
+            card_count: positions.len(),
+            positions,
+            significator: self.significator,
+        })
+    }
+}
+
+fn slugify(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::validate_spread;
+
+    #[test]
+    fn builds_a_valid_spread() {
+        let spread = SpreadDefinition::builder()
+            .name("Two Paths")
+            .description("A choice between options")
+            .position("Left", "The first option")
+            .position("Right", "The second option")
+            .position("Advice", "How to choose")
+            .build()
+            .unwrap();
+
+        assert_eq!(spread.id, "two_paths");
+        assert_eq!(spread.card_count, 3);
+        assert_eq!(spread.positions[2].index, 2);
+        assert!(validate_spread(&spread).is_ok());
+    }
+
+    #[test]
+    fn explicit_indices_are_sorted_and_checked() {
+        let spread = SpreadDefinition::builder()
+            .id("custom")
+            .position_at(1, "Second", "")
+            .position_at(0, "First", "")
+            .build()
+            .unwrap();
+        assert_eq!(spread.positions[0].name, "First");
+
+        let dup = SpreadDefinition::builder()
+            .id("dup")
+            .position_at(0, "A", "")
+            .position_at(0, "B", "")
+            .build();
+        assert_eq!(dup.unwrap_err(), SpreadBuildError::DuplicateIndex(0));
+
+        let gap = SpreadDefinition::builder().id("gap").position_at(1, "A", "").build();
+        assert_eq!(gap.unwrap_err(), SpreadBuildError::MissingIndex(0));
+    }
+
+    #[test]
+    fn empty_and_unnamed_spreads_fail() {
+        let empty = SpreadDefinition::builder().name("Nothing").build();
+        assert_eq!(empty.unwrap_err(), SpreadBuildError::EmptySpread);
+
+        let unnamed = SpreadDefinition::builder().position("A", "").build();
+        assert_eq!(unnamed.unwrap_err(), SpreadBuildError::MissingName);
+    }
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::{Arcana, Rank, Suit, TarotCard};
+
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// Card classification
+// ---------------------------------------------------------------------------
+//
+// `arcana` and `suit` are typed on the card itself and serialize to the same
+// lowercase strings as the JSON data. `number` stays numeric because deck
+// packs renumber majors (Strength and Justice swap between traditions), so
+// the minor rank is derived from it with `TarotCard::rank`.
+
+impl Arcana {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Major => "major",
+            Self::Minor => "minor",
+        }
+    }
+}
+
+impl fmt::Display for Arcana {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Arcana {
+    type Err = TarotError;
+
+    /// Case-insensitive: `"Major"`, `"minor"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "major" => Ok(Self::Major),
+            "minor" => Ok(Self::Minor),
+            _ => Err(TarotError::InvalidValue {
+                field: "arcana",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Self::Wands, Self::Cups, Self::Swords, Self::Pentacles];
+
+    /// Lowercase key, as used in card ids and JSON.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Wands => "wands",
+            Self::Cups => "cups",
+            Self::Swords => "swords",
+            Self::Pentacles => "pentacles",
+        }
+    }
+
+    /// Classical element, capitalised like `TarotCard::element`.
+    pub fn element(self) -> &'static str {
+        match self {
+            Self::Wands => "Fire",
+            Self::Cups => "Water",
+            Self::Swords => "Air",
+            Self::Pentacles => "Earth",
+        }
+    }
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Suit {
+    type Err = TarotError;
+
+    /// Case-insensitive suit key, e.g. `"Cups"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|suit| suit.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| TarotError::InvalidValue {
+                field: "suit",
+                value: s.to_string(),
+            })
+    }
+}
+
+impl Rank {
+    pub const ALL: [Rank; 14] = [
+        Self::Ace,
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Seven,
+        Self::Eight,
+        Self::Nine,
+        Self::Ten,
+        Self::Page,
+        Self::Knight,
+        Self::Queen,
+        Self::King,
+    ];
+
+    /// Rank for a minor card number: 1–10 for ace to ten, 11–14 for page to
+    /// king.
+    pub fn from_number(number: i32) -> Option<Self> {
+        Self::ALL.get(usize::try_from(number).ok()?.checked_sub(1)?).copied()
+    }
+
+    pub fn number(self) -> i32 {
+        self as i32 + 1
+    }
+
+    /// Lowercase key, as used in JSON: `"ace"`, `"two"` … `"king"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ace => "ace",
+            Self::Two => "two",
+            Self::Three => "three",
+            Self::Four => "four",
+            Self::Five => "five",
+            Self::Six => "six",
+            Self::Seven => "seven",
+            Self::Eight => "eight",
+            Self::Nine => "nine",
+            Self::Ten => "ten",
+            Self::Page => "page",
+            Self::Knight => "knight",
+            Self::Queen => "queen",
+            Self::King => "king",
+        }
+    }
+
+    pub fn is_court(self) -> bool {
+        self >= Self::Page
+    }
+}
+
+impl TarotCard {
+    pub fn is_major(&self) -> bool {
+        self.arcana == Arcana::Major
+    }
+
+    /// Rank of a minor card; `None` for majors.
+    pub fn rank(&self) -> Option<Rank> {
+        self.suit.and(Rank::from_number(self.number))
+    }
+
+    pub fn is_court(&self) -> bool {
+        self.rank().is_some_and(Rank::is_court)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, get_card};
+
+    #[test]
+    fn typed_fields_round_trip_as_strings() {
+        let deck = create_deck();
+        let knight = get_card(&deck, "cups_knight").unwrap();
+        assert_eq!(knight.suit, Some(Suit::Cups));
+        assert_eq!(knight.rank(), Some(Rank::Knight));
+        assert!(knight.is_court());
+
+        let json = serde_json::to_value(&knight).unwrap();
+        assert_eq!(json["arcana"], "minor");
+        assert_eq!(json["suit"], "cups");
+        assert_eq!(json["number"], 12);
+
+        let fool = get_card(&deck, "major_00_fool").unwrap();
+        assert!(fool.is_major() && fool.rank().is_none());
+        assert!(serde_json::to_value(&fool).unwrap()["suit"].is_null());
+    }
+
+    #[test]
+    fn parsing_and_ranks() {
+        assert_eq!("Pentacles".parse::<Suit>().unwrap(), Suit::Pentacles);
+        assert_eq!("MAJOR".parse::<Arcana>().unwrap(), Arcana::Major);
+        assert!("coins".parse::<Suit>().is_err());
+        assert_eq!(Rank::from_number(1), Some(Rank::Ace));
+        assert_eq!(Rank::from_number(14), Some(Rank::King));
+        assert_eq!(Rank::from_number(0), None);
+        assert!(Rank::ALL.iter().all(|r| Rank::from_number(r.number()) == Some(*r)));
+        assert_eq!(Suit::Swords.element(), "Air");
+    }
+}
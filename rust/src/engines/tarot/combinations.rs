@@ -0,0 +1,61 @@
+use crate::types::{CardCombination, CombinationMatch, DrawnCard};
+
+// ---------------------------------------------------------------------------
+// Card-pair combinations
+// ---------------------------------------------------------------------------
+
+const COMBINATIONS_JSON: &str = include_str!("../../../../data/tarot/combinations.json");
+
+/// Every known two-card combination from the embedded data.
+pub fn load_combinations() -> Vec<CardCombination> {
+    serde_json::from_str(COMBINATIONS_JSON).expect("Failed to parse combinations.json")
+}
+
+/// Scan dealt cards for known pairs, in the order the combinations are
+/// listed. Clarifiers count, since they are read alongside their position.
+pub fn find_combinations(
+    combinations: &[CardCombination],
+    drawn: &[DrawnCard],
+) -> Vec<CombinationMatch> {
+    let position_of = |id: &str| drawn.iter().find(|d| d.card.id == id).map(|d| d.position_index);
+    combinations
+        .iter()
+        .filter_map(|combo| {
+            let first = position_of(&combo.cards[0])?;
+            let second = position_of(&combo.cards[1])?;
+            Some(CombinationMatch {
+                combination: combo.clone(),
+                positions: [first, second],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{drawn_cards, TarotEngine};
+
+    #[test]
+    fn combination_data_references_real_cards() {
+        let engine = TarotEngine::new();
+        let combos = load_combinations();
+        assert!(combos.len() >= 40);
+        for combo in &combos {
+            assert_ne!(combo.cards[0], combo.cards[1]);
+            for id in &combo.cards {
+                assert!(engine.get_card(id).is_some(), "unknown card {}", id);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_pairs_regardless_of_order() {
+        let drawn = drawn_cards(&["major_19_sun", "cups_04", "major_16_tower"], &[]);
+
+        let found = find_combinations(&load_combinations(), &drawn);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].combination.name, "Breakthrough");
+        assert_eq!(found[0].positions, [2, 0]);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::types::{Decan, NatalChart, NatalDecan};
+
+// ---------------------------------------------------------------------------
+// Golden Dawn decans
+// ---------------------------------------------------------------------------
+//
+// The 36 pips from two to ten each rule one decan. Cardinal signs take the
+// 2–4, fixed signs the 5–7 and mutable signs the 8–10 of their element's
+// suit; rulers follow the Chaldean order starting from Mars at 0° Aries.
+
+const SIGNS: [&str; 12] = [
+    "Aries", "Taurus", "Gemini", "Cancer", "Leo", "Virgo",
+    "Libra", "Scorpio", "Sagittarius", "Capricorn", "Aquarius", "Pisces",
+];
+
+/// Chaldean order, rotated to begin with the ruler of Aries I.
+const CHALDEAN_FROM_MARS: [&str; 7] =
+    ["Mars", "Sun", "Venus", "Mercury", "Moon", "Saturn", "Jupiter"];
+
+/// Suits by element, in sign order (fire, earth, air, water).
+const SUITS: [&str; 4] = ["wands", "pentacles", "swords", "cups"];
+
+/// All 36 decans from 0° Aries.
+pub fn decans() -> Vec<Decan> {
+    (0..36)
+        .map(|i| {
+            let sign = i / 3;
+            let decan = i % 3;
+            let rank = (sign % 3) * 3 + decan + 2;
+            Decan {
+                card_id: format!("{}_{:02}", SUITS[sign % 4], rank),
+                sign: SIGNS[sign].to_string(),
+                decan: decan as u8 + 1,
+                planet: CHALDEAN_FROM_MARS[i % 7].to_string(),
+                start_degree: i as f64 * 10.0,
+                end_degree: (i + 1) as f64 * 10.0,
+            }
+        })
+        .collect()
+}
+
+/// The decan a pip card rules, or `None` for aces, courts and majors.
+pub fn decan_for_card(card_id: &str) -> Option<Decan> {
+    decans().into_iter().find(|d| d.card_id == card_id)
+}
+
+/// The decan containing an ecliptic longitude.
+pub fn decan_for_longitude(longitude: f64) -> Decan {
+    let index = (longitude.rem_euclid(360.0) / 10.0).floor() as usize;
+    decans().swap_remove(index.min(35))
+}
+
+/// Decan cards for each planet and the two angles of a natal chart.
+pub fn natal_decans(chart: &NatalChart) -> Vec<NatalDecan> {
+    let planets = [
+        &chart.sun, &chart.moon, &chart.mercury, &chart.venus, &chart.mars,
+        &chart.jupiter, &chart.saturn, &chart.uranus, &chart.neptune, &chart.pluto,
+    ];
+    planets
+        .iter()
+        .map(|p| (p.planet.clone(), p.total_degrees))
+        .chain([
+            ("ascendant".to_string(), chart.ascendant.total_degrees),
+            ("midheaven".to_string(), chart.midheaven.total_degrees),
+        ])
+        .map(|(point, longitude)| NatalDecan {
+            point,
+            longitude,
+            decan: decan_for_longitude(longitude),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::calculate_natal_chart;
+    use crate::engines::tarot::create_deck;
+    use crate::types::BirthData;
+
+    #[test]
+    fn decans_match_card_attributions() {
+        let deck = create_deck();
+        let all = decans();
+        assert_eq!(all.len(), 36);
+        for d in &all {
+            let card = deck.iter().find(|c| c.id == d.card_id).unwrap();
+            assert_eq!(card.zodiac.as_deref(), Some(d.sign.as_str()), "{}", d.card_id);
+            assert_eq!(card.planet.as_deref(), Some(d.planet.as_str()), "{}", d.card_id);
+        }
+    }
+
+    #[test]
+    fn lookups() {
+        let two_of_wands = decan_for_card("wands_02").unwrap();
+        assert_eq!((two_of_wands.sign.as_str(), two_of_wands.decan), ("Aries", 1));
+        assert!(decan_for_card("wands_01_ace").is_none());
+
+        let d = decan_for_longitude(125.0);
+        assert_eq!((d.card_id.as_str(), d.planet.as_str()), ("wands_05", "Saturn"));
+        assert_eq!(decan_for_longitude(-5.0).card_id, "cups_10");
+    }
+
+    #[test]
+    fn natal_chart_decans() {
+        let birth = BirthData {
+            year: 2000,
+            month: 1,
+            day: Some(1),
+            hour: Some(12),
+            minute: Some(0),
+            latitude: Some(51.5074),
+            longitude: Some(-0.1278),
+            timezone: Some(0.0),
+        };
+        let result = natal_decans(&calculate_natal_chart(&birth));
+        assert_eq!(result.len(), 12);
+        // The Sun sits around 10° Capricorn on 2000-01-01
+        assert_eq!(result[0].decan.card_id, "pentacles_03");
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::TarotCard;
+
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// DeckState — a shuffled deck kept between messages
+// ---------------------------------------------------------------------------
+
+/// The order of a shuffled deck, stored as card ids so it stays small enough
+/// to persist with a conversation. Cards are resolved against the engine's
+/// deck when drawn, so "shuffle now, draw later" picks up the deck's current
+/// names and translations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckState {
+    deck_id: String,
+    /// Remaining card ids; index 0 is the top of the deck
+    order: Vec<String>,
+}
+
+impl DeckState {
+    /// Record the order of `cards` (top card first) from the named deck.
+    pub fn new(deck_id: &str, cards: &[TarotCard]) -> Self {
+        Self {
+            deck_id: deck_id.to_string(),
+            order: cards.iter().map(|c| c.id.clone()).collect(),
+        }
+    }
+
+    pub fn deck_id(&self) -> &str {
+        &self.deck_id
+    }
+
+    /// Remaining card ids, top first.
+    pub fn order(&self) -> &[String] {
+        &self.order
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Resolve the remaining ids against `deck`, top first.
+    ///
+    /// # Errors
+    /// Returns [`TarotError::UnknownCard`] if a stored id is not in `deck`.
+    pub fn resolve(&self, deck: &[TarotCard]) -> Result<Vec<TarotCard>, TarotError> {
+        self.order
+            .iter()
+            .map(|id| {
+                deck.iter()
+                    .find(|c| &c.id == id)
+                    .cloned()
+                    .ok_or_else(|| TarotError::UnknownCard(id.clone()))
+            })
+            .collect()
+    }
+
+    /// Take `count` cards off the top of `deck`'s resolved order. Nothing is
+    /// removed if the draw fails.
+    ///
+    /// # Errors
+    /// Returns an error if fewer than `count` cards remain or a stored id is
+    /// not in `deck`.
+    pub fn take(&mut self, deck: &[TarotCard], count: usize) -> Result<Vec<TarotCard>, TarotError> {
+        if count > self.order.len() {
+            return Err(TarotError::NotEnoughCards {
+                needed: count,
+                available: self.order.len(),
+            });
+        }
+        let cards = self.resolve(deck)?;
+        self.order.drain(..count);
+        Ok(cards.into_iter().take(count).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{TarotEngine, DEFAULT_DECK_ID};
+
+    #[test]
+    fn shuffle_now_draw_later() {
+        let engine = TarotEngine::new();
+        let mut state = engine.shuffle_for_later(DEFAULT_DECK_ID).unwrap();
+        let top: Vec<String> = state.order()[..3].to_vec();
+
+        // Persist between messages
+        let json = serde_json::to_string(&state).unwrap();
+        let mut restored: DeckState = serde_json::from_str(&json).unwrap();
+
+        let drawn = engine.draw_from_state(&mut restored, 2, false).unwrap();
+        assert_eq!(drawn[0].card.id, top[0]);
+        assert_eq!(drawn[1].card.id, top[1]);
+        assert_eq!(restored.remaining(), 76);
+
+        let mut session = engine.session_from_state(restored, "single", false).unwrap();
+        assert_eq!(session.draw_next().unwrap().card.id, top[2]);
+
+        assert!(engine.draw_from_state(&mut state, 79, false).is_err());
+        assert_eq!(state.remaining(), 78);
+    }
+
+    #[test]
+    fn unknown_ids_are_reported() {
+        let engine = TarotEngine::new();
+        let json = r#"{"deck_id": "default", "order": ["major_00_fool", "major_99_nope"]}"#;
+        let mut state: DeckState = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            engine.draw_from_state(&mut state, 1, false).unwrap_err(),
+            TarotError::UnknownCard("major_99_nope".to_string())
+        );
+        assert_eq!(state.remaining(), 2);
+    }
+}
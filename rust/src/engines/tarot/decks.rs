@@ -0,0 +1,212 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::text::capitalize;
+use crate::types::{CardImage, DeckPack, Rank, TarotCard};
+
+use super::{create_deck, TarotError};
+
+// ---------------------------------------------------------------------------
+// Deck packs
+// ---------------------------------------------------------------------------
+
+const MARSEILLE_JSON: &str = include_str!("../../../../data/tarot/decks/marseille.json");
+const THOTH_JSON: &str = include_str!("../../../../data/tarot/decks/thoth.json");
+
+const SUITS: [&str; 4] = ["wands", "cups", "swords", "pentacles"];
+
+/// Where a [`TarotEngine`](super::TarotEngine) gets its cards from.
+#[derive(Debug, Clone)]
+pub enum DeckSource {
+    /// The embedded Rider-Waite-Smith deck
+    RiderWaiteSmith,
+    /// The embedded Tarot de Marseille pack
+    Marseille,
+    /// The embedded Thoth pack
+    Thoth,
+    /// A pack already in memory
+    Pack(DeckPack),
+    /// A pack in JSON form
+    Json(String),
+    /// A JSON pack file on disk
+    Path(PathBuf),
+}
+
+/// Parse a deck pack from JSON.
+///
+/// # Errors
+/// Returns an error if the JSON is malformed.
+pub fn parse_deck_pack(json: &str) -> Result<DeckPack, TarotError> {
+    serde_json::from_str(json).map_err(|e| TarotError::json("deck pack JSON", e))
+}
+
+/// Apply a pack to a base deck: rename minor cards from the pack's suit and
+/// court names, then apply per-card overrides.
+///
+/// # Errors
+/// Returns an error if the pack names an unknown suit, court rank or
+/// card id.
+pub fn apply_deck_pack(base: &[TarotCard], pack: &DeckPack) -> Result<Vec<TarotCard>, TarotError> {
+    let mut problems: Vec<String> = Vec::new();
+    for suit in pack.suit_names.keys().filter(|k| !SUITS.contains(&k.as_str())) {
+        problems.push(format!("unknown suit '{}'", suit));
+    }
+    let is_court = |key: &str| Rank::ALL.iter().any(|r| r.is_court() && r.as_str() == key);
+    for court in pack.court_names.keys().filter(|k| !is_court(k)) {
+        problems.push(format!("unknown court rank '{}'", court));
+    }
+    for o in pack.cards.iter().filter(|o| base.iter().all(|c| c.id != o.id)) {
+        problems.push(format!("overrides unknown card '{}'", o.id));
+    }
+    if !problems.is_empty() {
+        return Err(TarotError::InvalidDeck {
+            deck: pack.id.clone(),
+            problems,
+        });
+    }
+
+    let mut deck = base.to_vec();
+    for card in deck.iter_mut() {
+        let (Some(suit), Some(rank)) = (card.suit, card.rank()) else {
+            continue;
+        };
+        let (suit, rank) = (suit.as_str(), rank.as_str());
+        let suit_name = pack.suit_names.get(suit).cloned().unwrap_or_else(|| capitalize(suit));
+        let rank_name = pack.court_names.get(rank).cloned().unwrap_or_else(|| capitalize(rank));
+        card.name = format!("{} of {}", rank_name, suit_name);
+    }
+
+    if let Some(pattern) = &pack.image_pattern {
+        for card in deck.iter_mut() {
+            card.image = Some(CardImage {
+                file: pattern.replace("{id}", &card.id),
+                attribution: pack.image_attribution.clone(),
+                alt_text: String::new(),
+            });
+        }
+    }
+
+    for o in &pack.cards {
+        let card = deck
+            .iter_mut()
+            .find(|c| c.id == o.id)
+            .expect("override ids were checked above");
+        if let Some(name) = &o.name {
+            card.name = name.clone();
+        }
+        if let Some(number) = o.number {
+            card.number = number;
+            card.numerology = number;
+        }
+        if let Some(description) = &o.description {
+            card.description = description.clone();
+        }
+        if let Some(meaning) = &o.meaning_upright {
+            card.meaning_upright = meaning.clone();
+        }
+        if let Some(meaning) = &o.meaning_reversed {
+            card.meaning_reversed = meaning.clone();
+        }
+        if let Some(image) = &o.image {
+            card.image = Some(image.clone());
+        }
+    }
+
+    // Alt text is filled last so it uses the pack's final card names
+    for card in deck.iter_mut() {
+        if let Some(image) = card.image.as_mut().filter(|i| i.alt_text.is_empty()) {
+            image.alt_text = format!("{} from the {} deck", card.name, pack.name);
+        }
+    }
+    Ok(deck)
+}
+
+/// Build the full 78-card deck for a source.
+///
+/// # Errors
+/// Returns an error if the pack cannot be read, parsed or applied.
+pub fn load_deck(source: &DeckSource) -> Result<Vec<TarotCard>, TarotError> {
+    let pack = match source {
+        DeckSource::RiderWaiteSmith => return Ok(create_deck()),
+        DeckSource::Marseille => parse_deck_pack(MARSEILLE_JSON)?,
+        DeckSource::Thoth => parse_deck_pack(THOTH_JSON)?,
+        DeckSource::Pack(pack) => pack.clone(),
+        DeckSource::Json(json) => parse_deck_pack(json)?,
+        DeckSource::Path(path) => {
+            let json = fs::read_to_string(path).map_err(|e| TarotError::io(path, e))?;
+            parse_deck_pack(&json)?
+        }
+    };
+    apply_deck_pack(&create_deck(), &pack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CardOverride, Suit};
+
+    fn card<'a>(deck: &'a [TarotCard], id: &str) -> &'a TarotCard {
+        deck.iter().find(|c| c.id == id).unwrap()
+    }
+
+    #[test]
+    fn rider_waite_names_survive_an_empty_pack() {
+        let base = create_deck();
+        let pack = parse_deck_pack(r#"{"id": "plain", "name": "Plain"}"#).unwrap();
+        let deck = apply_deck_pack(&base, &pack).unwrap();
+        assert!(base.iter().zip(&deck).all(|(a, b)| a.name == b.name));
+    }
+
+    #[test]
+    fn thoth_pack_renames_and_reorders() {
+        let deck = load_deck(&DeckSource::Thoth).unwrap();
+        assert_eq!(deck.len(), 78);
+        let lust = card(&deck, "major_08_strength");
+        assert_eq!((lust.name.as_str(), lust.number), ("Lust", 11));
+        assert_eq!(card(&deck, "major_11_justice").number, 8);
+        assert_eq!(card(&deck, "pentacles_page").name, "Princess of Disks");
+        assert_eq!(card(&deck, "wands_king").name, "Knight of Wands");
+        assert_eq!(card(&deck, "swords_03").name, "Three of Swords: Sorrow");
+        assert_eq!(card(&deck, "pentacles_03").suit, Some(Suit::Pentacles));
+    }
+
+    #[test]
+    fn marseille_pack_loads() {
+        let deck = load_deck(&DeckSource::Marseille).unwrap();
+        assert_eq!(card(&deck, "major_01_magician").name, "Le Bateleur");
+        assert_eq!(card(&deck, "wands_page").name, "Valet of Batons");
+    }
+
+    #[test]
+    fn packs_attach_images() {
+        let json = r#"{
+            "id": "art", "name": "Art",
+            "imagePattern": "art/{id}.png", "imageAttribution": "Example Studio",
+            "cards": [
+                {"id": "major_00_fool", "name": "The Wanderer"},
+                {"id": "cups_02", "image": {"file": "special.png", "attribution": null,
+                                            "alt_text": "Two cups raised"}}
+            ]
+        }"#;
+        let deck = load_deck(&DeckSource::Json(json.to_string())).unwrap();
+        let fool = card(&deck, "major_00_fool").image.clone().unwrap();
+        assert_eq!(fool.file, "art/major_00_fool.png");
+        assert_eq!(fool.attribution.as_deref(), Some("Example Studio"));
+        assert_eq!(fool.alt_text, "The Wanderer from the Art deck");
+        assert_eq!(card(&deck, "cups_02").image.as_ref().unwrap().file, "special.png");
+
+        assert!(create_deck().iter().all(|c| c.image.is_none()));
+    }
+
+    #[test]
+    fn unknown_ids_are_rejected() {
+        let mut pack = parse_deck_pack(r#"{"id": "bad", "name": "Bad"}"#).unwrap();
+        pack.cards.push(CardOverride {
+            id: "major_99_nothing".to_string(),
+            ..Default::default()
+        });
+        assert!(load_deck(&DeckSource::Pack(pack)).is_err());
+        let json = r#"{"id": "bad", "name": "Bad", "suitNames": {"staves": "Staves"}}"#;
+        assert!(load_deck(&DeckSource::Json(json.to_string())).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::types::{DrawnCard, ElementalDignity, PositionDignity};
+
+// ---------------------------------------------------------------------------
+// Elemental dignities
+// ---------------------------------------------------------------------------
+//
+// Golden Dawn method: a card is read through the elements of the cards beside
+// it. Friendly neighbours strengthen it, contrary ones weaken it, and one of
+// each cancel out.
+
+/// Dignity between two elements (`"Fire"`, `"Water"`, `"Air"`, `"Earth"`,
+/// any case). Unknown elements are neutral.
+pub fn dignity_between(a: &str, b: &str) -> ElementalDignity {
+    let a = a.to_lowercase();
+    let b = b.to_lowercase();
+    if a == b {
+        return ElementalDignity::Strengthening;
+    }
+    let mut pair = [a.as_str(), b.as_str()];
+    pair.sort_unstable();
+    match pair {
+        ["air", "fire"] | ["earth", "water"] => ElementalDignity::Strengthening,
+        ["fire", "water"] | ["air", "earth"] => ElementalDignity::Weakening,
+        _ => ElementalDignity::Neutral,
+    }
+}
+
+fn combine(previous: Option<ElementalDignity>, next: Option<ElementalDignity>) -> ElementalDignity {
+    let score: i32 = [previous, next]
+        .iter()
+        .flatten()
+        .map(|d| match d {
+            ElementalDignity::Strengthening => 1,
+            ElementalDignity::Weakening => -1,
+            ElementalDignity::Neutral => 0,
+        })
+        .sum();
+    match score {
+        s if s > 0 => ElementalDignity::Strengthening,
+        s if s < 0 => ElementalDignity::Weakening,
+        _ => ElementalDignity::Neutral,
+    }
+}
+
+/// Annotate each dealt position with its dignity relative to the cards in
+/// the neighbouring positions. Clarifiers are ignored.
+pub fn elemental_dignities(drawn: &[DrawnCard]) -> Vec<PositionDignity> {
+    let mut dealt: Vec<&DrawnCard> = drawn.iter().filter(|d| d.clarifies.is_none()).collect();
+    dealt.sort_by_key(|d| d.position_index);
+
+    dealt
+        .iter()
+        .enumerate()
+        .map(|(i, d)| {
+            let relate = |other: &&DrawnCard| dignity_between(&d.card.element, &other.card.element);
+            let previous = i.checked_sub(1).and_then(|j| dealt.get(j)).map(relate);
+            let next = dealt.get(i + 1).map(relate);
+            PositionDignity {
+                position_index: d.position_index,
+                card_id: d.card.id.clone(),
+                element: d.card.element.clone(),
+                previous,
+                next,
+                overall: combine(previous, next),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::drawn_cards;
+
+    #[test]
+    fn element_pairs() {
+        assert_eq!(dignity_between("Fire", "air"), ElementalDignity::Strengthening);
+        assert_eq!(dignity_between("Water", "Water"), ElementalDignity::Strengthening);
+        assert_eq!(dignity_between("Water", "Fire"), ElementalDignity::Weakening);
+        assert_eq!(dignity_between("Earth", "Air"), ElementalDignity::Weakening);
+        assert_eq!(dignity_between("Earth", "Fire"), ElementalDignity::Neutral);
+    }
+
+    #[test]
+    fn positions_are_read_against_neighbours() {
+        // Wands (Fire), Cups (Water), Swords (Air)
+        let result = elemental_dignities(&drawn_cards(&["wands_02", "cups_02", "swords_02"], &[]));
+        assert_eq!(result[0].previous, None);
+        assert_eq!(result[0].overall, ElementalDignity::Weakening);
+        assert_eq!(result[1].overall, ElementalDignity::Weakening);
+        assert_eq!(result[2].previous, Some(ElementalDignity::Neutral));
+        assert_eq!(result[2].overall, ElementalDignity::Neutral);
+
+        let mixed = drawn_cards(&["wands_02", "swords_03", "pentacles_04"], &[]);
+        let mixed = elemental_dignities(&mixed);
+        assert_eq!(mixed[1].overall, ElementalDignity::Neutral);
+    }
+}
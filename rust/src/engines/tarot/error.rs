@@ -0,0 +1,98 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// TarotError — failures reported by the tarot engine
+// ---------------------------------------------------------------------------
+
+/// Why a tarot operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TarotError {
+    /// An operation needed more cards than were available
+    NotEnoughCards { needed: usize, available: usize },
+    /// A cut position outside `1..deck_len`
+    InvalidCut { position: usize, deck_len: usize },
+    UnknownCard(String),
+    UnknownSpread(String),
+    UnknownDeck(String),
+    UnsupportedLocale(String),
+    /// A deck id that cannot be registered (empty or reserved)
+    InvalidDeckId(String),
+    /// A drawn card refers to a position the spread does not define
+    UnknownPosition { spread: String, position: usize },
+    /// No card has been dealt at a position yet
+    PositionNotDealt(usize),
+    /// A calendar month or day out of range
+    InvalidDate { month: u32, day: u32 },
+    /// A spread that reserves a significator was drawn without one
+    MissingSignificator(String),
+    /// Every position of the spread has been filled
+    SpreadComplete(String),
+    /// The deck can only be cut once, before any card is dealt
+    CutNotAllowed(&'static str),
+    /// Every problem found in a spread definition
+    InvalidSpread { spread: String, problems: Vec<String> },
+    /// Every problem found in a deck or deck pack
+    InvalidDeck { deck: String, problems: Vec<String> },
+    /// JSON that could not be parsed or produced
+    Json { context: String, message: String },
+    Io { path: String, message: String },
+    /// A string that does not name a value of a typed field
+    InvalidValue { field: &'static str, value: String },
+    UnsupportedSchema(u32),
+}
+
+impl TarotError {
+    pub(crate) fn json(context: &str, err: serde_json::Error) -> Self {
+        Self::Json {
+            context: context.to_string(),
+            message: err.to_string(),
+        }
+    }
+
+    pub(crate) fn io(path: &std::path::Path, err: std::io::Error) -> Self {
+        Self::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TarotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughCards { needed, available } => {
+                write!(f, "needed {} cards but only {} are available", needed, available)
+            }
+            Self::InvalidCut { position, deck_len } => {
+                write!(f, "cannot cut a deck of {} at {}", deck_len, position)
+            }
+            Self::UnknownCard(id) => write!(f, "unknown card: {}", id),
+            Self::UnknownSpread(id) => write!(f, "unknown spread: {}", id),
+            Self::UnknownDeck(id) => write!(f, "unknown deck: {}", id),
+            Self::UnsupportedLocale(locale) => write!(f, "unsupported locale: {}", locale),
+            Self::InvalidDeckId(id) => write!(f, "invalid deck id: '{}'", id),
+            Self::UnknownPosition { spread, position } => {
+                write!(f, "spread '{}' has no position {}", spread, position)
+            }
+            Self::PositionNotDealt(i) => write!(f, "no card has been dealt at position {}", i),
+            Self::InvalidDate { month, day } => write!(f, "invalid date: {}/{}", month, day),
+            Self::MissingSignificator(spread) => {
+                write!(f, "spread '{}' needs a chosen significator", spread)
+            }
+            Self::SpreadComplete(spread) => write!(f, "spread '{}' is already complete", spread),
+            Self::CutNotAllowed(reason) => write!(f, "cannot cut: {}", reason),
+            Self::InvalidSpread { spread, problems } => {
+                write!(f, "invalid spread '{}': {}", spread, problems.join("; "))
+            }
+            Self::InvalidDeck { deck, problems } => {
+                write!(f, "invalid deck '{}': {}", deck, problems.join("; "))
+            }
+            Self::Json { context, message } => write!(f, "invalid {}: {}", context, message),
+            Self::Io { path, message } => write!(f, "failed to read {}: {}", path, message),
+            Self::InvalidValue { field, value } => write!(f, "invalid {}: '{}'", field, value),
+            Self::UnsupportedSchema(v) => write!(f, "unsupported schema version {}", v),
+        }
+    }
+}
+
+impl std::error::Error for TarotError {}
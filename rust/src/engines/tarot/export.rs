@@ -0,0 +1,166 @@
+use crate::types::{ExportedPosition, ExportedReading, ReadingInterpretation};
+
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// Reading export
+// ---------------------------------------------------------------------------
+
+/// Version of the [`ExportedReading`] JSON layout.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Convert an interpreted reading into its archival form.
+pub fn export_reading(reading: &ReadingInterpretation, timestamp_ms: u64) -> ExportedReading {
+    ExportedReading {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        spread_id: reading.spread_id.clone(),
+        spread_name: reading.spread_name.clone(),
+        timestamp: timestamp_ms,
+        positions: reading
+            .positions
+            .iter()
+            .map(|p| ExportedPosition {
+                index: p.position_index,
+                name: p.position_name.clone(),
+                card_id: p.card_id.clone(),
+                card_name: p.card_name.clone(),
+                orientation: if p.reversed { "reversed" } else { "upright" }.to_string(),
+                keywords: p.keywords.clone(),
+                meaning: p.meaning.clone(),
+                clarifiers: p.clarifiers.clone(),
+            })
+            .collect(),
+        combinations: reading
+            .synthesis
+            .combinations
+            .iter()
+            .map(|m| m.combination.name.clone())
+            .collect(),
+        synthesis: reading.synthesis.summary.clone(),
+    }
+}
+
+/// Pretty-printed JSON for an exported reading.
+///
+/// # Errors
+/// Returns an error if serialization fails.
+pub fn to_json(export: &ExportedReading) -> Result<String, TarotError> {
+    serde_json::to_string_pretty(export).map_err(|e| TarotError::json("exported reading", e))
+}
+
+/// Parse a reading previously written by [`to_json`].
+///
+/// # Errors
+/// Returns an error if the JSON is malformed or from a newer schema.
+pub fn from_json(json: &str) -> Result<ExportedReading, TarotError> {
+    let export: ExportedReading =
+        serde_json::from_str(json).map_err(|e| TarotError::json("exported reading", e))?;
+    if export.schema_version > EXPORT_SCHEMA_VERSION {
+        return Err(TarotError::UnsupportedSchema(export.schema_version));
+    }
+    Ok(export)
+}
+
+/// Format milliseconds since the Unix epoch as an ISO-8601 UTC timestamp.
+pub fn format_timestamp(timestamp_ms: u64) -> String {
+    let secs = timestamp_ms / 1000;
+    let (days, rem) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // Days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+/// Render an exported reading as Markdown.
+pub fn to_markdown(export: &ExportedReading) -> String {
+    let mut md = format!(
+        "# {}\n\n_{}_\n\n",
+        export.spread_name,
+        format_timestamp(export.timestamp)
+    );
+    for p in &export.positions {
+        md.push_str(&format!("## {}. {}: {}", p.index + 1, p.name, p.card_name));
+        if p.orientation == "reversed" {
+            md.push_str(" (reversed)");
+        }
+        md.push_str("\n\n");
+        if !p.keywords.is_empty() {
+            md.push_str(&format!("**Keywords:** {}\n\n", p.keywords.join(", ")));
+        }
+        md.push_str(&format!("{}\n\n", p.meaning));
+        if !p.clarifiers.is_empty() {
+            md.push_str(&format!("**Clarified by:** {}\n\n", p.clarifiers.join(", ")));
+        }
+    }
+    if !export.combinations.is_empty() {
+        md.push_str("## Combinations\n\n");
+        for name in &export.combinations {
+            md.push_str(&format!("- {}\n", name));
+        }
+        md.push('\n');
+    }
+    if !export.synthesis.is_empty() {
+        md.push_str(&format!("## Synthesis\n\n{}\n", export.synthesis));
+    }
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{drawn_cards, TarotEngine};
+
+    fn sample() -> ExportedReading {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("three_card").unwrap();
+        let ids = ["major_16_tower", "major_19_sun", "cups_02"];
+        let drawn = drawn_cards(&ids, &[false, false, true]);
+        let reading = engine.interpret_reading(spread, &drawn).unwrap();
+        export_reading(&reading, 1_710_504_000_000)
+    }
+
+    #[test]
+    fn timestamps_format_as_utc() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(1_710_504_000_000), "2024-03-15T12:00:00Z");
+        assert_eq!(format_timestamp(951_782_400_000), "2000-02-29T00:00:00Z");
+    }
+
+    #[test]
+    fn markdown_lists_positions_and_synthesis() {
+        let md = to_markdown(&sample());
+        assert!(md.starts_with("# Past, Present, Future\n\n_2024-03-15T12:00:00Z_"));
+        assert!(md.contains("## 1. Past: The Tower\n"));
+        assert!(md.contains("## 3. Future: Two of Cups (reversed)"));
+        assert!(md.contains("- Breakthrough"));
+        assert!(md.contains("## Synthesis"));
+    }
+
+    #[test]
+    fn json_round_trips_and_rejects_future_schemas() {
+        let export = sample();
+        let json = to_json(&export).unwrap();
+        assert!(json.contains("\"orientation\": \"reversed\""));
+        assert_eq!(from_json(&json).unwrap(), export);
+
+        let future = json.replace("\"schema_version\": 1", "\"schema_version\": 99");
+        assert!(from_json(&future).is_err());
+    }
+}
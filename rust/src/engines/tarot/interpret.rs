@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+
+use crate::text::capitalize;
+use crate::types::{
+    CardCombination, DrawnCard, PositionReading, ReadingInterpretation, ReadingSynthesis,
+    SpreadDefinition, Suit,
+};
+
+use super::combinations::{find_combinations, load_combinations};
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// Reading interpretation
+// ---------------------------------------------------------------------------
+
+fn suit_theme(suit: Suit) -> &'static str {
+    match suit {
+        Suit::Wands => "drive, ambition and creative fire",
+        Suit::Cups => "feelings, relationships and intuition",
+        Suit::Swords => "thought, conflict and communication",
+        Suit::Pentacles => "work, money and the material world",
+    }
+}
+
+/// The most frequent value if it appears at least twice and strictly more
+/// often than any other.
+fn dominant<T: Ord + Clone>(values: impl Iterator<Item = T>) -> Option<T> {
+    let mut counts: BTreeMap<T, usize> = BTreeMap::new();
+    for v in values {
+        *counts.entry(v).or_default() += 1;
+    }
+    let mut ranked: Vec<(T, usize)> = counts.into_iter().collect();
+    ranked.sort_by_key(|(_, n)| std::cmp::Reverse(*n));
+    match ranked.as_slice() {
+        [(top, n), rest @ ..] if *n >= 2 && rest.first().is_none_or(|r| r.1 < *n) => {
+            Some(top.clone())
+        }
+        _ => None,
+    }
+}
+
+fn position_text(reading: &PositionReading) -> String {
+    let orientation = if reading.reversed { " (reversed)" } else { "" };
+    let mut text = format!(
+        "{}: {}{}. {} Keywords: {}. {}",
+        reading.position_name,
+        reading.card_name,
+        orientation,
+        reading.position_description,
+        reading.keywords.join(", "),
+        reading.meaning
+    );
+    if !reading.clarifiers.is_empty() {
+        text.push_str(&format!(" Clarified by {}.", reading.clarifiers.join(", ")));
+    }
+    text
+}
+
+pub(crate) fn interpret_with(
+    combinations: &[CardCombination],
+    spread: &SpreadDefinition,
+    drawn: &[DrawnCard],
+) -> Result<ReadingInterpretation, TarotError> {
+    let mut dealt: Vec<&DrawnCard> = drawn.iter().filter(|d| d.clarifies.is_none()).collect();
+    dealt.sort_by_key(|d| d.position_index);
+
+    let mut positions = Vec::with_capacity(dealt.len());
+    for d in &dealt {
+        let position = spread.positions.get(d.position_index).ok_or_else(|| {
+            TarotError::UnknownPosition {
+                spread: spread.id.clone(),
+                position: d.position_index,
+            }
+        })?;
+        let (keywords, meaning) = if d.reversed {
+            (&d.card.keywords_reversed, &d.card.meaning_reversed)
+        } else {
+            (&d.card.keywords_upright, &d.card.meaning_upright)
+        };
+        let mut reading = PositionReading {
+            position_index: d.position_index,
+            position_name: position.name.clone(),
+            position_description: position.description.clone(),
+            card_id: d.card.id.clone(),
+            card_name: d.card.name.clone(),
+            reversed: d.reversed,
+            keywords: keywords.clone(),
+            meaning: meaning.clone(),
+            clarifiers: drawn
+                .iter()
+                .filter(|c| c.clarifies == Some(d.position_index))
+                .map(|c| c.card.name.clone())
+                .collect(),
+            text: String::new(),
+        };
+        reading.text = position_text(&reading);
+        positions.push(reading);
+    }
+
+    let total = dealt.len();
+    let major_count = dealt.iter().filter(|d| d.card.is_major()).count();
+    let reversed_count = dealt.iter().filter(|d| d.reversed).count();
+    let dominant_suit = dominant(dealt.iter().filter_map(|d| d.card.suit));
+    let dominant_element = dominant(dealt.iter().map(|d| d.card.element.clone()));
+    let combinations = find_combinations(combinations, drawn);
+
+    // Always open with the card count so a mixed spread still has a summary
+    let count = match total {
+        1 => "One card is read".to_string(),
+        n => format!("{} cards are read together", n),
+    };
+    let mut summary = vec![match &dominant_element {
+        Some(element) => format!("{}, with {} the strongest element.", count, element),
+        None => format!("{}, with no single element dominant.", count),
+    }];
+    if total > 0 && major_count * 2 > total {
+        summary.push(format!(
+            "Major Arcana dominate ({} of {}): larger forces and life lessons are at work.",
+            major_count, total
+        ));
+    } else if total > 0 && major_count == 0 {
+        summary.push("No Major Arcana appear: the matter rests in everyday choices.".to_string());
+    }
+    if let Some(suit) = dominant_suit {
+        summary.push(format!(
+            "{} lead the reading, pointing to {}.",
+            capitalize(suit.as_str()),
+            suit_theme(suit)
+        ));
+    }
+    if total > 0 && reversed_count * 2 > total {
+        summary.push(
+            "Most cards are reversed, suggesting blocked or internalised energy.".to_string(),
+        );
+    }
+    for m in &combinations {
+        summary.push(format!("{}: {}", m.combination.name, m.combination.meaning));
+    }
+
+    Ok(ReadingInterpretation {
+        spread_id: spread.id.clone(),
+        spread_name: spread.name.clone(),
+        positions,
+        synthesis: ReadingSynthesis {
+            major_count,
+            reversed_count,
+            dominant_suit,
+            dominant_element,
+            combinations,
+            summary: summary.join(" "),
+        },
+    })
+}
+
+/// Merge each position's description with its card's orientation-specific
+/// keywords and meaning, and add a synthesis of patterns across the spread.
+///
+/// # Errors
+/// Returns an error if a card was dealt into a position the spread
+/// does not have.
+pub fn interpret_reading(
+    spread: &SpreadDefinition,
+    drawn: &[DrawnCard],
+) -> Result<ReadingInterpretation, TarotError> {
+    interpret_with(&load_combinations(), spread, drawn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{drawn_cards, TarotEngine};
+
+    #[test]
+    fn positions_use_orientation_specific_text() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("three_card").unwrap();
+        let drawn = drawn_cards(&["cups_02", "cups_03", "major_19_sun"], &[false, true]);
+        let reading = interpret_reading(spread, &drawn).unwrap();
+
+        assert_eq!(reading.positions.len(), 3);
+        assert_eq!(reading.positions[1].meaning, drawn[1].card.meaning_reversed);
+        assert!(reading.positions[1].text.contains("(reversed)"));
+        assert!(reading.positions[0].text.starts_with("Past: Two of Cups"));
+        assert_eq!(reading.synthesis.dominant_suit, Some(Suit::Cups));
+        assert!(reading.synthesis.summary.contains("Cups lead"));
+    }
+
+    #[test]
+    fn mixed_spread_still_has_a_summary() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("three_card").unwrap();
+        let drawn = drawn_cards(&["wands_04", "swords_07", "major_19_sun"], &[]);
+        let reading = interpret_reading(spread, &drawn).unwrap();
+
+        assert_eq!(reading.synthesis.dominant_suit, None);
+        assert_eq!(
+            reading.synthesis.summary,
+            "3 cards are read together, with Fire the strongest element."
+        );
+    }
+
+    #[test]
+    fn synthesis_reports_combinations() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("three_card").unwrap();
+        let drawn = drawn_cards(&["major_16_tower", "major_19_sun", "wands_02"], &[]);
+        let reading = interpret_reading(spread, &drawn).unwrap();
+        assert_eq!(reading.synthesis.major_count, 2);
+        assert!(reading.synthesis.summary.contains("Breakthrough"));
+        assert!(reading.synthesis.dominant_suit.is_none());
+    }
+
+    #[test]
+    fn out_of_range_positions_fail() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("single").unwrap();
+        let drawn = drawn_cards(&["cups_02", "cups_03"], &[]);
+        assert!(interpret_reading(spread, &drawn).is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::engines::kabbalah::embedded_tree;
+use crate::types::{KabbalahAttribution, Suit, TarotCard, TreePath};
+
+// ---------------------------------------------------------------------------
+// Tree of Life attributions (Golden Dawn)
+// ---------------------------------------------------------------------------
+//
+// The sephiroth and paths come from the Kabbalah engine's embedded Tree,
+// which places each major by card id, so renumbered decks keep their
+// letters. Only the suit worlds and the placing of minor cards live here.
+
+fn world(suit: Suit) -> &'static str {
+    match suit {
+        Suit::Wands => "Atziluth",
+        Suit::Cups => "Briah",
+        Suit::Swords => "Yetzirah",
+        Suit::Pentacles => "Assiah",
+    }
+}
+
+/// Sephirah number for a minor card: pips by number, courts as King
+/// Chokmah, Queen Binah, Knight Tiphareth, Page Malkuth.
+fn minor_sephirah(number: i32) -> Option<u8> {
+    match number {
+        1..=10 => Some(number as u8),
+        11 => Some(10),
+        12 => Some(6),
+        13 => Some(3),
+        14 => Some(2),
+        _ => None,
+    }
+}
+
+fn sephirah_name(number: u8) -> Option<String> {
+    let tree = embedded_tree().ok()?;
+    tree.sephiroth.iter().find(|s| s.number == number).map(|s| s.name.clone())
+}
+
+/// Tree of Life attribution for a card, or `None` for a major on no path or
+/// a minor without a recognised number.
+pub fn kabbalah_for_card(card: &TarotCard) -> Option<KabbalahAttribution> {
+    match card.suit {
+        None => {
+            let tree = embedded_tree().ok()?;
+            let path = tree.paths.iter().find(|p| p.tarot_card == card.id)?;
+            let [upper, lower] = path.connects;
+            Some(KabbalahAttribution {
+                card_id: card.id.clone(),
+                hebrew_letter: Some(path.letter.clone()),
+                hebrew_glyph: Some(path.glyph.clone()),
+                path: Some(path.number),
+                connects: Some([sephirah_name(upper)?, sephirah_name(lower)?]),
+                sephirah: None,
+                sephirah_number: None,
+                world: None,
+            })
+        }
+        Some(suit) => {
+            let n = minor_sephirah(card.number)?;
+            Some(KabbalahAttribution {
+                card_id: card.id.clone(),
+                hebrew_letter: None,
+                hebrew_glyph: None,
+                path: None,
+                connects: None,
+                sephirah: Some(sephirah_name(n)?),
+                sephirah_number: Some(n),
+                world: Some(world(suit).to_string()),
+            })
+        }
+    }
+}
+
+fn card_on(deck: &[TarotCard], path: &TreePath) -> Option<TarotCard> {
+    deck.iter().find(|c| c.id == path.tarot_card).cloned()
+}
+
+/// The major on a Tree of Life path (11–32).
+pub fn card_for_path(deck: &[TarotCard], path: u8) -> Option<TarotCard> {
+    let tree = embedded_tree().ok()?;
+    card_on(deck, tree.paths.iter().find(|p| p.number == path)?)
+}
+
+/// The major attributed to a Hebrew letter (transliteration or glyph).
+pub fn card_for_hebrew_letter(deck: &[TarotCard], letter: &str) -> Option<TarotCard> {
+    let tree = embedded_tree().ok()?;
+    let path = tree
+        .paths
+        .iter()
+        .find(|p| p.letter.eq_ignore_ascii_case(letter) || p.glyph == letter)?;
+    card_on(deck, path)
+}
+
+/// Minor cards placed in a sephirah (case-insensitive name).
+pub fn cards_for_sephirah(deck: &[TarotCard], sephirah: &str) -> Vec<TarotCard> {
+    deck.iter()
+        .filter(|c| {
+            kabbalah_for_card(c)
+                .and_then(|k| k.sephirah)
+                .is_some_and(|s| s.eq_ignore_ascii_case(sephirah))
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::decks::{load_deck, DeckSource};
+    use crate::engines::tarot::create_deck;
+
+    #[test]
+    fn majors_sit_on_paths() {
+        let deck = create_deck();
+        let fool = kabbalah_for_card(&deck[0]).unwrap();
+        assert_eq!(fool.path, Some(11));
+        assert_eq!(fool.hebrew_letter.as_deref(), Some("Aleph"));
+        assert_eq!(fool.connects, Some(["Kether".to_string(), "Chokmah".to_string()]));
+
+        assert_eq!(card_for_path(&deck, 32).unwrap().id, "major_21_world");
+        assert_eq!(card_for_hebrew_letter(&deck, "teth").unwrap().id, "major_08_strength");
+        assert_eq!(card_for_hebrew_letter(&deck, "ל").unwrap().id, "major_11_justice");
+        assert!(card_for_path(&deck, 33).is_none());
+        assert!(card_for_path(&deck, 10).is_none());
+
+        // Thoth numbers Lust XI, but it stays on Teth
+        let thoth = load_deck(&DeckSource::Thoth).unwrap();
+        let lust = thoth.iter().find(|c| c.id == "major_08_strength").unwrap();
+        assert_eq!(kabbalah_for_card(lust).unwrap().hebrew_letter.as_deref(), Some("Teth"));
+    }
+
+    #[test]
+    fn minors_sit_in_sephiroth() {
+        let deck = create_deck();
+        let aces: Vec<String> =
+            cards_for_sephirah(&deck, "kether").into_iter().map(|c| c.id).collect();
+        assert_eq!(aces.len(), 4);
+        assert!(aces.iter().all(|id| id.ends_with("_01_ace")));
+
+        let six = deck.iter().find(|c| c.id == "cups_06").unwrap();
+        let k = kabbalah_for_card(six).unwrap();
+        assert_eq!(k.sephirah.as_deref(), Some("Tiphareth"));
+        assert_eq!(k.world.as_deref(), Some("Briah"));
+        // The four tens and the four pages
+        assert_eq!(cards_for_sephirah(&deck, "Malkuth").len(), 8);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use crate::types::{DrawnCard, Rank, SpreadDefinition, Suit, TarotCard};
+
+// ---------------------------------------------------------------------------
+// ASCII spread layouts
+// ---------------------------------------------------------------------------
+//
+// Each dealt card is drawn as a small box placed on a grid according to the
+// spread's traditional shape. Positions that share a grid cell (the Celtic
+// Cross crossing card) are stacked inside one box. Spreads without a known
+// shape are laid out in a single row in position order.
+
+/// Inner width of a card box; fits the longest label, `10:KnP(R)`.
+const CELL_WIDTH: usize = 9;
+
+/// `(position index, row, column)` for the spreads with a known shape.
+fn geometry(spread_id: &str) -> Option<&'static [(usize, usize, usize)]> {
+    match spread_id {
+        // Cross on the left, staff rising on the right with a gap between
+        "celtic_cross" => Some(&[
+            (0, 1, 1),
+            (1, 1, 1),
+            (2, 2, 1),
+            (3, 1, 0),
+            (4, 0, 1),
+            (5, 1, 2),
+            (6, 3, 4),
+            (7, 2, 4),
+            (8, 1, 4),
+            (9, 0, 4),
+        ]),
+        // The two people face each other across their connection
+        "relationship" => Some(&[(0, 0, 0), (1, 0, 2), (2, 0, 1), (3, 1, 0), (4, 1, 2), (5, 2, 1)]),
+        _ => None,
+    }
+}
+
+fn roman(n: u32) -> String {
+    if n == 0 {
+        return "0".to_string();
+    }
+    let mut n = n;
+    let mut out = String::new();
+    for (value, numeral) in [(10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")] {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Short label for a card: a Roman numeral for the majors (`XVI`), rank and
+/// suit initial for the minors (`AW`, `10S`, `KnC`).
+pub fn card_abbreviation(card: &TarotCard) -> String {
+    let (Some(suit), Some(rank)) = (card.suit, card.rank()) else {
+        return roman(card.number.max(0) as u32);
+    };
+    let rank = match rank {
+        Rank::Ace => "A".to_string(),
+        Rank::Page => "P".to_string(),
+        Rank::Knight => "Kn".to_string(),
+        Rank::Queen => "Q".to_string(),
+        Rank::King => "K".to_string(),
+        pip => pip.number().to_string(),
+    };
+    let initial = match suit {
+        Suit::Wands => 'W',
+        Suit::Cups => 'C',
+        Suit::Swords => 'S',
+        Suit::Pentacles => 'P',
+    };
+    format!("{}{}", rank, initial)
+}
+
+fn label(d: &DrawnCard) -> String {
+    let marker = if d.reversed { "(R)" } else { "" };
+    format!("{}:{}{}", d.position_index + 1, card_abbreviation(&d.card), marker)
+}
+
+/// Lay out the dealt cards of a reading as ASCII art, followed by a legend
+/// naming each position and card. `(R)` marks reversed cards; clarifiers are
+/// listed in the legend under the position they clarify.
+pub fn render_layout(spread: &SpreadDefinition, drawn: &[DrawnCard]) -> String {
+    let mut dealt: Vec<&DrawnCard> = drawn.iter().filter(|d| d.clarifies.is_none()).collect();
+    dealt.sort_by_key(|d| d.position_index);
+
+    // (row, col) -> cards stacked in that cell
+    let mut cells: BTreeMap<(usize, usize), Vec<&DrawnCard>> = BTreeMap::new();
+    let shape = geometry(&spread.id);
+    for (i, d) in dealt.iter().enumerate() {
+        let slot = shape
+            .and_then(|g| g.iter().find(|(p, _, _)| *p == d.position_index))
+            .map(|&(_, row, col)| (row, col))
+            .unwrap_or((0, i));
+        cells.entry(slot).or_default().push(d);
+    }
+
+    let rows = cells.keys().map(|(r, _)| r + 1).max().unwrap_or(0);
+    let cols = cells.keys().map(|(_, c)| c + 1).max().unwrap_or(0);
+    let blank = " ".repeat(CELL_WIDTH + 2);
+    let border = format!("+{}+", "-".repeat(CELL_WIDTH));
+
+    let mut out = String::new();
+    for row in 0..rows {
+        let height = (0..cols)
+            .filter_map(|col| cells.get(&(row, col)).map(Vec::len))
+            .max()
+            .unwrap_or(0);
+        for line in 0..height + 2 {
+            let parts: Vec<String> = (0..cols)
+                .map(|col| match cells.get(&(row, col)) {
+                    None => blank.clone(),
+                    Some(_) if line == 0 || line == height + 1 => border.clone(),
+                    Some(stack) => {
+                        let text = stack.get(line - 1).map(|d| label(d)).unwrap_or_default();
+                        format!("|{:^width$}|", text, width = CELL_WIDTH)
+                    }
+                })
+                .collect();
+            out.push_str(parts.join(" ").trim_end());
+            out.push('\n');
+        }
+    }
+
+    if !dealt.is_empty() {
+        out.push('\n');
+    }
+    for d in &dealt {
+        let position = spread
+            .positions
+            .get(d.position_index)
+            .map_or("?", |p| p.name.as_str());
+        let orientation = if d.reversed { " (reversed)" } else { "" };
+        out.push_str(&format!(
+            "{}. {}: {}{}\n",
+            d.position_index + 1,
+            position,
+            d.card.name,
+            orientation
+        ));
+        for c in drawn.iter().filter(|c| c.clarifies == Some(d.position_index)) {
+            let orientation = if c.reversed { " (reversed)" } else { "" };
+            out.push_str(&format!("   + {}{}\n", c.card.name, orientation));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, draw_cards, get_card, TarotEngine};
+
+    #[test]
+    fn abbreviations() {
+        let deck = create_deck();
+        let abbr = |id: &str| card_abbreviation(&get_card(&deck, id).unwrap());
+        assert_eq!(abbr("major_00_fool"), "0");
+        assert_eq!(abbr("major_16_tower"), "XVI");
+        assert_eq!(abbr("wands_01_ace"), "AW");
+        assert_eq!(abbr("cups_knight"), "KnC");
+        assert_eq!(abbr("pentacles_king"), "KP");
+    }
+
+    #[test]
+    fn celtic_cross_shape() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("celtic_cross").unwrap();
+        let mut drawn = draw_cards(&create_deck(), 10, false).unwrap();
+        drawn[1].reversed = true;
+        let text = render_layout(spread, &drawn);
+        let lines: Vec<&str> = text.lines().collect();
+
+        // Crown on top of the cross, outcome at the head of the staff
+        assert!(lines[1].contains("5:IV") && lines[1].contains("10:IX"));
+        // Present and crossing share one box
+        let present = lines.iter().position(|l| l.contains("1:0")).unwrap();
+        assert!(lines[present + 1].contains("2:I(R)"));
+        assert!(text.contains("2. Challenge / Crossing: The Magician (reversed)"));
+        assert!(lines.iter().all(|l| l.len() <= 5 * (CELL_WIDTH + 3)));
+    }
+
+    #[test]
+    fn unknown_spreads_use_one_row() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("three_card").unwrap();
+        let drawn = draw_cards(&create_deck(), 3, false).unwrap();
+        let text = render_layout(spread, &drawn);
+        let row = text.lines().nth(1).unwrap();
+        assert!(row.contains("1:0") && row.contains("2:I") && row.contains("3:II"));
+    }
+}
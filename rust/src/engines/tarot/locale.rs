@@ -0,0 +1,143 @@
+use crate::types::{LocalePack, TarotCard};
+
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// Localized card text
+// ---------------------------------------------------------------------------
+//
+// The embedded packs translate card names, keywords and upright and
+// reversed meanings; descriptions fall back to English until they are
+// translated too.
+
+const ES_JSON: &str = include_str!("../../../../data/tarot/locales/es.json");
+const FR_JSON: &str = include_str!("../../../../data/tarot/locales/fr.json");
+const DE_JSON: &str = include_str!("../../../../data/tarot/locales/de.json");
+const PT_JSON: &str = include_str!("../../../../data/tarot/locales/pt.json");
+const ZH_JSON: &str = include_str!("../../../../data/tarot/locales/zh.json");
+
+/// Locales with an embedded pack, besides English.
+pub const SUPPORTED_LOCALES: [&str; 5] = ["es", "fr", "de", "pt", "zh"];
+
+/// Parse a locale pack from JSON.
+///
+/// # Errors
+/// Returns an error if the JSON is malformed.
+pub fn parse_locale_pack(json: &str) -> Result<LocalePack, TarotError> {
+    serde_json::from_str(json).map_err(|e| TarotError::json("locale pack JSON", e))
+}
+
+/// The embedded pack for a language code. Region suffixes are ignored, so
+/// `"pt-BR"` loads `"pt"`.
+///
+/// # Errors
+/// Returns an error if no pack exists for the language.
+pub fn locale_pack(locale: &str) -> Result<LocalePack, TarotError> {
+    let lang = locale.split(['-', '_']).next().unwrap_or("").to_lowercase();
+    let json = match lang.as_str() {
+        "es" => ES_JSON,
+        "fr" => FR_JSON,
+        "de" => DE_JSON,
+        "pt" => PT_JSON,
+        "zh" => ZH_JSON,
+        _ => return Err(TarotError::UnsupportedLocale(locale.to_string())),
+    };
+    parse_locale_pack(json)
+}
+
+/// Overlay a pack's translations on a deck, field by field.
+///
+/// # Errors
+/// Returns an error if the pack translates a card not in the deck.
+pub fn apply_locale_pack(
+    deck: &[TarotCard],
+    pack: &LocalePack,
+) -> Result<Vec<TarotCard>, TarotError> {
+    let mut deck = deck.to_vec();
+    for t in &pack.cards {
+        let card = deck
+            .iter_mut()
+            .find(|c| c.id == t.id)
+            .ok_or_else(|| TarotError::UnknownCard(t.id.clone()))?;
+        if let Some(name) = &t.name {
+            card.name = name.clone();
+        }
+        if let Some(keywords) = &t.keywords_upright {
+            card.keywords_upright = keywords.clone();
+        }
+        if let Some(keywords) = &t.keywords_reversed {
+            card.keywords_reversed = keywords.clone();
+        }
+        if let Some(meaning) = &t.meaning_upright {
+            card.meaning_upright = meaning.clone();
+        }
+        if let Some(meaning) = &t.meaning_reversed {
+            card.meaning_reversed = meaning.clone();
+        }
+        if let Some(description) = &t.description {
+            card.description = description.clone();
+        }
+    }
+    Ok(deck)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, TarotEngine};
+    use crate::types::CardTranslation;
+
+    #[test]
+    fn every_pack_translates_every_card() {
+        let deck = create_deck();
+        for locale in SUPPORTED_LOCALES {
+            let pack = locale_pack(locale).unwrap();
+            assert_eq!(pack.locale, locale);
+            assert_eq!(pack.cards.len(), 78, "{}", locale);
+            for card in &pack.cards {
+                let translated = card.name.is_some()
+                    && card.keywords_upright.is_some()
+                    && card.keywords_reversed.is_some()
+                    && card.meaning_upright.is_some()
+                    && card.meaning_reversed.is_some();
+                assert!(translated, "{} {}", locale, card.id);
+            }
+            assert!(apply_locale_pack(&deck, &pack).is_ok());
+        }
+    }
+
+    #[test]
+    fn engine_falls_back_field_by_field() {
+        let english = TarotEngine::new();
+        let spanish = TarotEngine::with_locale("es-MX").unwrap();
+        let death = spanish.get_card("major_13_death").unwrap();
+        let english_death = english.get_card("major_13_death").unwrap();
+        assert_eq!(death.name, "La Muerte");
+        assert!(death.meaning_upright.starts_with("La Muerte es una carta"));
+        assert_eq!(death.keywords_upright[0], "transformación");
+        // Descriptions are not translated yet and stay in English
+        assert_eq!(death.description, english_death.description);
+        assert_eq!(spanish.get_card("cups_02").unwrap().name, "Dos de Copas");
+        let french = TarotEngine::with_locale("fr").unwrap();
+        assert_eq!(french.get_card("swords_02").unwrap().name, "Deux d'Épées");
+
+        assert!(TarotEngine::with_locale("en").is_ok());
+        assert!(TarotEngine::with_locale("xx").is_err());
+    }
+
+    #[test]
+    fn partial_translations_keep_english_fields() {
+        let deck = create_deck();
+        let pack = LocalePack {
+            locale: "test".to_string(),
+            cards: vec![CardTranslation {
+                id: "major_00_fool".to_string(),
+                meaning_upright: Some("Translated".to_string()),
+                ..Default::default()
+            }],
+        };
+        let localized = apply_locale_pack(&deck, &pack).unwrap();
+        assert_eq!(localized[0].name, deck[0].name);
+        assert_eq!(localized[0].meaning_upright, "Translated");
+    }
+}
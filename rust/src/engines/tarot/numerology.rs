@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+
+use crate::engines::calendar::days_in_month;
+use crate::engines::numerology::digit_sum;
+use crate::types::{
+    DrawnCard, PersonalYearCard, Rank, RankCount, SpreadNumerology, Suit, TarotCard,
+};
+
+use super::TarotError;
+
+pub use crate::engines::numerology::reduce_number;
+
+// ---------------------------------------------------------------------------
+// Spread numerology
+// ---------------------------------------------------------------------------
+
+/// Reduce to a major arcana number: sum digits until 22 or less, reading 22
+/// as The Fool.
+fn to_major(mut n: u32) -> u32 {
+    while n > 22 {
+        n = digit_sum(n);
+    }
+    n % 22
+}
+
+/// Profile the dealt cards of a spread (clarifiers are ignored).
+pub fn analyze_spread_numerology(drawn: &[DrawnCard]) -> SpreadNumerology {
+    let dealt: Vec<&TarotCard> = drawn
+        .iter()
+        .filter(|d| d.clarifies.is_none())
+        .map(|d| &d.card)
+        .collect();
+    let numbered: Vec<&TarotCard> = dealt.iter().copied().filter(|c| !c.is_court()).collect();
+
+    let total: u32 = numbered.iter().map(|c| c.numerology.max(0) as u32).sum();
+    let quintessence = (total > 0).then(|| to_major(total));
+
+    let mut number_counts: BTreeMap<i32, usize> = BTreeMap::new();
+    for c in &numbered {
+        *number_counts.entry(c.numerology).or_default() += 1;
+    }
+    let top = number_counts.values().copied().max().unwrap_or(0);
+    let dominant_numbers = if top >= 2 {
+        number_counts.iter().filter(|(_, &n)| n == top).map(|(&k, _)| k).collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut rank_counts: BTreeMap<Rank, usize> = BTreeMap::new();
+    let mut suit_counts: BTreeMap<Suit, usize> = BTreeMap::new();
+    for c in &dealt {
+        if let (Some(suit), Some(rank)) = (c.suit, c.rank()) {
+            *rank_counts.entry(rank).or_default() += 1;
+            *suit_counts.entry(suit).or_default() += 1;
+        }
+    }
+    let repeated_ranks = rank_counts
+        .into_iter()
+        .filter(|&(_, count)| count > 1)
+        .map(|(rank, count)| RankCount { rank, count })
+        .collect();
+
+    let major_count = dealt.iter().filter(|c| c.is_major()).count();
+    SpreadNumerology {
+        total,
+        reduced_number: reduce_number(total),
+        quintessence,
+        dominant_numbers,
+        repeated_ranks,
+        suit_counts,
+        major_count,
+        minor_count: dealt.len() - major_count,
+        major_ratio: if dealt.is_empty() {
+            0.0
+        } else {
+            major_count as f64 / dealt.len() as f64
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Personal year card
+// ---------------------------------------------------------------------------
+
+/// Major arcana number for the year beginning on a birthday in `year`: birth
+/// month + birth day + `year`, with the digits summed until 22 or less.
+///
+/// # Errors
+/// Returns [`TarotError::InvalidDate`] if the month is out of range or the
+/// day is not in it. 29 February is accepted.
+pub fn personal_year_number(
+    birth_month: u32,
+    birth_day: u32,
+    year: i32,
+) -> Result<u32, TarotError> {
+    // Checked against a leap year, as the birthday has no year of its own
+    if !(1..=12).contains(&birth_month)
+        || birth_day == 0
+        || birth_day > days_in_month(2000, birth_month)
+    {
+        return Err(TarotError::InvalidDate {
+            month: birth_month,
+            day: birth_day,
+        });
+    }
+    Ok(to_major(birth_month + birth_day + year.unsigned_abs()))
+}
+
+/// The personal year card from `deck`, so named decks and locales are
+/// respected.
+///
+/// # Errors
+/// Returns an error if the date is invalid or the deck lacks the major.
+pub fn personal_year_card(
+    deck: &[TarotCard],
+    birth_month: u32,
+    birth_day: u32,
+    year: i32,
+) -> Result<PersonalYearCard, TarotError> {
+    let number = personal_year_number(birth_month, birth_day, year)?;
+    let card = deck
+        .iter()
+        .find(|c| c.is_major() && c.number == number as i32)
+        .cloned()
+        .ok_or_else(|| TarotError::UnknownCard(format!("major {}", number)))?;
+    Ok(PersonalYearCard { year, number, card })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{drawn_cards, TarotEngine};
+
+    #[test]
+    fn reduction() {
+        assert_eq!(reduce_number(0), 0);
+        assert_eq!(reduce_number(9), 9);
+        assert_eq!(reduce_number(38), 2);
+    }
+
+    #[test]
+    fn profiles_a_spread() {
+        // 19 + 7 + 7 + 3 = 36 → 9; quintessence 3+6 = 9 (The Hermit)
+        let dealt = drawn_cards(
+            &["major_19_sun", "cups_07", "swords_07", "pentacles_03", "cups_queen", "wands_queen"],
+            &[],
+        );
+        let n = analyze_spread_numerology(&dealt);
+        assert_eq!(n.total, 36);
+        assert_eq!(n.reduced_number, 9);
+        assert_eq!(n.quintessence, Some(9));
+        assert_eq!(n.dominant_numbers, vec![7]);
+        assert_eq!(
+            n.repeated_ranks,
+            vec![
+                RankCount { rank: Rank::Seven, count: 2 },
+                RankCount { rank: Rank::Queen, count: 2 },
+            ]
+        );
+        assert_eq!(n.suit_counts[&Suit::Cups], 2);
+        assert_eq!((n.major_count, n.minor_count), (1, 5));
+    }
+
+    #[test]
+    fn personal_year() {
+        // 6 + 15 + 2025 = 2046 → 12, The Hanged Man
+        let deck = TarotEngine::new().create_deck();
+        let card = personal_year_card(&deck, 6, 15, 2025).unwrap();
+        assert_eq!(card.number, 12);
+        assert_eq!(card.card.id, "major_12_hanged_man");
+        // 12 + 31 + 2046 = 2089 → 19
+        assert_eq!(personal_year_number(12, 31, 2046).unwrap(), 19);
+        assert!(personal_year_number(13, 1, 2025).is_err());
+        assert!(personal_year_number(2, 29, 2025).is_ok());
+        assert!(personal_year_number(2, 30, 2025).is_err());
+        assert!(personal_year_number(4, 31, 2025).is_err());
+    }
+
+    #[test]
+    fn quintessence_of_22_is_the_fool() {
+        let n = analyze_spread_numerology(&drawn_cards(&["major_21_world", "cups_01_ace"], &[]));
+        assert_eq!(n.quintessence, Some(0));
+    }
+}
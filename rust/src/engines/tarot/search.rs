@@ -0,0 +1,89 @@
+use crate::text;
+use crate::types::TarotCard;
+
+// ---------------------------------------------------------------------------
+// Fuzzy card lookup
+// ---------------------------------------------------------------------------
+//
+// Names are compared after lowercasing, dropping punctuation and a leading
+// "the", and spelling out pip numbers, so "2 of cups", "Two of Cups" and
+// "the two of cups" are the same query. Similarity is then measured by
+// the shared edit-distance helper in `crate::text`.
+
+/// Lowest similarity (0.0–1.0) accepted as a match.
+pub const MIN_NAME_SIMILARITY: f64 = 0.75;
+
+const NUMBER_WORDS: [(&str, &str); 10] = [
+    ("1", "ace"),
+    ("2", "two"),
+    ("3", "three"),
+    ("4", "four"),
+    ("5", "five"),
+    ("6", "six"),
+    ("7", "seven"),
+    ("8", "eight"),
+    ("9", "nine"),
+    ("10", "ten"),
+];
+
+fn normalize(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c.to_ascii_lowercase() } else { ' ' })
+        .collect();
+    let words: Vec<&str> = cleaned
+        .split_whitespace()
+        .map(|w| NUMBER_WORDS.iter().find(|(n, _)| *n == w).map_or(w, |(_, word)| *word))
+        .collect();
+    match words.split_first() {
+        Some((&"the", rest)) if !rest.is_empty() => rest.join(" "),
+        _ => words.join(" "),
+    }
+}
+
+/// Similarity of two card names from 0.0 (nothing alike) to 1.0 (equal
+/// after normalisation).
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    text::name_similarity(&normalize(a), &normalize(b))
+}
+
+/// The card whose name best matches `query`, tolerating case, punctuation
+/// and small typos (`"the hight priestess"`). Card ids are accepted too.
+/// Returns `None` if nothing reaches [`MIN_NAME_SIMILARITY`].
+pub fn find_card_by_name(deck: &[TarotCard], query: &str) -> Option<TarotCard> {
+    deck.iter()
+        .map(|c| {
+            let score = name_similarity(query, &c.name).max(name_similarity(query, &c.id));
+            (score, c)
+        })
+        .filter(|(score, _)| *score >= MIN_NAME_SIMILARITY)
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .map(|(_, c)| c.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::create_deck;
+
+    fn find(query: &str) -> Option<String> {
+        find_card_by_name(&create_deck(), query).map(|c| c.id)
+    }
+
+    #[test]
+    fn tolerates_typos_and_spelling() {
+        assert_eq!(find("the hight priestess").as_deref(), Some("major_02_high_priestess"));
+        assert_eq!(find("HANGED MAN").as_deref(), Some("major_12_hanged_man"));
+        assert_eq!(find("2 of cups").as_deref(), Some("cups_02"));
+        assert_eq!(find("Queen of Swrods").as_deref(), Some("swords_queen"));
+        assert_eq!(find("wands_01_ace").as_deref(), Some("wands_01_ace"));
+        assert_eq!(find("the moon").as_deref(), Some("major_18_moon"));
+        assert!(find("banana bread").is_none());
+    }
+
+    #[test]
+    fn similarity_bounds() {
+        assert_eq!(name_similarity("The Star", "star"), 1.0);
+        assert!(name_similarity("sun", "moon") < MIN_NAME_SIMILARITY);
+    }
+}
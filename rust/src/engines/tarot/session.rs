@@ -0,0 +1,342 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{DrawnCard, Reading, SpreadDefinition, SpreadPosition, TarotCard};
+
+use super::{
+    create_deck, cut_deck, cut_deck_random_with_rng, shuffle_deck_with_rng, TarotError,
+};
+
+// ---------------------------------------------------------------------------
+// ReadingSession — a spread being laid out card by card
+// ---------------------------------------------------------------------------
+
+/// A reading in progress: owns the shuffled deck, removes cards as they are
+/// drawn and fills the spread's positions in order.
+///
+/// The whole session is serializable, so a multi-turn conversation can store
+/// it between messages and resume drawing later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingSession {
+    spread: SpreadDefinition,
+    /// Remaining cards; index 0 is the top of the deck
+    deck: Vec<TarotCard>,
+    drawn: Vec<DrawnCard>,
+    /// Extra cards drawn to clarify a dealt position
+    clarifiers: Vec<DrawnCard>,
+    allow_reversals: bool,
+    /// Where the deck was cut before dealing, if it was
+    #[serde(default)]
+    cut_index: Option<usize>,
+}
+
+impl ReadingSession {
+    /// Start a session with a freshly shuffled 78-card deck.
+    pub fn new(spread: SpreadDefinition, allow_reversals: bool) -> Self {
+        Self::with_rng(spread, allow_reversals, &mut rand::thread_rng())
+    }
+
+    /// Start a session, shuffling with a caller-supplied RNG.
+    pub fn with_rng<R: Rng + ?Sized>(
+        spread: SpreadDefinition,
+        allow_reversals: bool,
+        rng: &mut R,
+    ) -> Self {
+        let mut deck = create_deck();
+        shuffle_deck_with_rng(&mut deck, rng);
+        Self::from_deck(spread, deck, allow_reversals)
+    }
+
+    /// Start a session from an already prepared deck (top card first).
+    pub fn from_deck(
+        spread: SpreadDefinition,
+        deck: Vec<TarotCard>,
+        allow_reversals: bool,
+    ) -> Self {
+        Self {
+            spread,
+            deck,
+            drawn: Vec::new(),
+            clarifiers: Vec::new(),
+            allow_reversals,
+            cut_index: None,
+        }
+    }
+
+    pub fn spread(&self) -> &SpreadDefinition {
+        &self.spread
+    }
+
+    /// Cards placed in spread positions so far, in position order.
+    pub fn drawn(&self) -> &[DrawnCard] {
+        &self.drawn
+    }
+
+    pub fn clarifiers(&self) -> &[DrawnCard] {
+        &self.clarifiers
+    }
+
+    /// Where the deck was cut, if it was.
+    pub fn cut_index(&self) -> Option<usize> {
+        self.cut_index
+    }
+
+    /// Cut the deck at `position` before the first card is dealt.
+    ///
+    /// # Errors
+    /// Returns an error if cards have already been drawn or the
+    /// position is out of range.
+    pub fn cut(&mut self, position: usize) -> Result<(), TarotError> {
+        self.ensure_uncut()?;
+        cut_deck(&mut self.deck, position)?;
+        self.cut_index = Some(position);
+        Ok(())
+    }
+
+    /// Cut the deck at a random position and return it.
+    ///
+    /// # Errors
+    /// Returns an error if cards have already been drawn or the deck
+    /// is too small to cut.
+    pub fn cut_random_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<usize, TarotError> {
+        self.ensure_uncut()?;
+        let position = cut_deck_random_with_rng(&mut self.deck, rng)?;
+        self.cut_index = Some(position);
+        Ok(position)
+    }
+
+    fn ensure_uncut(&self) -> Result<(), TarotError> {
+        if !self.drawn.is_empty() || !self.clarifiers.is_empty() {
+            return Err(TarotError::CutNotAllowed("cards have already been dealt"));
+        }
+        if self.cut_index.is_some() {
+            return Err(TarotError::CutNotAllowed("the deck has already been cut"));
+        }
+        Ok(())
+    }
+
+    /// Number of cards left in the deck.
+    pub fn remaining(&self) -> usize {
+        self.deck.len()
+    }
+
+    /// The next position to be filled, if any.
+    pub fn next_position(&self) -> Option<&SpreadPosition> {
+        self.spread.positions.get(self.drawn.len())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.drawn.len() >= self.spread.card_count
+    }
+
+    fn take_top<R: Rng + ?Sized>(
+        &mut self,
+        position_index: usize,
+        clarifies: Option<usize>,
+        rng: &mut R,
+    ) -> Result<DrawnCard, TarotError> {
+        if self.deck.is_empty() {
+            return Err(TarotError::NotEnoughCards {
+                needed: 1,
+                available: 0,
+            });
+        }
+        let card = self.deck.remove(0);
+        let rule = match clarifies {
+            None => self.spread.positions.get(position_index).map(|p| p.reversal),
+            Some(_) => None,
+        };
+        let allow_reversals = self.allow_reversals;
+        let reversed =
+            rule.unwrap_or_default().resolve(|| allow_reversals && rng.gen_bool(0.5));
+        Ok(DrawnCard {
+            card,
+            reversed,
+            position_index,
+            clarifies,
+        })
+    }
+
+    /// Draw the top card into the next spread position.
+    ///
+    /// # Errors
+    /// Returns an error if the spread is already complete or the deck
+    /// is empty.
+    pub fn draw_next(&mut self) -> Result<&DrawnCard, TarotError> {
+        self.draw_next_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Like [`draw_next`](Self::draw_next), deciding reversal with `rng`.
+    ///
+    /// # Errors
+    /// Returns an error if the spread is already complete or the deck
+    /// is empty.
+    pub fn draw_next_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<&DrawnCard, TarotError> {
+        if self.is_complete() {
+            return Err(TarotError::SpreadComplete(self.spread.id.clone()));
+        }
+        let drawn = self.take_top(self.drawn.len(), None, rng)?;
+        self.drawn.push(drawn);
+        Ok(self.drawn.last().expect("card was just pushed"))
+    }
+
+    /// Fill every remaining position.
+    ///
+    /// # Errors
+    /// Returns an error if the deck runs out.
+    pub fn draw_remaining(&mut self) -> Result<&[DrawnCard], TarotError> {
+        let mut rng = rand::thread_rng();
+        while !self.is_complete() {
+            self.draw_next_with_rng(&mut rng)?;
+        }
+        Ok(&self.drawn)
+    }
+
+    /// Draw an extra card clarifying an already drawn position.
+    ///
+    /// # Errors
+    /// Returns an error if the position has not been drawn yet or the
+    /// deck is empty.
+    pub fn draw_clarifier(&mut self, position_index: usize) -> Result<&DrawnCard, TarotError> {
+        self.draw_clarifier_with_rng(position_index, &mut rand::thread_rng())
+    }
+
+    /// Like [`draw_clarifier`](Self::draw_clarifier), deciding reversal with `rng`.
+    ///
+    /// # Errors
+    /// Returns an error if the position has not been drawn yet or the
+    /// deck is empty.
+    pub fn draw_clarifier_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        position_index: usize,
+        rng: &mut R,
+    ) -> Result<&DrawnCard, TarotError> {
+        if position_index >= self.drawn.len() {
+            return Err(TarotError::PositionNotDealt(position_index));
+        }
+        let clarifier = self.take_top(position_index, Some(position_index), rng)?;
+        self.clarifiers.push(clarifier);
+        Ok(self.clarifiers.last().expect("card was just pushed"))
+    }
+
+    /// Close the session as a [`Reading`] of the cards drawn so far, with
+    /// clarifiers after the dealt cards. Question, querent, seed and tags are
+    /// left for the caller to fill in.
+    pub fn into_reading(self, deck_id: &str, timestamp: u64) -> Reading {
+        Reading {
+            question: None,
+            querent_id: None,
+            deck_id: deck_id.to_string(),
+            spread_id: self.spread.id,
+            seed: None,
+            cut_index: self.cut_index,
+            timestamp,
+            cards: self.drawn.into_iter().chain(self.clarifiers).collect(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{seeded_rng, TarotEngine};
+    use crate::types::ReversalRule;
+
+    fn three_card() -> SpreadDefinition {
+        TarotEngine::new().get_spread("three_card").unwrap().clone()
+    }
+
+    #[test]
+    fn draws_deplete_the_deck_in_position_order() {
+        let mut session = ReadingSession::with_rng(three_card(), true, &mut seeded_rng(7));
+        assert_eq!(session.next_position().unwrap().name, "Past");
+
+        let first = session.draw_next().unwrap().card.id.clone();
+        assert_eq!(session.remaining(), 77);
+        session.draw_remaining().unwrap();
+
+        assert!(session.is_complete());
+        assert_eq!(session.remaining(), 75);
+        assert_eq!(session.drawn()[0].card.id, first);
+        assert!(session.draw_next().is_err());
+
+        let ids: std::collections::HashSet<_> =
+            session.drawn().iter().map(|d| &d.card.id).collect();
+        assert_eq!(ids.len(), 3);
+    }
+
+    #[test]
+    fn cut_is_recorded_and_only_allowed_before_dealing() {
+        let mut session = ReadingSession::with_rng(three_card(), false, &mut seeded_rng(2));
+        let eleventh = session.deck[10].id.clone();
+        session.cut(10).unwrap();
+        assert_eq!(session.cut_index(), Some(10));
+        assert!(session.cut(5).is_err());
+        assert_eq!(session.draw_next().unwrap().card.id, eleventh);
+
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: ReadingSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.cut_index(), Some(10));
+
+        let mut late = ReadingSession::new(three_card(), false);
+        late.draw_next().unwrap();
+        assert!(late.cut_random_with_rng(&mut seeded_rng(1)).is_err());
+    }
+
+    #[test]
+    fn clarifiers_require_a_drawn_position() {
+        let mut session = ReadingSession::new(three_card(), false);
+        assert!(session.draw_clarifier(0).is_err());
+        session.draw_next().unwrap();
+        let clarifier = session.draw_clarifier(0).unwrap();
+        assert_eq!(clarifier.clarifies, Some(0));
+        assert_eq!(session.remaining(), 76);
+    }
+
+    #[test]
+    fn positions_with_fixed_reversals() {
+        let spread = SpreadDefinition::builder()
+            .name("Light and Shadow")
+            .position("Light", "What helps")
+            .position("Shadow", "What is hidden")
+            .reversal(ReversalRule::Always)
+            .position("Outcome", "Where it leads")
+            .reversal(ReversalRule::Never)
+            .build()
+            .unwrap();
+        for seed in 0..20 {
+            let mut session = ReadingSession::with_rng(spread.clone(), true, &mut seeded_rng(seed));
+            session.draw_remaining().unwrap();
+            assert!(session.drawn()[1].reversed);
+            assert!(!session.drawn()[2].reversed);
+        }
+        let mut upright_only = ReadingSession::new(spread.clone(), false);
+        upright_only.draw_remaining().unwrap();
+        assert!(upright_only.drawn()[1].reversed);
+
+        let json = serde_json::to_value(&spread.positions).unwrap();
+        assert!(json[0].get("reversal").is_none());
+        assert_eq!(json[1]["reversal"], "always");
+    }
+
+    #[test]
+    fn session_round_trips_through_json() {
+        let mut session = ReadingSession::new(three_card(), true);
+        session.draw_next().unwrap();
+
+        let json = serde_json::to_string(&session).unwrap();
+        let mut restored: ReadingSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.drawn().len(), 1);
+        assert_eq!(restored.remaining(), session.remaining());
+
+        let next_top = session.draw_next().unwrap().card.id.clone();
+        assert_eq!(restored.draw_next().unwrap().card.id, next_top);
+    }
+}
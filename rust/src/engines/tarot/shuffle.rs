@@ -0,0 +1,205 @@
+use rand::Rng;
+
+use crate::types::{DrawnCard, OrientedCard, ShuffleOptions, ShuffledReading, TarotCard};
+
+use super::TarotError;
+
+// ---------------------------------------------------------------------------
+// Physical shuffle simulation
+// ---------------------------------------------------------------------------
+//
+// Riffles follow the Gilbert–Shannon–Reeds model: the deck is cut at a
+// binomially distributed point and cards drop from each packet with
+// probability proportional to its remaining size. Reversals are not coin
+// flips but arise from turning a packet end over end, as readers do by hand.
+
+/// Largest packet an overhand pass moves at once.
+const OVERHAND_MAX_PACKET: usize = 8;
+
+/// Lay out a deck with every card upright, top card first.
+pub fn orient(deck: Vec<TarotCard>) -> Vec<OrientedCard> {
+    deck.into_iter()
+        .map(|card| OrientedCard {
+            card,
+            reversed: false,
+        })
+        .collect()
+}
+
+/// Turn a packet end over end: its order reverses and every card flips.
+fn rotate(packet: &mut [OrientedCard]) {
+    packet.reverse();
+    for c in packet.iter_mut() {
+        c.reversed = !c.reversed;
+    }
+}
+
+/// One riffle shuffle. If `rotate_half` is set, the lower half is turned end
+/// over end before the halves are interleaved.
+pub fn riffle_shuffle<R: Rng + ?Sized>(
+    deck: &mut Vec<OrientedCard>,
+    rotate_half: bool,
+    rng: &mut R,
+) {
+    let cut = (0..deck.len()).filter(|_| rng.gen_bool(0.5)).count();
+    let mut lower = deck.split_off(cut);
+    if rotate_half {
+        rotate(&mut lower);
+    }
+    let upper = std::mem::take(deck);
+
+    let (mut left_a, mut left_b) = (upper.len(), lower.len());
+    let (mut a, mut b) = (upper.into_iter(), lower.into_iter());
+    while left_a + left_b > 0 {
+        if rng.gen_range(0..left_a + left_b) < left_a {
+            deck.extend(a.next());
+            left_a -= 1;
+        } else {
+            deck.extend(b.next());
+            left_b -= 1;
+        }
+    }
+}
+
+/// One overhand pass: small packets are slid off the top onto a new pile,
+/// reversing their order relative to each other.
+pub fn overhand_shuffle<R: Rng + ?Sized>(deck: &mut Vec<OrientedCard>, rng: &mut R) {
+    let mut pile: Vec<Vec<OrientedCard>> = Vec::new();
+    while !deck.is_empty() {
+        let size = rng.gen_range(1..=OVERHAND_MAX_PACKET).min(deck.len());
+        pile.push(deck.drain(..size).collect());
+    }
+    *deck = pile.into_iter().rev().flatten().collect();
+}
+
+/// Run the riffles and overhand passes described by `options`. Cards that
+/// jump out during a riffle are removed from the deck and returned.
+pub fn simulate_shuffle<R: Rng + ?Sized>(
+    deck: &mut Vec<OrientedCard>,
+    options: &ShuffleOptions,
+    rng: &mut R,
+) -> Vec<OrientedCard> {
+    let mut jumpers = Vec::new();
+    for _ in 0..options.riffles {
+        let rotate_half = rng.gen_bool(options.rotate_chance.clamp(0.0, 1.0));
+        riffle_shuffle(deck, rotate_half, rng);
+        if deck.len() > 1 && rng.gen_bool(options.jumper_chance.clamp(0.0, 1.0)) {
+            let index = rng.gen_range(0..deck.len());
+            jumpers.push(deck.remove(index));
+        }
+    }
+    for _ in 0..options.overhand_passes {
+        overhand_shuffle(deck, rng);
+    }
+    jumpers
+}
+
+/// Deal `count` cards off the top, keeping the orientation they lie in.
+///
+/// # Errors
+/// Returns an error if the deck holds fewer than `count` cards.
+pub fn deal(deck: &[OrientedCard], count: usize) -> Result<Vec<DrawnCard>, TarotError> {
+    if count > deck.len() {
+        return Err(TarotError::NotEnoughCards {
+            needed: count,
+            available: deck.len(),
+        });
+    }
+    Ok(deck
+        .iter()
+        .take(count)
+        .enumerate()
+        .map(|(i, c)| DrawnCard {
+            card: c.card.clone(),
+            reversed: c.reversed,
+            position_index: i,
+            clarifies: None,
+        })
+        .collect())
+}
+
+/// Hand-shuffle a deck and deal `count` cards, returning any jumpers with
+/// the spread.
+///
+/// # Errors
+/// Returns an error if too few cards remain after jumpers leave.
+pub fn shuffled_reading<R: Rng + ?Sized>(
+    deck: Vec<TarotCard>,
+    count: usize,
+    options: &ShuffleOptions,
+    rng: &mut R,
+) -> Result<ShuffledReading, TarotError> {
+    let mut deck = orient(deck);
+    let jumpers = simulate_shuffle(&mut deck, options, rng);
+    Ok(ShuffledReading {
+        cards: deal(&deck, count)?,
+        jumpers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, seeded_rng};
+
+    fn ids(deck: &[OrientedCard]) -> Vec<String> {
+        deck.iter().map(|c| c.card.id.clone()).collect()
+    }
+
+    #[test]
+    fn shuffles_keep_every_card() {
+        let mut rng = seeded_rng(5);
+        let mut deck = orient(create_deck());
+        let mut before = ids(&deck);
+        simulate_shuffle(&mut deck, &ShuffleOptions::default(), &mut rng);
+        let mut after = ids(&deck);
+        assert_ne!(before, after);
+        before.sort();
+        after.sort();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn rotation_creates_reversals() {
+        let mut rng = seeded_rng(9);
+        let mut deck = orient(create_deck());
+        riffle_shuffle(&mut deck, true, &mut rng);
+        let reversed = deck.iter().filter(|c| c.reversed).count();
+        assert!(reversed > 0 && reversed < 78);
+
+        let mut upright = orient(create_deck());
+        let options = ShuffleOptions {
+            rotate_chance: 0.0,
+            ..Default::default()
+        };
+        simulate_shuffle(&mut upright, &options, &mut rng);
+        assert!(upright.iter().all(|c| !c.reversed));
+    }
+
+    #[test]
+    fn jumpers_leave_the_deck() {
+        let options = ShuffleOptions {
+            jumper_chance: 1.0,
+            ..Default::default()
+        };
+        let reading = shuffled_reading(create_deck(), 10, &options, &mut seeded_rng(4)).unwrap();
+        assert_eq!(reading.jumpers.len(), 7);
+        assert!(reading
+            .cards
+            .iter()
+            .all(|d| reading.jumpers.iter().all(|j| j.card.id != d.card.id)));
+
+        let calm = ShuffleOptions::default();
+        let reading = shuffled_reading(create_deck(), 3, &calm, &mut seeded_rng(4)).unwrap();
+        assert!(reading.jumpers.is_empty());
+    }
+
+    #[test]
+    fn deal_keeps_orientation() {
+        let mut deck = orient(create_deck());
+        deck[1].reversed = true;
+        let drawn = deal(&deck, 3).unwrap();
+        assert!(!drawn[0].reversed && drawn[1].reversed);
+        assert!(deal(&deck, 79).is_err());
+    }
+}
@@ -0,0 +1,187 @@
+use rand::Rng;
+
+use crate::types::{SignificatorMode, SignificatorReading, SpreadDefinition, TarotCard};
+
+use super::{apply_reversal_rules, draw_cards_with_rng, shuffle_deck_with_rng, TarotError};
+
+// ---------------------------------------------------------------------------
+// Significators
+// ---------------------------------------------------------------------------
+//
+// Suit follows the element (Wands fire, Cups water, Swords air, Pentacles
+// earth). By sign, the court rank follows modality: Queens cardinal, Kings
+// fixed, Knights mutable; Pages hold no sign. By profile, the rank follows
+// maturity: Page, Knight, Queen, King.
+
+const ZODIAC: [&str; 12] = [
+    "aries", "taurus", "gemini", "cancer", "leo", "virgo",
+    "libra", "scorpio", "sagittarius", "capricorn", "aquarius", "pisces",
+];
+
+fn suit_for_element(element: &str) -> Option<&'static str> {
+    match element.to_lowercase().as_str() {
+        "fire" => Some("wands"),
+        "water" => Some("cups"),
+        "air" => Some("swords"),
+        "earth" => Some("pentacles"),
+        _ => None,
+    }
+}
+
+fn court_card(deck: &[TarotCard], suit: &str, rank: &str) -> Option<TarotCard> {
+    let id = format!("{}_{}", suit, rank);
+    deck.iter().find(|c| c.id == id).cloned()
+}
+
+/// The court card traditionally signifying someone of a sun sign, e.g.
+/// `"leo"` → King of Wands. Returns `None` for an unknown sign.
+pub fn choose_significator_by_zodiac(deck: &[TarotCard], sign: &str) -> Option<TarotCard> {
+    let index = ZODIAC.iter().position(|s| s.eq_ignore_ascii_case(sign))?;
+    let suit = ["wands", "pentacles", "swords", "cups"][index % 4];
+    let rank = ["queen", "king", "knight"][index % 3];
+    court_card(deck, suit, rank)
+}
+
+/// The court card for a querent's age and temperament element: Page under
+/// 20, Knight under 35, Queen under 50, King from 50. Returns `None` for an
+/// unknown element.
+pub fn choose_significator_by_profile(
+    deck: &[TarotCard],
+    age: u32,
+    element: &str,
+) -> Option<TarotCard> {
+    let suit = suit_for_element(element)?;
+    let rank = match age {
+        0..=19 => "page",
+        20..=34 => "knight",
+        35..=49 => "queen",
+        _ => "king",
+    };
+    court_card(deck, suit, rank)
+}
+
+/// Deal a spread, first setting aside its significator according to the
+/// spread's [`SignificatorMode`]. `chosen_id` is required for
+/// [`SignificatorMode::Removed`] and ignored otherwise; spreads without a
+/// significator slot are dealt normally.
+///
+/// # Errors
+/// Returns an error if a removed significator is missing or not in
+/// the deck, or the deck is too small for the spread.
+pub fn draw_with_significator_with_rng<R: Rng + ?Sized>(
+    deck: &[TarotCard],
+    spread: &SpreadDefinition,
+    chosen_id: Option<&str>,
+    allow_reversals: bool,
+    rng: &mut R,
+) -> Result<SignificatorReading, TarotError> {
+    let mut remaining = deck.to_vec();
+    let mut significator = None;
+
+    if spread.significator == Some(SignificatorMode::Removed) {
+        let id = chosen_id
+            .ok_or_else(|| TarotError::MissingSignificator(spread.id.clone()))?;
+        let pos = remaining
+            .iter()
+            .position(|c| c.id == id)
+            .ok_or_else(|| TarotError::UnknownCard(id.to_string()))?;
+        significator = Some(remaining.remove(pos));
+    }
+
+    shuffle_deck_with_rng(&mut remaining, rng);
+
+    if spread.significator == Some(SignificatorMode::Drawn) {
+        if remaining.is_empty() {
+            return Err(TarotError::NotEnoughCards {
+                needed: 1,
+                available: 0,
+            });
+        }
+        significator = Some(remaining.remove(0));
+    }
+
+    let mut cards = draw_cards_with_rng(&remaining, spread.card_count, allow_reversals, rng)?;
+    apply_reversal_rules(spread, &mut cards);
+    Ok(SignificatorReading {
+        significator,
+        cards,
+    })
+}
+
+/// Like [`draw_with_significator_with_rng`] using `rand::thread_rng()`.
+///
+/// # Errors
+/// Returns an error if a removed significator is missing or not in
+/// the deck, or the deck is too small for the spread.
+pub fn draw_with_significator(
+    deck: &[TarotCard],
+    spread: &SpreadDefinition,
+    chosen_id: Option<&str>,
+    allow_reversals: bool,
+) -> Result<SignificatorReading, TarotError> {
+    draw_with_significator_with_rng(
+        deck,
+        spread,
+        chosen_id,
+        allow_reversals,
+        &mut rand::thread_rng(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, seeded_rng};
+
+    fn spread(mode: SignificatorMode) -> SpreadDefinition {
+        SpreadDefinition::builder()
+            .id("with_sig")
+            .position("Situation", "")
+            .position("Outcome", "")
+            .significator(mode)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn zodiac_significators() {
+        let deck = create_deck();
+        let pick = |sign| choose_significator_by_zodiac(&deck, sign).unwrap().id;
+        assert_eq!(pick("leo"), "wands_king");
+        assert_eq!(pick("Cancer"), "cups_queen");
+        assert_eq!(pick("gemini"), "swords_knight");
+        assert!(choose_significator_by_zodiac(&deck, "ophiuchus").is_none());
+    }
+
+    #[test]
+    fn profile_significators() {
+        let deck = create_deck();
+        let pick = |age, element| choose_significator_by_profile(&deck, age, element);
+        assert_eq!(pick(12, "earth").unwrap().id, "pentacles_page");
+        assert_eq!(pick(60, "Water").unwrap().id, "cups_king");
+        assert!(pick(30, "aether").is_none());
+    }
+
+    #[test]
+    fn removed_significator_never_reappears() {
+        let deck = create_deck();
+        let mut rng = seeded_rng(11);
+        let spread = spread(SignificatorMode::Removed);
+        let reading =
+            draw_with_significator_with_rng(&deck, &spread, Some("cups_queen"), true, &mut rng)
+                .unwrap();
+        assert_eq!(reading.significator.unwrap().id, "cups_queen");
+        assert_eq!(reading.cards.len(), 2);
+        assert!(reading.cards.iter().all(|d| d.card.id != "cups_queen"));
+        assert!(draw_with_significator(&deck, &spread, None, true).is_err());
+    }
+
+    #[test]
+    fn drawn_significator_comes_off_the_top() {
+        let deck = create_deck();
+        let reading =
+            draw_with_significator(&deck, &spread(SignificatorMode::Drawn), None, false).unwrap();
+        let sig = reading.significator.unwrap();
+        assert!(reading.cards.iter().all(|d| d.card.id != sig.id));
+    }
+}
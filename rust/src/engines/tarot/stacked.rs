@@ -0,0 +1,137 @@
+use rand::Rng;
+
+use crate::types::{DeckStack, TarotCard};
+
+use super::{shuffle_deck_with_rng, TarotError};
+
+// ---------------------------------------------------------------------------
+// Stacked and weighted decks
+// ---------------------------------------------------------------------------
+//
+// Test and demo support: a stack replaces the fair shuffle so known cards
+// come up without touching the RNG. A fixed order puts the listed cards on
+// top and shuffles the rest beneath them. Weights use the Efraimidis–Spirakis
+// key `u^(1/w)`, giving a weighted random permutation in which each card's
+// chance of surfacing next is proportional to its weight.
+
+/// Check that every card a stack names is in `deck`, that an order lists no
+/// card twice and that weights are finite and non-negative.
+///
+/// # Errors
+/// Returns [`TarotError::InvalidDeck`] listing every problem found.
+pub fn validate_stack(deck: &[TarotCard], stack: &DeckStack) -> Result<(), TarotError> {
+    let mut problems = Vec::new();
+    let ids: Vec<&String> = match stack {
+        DeckStack::Order(order) => order.iter().collect(),
+        DeckStack::Weights(weights) => weights.keys().collect(),
+    };
+    for id in ids.iter().filter(|id| deck.iter().all(|c| &&c.id != *id)) {
+        problems.push(format!("unknown card '{}'", id));
+    }
+    match stack {
+        DeckStack::Order(order) => {
+            for (i, id) in order.iter().enumerate() {
+                if order[..i].contains(id) {
+                    problems.push(format!("card '{}' is stacked more than once", id));
+                }
+            }
+        }
+        DeckStack::Weights(weights) => {
+            for (id, w) in weights.iter().filter(|(_, w)| !w.is_finite() || **w < 0.0) {
+                problems.push(format!("card '{}' has invalid weight {}", id, w));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(TarotError::InvalidDeck {
+            deck: "stack".to_string(),
+            problems,
+        })
+    }
+}
+
+/// Reorder `cards` according to `stack`. Cards a stack does not name are
+/// shuffled fairly (order) or weigh 1.0 (weights); weight 0 sinks a card to
+/// the bottom.
+pub fn arrange_with_rng<R: Rng + ?Sized>(cards: &mut [TarotCard], stack: &DeckStack, rng: &mut R) {
+    match stack {
+        DeckStack::Order(order) => {
+            shuffle_deck_with_rng(cards, rng);
+            // Stable, so unlisted cards keep their shuffled order
+            cards.sort_by_key(|c| order.iter().position(|id| id == &c.id).unwrap_or(usize::MAX));
+        }
+        DeckStack::Weights(weights) => {
+            let mut keyed: Vec<(f64, TarotCard)> = cards
+                .iter()
+                .map(|c| {
+                    let w = weights.get(&c.id).copied().unwrap_or(1.0);
+                    let key = if w > 0.0 { rng.gen::<f64>().powf(1.0 / w) } else { -1.0 };
+                    (key, c.clone())
+                })
+                .collect();
+            keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+            for (slot, (_, card)) in cards.iter_mut().zip(keyed) {
+                *slot = card;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, seeded_rng, TarotEngine};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn fixed_order_comes_off_the_top() {
+        let mut engine = TarotEngine::new();
+        let order = vec!["major_16_tower".to_string(), "cups_02".to_string()];
+        engine.stack_deck(DeckStack::Order(order.clone())).unwrap();
+        for _ in 0..5 {
+            let drawn = engine.draw_from("default", 3, false).unwrap();
+            assert_eq!(drawn[0].card.id, order[0]);
+            assert_eq!(drawn[1].card.id, order[1]);
+        }
+        let mut session = engine.start_session("three_card", false).unwrap();
+        assert_eq!(session.draw_next().unwrap().card.id, order[0]);
+
+        engine.unstack_deck();
+        let ids: Vec<String> = (0..20)
+            .map(|_| engine.draw_from("default", 1, false).unwrap()[0].card.id.clone())
+            .collect();
+        assert!(ids.iter().any(|id| id != &order[0]));
+    }
+
+    #[test]
+    fn weights_favour_heavy_cards() {
+        let weights = BTreeMap::from([
+            ("major_19_sun".to_string(), 1000.0),
+            ("major_00_fool".to_string(), 0.0),
+        ]);
+        let stack = DeckStack::Weights(weights);
+        let mut rng = seeded_rng(3);
+        let mut top_sun = 0;
+        for _ in 0..50 {
+            let mut deck = create_deck();
+            arrange_with_rng(&mut deck, &stack, &mut rng);
+            assert_eq!(deck.last().unwrap().id, "major_00_fool");
+            top_sun += usize::from(deck[0].id == "major_19_sun");
+        }
+        assert!(top_sun >= 40);
+    }
+
+    #[test]
+    fn invalid_stacks_are_rejected() {
+        let deck = create_deck();
+        let order = DeckStack::Order(vec!["cups_02".into(), "cups_02".into(), "nope".into()]);
+        match validate_stack(&deck, &order) {
+            Err(TarotError::InvalidDeck { problems, .. }) => assert_eq!(problems.len(), 2),
+            other => panic!("expected InvalidDeck, got {:?}", other),
+        }
+        let weights = DeckStack::Weights(BTreeMap::from([("cups_02".to_string(), -1.0)]));
+        assert!(validate_stack(&deck, &weights).is_err());
+    }
+}
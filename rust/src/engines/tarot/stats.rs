@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CardFrequency, DrawStatsSummary, DrawnCard, Suit};
+
+// ---------------------------------------------------------------------------
+// Draw statistics
+// ---------------------------------------------------------------------------
+
+/// Running tallies of drawn cards. Serializable so counts can be carried
+/// across sessions.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DrawStatistics {
+    cards: BTreeMap<String, CardFrequency>,
+    suits: BTreeMap<Suit, u64>,
+    major_draws: u64,
+}
+
+impl DrawStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tally a batch of drawn cards.
+    pub fn record(&mut self, drawn: &[DrawnCard]) {
+        for d in drawn {
+            let entry = self.cards.entry(d.card.id.clone()).or_insert_with(|| CardFrequency {
+                card_id: d.card.id.clone(),
+                card_name: d.card.name.clone(),
+                draws: 0,
+                reversals: 0,
+            });
+            entry.draws += 1;
+            entry.reversals += u64::from(d.reversed);
+            match d.card.suit {
+                Some(suit) => *self.suits.entry(suit).or_default() += 1,
+                None => self.major_draws += 1,
+            }
+        }
+    }
+
+    pub fn total_draws(&self) -> u64 {
+        self.cards.values().map(|c| c.draws).sum()
+    }
+
+    /// Tally for one card, if it has been drawn.
+    pub fn card(&self, id: &str) -> Option<&CardFrequency> {
+        self.cards.get(id)
+    }
+
+    pub fn summary(&self) -> DrawStatsSummary {
+        let total_draws = self.total_draws();
+        let reversals: u64 = self.cards.values().map(|c| c.reversals).sum();
+        let mut most_drawn: Vec<CardFrequency> = self.cards.values().cloned().collect();
+        most_drawn.sort_by(|a, b| b.draws.cmp(&a.draws).then_with(|| a.card_id.cmp(&b.card_id)));
+        DrawStatsSummary {
+            total_draws,
+            reversal_rate: if total_draws == 0 {
+                0.0
+            } else {
+                reversals as f64 / total_draws as f64
+            },
+            major_draws: self.major_draws,
+            most_drawn,
+            suit_distribution: self.suits.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::TarotEngine;
+    use crate::rng::seeded_rng;
+
+    #[test]
+    fn engine_collects_only_when_enabled() {
+        let mut engine = TarotEngine::new();
+        let deck = engine.create_deck();
+        engine.draw_cards(&deck, 3, true).unwrap();
+        assert!(engine.statistics().is_none());
+
+        engine.enable_statistics();
+        engine.draw_cards(&deck, 3, false).unwrap();
+        engine.draw_cards(&deck, 1, false).unwrap();
+        let summary = engine.statistics().unwrap();
+        assert_eq!(summary.total_draws, 4);
+        assert_eq!(summary.most_drawn[0].card_id, deck[0].id);
+        assert_eq!(summary.most_drawn[0].draws, 2);
+        assert_eq!(summary.major_draws, 4);
+        assert_eq!(summary.reversal_rate, 0.0);
+    }
+
+    #[test]
+    fn reversal_rate_is_fair_over_many_draws() {
+        let mut stats = DrawStatistics::new();
+        let engine = TarotEngine::new();
+        let mut rng = seeded_rng(42);
+        for _ in 0..200 {
+            let mut deck = engine.create_deck();
+            engine.shuffle_deck_with_rng(&mut deck, &mut rng);
+            stats.record(&engine.draw_cards_with_rng(&deck, 10, true, &mut rng).unwrap());
+        }
+        let summary = stats.summary();
+        assert_eq!(summary.total_draws, 2000);
+        assert!((summary.reversal_rate - 0.5).abs() < 0.05);
+        assert_eq!(summary.most_drawn.len(), 78);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        let restored: DrawStatistics = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.total_draws(), 2000);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::types::{ArcStage, DrawnCard, NarrativeArc, StoryBeat};
+
+use super::TarotError;
+
+// Story mode: arrange a drawn card sequence into a three-act narrative arc
+// for creative-writing and roleplay features rather than fortune-telling.
+
+/// Minimum number of cards needed to fill every act.
+pub const MIN_ARC_CARDS: usize = 3;
+
+/// Motif contributed by each tarot element.
+fn element_motif(element: &str) -> &'static str {
+    match element {
+        "Fire" => "drive",
+        "Water" => "longing",
+        "Air" => "conflict of ideas",
+        "Earth" => "the material world",
+        _ => "the unknown",
+    }
+}
+
+fn beat_for(card: &DrawnCard, stage: ArcStage) -> StoryBeat {
+    let keywords = if card.reversed {
+        &card.card.keywords_reversed
+    } else {
+        &card.card.keywords_upright
+    };
+    let mut motifs: Vec<String> = keywords.iter().take(2).cloned().collect();
+    motifs.push(element_motif(&card.card.element).to_string());
+
+    StoryBeat {
+        stage,
+        card_id: card.card.id.clone(),
+        card_name: card.card.name.clone(),
+        reversed: card.reversed,
+        motifs,
+        tone: if card.reversed { "shadow" } else { "light" }.to_string(),
+    }
+}
+
+/// Build a narrative arc from cards in draw order.
+///
+/// The first third of the sequence forms the setup, the last third the
+/// resolution, and everything in between the conflict (which absorbs any
+/// remainder so the middle act is never empty).
+///
+/// # Errors
+/// Returns an error if fewer than [`MIN_ARC_CARDS`] cards are given.
+pub fn build_narrative_arc(cards: &[DrawnCard]) -> Result<NarrativeArc, TarotError> {
+    if cards.len() < MIN_ARC_CARDS {
+        return Err(TarotError::NotEnoughCards {
+            needed: MIN_ARC_CARDS,
+            available: cards.len(),
+        });
+    }
+
+    let act = cards.len() / 3;
+    let conflict_end = cards.len() - act;
+
+    let setup = cards[..act].iter().map(|c| beat_for(c, ArcStage::Setup)).collect();
+    let conflict = cards[act..conflict_end]
+        .iter()
+        .map(|c| beat_for(c, ArcStage::Conflict))
+        .collect();
+    let resolution = cards[conflict_end..]
+        .iter()
+        .map(|c| beat_for(c, ArcStage::Resolution))
+        .collect();
+
+    // Ties resolve to the element that appears first in the sequence.
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for c in cards {
+        match counts.iter_mut().find(|(e, _)| *e == c.card.element) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((&c.card.element, 1)),
+        }
+    }
+    let max = counts.iter().map(|(_, n)| *n).max().unwrap_or(0);
+    let dominant_element = counts
+        .iter()
+        .find(|(_, n)| *n == max)
+        .map(|(e, _)| e.to_string())
+        .unwrap_or_default();
+
+    Ok(NarrativeArc {
+        setup,
+        conflict,
+        resolution,
+        dominant_element,
+        archetypal_beats: cards.iter().filter(|c| c.card.is_major()).count(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::{create_deck, draw_cards};
+
+    #[test]
+    fn splits_sequence_into_three_acts() {
+        let deck = create_deck();
+        let drawn = draw_cards(&deck, 7, false).unwrap();
+        let arc = build_narrative_arc(&drawn).unwrap();
+
+        assert_eq!(arc.setup.len(), 2);
+        assert_eq!(arc.conflict.len(), 3);
+        assert_eq!(arc.resolution.len(), 2);
+        assert_eq!(arc.setup[0].card_id, "major_00_fool");
+        assert_eq!(arc.archetypal_beats, 7);
+        assert!(arc.setup[0].motifs.contains(&"new beginnings".to_string()));
+    }
+
+    #[test]
+    fn rejects_short_sequences() {
+        let deck = create_deck();
+        let drawn = draw_cards(&deck, 2, false).unwrap();
+        assert!(build_narrative_arc(&drawn).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use crate::types::{DrawnCard, Suit, TimeUnit, TimingPrediction, TimingRules};
+
+// ---------------------------------------------------------------------------
+// Timing predictions
+// ---------------------------------------------------------------------------
+//
+// Pips count their number in the suit's unit (Three of Cups = three weeks by
+// default). Majors point to the season of their zodiac sign, if any; courts
+// and other majors describe people or forces rather than time.
+
+/// Tropical season of each sign, in sign order.
+const SEASONS: [(&str, &str); 12] = [
+    ("Aries", "March 21 – April 19"),
+    ("Taurus", "April 20 – May 20"),
+    ("Gemini", "May 21 – June 20"),
+    ("Cancer", "June 21 – July 22"),
+    ("Leo", "July 23 – August 22"),
+    ("Virgo", "August 23 – September 22"),
+    ("Libra", "September 23 – October 22"),
+    ("Scorpio", "October 23 – November 21"),
+    ("Sagittarius", "November 22 – December 21"),
+    ("Capricorn", "December 22 – January 19"),
+    ("Aquarius", "January 20 – February 18"),
+    ("Pisces", "February 19 – March 20"),
+];
+
+fn unit_name(unit: TimeUnit, amount: u32) -> &'static str {
+    match (unit, amount == 1) {
+        (TimeUnit::Days, true) => "day",
+        (TimeUnit::Days, false) => "days",
+        (TimeUnit::Weeks, true) => "week",
+        (TimeUnit::Weeks, false) => "weeks",
+        (TimeUnit::Months, true) => "month",
+        (TimeUnit::Months, false) => "months",
+        (TimeUnit::Years, true) => "year",
+        (TimeUnit::Years, false) => "years",
+    }
+}
+
+fn suit_unit(rules: &TimingRules, suit: Suit) -> TimeUnit {
+    match suit {
+        Suit::Wands => rules.wands,
+        Suit::Cups => rules.cups,
+        Suit::Swords => rules.swords,
+        Suit::Pentacles => rules.pentacles,
+    }
+}
+
+/// Timeframe suggested by a single card.
+pub fn predict_timing(drawn: &DrawnCard, rules: &TimingRules) -> TimingPrediction {
+    let card = &drawn.card;
+    let pip = card.suit.filter(|_| !card.is_court());
+    let (amount, unit) = match pip.map(|suit| suit_unit(rules, suit)) {
+        Some(unit) => (Some(card.number as u32), Some(unit)),
+        None => (None, None),
+    };
+    let season = card
+        .zodiac
+        .as_deref()
+        .filter(|_| card.suit.is_none())
+        .and_then(|sign| SEASONS.iter().find(|(s, _)| s.eq_ignore_ascii_case(sign)))
+        .map(|(sign, dates)| format!("{} season ({})", sign, dates));
+
+    let mut description = match (amount, unit, &season) {
+        (Some(n), Some(u), _) => format!("Within about {} {}.", n, unit_name(u, n)),
+        (_, _, Some(season)) => format!("Around {}.", season),
+        _ => "No specific timeframe; the outcome depends on the people involved.".to_string(),
+    };
+    if drawn.reversed {
+        description.push_str(" Reversed: expect delays.");
+    }
+
+    TimingPrediction {
+        card_id: card.id.clone(),
+        amount,
+        unit,
+        season,
+        reversed: drawn.reversed,
+        description,
+    }
+}
+
+/// Timeframe from the spread's final dealt position, if any card was dealt.
+pub fn timing_from_spread(drawn: &[DrawnCard], rules: &TimingRules) -> Option<TimingPrediction> {
+    drawn
+        .iter()
+        .filter(|d| d.clarifies.is_none())
+        .max_by_key(|d| d.position_index)
+        .map(|d| predict_timing(d, rules))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::drawn_cards;
+
+    fn drawn(id: &str, reversed: bool) -> DrawnCard {
+        drawn_cards(&[id], &[reversed]).remove(0)
+    }
+
+    #[test]
+    fn pips_count_in_their_suit_unit() {
+        let rules = TimingRules::default();
+        let t = predict_timing(&drawn("cups_03", false), &rules);
+        assert_eq!((t.amount, t.unit), (Some(3), Some(TimeUnit::Weeks)));
+        assert_eq!(t.description, "Within about 3 weeks.");
+
+        let ace = predict_timing(&drawn("wands_01_ace", true), &rules);
+        assert_eq!(ace.description, "Within about 1 day. Reversed: expect delays.");
+
+        let custom = TimingRules {
+            cups: TimeUnit::Days,
+            ..Default::default()
+        };
+        assert_eq!(predict_timing(&drawn("cups_03", false), &custom).unit, Some(TimeUnit::Days));
+    }
+
+    #[test]
+    fn majors_and_courts() {
+        let rules = TimingRules::default();
+        let emperor = predict_timing(&drawn("major_04_emperor", false), &rules);
+        assert!(emperor.amount.is_none());
+        assert!(emperor.season.unwrap().starts_with("Aries season"));
+
+        let queen = predict_timing(&drawn("cups_queen", false), &rules);
+        assert!(queen.amount.is_none() && queen.season.is_none());
+    }
+
+    #[test]
+    fn spread_uses_final_position() {
+        let mut cards = drawn_cards(&["pentacles_02", "swords_05"], &[]);
+        cards.reverse();
+        let t = timing_from_spread(&cards, &TimingRules::default()).unwrap();
+        assert_eq!((t.amount, t.unit), (Some(5), Some(TimeUnit::Months)));
+        assert!(timing_from_spread(&[], &TimingRules::default()).is_none());
+    }
+}
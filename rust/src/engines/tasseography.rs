@@ -0,0 +1,285 @@
+mod error;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::text::name_similarity;
+use crate::types::{CupZone, Omen, PerceivedSymbol, PlacedSymbol, TeaLeafReading, TeaLeafSymbol};
+
+pub use error::TasseographyError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const SYMBOLS_JSON: &str = include_str!("../../../data/tasseography/symbols.json");
+
+fn load_symbols() -> Result<Vec<TeaLeafSymbol>, TasseographyError> {
+    serde_json::from_str(SYMBOLS_JSON).map_err(|e| TasseographyError::DataCorrupt(e.to_string()))
+}
+
+/// Check that every symbol has a meaning and that no id, name or alias
+/// names two symbols.
+fn validate_symbols(symbols: &[TeaLeafSymbol]) -> Result<(), TasseographyError> {
+    let mut problems = Vec::new();
+    let mut terms: Vec<(String, &str)> = Vec::new();
+    for symbol in symbols {
+        if symbol.meaning.trim().is_empty() {
+            problems.push(format!("symbol {} has no meaning", symbol.id));
+        }
+        for term in symbol_terms(symbol) {
+            let term = term.to_lowercase();
+            match terms.iter().find(|(t, _)| *t == term) {
+                Some((_, other)) if *other != symbol.id => {
+                    problems.push(format!("'{}' names both {} and {}", term, other, symbol.id));
+                }
+                Some(_) => {}
+                None => terms.push((term, &symbol.id)),
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(TasseographyError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cup zones
+// ---------------------------------------------------------------------------
+//
+// The cup is read as a clock running from the rim down: shapes near the rim
+// are close at hand, those on the sides follow in the coming weeks and
+// those in the bottom lie furthest off.
+
+impl CupZone {
+    /// Rim first, so sorting by zone puts the soonest events first.
+    pub const ALL: [CupZone; 3] = [Self::Rim, Self::Middle, Self::Bottom];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rim => "rim",
+            Self::Middle => "middle",
+            Self::Bottom => "bottom",
+        }
+    }
+
+    /// When a symbol in this zone comes to pass.
+    pub fn timing(self) -> &'static str {
+        match self {
+            Self::Rim => "now or within days",
+            Self::Middle => "within weeks",
+            Self::Bottom => "months away or in the distant future",
+        }
+    }
+}
+
+impl fmt::Display for CupZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for CupZone {
+    type Err = TasseographyError;
+
+    /// Case-insensitive zone name, also accepting `"top"`, `"side"` or
+    /// `"sides"` and `"base"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "rim" | "top" => Ok(Self::Rim),
+            "middle" | "side" | "sides" => Ok(Self::Middle),
+            "bottom" | "base" => Ok(Self::Bottom),
+            _ => Err(TasseographyError::InvalidZone(s.to_string())),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lookup and interpretation
+// ---------------------------------------------------------------------------
+//
+// Shapes in the leaves are seldom exact, so a perceived symbol is matched
+// by normalised similarity against every id, name and alias, the same
+// measure tarot card search uses. Plurals and small misspellings still
+// find their symbol ("birds", "horse shoe").
+
+/// Lowest similarity (0.0–1.0) accepted by [`lookup_symbol`].
+pub const MIN_SYMBOL_SIMILARITY: f64 = 0.75;
+
+fn symbol_terms(symbol: &TeaLeafSymbol) -> impl Iterator<Item = &str> {
+    [symbol.id.as_str(), symbol.name.as_str()]
+        .into_iter()
+        .chain(symbol.aliases.iter().map(String::as_str))
+}
+
+/// The symbol whose id, name or alias best matches `term`, or `None` if
+/// nothing reaches [`MIN_SYMBOL_SIMILARITY`]. Ties go to the earlier entry.
+pub fn lookup_symbol<'a>(symbols: &'a [TeaLeafSymbol], term: &str) -> Option<&'a TeaLeafSymbol> {
+    let mut best: Option<(f64, &TeaLeafSymbol)> = None;
+    for symbol in symbols {
+        let score = symbol_terms(symbol).map(|t| name_similarity(term, t)).fold(0.0, f64::max);
+        if score >= MIN_SYMBOL_SIMILARITY && best.is_none_or(|(top, _)| score > top) {
+            best = Some((score, symbol));
+        }
+    }
+    best.map(|(_, symbol)| symbol)
+}
+
+/// Read placed symbols together, soonest first. Symbols in the same zone
+/// keep the order they were given in.
+pub fn read_cup(mut symbols: Vec<(TeaLeafSymbol, CupZone)>) -> TeaLeafReading {
+    symbols.sort_by_key(|(_, zone)| *zone);
+    let placed: Vec<PlacedSymbol> = symbols
+        .into_iter()
+        .map(|(symbol, zone)| PlacedSymbol {
+            text: format!("{} ({}): {}", symbol.name, zone.timing(), symbol.meaning),
+            timing: zone.timing().to_string(),
+            symbol,
+            zone,
+        })
+        .collect();
+
+    let overall = [Omen::Favourable, Omen::Cautionary, Omen::Neutral]
+        .into_iter()
+        .find(|omen| placed.iter().filter(|p| p.symbol.omen == *omen).count() * 2 > placed.len());
+
+    let mut summary: Vec<String> = placed.iter().map(|p| p.text.clone()).collect();
+    summary.push(
+        match (placed.is_empty(), overall) {
+            (true, _) => "No symbols were read in the cup.",
+            (false, Some(Omen::Favourable)) => "Most of the signs in the cup are favourable.",
+            (false, Some(Omen::Cautionary)) => "Most of the signs in the cup counsel caution.",
+            (false, Some(Omen::Neutral)) => {
+                "Most of the signs are neutral and take their colour from each other."
+            }
+            (false, None) => "The signs in the cup are mixed.",
+        }
+        .to_string(),
+    );
+
+    TeaLeafReading {
+        symbols: placed,
+        overall,
+        summary: summary.join(" "),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TasseographyEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct TasseographyEngine {
+    symbols: Vec<TeaLeafSymbol>,
+}
+
+impl TasseographyEngine {
+    /// Create an engine from the embedded symbol dictionary.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded tea-leaf data: {}", e))
+    }
+
+    /// Load and validate the embedded symbols.
+    ///
+    /// # Errors
+    /// Returns [`TasseographyError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, TasseographyError> {
+        let symbols = load_symbols()?;
+        validate_symbols(&symbols)?;
+        Ok(Self { symbols })
+    }
+
+    pub fn symbols(&self) -> &[TeaLeafSymbol] {
+        &self.symbols
+    }
+
+    /// Look up a symbol by id, name or alias, tolerating plurals and typos.
+    pub fn lookup(&self, term: &str) -> Option<&TeaLeafSymbol> {
+        lookup_symbol(&self.symbols, term)
+    }
+
+    /// Interpret the shapes seen in a cup with where each one lay.
+    ///
+    /// # Errors
+    /// Returns [`TasseographyError::UnknownSymbol`] if a shape names no
+    /// symbol.
+    pub fn interpret(&self, seen: &[PerceivedSymbol]) -> Result<TeaLeafReading, TasseographyError> {
+        let symbols = seen
+            .iter()
+            .map(|p| {
+                self.lookup(&p.symbol)
+                    .map(|s| (s.clone(), p.zone))
+                    .ok_or_else(|| TasseographyError::UnknownSymbol(p.symbol.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(read_cup(symbols))
+    }
+}
+
+impl Default for TasseographyEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seen(symbol: &str, zone: CupZone) -> PerceivedSymbol {
+        PerceivedSymbol {
+            symbol: symbol.to_string(),
+            zone,
+        }
+    }
+
+    #[test]
+    fn lookup_and_zones() {
+        let engine = TasseographyEngine::new();
+        assert!(engine.symbols().len() >= 40);
+        assert_eq!(engine.lookup("Ship").unwrap().id, "boat");
+        assert_eq!(engine.lookup("birds").unwrap().id, "bird");
+        assert_eq!(engine.lookup("horse shoe").unwrap().id, "horseshoe");
+        assert_eq!(engine.lookup("question mark").unwrap().id, "question_mark");
+        assert!(engine.lookup("helicopter").is_none());
+
+        assert_eq!("Sides".parse::<CupZone>().unwrap(), CupZone::Middle);
+        assert_eq!("top".parse::<CupZone>().unwrap(), CupZone::Rim);
+        assert!("handle".parse::<CupZone>().is_err());
+        assert_eq!(serde_json::to_value(CupZone::Bottom).unwrap(), "bottom");
+    }
+
+    #[test]
+    fn reads_soonest_first() {
+        let engine = TasseographyEngine::new();
+        let reading = engine
+            .interpret(&[
+                seen("tree", CupZone::Bottom),
+                seen("anchor", CupZone::Rim),
+                seen("snake", CupZone::Middle),
+                seen("star", CupZone::Rim),
+            ])
+            .unwrap();
+        let ids: Vec<&str> = reading.symbols.iter().map(|p| p.symbol.id.as_str()).collect();
+        assert_eq!(ids, ["anchor", "star", "snake", "tree"]);
+        assert_eq!(reading.symbols[2].timing, "within weeks");
+        assert_eq!(reading.overall, Some(Omen::Favourable));
+        assert!(reading.summary.starts_with("Anchor (now or within days): "));
+        assert!(reading.summary.ends_with("are favourable."));
+
+        let mixed = engine
+            .interpret(&[seen("cat", CupZone::Rim), seen("heart", CupZone::Rim)])
+            .unwrap();
+        assert_eq!(mixed.overall, None);
+        assert_eq!(engine.interpret(&[]).unwrap().summary, "No symbols were read in the cup.");
+        assert_eq!(
+            engine.interpret(&[seen("teapot", CupZone::Rim)]).unwrap_err(),
+            TasseographyError::UnknownSymbol("teapot".to_string())
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// TasseographyError — failures reported by the tea-leaf engine
+// ---------------------------------------------------------------------------
+
+/// Why a tea-leaf reading failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TasseographyError {
+    UnknownSymbol(String),
+    /// A string that names no part of the cup
+    InvalidZone(String),
+    /// The embedded symbol data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for TasseographyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol(name) => write!(f, "unknown tea-leaf symbol: {}", name),
+            Self::InvalidZone(zone) => write!(f, "invalid cup zone: '{}'", zone),
+            Self::DataCorrupt(detail) => write!(f, "corrupt tea-leaf data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for TasseographyError {}
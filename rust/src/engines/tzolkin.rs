@@ -0,0 +1,328 @@
+mod error;
+
+use crate::engines::astrology::to_julian_day;
+use crate::engines::calendar::{days_in_month, is_leap_year};
+use crate::types::{DaySign, GalacticSignature, TzolkinCount, TzolkinDay, TzolkinTone, Wavespell};
+
+pub use error::TzolkinError;
+
+// ---------------------------------------------------------------------------
+// Static data loaded at compile time
+// ---------------------------------------------------------------------------
+
+const SIGNS_JSON: &str = include_str!("../../../data/tzolkin/signs.json");
+const TONES_JSON: &str = include_str!("../../../data/tzolkin/tones.json");
+
+fn load_signs() -> Result<Vec<DaySign>, TzolkinError> {
+    serde_json::from_str(SIGNS_JSON).map_err(|e| TzolkinError::DataCorrupt(e.to_string()))
+}
+
+fn load_tones() -> Result<Vec<TzolkinTone>, TzolkinError> {
+    serde_json::from_str(TONES_JSON).map_err(|e| TzolkinError::DataCorrupt(e.to_string()))
+}
+
+/// Check that there are 20 signs and 13 tones, each numbered in order.
+fn validate_data(signs: &[DaySign], tones: &[TzolkinTone]) -> Result<(), TzolkinError> {
+    let mut problems = Vec::new();
+    if signs.len() != 20 {
+        problems.push(format!("{} signs instead of 20", signs.len()));
+    }
+    if tones.len() != 13 {
+        problems.push(format!("{} tones instead of 13", tones.len()));
+    }
+    for (expected, sign) in (1..).zip(signs) {
+        if sign.number != expected {
+            problems.push(format!("sign {} is numbered {}", sign.name, sign.number));
+        }
+    }
+    for (expected, tone) in (1..).zip(tones) {
+        if tone.number != expected {
+            problems.push(format!("tone {} is numbered {}", tone.name, tone.number));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(TzolkinError::DataCorrupt(problems.join("; ")))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// The 260-day count
+// ---------------------------------------------------------------------------
+//
+// A kin (1–260) pairs a tone (1–13) with a sign (1–20), both advancing one
+// step a day, so kin 1 is 1 Imix and kin 260 is 13 Ahau. The traditional
+// count runs unbroken from the Long Count epoch, Julian day 584283, which
+// was 4 Ahau (kin 160). The Dreamspell count holds 29 February outside the
+// count, giving it the same kin as the day before, and is anchored on
+// 21 December 2012 as kin 207.
+
+const GMT_CORRELATION: i64 = 584_283;
+const EPOCH_KIN_INDEX: i64 = 159;
+const DREAMSPELL_ANCHOR: (i32, u32, u32) = (2012, 12, 21);
+const DREAMSPELL_ANCHOR_KIN_INDEX: i64 = 206;
+
+fn julian_day_number(year: i32, month: u32, day: u32) -> i64 {
+    to_julian_day(year, month, day, 12, 0).round() as i64
+}
+
+/// Julian day number less the 29 Februaries up to and including the date.
+fn dreamspell_day_number(year: i32, month: u32, day: u32) -> i64 {
+    let y = i64::from(year) - 1;
+    let mut leap_days = y.div_euclid(4) - y.div_euclid(100) + y.div_euclid(400);
+    if is_leap_year(year) && (month > 2 || (month == 2 && day == 29)) {
+        leap_days += 1;
+    }
+    julian_day_number(year, month, day) - leap_days
+}
+
+/// The kin of a Gregorian date.
+///
+/// # Errors
+/// Returns [`TzolkinError::InvalidDate`] if the month or day is out of
+/// range.
+pub fn kin_for_date(
+    year: i32,
+    month: u32,
+    day: u32,
+    count: TzolkinCount,
+) -> Result<u32, TzolkinError> {
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(TzolkinError::InvalidDate { year, month, day });
+    }
+    let index = match count {
+        TzolkinCount::Traditional => {
+            julian_day_number(year, month, day) - GMT_CORRELATION + EPOCH_KIN_INDEX
+        }
+        TzolkinCount::Dreamspell => {
+            let (ay, am, ad) = DREAMSPELL_ANCHOR;
+            dreamspell_day_number(year, month, day) - dreamspell_day_number(ay, am, ad)
+                + DREAMSPELL_ANCHOR_KIN_INDEX
+        }
+    };
+    Ok(index.rem_euclid(260) as u32 + 1)
+}
+
+/// Position 0–259 of a kin in the count.
+fn kin_index(kin: u32) -> u32 {
+    (kin % 260 + 259) % 260
+}
+
+/// Tone 1–13 of a kin. Kins run 1–260; others are read around the cycle,
+/// so 0 is kin 260 and 261 is kin 1.
+pub fn tone_of(kin: u32) -> u32 {
+    kin_index(kin) % 13 + 1
+}
+
+/// Sign 1–20 of a kin, read around the cycle like [`tone_of`].
+pub fn sign_of(kin: u32) -> u32 {
+    kin_index(kin) % 20 + 1
+}
+
+/// The kin with the given tone and sign, e.g. 4 and 20 give kin 160.
+/// Returns `None` if either is out of range.
+pub fn kin_from(tone: u32, sign: u32) -> Option<u32> {
+    if !(1..=13).contains(&tone) || !(1..=20).contains(&sign) {
+        return None;
+    }
+    (1..=260).find(|&kin| tone_of(kin) == tone && sign_of(kin) == sign)
+}
+
+/// Sign number `n` wrapped into 1–20, so 0 is Ahau.
+fn wrap_sign(n: i64) -> u32 {
+    (n - 1).rem_euclid(20) as u32 + 1
+}
+
+/// Steps from a sign to its guide, by tone: the guide shares the sign's
+/// colour, so the offset is always a multiple of four.
+fn guide_offset(tone: u32) -> i64 {
+    [0, 12, 4, 16, 8][((tone - 1) % 5) as usize]
+}
+
+// ---------------------------------------------------------------------------
+// TzolkinEngine — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct TzolkinEngine {
+    signs: Vec<DaySign>,
+    tones: Vec<TzolkinTone>,
+    count: TzolkinCount,
+}
+
+impl TzolkinEngine {
+    /// Create an engine from the embedded signs and tones, following the
+    /// traditional count.
+    ///
+    /// # Panics
+    /// Panics if the embedded data is invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded tzolkin data: {}", e))
+    }
+
+    /// Load and validate the embedded signs and tones.
+    ///
+    /// # Errors
+    /// Returns [`TzolkinError::DataCorrupt`] if the data is invalid.
+    pub fn try_new() -> Result<Self, TzolkinError> {
+        let signs = load_signs()?;
+        let tones = load_tones()?;
+        validate_data(&signs, &tones)?;
+        Ok(Self {
+            signs,
+            tones,
+            count: TzolkinCount::default(),
+        })
+    }
+
+    pub fn count(&self) -> TzolkinCount {
+        self.count
+    }
+
+    /// Choose which count dates are matched to.
+    pub fn set_count(&mut self, count: TzolkinCount) {
+        self.count = count;
+    }
+
+    /// All 20 signs, Imix to Ahau.
+    pub fn signs(&self) -> &[DaySign] {
+        &self.signs
+    }
+
+    pub fn tones(&self) -> &[TzolkinTone] {
+        &self.tones
+    }
+
+    /// Look up a sign by Yucatec or Dreamspell name (case-insensitive).
+    pub fn get_sign(&self, name: &str) -> Option<&DaySign> {
+        let name = name.trim();
+        self.signs.iter().find(|s| {
+            s.name.eq_ignore_ascii_case(name) || s.dreamspell_name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    fn sign(&self, number: u32) -> DaySign {
+        self.signs[(number - 1) as usize].clone()
+    }
+
+    /// The tone, sign and wavespell of `kin` (1–260) on a date.
+    fn tzolkin_day(&self, kin: u32, year: i32, month: u32, day: u32) -> TzolkinDay {
+        let tone = self.tones[(tone_of(kin) - 1) as usize].clone();
+        let sign = self.sign(sign_of(kin));
+        let wavespell = (kin - 1) / 13 + 1;
+        let first_kin = (wavespell - 1) * 13 + 1;
+        TzolkinDay {
+            year,
+            month,
+            day,
+            count: self.count,
+            kin,
+            name: format!("{} {}", tone.number, sign.name),
+            galactic_name: format!("{} {} {}", sign.color, tone.name, sign.dreamspell_name),
+            tone,
+            sign,
+            wavespell: Wavespell {
+                number: wavespell,
+                first_kin,
+                sign: self.sign(sign_of(first_kin)),
+            },
+        }
+    }
+
+    /// The day in the 260-day count for a Gregorian date.
+    ///
+    /// # Errors
+    /// Returns [`TzolkinError::InvalidDate`] for a date that does not exist.
+    pub fn day(&self, year: i32, month: u32, day: u32) -> Result<TzolkinDay, TzolkinError> {
+        let kin = kin_for_date(year, month, day, self.count)?;
+        Ok(self.tzolkin_day(kin, year, month, day))
+    }
+
+    /// The birth kin and its guide, analog, antipode and occult signs.
+    ///
+    /// # Errors
+    /// Returns [`TzolkinError::InvalidDate`] for a date that does not exist.
+    pub fn galactic_signature(
+        &self,
+        year: i32,
+        month: u32,
+        day: u32,
+    ) -> Result<GalacticSignature, TzolkinError> {
+        let day = self.day(year, month, day)?;
+        let sign = i64::from(day.sign.number);
+        Ok(GalacticSignature {
+            guide: self.sign(wrap_sign(sign + guide_offset(day.tone.number))),
+            analog: self.sign(wrap_sign(19 - sign)),
+            antipode: self.sign(wrap_sign(sign + 10)),
+            occult: self.sign(wrap_sign(21 - sign)),
+            day,
+        })
+    }
+}
+
+impl Default for TzolkinEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traditional_count() {
+        let engine = TzolkinEngine::new();
+        let day = engine.day(2012, 12, 21).unwrap();
+        assert_eq!((day.kin, day.name.as_str()), (160, "4 Ahau"));
+        assert_eq!(day.galactic_name, "Yellow Self-Existing Sun");
+        assert_eq!(day.wavespell.number, 13);
+        assert_eq!(day.wavespell.first_kin, 157);
+        assert_eq!(day.wavespell.sign.name, "Caban");
+        assert_eq!(engine.day(2012, 12, 22).unwrap().name, "5 Imix");
+        // 260 days later the count repeats
+        assert_eq!(kin_for_date(2013, 9, 7, TzolkinCount::Traditional), Ok(160));
+        assert_eq!(
+            engine.day(2023, 2, 29).unwrap_err(),
+            TzolkinError::InvalidDate {
+                year: 2023,
+                month: 2,
+                day: 29
+            }
+        );
+    }
+
+    #[test]
+    fn dreamspell_count_skips_leap_days() {
+        let dreamspell = |y, m, d| kin_for_date(y, m, d, TzolkinCount::Dreamspell).unwrap();
+        assert_eq!(dreamspell(2012, 12, 21), 207);
+        assert_eq!(dreamspell(2013, 7, 26), 164);
+        assert_eq!(dreamspell(2024, 2, 29), dreamspell(2024, 2, 28));
+        assert_eq!(dreamspell(2024, 3, 1), dreamspell(2024, 2, 28) % 260 + 1);
+        // José Argüelles, born 24 January 1939: Blue Spectral Monkey
+        assert_eq!(dreamspell(1939, 1, 24), 11);
+    }
+
+    #[test]
+    fn galactic_signature_oracle() {
+        let mut engine = TzolkinEngine::new();
+        engine.set_count(TzolkinCount::Dreamspell);
+        let sig = engine.galactic_signature(1939, 1, 24).unwrap();
+        assert_eq!(sig.day.galactic_name, "Blue Spectral Monkey");
+        assert_eq!(sig.guide.dreamspell_name, "Monkey");
+        assert_eq!(sig.analog.dreamspell_name, "Star");
+        assert_eq!(sig.antipode.dreamspell_name, "Dragon");
+        assert_eq!(sig.occult.dreamspell_name, "Dog");
+        assert_eq!(sig.day.count, TzolkinCount::Dreamspell);
+
+        assert_eq!(kin_from(4, 20), Some(160));
+        assert_eq!(kin_from(1, 1), Some(1));
+        assert_eq!(kin_from(14, 1), None);
+        assert_eq!((tone_of(160), sign_of(160)), (4, 20));
+        assert_eq!((tone_of(0), sign_of(0)), (13, 20));
+        assert_eq!((tone_of(261), sign_of(261)), (1, 1));
+        assert_eq!(engine.get_sign("jaguar"), None);
+        assert_eq!(engine.get_sign("wizard").unwrap().name, "Ix");
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// TzolkinError — failures reported by the Tzolkin engine
+// ---------------------------------------------------------------------------
+
+/// Why a Tzolkin operation failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TzolkinError {
+    /// A month or day out of range for the year
+    InvalidDate { year: i32, month: u32, day: u32 },
+    /// The embedded sign or tone data is inconsistent
+    DataCorrupt(String),
+}
+
+impl fmt::Display for TzolkinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDate { year, month, day } => {
+                write!(f, "invalid date: {}-{:02}-{:02}", year, month, day)
+            }
+            Self::DataCorrupt(detail) => write!(f, "corrupt tzolkin data: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for TzolkinError {}
@@ -0,0 +1,259 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::journal::{Journal, JournalError, JournalStore};
+use crate::types::{FeedbackEntry, FeedbackSummary, JournalKind, JournalPayload, JournalQuery};
+
+// ---------------------------------------------------------------------------
+// Feedback — user reactions to reading elements
+// ---------------------------------------------------------------------------
+//
+// Feedback is kept in the journal as its own payload kind, so any
+// [`JournalStore`] persists it alongside the readings it refers to. Each
+// entry's sentiment comes from its rating when there is one, mapping 1–5
+// onto -1.0 to 1.0, and otherwise from the words of its text: accuracy
+// words count for, misses against, and "not" or "didn't" just before a word
+// turns it around. Entries with neither are counted but carry no sentiment.
+
+/// Highest rating a user can give.
+pub const MAX_RATING: u8 = 5;
+
+const POSITIVE_WORDS: [&str; 14] = [
+    "accurate", "exactly", "helpful", "insightful", "meaningful", "perfect", "relevant",
+    "resonated", "resonates", "right", "spot", "true", "uncanny", "useful",
+];
+
+const NEGATIVE_WORDS: [&str; 10] = [
+    "confusing", "generic", "inaccurate", "irrelevant", "meaningless", "missed", "off",
+    "unhelpful", "vague", "wrong",
+];
+
+const NEGATORS: [&str; 6] = ["not", "didn't", "doesn't", "isn't", "wasn't", "never"];
+
+/// Why feedback could not be recorded or read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedbackError {
+    /// Feedback must name what it is about
+    EmptyElement,
+    /// A rating outside 1–[`MAX_RATING`]
+    InvalidRating(u8),
+    /// The journal store failed
+    Store(JournalError),
+}
+
+impl fmt::Display for FeedbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyElement => f.write_str("feedback has no element"),
+            Self::InvalidRating(r) => write!(f, "rating {} is outside 1-{}", r, MAX_RATING),
+            Self::Store(e) => write!(f, "feedback store: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FeedbackError {}
+
+/// Element name for feedback on a hexagram.
+pub fn hexagram_element(number: u32) -> String {
+    format!("hexagram:{}", number)
+}
+
+/// Sentiment of free text from -1.0 to 1.0, or `None` if no word in it
+/// speaks to accuracy.
+pub fn text_sentiment(text: &str) -> Option<f64> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+        .filter(|w| !w.is_empty())
+        .collect();
+    let (mut positive, mut negative) = (0u32, 0u32);
+    for (i, word) in words.iter().enumerate() {
+        let polarity = if POSITIVE_WORDS.contains(word) {
+            1
+        } else if NEGATIVE_WORDS.contains(word) {
+            -1
+        } else {
+            continue;
+        };
+        let negated = words[i.saturating_sub(2)..i].iter().any(|w| NEGATORS.contains(w));
+        if (polarity > 0) != negated {
+            positive += 1;
+        } else {
+            negative += 1;
+        }
+    }
+    let total = positive + negative;
+    (total > 0).then(|| (f64::from(positive) - f64::from(negative)) / f64::from(total))
+}
+
+/// Sentiment of one entry from -1.0 to 1.0: its rating if it has one,
+/// otherwise its text.
+pub fn entry_sentiment(entry: &FeedbackEntry) -> Option<f64> {
+    match entry.rating {
+        Some(r) => Some((f64::from(r) - 3.0) / 2.0),
+        None => text_sentiment(&entry.user_text),
+    }
+}
+
+/// Summaries of `entries` per element, most discussed first.
+pub fn summarize(entries: &[FeedbackEntry]) -> Vec<FeedbackSummary> {
+    let mut by_element: BTreeMap<&str, Vec<&FeedbackEntry>> = BTreeMap::new();
+    for entry in entries {
+        by_element.entry(&entry.element).or_default().push(entry);
+    }
+    let mean = |values: &[f64]| {
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    };
+    let mut summaries: Vec<FeedbackSummary> = by_element
+        .into_iter()
+        .map(|(element, group)| {
+            let ratings: Vec<f64> = group.iter().filter_map(|e| e.rating).map(f64::from).collect();
+            let sentiments: Vec<f64> = group.iter().filter_map(|e| entry_sentiment(e)).collect();
+            FeedbackSummary {
+                element: element.to_string(),
+                count: group.len(),
+                rated: ratings.len(),
+                mean_rating: mean(&ratings),
+                sentiment: mean(&sentiments),
+                last_timestamp: group.iter().map(|e| e.timestamp).max().unwrap_or(0),
+            }
+        })
+        .collect();
+    // Stable, so ties stay in element order
+    summaries.sort_by_key(|s| std::cmp::Reverse(s.count));
+    summaries
+}
+
+// ---------------------------------------------------------------------------
+// FeedbackLog — feedback over a journal store
+// ---------------------------------------------------------------------------
+
+/// Records feedback in a [`Journal`] and aggregates it per element.
+pub struct FeedbackLog<S: JournalStore> {
+    journal: Journal<S>,
+}
+
+impl<S: JournalStore> FeedbackLog<S> {
+    pub fn new(store: S) -> Self {
+        Self {
+            journal: Journal::new(store),
+        }
+    }
+
+    pub fn journal(&self) -> &Journal<S> {
+        &self.journal
+    }
+
+    /// Record a reaction and return its journal entry id.
+    ///
+    /// # Errors
+    /// Returns an error if the element is blank, the rating is outside
+    /// 1–[`MAX_RATING`] or the store rejects the entry.
+    pub fn record(&mut self, entry: FeedbackEntry) -> Result<String, FeedbackError> {
+        if entry.element.trim().is_empty() {
+            return Err(FeedbackError::EmptyElement);
+        }
+        if let Some(r) = entry.rating.filter(|r| !(1..=MAX_RATING).contains(r)) {
+            return Err(FeedbackError::InvalidRating(r));
+        }
+        self.journal.record_feedback(entry, &[]).map_err(FeedbackError::Store)
+    }
+
+    /// Every feedback entry, oldest first.
+    ///
+    /// # Errors
+    /// Returns [`FeedbackError::Store`] if the store cannot be read.
+    pub fn entries(&self) -> Result<Vec<FeedbackEntry>, FeedbackError> {
+        let query = JournalQuery {
+            kind: Some(JournalKind::Feedback),
+            ..Default::default()
+        };
+        Ok(self
+            .journal
+            .query(&query)
+            .map_err(FeedbackError::Store)?
+            .into_iter()
+            .filter_map(|e| match e.payload {
+                JournalPayload::Feedback(feedback) => Some(feedback),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Feedback on one element, oldest first.
+    ///
+    /// # Errors
+    /// Returns [`FeedbackError::Store`] if the store cannot be read.
+    pub fn for_element(&self, element: &str) -> Result<Vec<FeedbackEntry>, FeedbackError> {
+        Ok(self.entries()?.into_iter().filter(|e| e.element == element).collect())
+    }
+
+    /// Summaries of every element with feedback, most discussed first.
+    ///
+    /// # Errors
+    /// Returns [`FeedbackError::Store`] if the store cannot be read.
+    pub fn summaries(&self) -> Result<Vec<FeedbackSummary>, FeedbackError> {
+        Ok(summarize(&self.entries()?))
+    }
+
+    /// Summary for one element, or `None` if it has no feedback.
+    ///
+    /// # Errors
+    /// Returns [`FeedbackError::Store`] if the store cannot be read.
+    pub fn summary(&self, element: &str) -> Result<Option<FeedbackSummary>, FeedbackError> {
+        Ok(summarize(&self.for_element(element)?).pop())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::journal::MemoryStore;
+
+    fn entry(element: &str, text: &str, rating: Option<u8>, timestamp: u64) -> FeedbackEntry {
+        FeedbackEntry {
+            element: element.to_string(),
+            user_text: text.to_string(),
+            timestamp,
+            rating,
+        }
+    }
+
+    #[test]
+    fn sentiment_from_ratings_and_text() {
+        assert_eq!(text_sentiment("That was spot on, uncanny really"), Some(1.0));
+        assert_eq!(text_sentiment("It didn't resonate, felt wrong"), Some(-1.0));
+        assert_eq!(text_sentiment("not accurate at all"), Some(-1.0));
+        assert_eq!(text_sentiment("Helpful but a bit vague"), Some(0.0));
+        assert_eq!(text_sentiment("I'll think about it"), None);
+        assert_eq!(entry_sentiment(&entry("cups_02", "wrong", Some(5), 0)), Some(1.0));
+        assert_eq!(entry_sentiment(&entry("cups_02", "", Some(2), 0)), Some(-0.5));
+    }
+
+    #[test]
+    fn records_and_aggregates_through_the_journal() {
+        let mut log = FeedbackLog::new(MemoryStore::default());
+        let tower = "major_16_tower";
+        log.record(entry(tower, "Uncannily accurate", Some(5), 1_000)).unwrap();
+        log.record(entry(tower, "This one missed", None, 3_000)).unwrap();
+        log.record(entry(&hexagram_element(44), "Meaningful", Some(4), 2_000)).unwrap();
+        assert_eq!(
+            log.record(entry(tower, "", Some(6), 0)),
+            Err(FeedbackError::InvalidRating(6))
+        );
+        assert_eq!(log.record(entry(" ", "", None, 0)), Err(FeedbackError::EmptyElement));
+
+        let summaries = log.summaries().unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].element, tower);
+        assert_eq!((summaries[0].count, summaries[0].rated), (2, 1));
+        assert_eq!(summaries[0].mean_rating, Some(5.0));
+        assert_eq!(summaries[0].sentiment, Some(0.0));
+        assert_eq!(summaries[0].last_timestamp, 3_000);
+
+        let hexagram = log.summary("hexagram:44").unwrap().unwrap();
+        assert_eq!(hexagram.sentiment, Some(0.5));
+        assert!(log.summary("cups_02").unwrap().is_none());
+        assert_eq!(log.journal().list().unwrap().len(), 3);
+    }
+}
@@ -0,0 +1,258 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::engines::{astrology, iching, tarot};
+use crate::identity;
+use crate::types::BirthData;
+
+// ---------------------------------------------------------------------------
+// Golden test vectors
+// ---------------------------------------------------------------------------
+//
+// Canonical input/output pairs produced by this crate, written as JSON so the
+// TypeScript plugin (or any binding) can check its own results against the
+// Rust reference implementation.
+
+/// A single named input/output pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenVector {
+    pub name: String,
+    pub input: Value,
+    pub output: Value,
+}
+
+/// All vectors for one engine, written to `<engine>.json`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenSuite {
+    pub engine: String,
+    /// Crate version that produced the vectors
+    pub version: String,
+    pub vectors: Vec<GoldenVector>,
+}
+
+fn vector(name: &str, input: Value, output: Value) -> GoldenVector {
+    GoldenVector {
+        name: name.to_string(),
+        input,
+        output,
+    }
+}
+
+fn suite(engine: &str, vectors: Vec<GoldenVector>) -> GoldenSuite {
+    GoldenSuite {
+        engine: engine.to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        vectors,
+    }
+}
+
+/// Reference birth data used by the chart vectors.
+fn reference_births() -> Vec<(&'static str, BirthData)> {
+    vec![
+        (
+            "new_york_1990",
+            BirthData {
+                year: 1990,
+                month: 6,
+                day: Some(15),
+                hour: Some(14),
+                minute: Some(30),
+                latitude: Some(40.7128),
+                longitude: Some(-74.0060),
+                timezone: Some(-4.0),
+            },
+        ),
+        (
+            "london_j2000",
+            BirthData {
+                year: 2000,
+                month: 1,
+                day: Some(1),
+                hour: Some(12),
+                minute: Some(0),
+                latitude: Some(51.5074),
+                longitude: Some(-0.1278),
+                timezone: Some(0.0),
+            },
+        ),
+    ]
+}
+
+/// Julian Day conversions, sun signs, sign positions and natal charts.
+pub fn astrology_vectors() -> GoldenSuite {
+    let mut vectors = Vec::new();
+
+    for (y, m, d, h, min) in [(2000, 1, 1, 12, 0), (1957, 10, 4, 19, 28), (1990, 6, 15, 18, 30)] {
+        vectors.push(vector(
+            &format!("julian_day_{}_{:02}_{:02}", y, m, d),
+            json!({ "year": y, "month": m, "day": d, "hour": h, "minute": min }),
+            json!(astrology::to_julian_day(y, m, d, h, min)),
+        ));
+    }
+
+    for (m, d) in [(1, 15), (3, 25), (7, 4), (8, 15), (12, 25)] {
+        vectors.push(vector(
+            &format!("sun_sign_{:02}_{:02}", m, d),
+            json!({ "month": m, "day": d }),
+            json!(astrology::calculate_sun_sign(m, d)),
+        ));
+    }
+
+    for deg in [0.0, 45.5, 120.0, 359.99] {
+        vectors.push(vector(
+            &format!("degrees_to_sign_{}", deg),
+            json!({ "totalDegrees": deg }),
+            json!(astrology::degrees_to_sign(deg)),
+        ));
+    }
+
+    for (name, birth) in reference_births() {
+        vectors.push(vector(
+            &format!("natal_chart_{}", name),
+            json!(birth),
+            json!(astrology::calculate_natal_chart(&birth)),
+        ));
+    }
+
+    suite("astrology", vectors)
+}
+
+/// Binary pattern lookups for every hexagram, plus seeded casts. Like the
+/// tarot draws, the cast vectors are only stable for this crate's RNG.
+pub fn iching_vectors() -> GoldenSuite {
+    let mut vectors: Vec<GoldenVector> = (0..64u32)
+        .map(|n| {
+            let binary: String = (0..6)
+                .map(|bit| if n & (1 << bit) != 0 { '1' } else { '0' })
+                .collect();
+            let number = iching::binary_to_hexagram_number(&binary).ok();
+            vector(
+                &format!("binary_{}", binary),
+                json!({ "binary": binary }),
+                json!(number),
+            )
+        })
+        .collect();
+
+    for seed in [1u64, 42, 2024] {
+        let cast = iching::cast_hexagram_with_rng(&mut iching::seeded_rng(seed))
+            .expect("embedded hexagrams are valid");
+        vectors.push(vector(
+            &format!("seeded_cast_{}", seed),
+            json!({ "seed": seed }),
+            json!(cast),
+        ));
+    }
+
+    suite("iching", vectors)
+}
+
+/// Seeded shuffles and draws, plus the hash-based card of the day. The seeded
+/// RNG is only stable for a given version of this crate, so the draw vectors
+/// target bindings to it rather than independent reimplementations.
+pub fn tarot_vectors() -> GoldenSuite {
+    let mut vectors: Vec<GoldenVector> = [1u64, 42, 2024]
+        .iter()
+        .map(|&seed| {
+            let mut rng = tarot::seeded_rng(seed);
+            let mut deck = tarot::create_deck();
+            tarot::shuffle_deck_with_rng(&mut deck, &mut rng);
+            let drawn: Vec<Value> = tarot::draw_cards_with_rng(&deck, 10, true, &mut rng)
+                .expect("a full deck holds 10 cards")
+                .iter()
+                .map(|d| json!({ "id": d.card.id, "reversed": d.reversed }))
+                .collect();
+            vector(
+                &format!("seeded_draw_{}", seed),
+                json!({ "seed": seed, "count": 10, "allowReversals": true }),
+                json!(drawn),
+            )
+        })
+        .collect();
+
+    for (date, user) in [((2024, 1, 1), None), ((2024, 1, 1), Some("user-1"))] {
+        let (y, m, d) = date;
+        let daily = tarot::card_of_the_day(y, m, d, user);
+        vectors.push(vector(
+            &format!("card_of_the_day_{}_{}", y, user.unwrap_or("anonymous")),
+            json!({ "year": y, "month": m, "day": d, "userSeed": user }),
+            json!({ "id": daily.card.id, "reversed": daily.reversed }),
+        ));
+    }
+
+    suite("tarot", vectors)
+}
+
+/// Content-addressed ids, so both sides agree on persistence keys.
+pub fn identity_vectors() -> GoldenSuite {
+    let mut vectors: Vec<GoldenVector> = reference_births()
+        .into_iter()
+        .map(|(name, birth)| {
+            vector(
+                &format!("chart_id_{}", name),
+                json!(birth),
+                json!(identity::chart_id(&birth)),
+            )
+        })
+        .collect();
+
+    for text in ["", "a", "foobar"] {
+        vectors.push(vector(
+            &format!("fnv1a_64_{}", text),
+            json!({ "text": text }),
+            json!(format!("{:016x}", identity::fnv1a_64(text.as_bytes()))),
+        ));
+    }
+
+    suite("identity", vectors)
+}
+
+/// Every golden suite this crate publishes.
+pub fn all_suites() -> Vec<GoldenSuite> {
+    vec![astrology_vectors(), iching_vectors(), tarot_vectors(), identity_vectors()]
+}
+
+/// Write each suite to `<dir>/<engine>.json`, creating `dir` if needed.
+/// Returns the paths written.
+pub fn write_golden_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    all_suites()
+        .into_iter()
+        .map(|s| {
+            let path = dir.join(format!("{}.json", s.engine));
+            let body = serde_json::to_string_pretty(&s)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            fs::write(&path, body + "\n")?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suites_are_deterministic() {
+        assert_eq!(all_suites(), all_suites());
+        let iching = iching_vectors();
+        assert_eq!(iching.vectors.iter().filter(|v| v.name.starts_with("binary_")).count(), 64);
+        assert!(iching.vectors.iter().all(|v| !v.output.is_null()));
+    }
+
+    #[test]
+    fn writes_one_file_per_suite() {
+        let dir = std::env::temp_dir().join(format!("mysticism-golden-{}", std::process::id()));
+        let paths = write_golden_files(&dir).unwrap();
+        assert_eq!(paths.len(), all_suites().len());
+
+        let text = fs::read_to_string(dir.join("astrology.json")).unwrap();
+        let parsed: GoldenSuite = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, astrology_vectors());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::types::BirthData;
+
+// ---------------------------------------------------------------------------
+// Canonical identifiers
+// ---------------------------------------------------------------------------
+//
+// Ids have the form `<engine>:<16 hex digits>` where the digits are the
+// 64-bit FNV-1a hash of `engine`, the seed (or `-` when absent) and the
+// canonical JSON of the inputs, joined with NUL bytes. FNV-1a is used instead
+// of `std::hash` because it is stable across releases and trivial to port to
+// the TypeScript side, which checks itself against the same vectors in
+// data/identity/canonical.json.
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hash.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Serialize a value to compact JSON with object keys sorted, so that
+/// structurally equal inputs always produce the same bytes.
+///
+/// Numbers are written as serde_json writes them, except that a float with
+/// no fractional part below 2^53 loses its `.0` (`-4.0` becomes `-4`,
+/// `-0.0` becomes `0`). The TypeScript side writes the same layout by hand.
+///
+/// # Errors
+/// Returns an error string if the value cannot be represented as JSON.
+pub fn canonical_json<T: Serialize>(value: &T) -> Result<String, String> {
+    // `serde_json::Value` stores objects in a BTreeMap, which sorts keys.
+    let value = serde_json::to_value(value).map_err(|e| e.to_string())?;
+    let mut out = String::new();
+    write_canonical(&value, &mut out).map_err(|e| e.to_string())?;
+    Ok(out)
+}
+
+/// Largest magnitude at which every integral float is exact, as in
+/// JavaScript's `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+fn write_canonical(value: &Value, out: &mut String) -> serde_json::Result<()> {
+    match value {
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if n.is_f64() && f.fract() == 0.0 && f.abs() <= MAX_SAFE_INTEGER => {
+                out.push_str(&(f as i64).to_string());
+            }
+            _ => out.push_str(&n.to_string()),
+        },
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push('{');
+            for (i, (key, item)) in map.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::to_string(key)?);
+                out.push(':');
+                write_canonical(item, out)?;
+            }
+            out.push('}');
+        }
+        other => out.push_str(&serde_json::to_string(other)?),
+    }
+    Ok(())
+}
+
+/// Build a content-addressed id from an engine name, optional RNG seed and
+/// the reading inputs.
+///
+/// # Errors
+/// Returns an error string if the inputs cannot be serialized.
+pub fn content_id<T: Serialize>(engine: &str, seed: Option<u64>, inputs: &T) -> Result<String, String> {
+    let seed_part = seed.map_or_else(|| "-".to_string(), |s| s.to_string());
+    let payload = format!("{}\0{}\0{}", engine, seed_part, canonical_json(inputs)?);
+    Ok(format!("{}:{:016x}", engine, fnv1a_64(payload.as_bytes())))
+}
+
+/// Id for a natal chart computed from the given birth data.
+pub fn chart_id(birth_data: &BirthData) -> String {
+    content_id("astrology", None, birth_data).expect("BirthData is always serializable")
+}
+
+// ---------------------------------------------------------------------------
+// Deduplication
+// ---------------------------------------------------------------------------
+
+/// Remove items whose id has already been seen, keeping the first occurrence
+/// and preserving order.
+pub fn dedup_by_id<T, F>(items: Vec<T>, id: F) -> Vec<T>
+where
+    F: Fn(&T) -> String,
+{
+    let mut seen = HashSet::new();
+    items.into_iter().filter(|item| seen.insert(id(item))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::birth;
+
+    #[test]
+    fn fnv1a_reference_values() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn ids_are_stable_and_input_sensitive() {
+        let a = chart_id(&birth());
+        assert_eq!(a, chart_id(&birth()));
+        assert!(a.starts_with("astrology:"));
+
+        let mut other = birth();
+        other.minute = Some(31);
+        assert_ne!(a, chart_id(&other));
+
+        let seeded = content_id("tarot", Some(7), &"celtic_cross").unwrap();
+        assert_ne!(seeded, content_id("tarot", Some(8), &"celtic_cross").unwrap());
+    }
+
+    #[test]
+    fn matches_shared_vectors() {
+        let vectors: Vec<Value> =
+            serde_json::from_str(include_str!("../../data/identity/canonical.json")).unwrap();
+        for v in &vectors {
+            let input = &v["input"];
+            assert_eq!(canonical_json(input).unwrap(), v["canonical"], "{}", v["name"]);
+            let id = content_id(v["engine"].as_str().unwrap(), v["seed"].as_u64(), input);
+            assert_eq!(id.unwrap(), v["id"], "{}", v["name"]);
+        }
+        // The typed birth data hashes like its JSON form
+        assert_eq!(chart_id(&birth()), vectors[0]["id"]);
+    }
+
+    #[test]
+    fn dedup_keeps_first_occurrence() {
+        let items = vec![("a", 1), ("b", 2), ("a", 3)];
+        let unique = dedup_by_id(items, |(k, _)| k.to_string());
+        assert_eq!(unique, vec![("a", 1), ("b", 2)]);
+    }
+}
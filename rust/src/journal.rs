@@ -0,0 +1,402 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use crate::identity;
+use crate::types::{
+    CastResult, ExportedReading, FeedbackEntry, IChingConsultation, JournalEntry, JournalPayload,
+    JournalQuery, NatalChart, Reading,
+};
+
+/// Why a journal could not be written or read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalError {
+    /// The store's backing file could not be opened, read or written
+    Io(String),
+    /// An entry could not be serialized or given an id
+    Encode(String),
+    /// A stored entry could not be parsed
+    DataCorrupt(String),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(message) => write!(f, "journal i/o: {}", message),
+            Self::Encode(message) => write!(f, "cannot encode journal entry: {}", message),
+            Self::DataCorrupt(detail) => write!(f, "corrupt journal: {}", detail),
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+// ---------------------------------------------------------------------------
+// Storage
+// ---------------------------------------------------------------------------
+
+/// Where journal entries are kept. Stores only append and list; filtering
+/// happens in [`Journal`].
+pub trait JournalStore {
+    /// Persist one entry.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the entry cannot be stored.
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), JournalError>;
+
+    /// Every stored entry, oldest first.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store cannot be read.
+    fn entries(&self) -> Result<Vec<JournalEntry>, JournalError>;
+}
+
+/// Keeps entries in memory for the life of the process.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    entries: Vec<JournalEntry>,
+}
+
+impl JournalStore for MemoryStore {
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), JournalError> {
+        self.entries.push(entry.clone());
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        Ok(self.entries.clone())
+    }
+}
+
+/// Appends one JSON object per line to a file. A missing file is an empty
+/// journal.
+#[derive(Debug, Clone)]
+pub struct JsonLinesStore {
+    path: PathBuf,
+}
+
+impl JsonLinesStore {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl JournalStore for JsonLinesStore {
+    fn append(&mut self, entry: &JournalEntry) -> Result<(), JournalError> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|e| JournalError::Io(e.to_string()))?;
+        }
+        let line = serde_json::to_string(entry).map_err(|e| JournalError::Encode(e.to_string()))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| JournalError::Io(format!("{}: {}", self.path.display(), e)))?;
+        writeln!(file, "{}", line).map_err(|e| JournalError::Io(e.to_string()))
+    }
+
+    fn entries(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        let file = match fs::File::open(&self.path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(JournalError::Io(format!("{}: {}", self.path.display(), e))),
+        };
+        BufReader::new(file)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !matches!(line, Ok(l) if l.trim().is_empty()))
+            .map(|(i, line)| {
+                let line = line.map_err(|e| JournalError::Io(e.to_string()))?;
+                serde_json::from_str(&line).map_err(|e| {
+                    let at = format!("{} line {}", self.path.display(), i + 1);
+                    JournalError::DataCorrupt(format!("{}: {}", at, e))
+                })
+            })
+            .collect()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Journal
+// ---------------------------------------------------------------------------
+
+/// A log of completed readings, casts and charts over a [`JournalStore`].
+pub struct Journal<S: JournalStore> {
+    store: S,
+}
+
+impl<S: JournalStore> Journal<S> {
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Record any payload and return the new entry's id.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record(
+        &mut self,
+        payload: JournalPayload,
+        timestamp: u64,
+        tags: &[&str],
+        note: Option<&str>,
+    ) -> Result<String, JournalError> {
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        let id = identity::content_id("journal", None, &(timestamp, &tags, &payload))
+            .map_err(JournalError::Encode)?;
+        let entry = JournalEntry {
+            id: id.clone(),
+            timestamp,
+            tags,
+            note: note.map(str::to_string),
+            payload,
+        };
+        self.store.append(&entry)?;
+        Ok(id)
+    }
+
+    /// Record a completed tarot reading.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_tarot(
+        &mut self,
+        reading: ExportedReading,
+        tags: &[&str],
+    ) -> Result<String, JournalError> {
+        let timestamp = reading.timestamp;
+        self.record(JournalPayload::Tarot(reading), timestamp, tags, None)
+    }
+
+    /// Record a [`Reading`] under its own timestamp and tags.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_reading(&mut self, reading: Reading) -> Result<String, JournalError> {
+        let timestamp = reading.timestamp;
+        let tags = reading.tags.clone();
+        let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+        self.record(JournalPayload::TarotReading(Box::new(reading)), timestamp, &tags, None)
+    }
+
+    /// Record an I Ching cast.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_cast(
+        &mut self,
+        cast: CastResult,
+        timestamp: u64,
+        tags: &[&str],
+    ) -> Result<String, JournalError> {
+        self.record(JournalPayload::Iching(cast), timestamp, tags, None)
+    }
+
+    /// Record an I Ching consultation under its own timestamp and tags.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_consultation(
+        &mut self,
+        consultation: IChingConsultation,
+    ) -> Result<String, JournalError> {
+        let timestamp = consultation.timestamp;
+        let tags = consultation.tags.clone();
+        let tags: Vec<&str> = tags.iter().map(String::as_str).collect();
+        let payload = JournalPayload::IchingConsultation(Box::new(consultation));
+        self.record(payload, timestamp, &tags, None)
+    }
+
+    /// Record a natal chart.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_chart(
+        &mut self,
+        chart: NatalChart,
+        timestamp: u64,
+        tags: &[&str],
+    ) -> Result<String, JournalError> {
+        self.record(JournalPayload::Chart(Box::new(chart)), timestamp, tags, None)
+    }
+
+    /// Record a user's feedback under its own timestamp.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store rejects the entry.
+    pub fn record_feedback(
+        &mut self,
+        feedback: FeedbackEntry,
+        tags: &[&str],
+    ) -> Result<String, JournalError> {
+        let timestamp = feedback.timestamp;
+        self.record(JournalPayload::Feedback(feedback), timestamp, tags, None)
+    }
+
+    /// Every entry, oldest first.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store cannot be read.
+    pub fn list(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        let mut entries = self.store.entries()?;
+        entries.sort_by_key(|e| e.timestamp);
+        Ok(entries)
+    }
+
+    /// Entries matching every set field of `query`, oldest first.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store cannot be read.
+    pub fn query(&self, query: &JournalQuery) -> Result<Vec<JournalEntry>, JournalError> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(|e| query.from.is_none_or(|from| e.timestamp >= from))
+            .filter(|e| query.to.is_none_or(|to| e.timestamp < to))
+            .filter(|e| query.tag.as_ref().is_none_or(|t| e.tags.contains(t)))
+            .filter(|e| query.kind.is_none_or(|k| e.payload.kind() == k))
+            .filter(|e| query.hexagram.is_none_or(|n| cast_involves(e.payload.cast(), n)))
+            .collect())
+    }
+
+    /// Look up an entry by id.
+    ///
+    /// # Errors
+    /// Returns a [`JournalError`] if the store cannot be read.
+    pub fn get(&self, id: &str) -> Result<Option<JournalEntry>, JournalError> {
+        Ok(self.store.entries()?.into_iter().find(|e| e.id == id))
+    }
+}
+
+fn cast_involves(cast: Option<&CastResult>, hexagram: u32) -> bool {
+    cast.is_some_and(|c| {
+        c.hexagram_number == hexagram || c.transformed_hexagram_number == Some(hexagram)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::iching::IChingEngine;
+    use crate::engines::tarot::export::export_reading;
+    use crate::engines::tarot::TarotEngine;
+    use crate::types::JournalKind;
+
+    fn reading(timestamp: u64) -> ExportedReading {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("single").unwrap();
+        let drawn = engine.draw_cards(&engine.create_deck(), 1, false).unwrap();
+        export_reading(&engine.interpret_reading(spread, &drawn).unwrap(), timestamp)
+    }
+
+    fn fill<S: JournalStore>(journal: &mut Journal<S>) {
+        journal.record_tarot(reading(3_000), &["love"]).unwrap();
+        journal.record_tarot(reading(1_000), &["work", "weekly"]).unwrap();
+        journal.record_cast(IChingEngine::new().cast_hexagram(), 2_000, &["work"]).unwrap();
+    }
+
+    #[test]
+    fn memory_journal_queries() {
+        let mut journal = Journal::new(MemoryStore::default());
+        fill(&mut journal);
+
+        let all = journal.list().unwrap();
+        assert_eq!(all.iter().map(|e| e.timestamp).collect::<Vec<_>>(), vec![1_000, 2_000, 3_000]);
+
+        let work = JournalQuery {
+            tag: Some("work".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(journal.query(&work).unwrap().len(), 2);
+
+        let tarot_before_3s = JournalQuery {
+            to: Some(3_000),
+            kind: Some(JournalKind::Tarot),
+            ..Default::default()
+        };
+        let found = journal.query(&tarot_before_3s).unwrap();
+        assert_eq!(found.len(), 1);
+        assert!(journal.get(&found[0].id).unwrap().is_some());
+    }
+
+    #[test]
+    fn json_lines_journal_persists() {
+        let path = std::env::temp_dir()
+            .join(format!("mysticism-journal-{}", std::process::id()))
+            .join("journal.jsonl");
+        let mut journal = Journal::new(JsonLinesStore::new(&path));
+        assert!(journal.list().unwrap().is_empty());
+        fill(&mut journal);
+
+        let reopened = Journal::new(JsonLinesStore::new(&path));
+        let entries = reopened.list().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].payload.kind(), JournalKind::Iching);
+
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(file, "not json").unwrap();
+        assert!(matches!(reopened.list(), Err(JournalError::DataCorrupt(_))));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn readings_keep_their_tags() {
+        let engine = TarotEngine::new();
+        let request = crate::types::ReadingRequest {
+            tags: vec!["love".to_string()],
+            ..Default::default()
+        };
+        let mut journal = Journal::new(MemoryStore::default());
+        let id = journal.record_reading(engine.read(&request, 5_000).unwrap()).unwrap();
+
+        let entry = journal.get(&id).unwrap().unwrap();
+        assert_eq!(entry.tags, vec!["love"]);
+        assert_eq!(entry.payload.kind(), JournalKind::TarotReading);
+    }
+
+    #[test]
+    fn consultations_by_date_and_hexagram() {
+        let engine = IChingEngine::new();
+        let mut journal = Journal::new(MemoryStore::default());
+        let consult = |lines: [u8; 6], timestamp: u64| IChingConsultation {
+            question: Some("Should I move?".to_string()),
+            method: crate::types::CastMethod::Manual,
+            cast: engine.cast_from_lines(&lines).unwrap(),
+            rule: crate::types::ChangingLineRule::ZhuXi,
+            timestamp,
+            tags: vec!["home".to_string()],
+        };
+        journal.record_consultation(consult([9, 7, 7, 7, 7, 7], 1_000)).unwrap();
+        journal.record_consultation(consult([8; 6], 2_000)).unwrap();
+        fill(&mut journal);
+
+        let qian = JournalQuery {
+            hexagram: Some(44),
+            ..Default::default()
+        };
+        let found = journal.query(&qian).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].payload.kind(), JournalKind::IchingConsultation);
+        assert_eq!(found[0].tags, vec!["home"]);
+
+        let later = JournalQuery {
+            from: Some(1_500),
+            kind: Some(JournalKind::IchingConsultation),
+            ..Default::default()
+        };
+        let found = journal.query(&later).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].payload.cast().unwrap().hexagram_number, 2);
+    }
+}
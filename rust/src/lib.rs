@@ -1,7 +1,39 @@
 pub mod types;
 pub mod engines;
+pub mod identity;
+pub mod journal;
+pub mod daily;
+pub mod compatibility;
+pub mod feedback;
+pub mod prompting;
+pub mod scheduler;
+pub mod router;
+pub mod golden;
+pub mod rng;
+pub(crate) mod text;
+#[cfg(test)]
+mod test_support;
 
 pub use types::*;
-pub use engines::tarot::TarotEngine;
-pub use engines::iching::IChingEngine;
+pub use engines::tarot::{TarotEngine, TarotError};
+pub use engines::iching::{IChingEngine, IChingError};
 pub use engines::astrology::AstrologyEngine;
+pub use engines::bazi::{BaziEngine, BaziError};
+pub use engines::numerology::{NumerologyEngine, NumerologyError};
+pub use engines::runes::{RuneEngine, RuneError};
+pub use engines::geomancy::{GeomancyEngine, GeomancyError};
+pub use engines::ogham::{OghamEngine, OghamError};
+pub use engines::dreams::{DreamEngine, DreamError};
+pub use engines::tzolkin::{TzolkinEngine, TzolkinError};
+pub use engines::pendulum::{PendulumEngine, PendulumError};
+pub use engines::bibliomancy::{BibliomancyEngine, BibliomancyError};
+pub use engines::kabbalah::{KabbalahEngine, KabbalahError};
+pub use engines::tasseography::{TasseographyEngine, TasseographyError};
+pub use engines::dice::{DiceEngine, DiceError};
+pub use engines::dominoes::{DominoEngine, DominoError};
+pub use daily::{DailyReader, DailyReadingError};
+pub use compatibility::{compatibility_report, CompatibilityError};
+pub use feedback::{FeedbackError, FeedbackLog};
+pub use prompting::{build_context, PromptContext};
+pub use scheduler::{upcoming_readings, ScheduleError};
+pub use router::{QuestionRouter, RouterError};
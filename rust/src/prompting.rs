@@ -0,0 +1,329 @@
+use crate::engines::iching::get_hexagram;
+use crate::text::capitalize;
+use crate::types::{
+    CastResult, DrawnCard, Hexagram, NatalChart, PlanetPosition, PromptOptions,
+    ReadingInterpretation, Verbosity,
+};
+
+// ---------------------------------------------------------------------------
+// Prompt context — readings as compact text for a language model
+// ---------------------------------------------------------------------------
+//
+// Each reading type lists its lines most important first. When a budget is
+// set and the requested verbosity does not fit, the next terser level is
+// tried, and at `Brief` trailing lines are dropped until the rest fits.
+// Tokens are estimated at four characters each, which is close enough for
+// English text under the common tokenizers without depending on one.
+
+/// Characters per token assumed by [`estimate_tokens`].
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// Anything that can be described to a language model.
+pub trait PromptContext {
+    /// Lines describing `self` at the given verbosity, most important first.
+    fn context_lines(&self, verbosity: Verbosity) -> Vec<String>;
+}
+
+/// Rough token count of `text`.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Join `lines` into as many as fit in `max_tokens`, cutting the first line
+/// short if even it does not fit.
+fn fit_lines(lines: &[String], max_tokens: usize) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let candidate = if out.is_empty() { line.clone() } else { format!("{}\n{}", out, line) };
+        if estimate_tokens(&candidate) > max_tokens {
+            break;
+        }
+        out = candidate;
+    }
+    if out.is_empty() {
+        if let Some(first) = lines.first() {
+            let keep = (max_tokens * CHARS_PER_TOKEN).saturating_sub(1);
+            out = first.chars().take(keep).collect();
+            if keep > 0 {
+                out.push('…');
+            }
+        }
+    }
+    out
+}
+
+/// Context for `value`, within the options' budget.
+pub fn build_context<T: PromptContext + ?Sized>(value: &T, options: &PromptOptions) -> String {
+    let Some(max_tokens) = options.max_tokens else {
+        return value.context_lines(options.verbosity).join("\n");
+    };
+    for verbosity in [Verbosity::Detailed, Verbosity::Standard, Verbosity::Brief] {
+        if verbosity > options.verbosity {
+            continue;
+        }
+        let text = value.context_lines(verbosity).join("\n");
+        if estimate_tokens(&text) <= max_tokens {
+            return text;
+        }
+    }
+    fit_lines(&value.context_lines(Verbosity::Brief), max_tokens)
+}
+
+// ---------------------------------------------------------------------------
+// Natal charts
+// ---------------------------------------------------------------------------
+
+fn placement(p: &PlanetPosition, with_house: bool) -> String {
+    let mut text = format!("{} {} {:.0}°", capitalize(&p.planet), capitalize(&p.sign), p.degrees);
+    if with_house {
+        text.push_str(&format!(" (house {})", p.house));
+    }
+    if p.retrograde {
+        text.push_str(" R");
+    }
+    text
+}
+
+/// Aspects shown at `Standard`; `Detailed` shows them all.
+const STANDARD_ASPECTS: usize = 5;
+
+impl PromptContext for NatalChart {
+    fn context_lines(&self, verbosity: Verbosity) -> Vec<String> {
+        let rising = format!(
+            "Ascendant {} {:.0}°",
+            capitalize(&self.ascendant.sign),
+            self.ascendant.degrees
+        );
+        if verbosity == Verbosity::Brief {
+            return vec![format!(
+                "Natal chart: {}, {}, {}",
+                placement(&self.sun, false),
+                placement(&self.moon, false),
+                rising
+            )];
+        }
+        let planets = [
+            &self.sun, &self.moon, &self.mercury, &self.venus, &self.mars,
+            &self.jupiter, &self.saturn, &self.uranus, &self.neptune, &self.pluto,
+        ];
+        let mut lines = vec![format!(
+            "Natal chart: {}; Midheaven {} {:.0}°",
+            rising,
+            capitalize(&self.midheaven.sign),
+            self.midheaven.degrees
+        )];
+        lines.push(format!(
+            "Planets: {}",
+            planets.iter().map(|p| placement(p, true)).collect::<Vec<_>>().join(", ")
+        ));
+        let shown = match verbosity {
+            Verbosity::Detailed => self.aspects.len(),
+            _ => STANDARD_ASPECTS.min(self.aspects.len()),
+        };
+        if shown > 0 {
+            let aspects: Vec<String> = self.aspects[..shown]
+                .iter()
+                .map(|a| {
+                    let mut text = format!(
+                        "{} {} {}",
+                        capitalize(&a.planet1),
+                        a.aspect_name.to_lowercase(),
+                        capitalize(&a.planet2)
+                    );
+                    if verbosity == Verbosity::Detailed {
+                        text.push_str(&format!(" (orb {:.1}°, {})", a.orb, a.nature));
+                    }
+                    text
+                })
+                .collect();
+            lines.push(format!("Aspects: {}", aspects.join("; ")));
+        }
+        lines
+    }
+}
+
+// ---------------------------------------------------------------------------
+// I Ching casts
+// ---------------------------------------------------------------------------
+
+fn hexagram_title(number: u32, hexagram: Option<&Hexagram>) -> String {
+    match hexagram {
+        Some(h) => format!("{} {} ({})", number, h.name, h.english_name),
+        None => number.to_string(),
+    }
+}
+
+impl PromptContext for CastResult {
+    fn context_lines(&self, verbosity: Verbosity) -> Vec<String> {
+        let primary = get_hexagram(self.hexagram_number).ok();
+        let result = self.transformed_hexagram_number.map(|n| (n, get_hexagram(n).ok()));
+
+        let mut headline = format!(
+            "I Ching: hexagram {}",
+            hexagram_title(self.hexagram_number, primary.as_ref())
+        );
+        if !self.changing_lines.is_empty() {
+            let lines: Vec<String> = self.changing_lines.iter().map(usize::to_string).collect();
+            headline.push_str(&format!("; changing lines {}", lines.join(", ")));
+        }
+        if let Some((n, h)) = &result {
+            headline.push_str(&format!(" → {}", hexagram_title(*n, h.as_ref())));
+        }
+        let mut lines = vec![headline];
+        let Some(primary) = primary else {
+            return lines;
+        };
+        if verbosity == Verbosity::Brief {
+            return lines;
+        }
+
+        lines.push(format!("Keywords: {}", primary.keywords.join(", ")));
+        lines.push(format!("Judgment: {}", primary.judgment));
+        if verbosity == Verbosity::Detailed {
+            lines.push(format!("Image: {}", primary.image));
+        }
+        for line in primary.lines.iter().filter(|l| {
+            usize::try_from(l.position).is_ok_and(|p| self.changing_lines.contains(&p))
+        }) {
+            let mut text = format!("Line {}: {}", line.position, line.text);
+            if verbosity == Verbosity::Detailed {
+                text.push_str(&format!(" — {}", line.meaning));
+            }
+            lines.push(text);
+        }
+        if let Some((_, Some(h))) = &result {
+            lines.push(format!("Resulting keywords: {}", h.keywords.join(", ")));
+            if verbosity == Verbosity::Detailed {
+                lines.push(format!("Resulting judgment: {}", h.judgment));
+            }
+        }
+        lines
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tarot spreads
+// ---------------------------------------------------------------------------
+
+fn card_label(name: &str, reversed: bool) -> String {
+    if reversed { format!("{} (reversed)", name) } else { name.to_string() }
+}
+
+impl PromptContext for ReadingInterpretation {
+    fn context_lines(&self, verbosity: Verbosity) -> Vec<String> {
+        if verbosity == Verbosity::Brief {
+            let cards: Vec<String> = self
+                .positions
+                .iter()
+                .map(|p| format!("{}: {}", p.position_name, card_label(&p.card_name, p.reversed)))
+                .collect();
+            return vec![format!("Tarot, {}: {}", self.spread_name, cards.join("; "))];
+        }
+        let mut lines = vec![format!("Tarot, {}", self.spread_name)];
+        for p in &self.positions {
+            let mut text = format!(
+                "{}: {} — {}",
+                p.position_name,
+                card_label(&p.card_name, p.reversed),
+                p.keywords.join(", ")
+            );
+            if verbosity == Verbosity::Detailed {
+                text.push_str(&format!(". {}", p.meaning));
+                if !p.clarifiers.is_empty() {
+                    text.push_str(&format!(" Clarified by {}.", p.clarifiers.join(", ")));
+                }
+            }
+            lines.push(text);
+        }
+        if !self.synthesis.summary.is_empty() {
+            lines.push(format!("Synthesis: {}", self.synthesis.summary));
+        }
+        lines
+    }
+}
+
+fn card_keywords(d: &DrawnCard) -> &[String] {
+    if d.reversed { &d.card.keywords_reversed } else { &d.card.keywords_upright }
+}
+
+impl PromptContext for [DrawnCard] {
+    fn context_lines(&self, verbosity: Verbosity) -> Vec<String> {
+        if verbosity == Verbosity::Brief {
+            let cards: Vec<String> =
+                self.iter().map(|d| card_label(&d.card.name, d.reversed)).collect();
+            return vec![format!("Tarot cards: {}", cards.join(", "))];
+        }
+        self.iter()
+            .map(|d| {
+                let mut text = format!(
+                    "{}. {} — {}",
+                    d.position_index + 1,
+                    card_label(&d.card.name, d.reversed),
+                    card_keywords(d).join(", ")
+                );
+                if verbosity == Verbosity::Detailed {
+                    let meaning =
+                        if d.reversed { &d.card.meaning_reversed } else { &d.card.meaning_upright };
+                    text.push_str(&format!(". {}", meaning));
+                }
+                text
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::astrology::calculate_natal_chart;
+    use crate::engines::iching::IChingEngine;
+    use crate::engines::tarot::TarotEngine;
+    use crate::test_support::birth;
+
+    fn options(verbosity: Verbosity, max_tokens: Option<usize>) -> PromptOptions {
+        PromptOptions { verbosity, max_tokens }
+    }
+
+    #[test]
+    fn casts_at_each_verbosity() {
+        let cast = IChingEngine::new().cast_from_lines(&[9, 7, 7, 7, 7, 7]).unwrap();
+        let brief = build_context(&cast, &options(Verbosity::Brief, None));
+        assert_eq!(brief.lines().count(), 1);
+        assert!(brief.starts_with("I Ching: hexagram 1 Qian"));
+        assert!(brief.contains("changing lines 1 → 44"));
+
+        let standard = build_context(&cast, &options(Verbosity::Standard, None));
+        let detailed = build_context(&cast, &options(Verbosity::Detailed, None));
+        assert!(standard.contains("\nLine 1: ") && standard.contains("Judgment: "));
+        assert!(detailed.len() > standard.len() && standard.len() > brief.len());
+    }
+
+    #[test]
+    fn budgets_step_down_then_cut() {
+        let engine = TarotEngine::new();
+        let spread = engine.get_spread("celtic_cross").unwrap();
+        let drawn = engine.draw_cards(&engine.create_deck(), 10, true).unwrap();
+        let reading = engine.interpret_reading(spread, &drawn).unwrap();
+
+        let full = build_context(&reading, &options(Verbosity::Detailed, None));
+        let brief = build_context(&reading, &options(Verbosity::Brief, None));
+        let budget = estimate_tokens(&brief);
+        assert!(estimate_tokens(&full) > budget);
+        assert_eq!(build_context(&reading, &options(Verbosity::Detailed, Some(budget))), brief);
+
+        let cut = build_context(&reading, &options(Verbosity::Detailed, Some(10)));
+        assert!(estimate_tokens(&cut) <= 10 && cut.ends_with('…'));
+        assert_eq!(build_context(&drawn[..], &options(Verbosity::Brief, None)).lines().count(), 1);
+    }
+
+    #[test]
+    fn charts_lead_with_the_big_three() {
+        let chart = calculate_natal_chart(&birth());
+        let brief = build_context(&chart, &options(Verbosity::Brief, None));
+        assert!(brief.starts_with("Natal chart: Sun Gemini"));
+        assert!(brief.contains("Ascendant "));
+        let detailed = chart.context_lines(Verbosity::Detailed);
+        assert!(detailed[1].starts_with("Planets: Sun Gemini"));
+        assert!(detailed.last().unwrap().contains("orb "));
+    }
+}
@@ -0,0 +1,16 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// ---------------------------------------------------------------------------
+// Seeded randomness
+// ---------------------------------------------------------------------------
+//
+// Every engine that draws, casts or shuffles takes any `Rng`; this is the
+// one they re-export for reproducible results.
+
+/// A deterministic RNG for reproducible shuffles, draws and casts.
+///
+/// The same seed yields the same sequence for a given version of this crate.
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
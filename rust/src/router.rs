@@ -0,0 +1,240 @@
+use std::fmt;
+
+use crate::types::{Modality, RouteSuggestion, RouteTable, RouteTarget};
+
+// ---------------------------------------------------------------------------
+// Question router
+// ---------------------------------------------------------------------------
+//
+// Each rule's score is the sum of the weights of its keywords found in the
+// question, matched on whole words with a plural "s" allowed. Confidence is
+// a rule's share of all the weight found plus one, so a single weak keyword
+// gives a modest 0.5 and a question pulling two ways splits the difference.
+// A question matching nothing goes to the table's fallback with confidence
+// 0.0, which a host can read as "ask what kind of reading they want".
+
+const ROUTES_JSON: &str = include_str!("../../data/router/routes.json");
+
+/// Why a route table was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouterError {
+    /// Every problem found in the table
+    InvalidTable(String),
+}
+
+impl fmt::Display for RouterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTable(problems) => write!(f, "invalid route table: {}", problems),
+        }
+    }
+}
+
+impl std::error::Error for RouterError {}
+
+/// Check that the table has rules, that tarot targets name a spread and
+/// that every keyword is lowercase with a positive weight.
+///
+/// # Errors
+/// Returns [`RouterError::InvalidTable`] listing every problem found.
+pub fn validate_table(table: &RouteTable) -> Result<(), RouterError> {
+    let mut problems = Vec::new();
+    if table.routes.is_empty() {
+        problems.push("no routes".to_string());
+    }
+    let targets = table
+        .routes
+        .iter()
+        .map(|r| (r.modality, &r.template))
+        .chain([(table.fallback.modality, &table.fallback.template)]);
+    for (modality, template) in targets {
+        if modality == Modality::Tarot && template.is_none() {
+            problems.push("a tarot route has no spread".to_string());
+        }
+    }
+    for (i, rule) in table.routes.iter().enumerate() {
+        if rule.keywords.is_empty() {
+            problems.push(format!("route {} has no keywords", i));
+        }
+        for (keyword, weight) in &rule.keywords {
+            if keyword.trim().is_empty() || keyword.to_lowercase() != *keyword {
+                problems.push(format!("route {} keyword '{}' must be lowercase", i, keyword));
+            }
+            if !weight.is_finite() || *weight <= 0.0 {
+                problems.push(format!("route {} keyword '{}' has weight {}", i, keyword, weight));
+            }
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(RouterError::InvalidTable(problems.join("; ")))
+    }
+}
+
+/// Lowercase words of `text` separated and surrounded by single spaces.
+fn padded_words(text: &str) -> String {
+    let cleaned: String = text
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '\'' { c } else { ' ' })
+        .collect();
+    format!(" {} ", cleaned.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Suggestions for `question`, most likely first.
+pub fn route_question(table: &RouteTable, question: &str) -> Vec<RouteSuggestion> {
+    let text = padded_words(question);
+    let mut scored: Vec<(f64, RouteSuggestion)> = table
+        .routes
+        .iter()
+        .filter_map(|rule| {
+            let matched: Vec<(&String, f64)> = rule
+                .keywords
+                .iter()
+                .filter(|(k, _)| {
+                    text.contains(&format!(" {} ", k)) || text.contains(&format!(" {}s ", k))
+                })
+                .map(|(k, w)| (k, *w))
+                .collect();
+            let score: f64 = matched.iter().map(|(_, w)| w).sum();
+            (score > 0.0).then(|| {
+                let suggestion = RouteSuggestion {
+                    target: RouteTarget {
+                        modality: rule.modality,
+                        template: rule.template.clone(),
+                    },
+                    confidence: 0.0,
+                    matched: matched.into_iter().map(|(k, _)| k.clone()).collect(),
+                };
+                (score, suggestion)
+            })
+        })
+        .collect();
+    if scored.is_empty() {
+        return vec![RouteSuggestion {
+            target: table.fallback.clone(),
+            confidence: 0.0,
+            matched: Vec::new(),
+        }];
+    }
+    let total: f64 = scored.iter().map(|(s, _)| s).sum::<f64>() + 1.0;
+    // Stable, so equal scores keep table order
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored
+        .into_iter()
+        .map(|(score, mut suggestion)| {
+            suggestion.confidence = (score / total * 1000.0).round() / 1000.0;
+            suggestion
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// QuestionRouter — stateful wrapper
+// ---------------------------------------------------------------------------
+
+pub struct QuestionRouter {
+    table: RouteTable,
+}
+
+impl QuestionRouter {
+    /// Create a router from the embedded rules.
+    ///
+    /// # Panics
+    /// Panics if the embedded rules are invalid; use
+    /// [`try_new`](Self::try_new) to handle that case.
+    pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|e| panic!("embedded route table: {}", e))
+    }
+
+    /// Load and validate the embedded rules.
+    ///
+    /// # Errors
+    /// Returns [`RouterError::InvalidTable`] if they do not parse or
+    /// validate.
+    pub fn try_new() -> Result<Self, RouterError> {
+        let table: RouteTable = serde_json::from_str(ROUTES_JSON)
+            .map_err(|e| RouterError::InvalidTable(e.to_string()))?;
+        Self::with_table(table)
+    }
+
+    /// A router over a host's own rules.
+    ///
+    /// # Errors
+    /// Returns [`RouterError::InvalidTable`] if the table is invalid.
+    pub fn with_table(table: RouteTable) -> Result<Self, RouterError> {
+        validate_table(&table)?;
+        Ok(Self { table })
+    }
+
+    pub fn table(&self) -> &RouteTable {
+        &self.table
+    }
+
+    /// Suggestions for `question`, most likely first. Never empty.
+    pub fn route(&self, question: &str) -> Vec<RouteSuggestion> {
+        route_question(&self.table, question)
+    }
+
+    /// The most likely suggestion for `question`.
+    pub fn best(&self, question: &str) -> RouteSuggestion {
+        self.route(question).swap_remove(0)
+    }
+}
+
+impl Default for QuestionRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::tarot::TarotEngine;
+
+    fn best(question: &str) -> (Modality, Option<String>) {
+        let RouteTarget { modality, template } = QuestionRouter::new().best(question).target;
+        (modality, template)
+    }
+
+    #[test]
+    fn routes_common_questions() {
+        let spread = |id: &str| (Modality::Tarot, Some(id.to_string()));
+        assert_eq!(best("Does my crush feel about me the way I do?"), spread("relationship"));
+        assert_eq!(best("Will I get the promotion at work?"), spread("career"));
+        assert_eq!(best("Should I wait or act now on the move?"), (Modality::Iching, None));
+        let natal = best("What does my rising sign say about me?");
+        assert_eq!(natal, (Modality::NatalAstrology, None));
+        let transit = best("Is Mercury retrograde messing up this week?");
+        assert_eq!(transit.0, Modality::TransitAstrology);
+
+        let fallback = QuestionRouter::new().route("hmm");
+        assert_eq!(fallback.len(), 1);
+        assert_eq!(fallback[0].confidence, 0.0);
+        assert_eq!(fallback[0].target.template.as_deref(), Some("three_card"));
+    }
+
+    #[test]
+    fn confidence_splits_between_routes() {
+        let router = QuestionRouter::new();
+        let mixed = router.route("Should I quit my job for my partner?");
+        assert_eq!(mixed[0].target.modality, Modality::Tarot);
+        assert!(mixed.len() >= 2);
+        assert!(mixed.windows(2).all(|w| w[0].confidence >= w[1].confidence));
+        assert!(mixed.iter().map(|s| s.confidence).sum::<f64>() < 1.0);
+        assert!(router.best("my birth chart").confidence > router.best("born").confidence);
+
+        // Every embedded tarot template is a real spread
+        let tarot = TarotEngine::new();
+        for rule in &router.table().routes {
+            if let Some(id) = &rule.template {
+                assert!(tarot.get_spread(id).is_some(), "unknown spread {}", id);
+            }
+        }
+        let mut table = router.table().clone();
+        table.routes[0].keywords.insert("Love".to_string(), -1.0);
+        assert!(QuestionRouter::with_table(table).is_err());
+    }
+}
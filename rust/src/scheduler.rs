@@ -0,0 +1,280 @@
+use std::fmt;
+
+use crate::engines::astrology::cycles::find_aspect_perfections;
+use crate::engines::astrology::moon_phase;
+use crate::types::{ScheduleKind, ScheduleOptions, ScheduledReading, Weekday};
+
+// ---------------------------------------------------------------------------
+// Reading scheduler
+// ---------------------------------------------------------------------------
+//
+// Works in milliseconds since the Unix epoch, the journal's clock. Daily and
+// weekly readings fall at a local clock time under a fixed UTC offset; new
+// and full moons at the exact Sun–Moon conjunction or opposition, found
+// with the astrology engine's aspect solver. Nothing here keeps time: a
+// host asks what is due in a window and arranges its own wake-ups.
+
+const MS_PER_MINUTE: i64 = 60_000;
+const MS_PER_DAY: i64 = 86_400_000;
+/// Julian Day of the Unix epoch.
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Why a schedule could not be computed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// A local time outside 00:00–23:59
+    InvalidTime { hour: u32, minute: u32 },
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTime { hour, minute } => {
+                write!(f, "invalid time of day: {:02}:{:02}", hour, minute)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+impl Weekday {
+    pub const ALL: [Weekday; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// The weekday of a day counted from 1970-01-01, a Thursday.
+    pub fn from_epoch_day(day: i64) -> Self {
+        Self::ALL[(day + 3).rem_euclid(7) as usize]
+    }
+}
+
+fn check_time((hour, minute): (u32, u32)) -> Result<i64, ScheduleError> {
+    if hour > 23 || minute > 59 {
+        return Err(ScheduleError::InvalidTime { hour, minute });
+    }
+    Ok(i64::from(hour * 60 + minute) * MS_PER_MINUTE)
+}
+
+/// Julian Day (UT) of a Unix timestamp in milliseconds.
+pub fn timestamp_to_jd(timestamp_ms: u64) -> f64 {
+    UNIX_EPOCH_JD + timestamp_ms as f64 / MS_PER_DAY as f64
+}
+
+/// Unix timestamp in milliseconds of a Julian Day (UT), clamped at the
+/// epoch.
+pub fn jd_to_timestamp(jd: f64) -> u64 {
+    ((jd - UNIX_EPOCH_JD) * MS_PER_DAY as f64).round().max(0.0) as u64
+}
+
+/// Every local `time` on days accepted by `on_day`, from `from` (inclusive)
+/// to `until` (exclusive). Timestamps past `i64::MAX` milliseconds are
+/// treated as `i64::MAX`; no reading falls that late.
+fn local_occurrences(
+    from: u64,
+    until: u64,
+    offset_minutes: i32,
+    time: (u32, u32),
+    on_day: impl Fn(i64) -> bool,
+) -> Result<Vec<u64>, ScheduleError> {
+    let time_ms = check_time(time)?;
+    let offset_ms = i64::from(offset_minutes) * MS_PER_MINUTE;
+    let from = i64::try_from(from).unwrap_or(i64::MAX);
+    let until = i64::try_from(until).unwrap_or(i64::MAX);
+    let mut day = from.saturating_add(offset_ms).div_euclid(MS_PER_DAY);
+    let mut due = Vec::new();
+    loop {
+        let at = day
+            .checked_mul(MS_PER_DAY)
+            .and_then(|ms| ms.checked_add(time_ms - offset_ms))
+            .unwrap_or(i64::MAX);
+        if at >= until {
+            return Ok(due);
+        }
+        if at >= from && on_day(day) {
+            due.push(at as u64);
+        }
+        day += 1;
+    }
+}
+
+/// Daily card times in `[from, until)`.
+///
+/// # Errors
+/// Returns [`ScheduleError::InvalidTime`] if `daily_time` is not a time of
+/// day.
+pub fn daily_card_times(
+    from: u64,
+    until: u64,
+    options: &ScheduleOptions,
+) -> Result<Vec<u64>, ScheduleError> {
+    local_occurrences(from, until, options.utc_offset_minutes, options.daily_time, |_| true)
+}
+
+/// Weekly spread times in `[from, until)`.
+///
+/// # Errors
+/// Returns [`ScheduleError::InvalidTime`] if `weekly_time` is not a time of
+/// day.
+pub fn weekly_spread_times(
+    from: u64,
+    until: u64,
+    options: &ScheduleOptions,
+) -> Result<Vec<u64>, ScheduleError> {
+    local_occurrences(from, until, options.utc_offset_minutes, options.weekly_time, |day| {
+        Weekday::from_epoch_day(day) == options.weekly_day
+    })
+}
+
+/// New and full moons in `[from, until)`, in order.
+pub fn lunar_readings(from: u64, until: u64) -> Vec<ScheduledReading> {
+    if until <= from {
+        return Vec::new();
+    }
+    let (start, end) = (timestamp_to_jd(from), timestamp_to_jd(until));
+    let mut readings: Vec<ScheduledReading> =
+        [(ScheduleKind::NewMoon, 0.0), (ScheduleKind::FullMoon, 180.0)]
+            .into_iter()
+            .flat_map(|(kind, angle)| {
+                find_aspect_perfections("moon", "sun", angle, start, end)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |jd| ScheduledReading {
+                        kind,
+                        due: jd_to_timestamp(jd),
+                        moon: Some(moon_phase(jd).moon),
+                    })
+            })
+            .filter(|r| (from..until).contains(&r.due))
+            .collect();
+    readings.sort_by_key(|r| r.due);
+    readings
+}
+
+/// Every enabled reading due in `[from, until)`, soonest first.
+///
+/// # Errors
+/// Returns [`ScheduleError::InvalidTime`] if an enabled daily or weekly
+/// time is not a time of day.
+pub fn upcoming_readings(
+    from: u64,
+    until: u64,
+    options: &ScheduleOptions,
+) -> Result<Vec<ScheduledReading>, ScheduleError> {
+    let clock = |kind, times: Vec<u64>| {
+        times.into_iter().map(move |due| ScheduledReading { kind, due, moon: None })
+    };
+    let mut readings = Vec::new();
+    if options.daily {
+        readings.extend(clock(ScheduleKind::DailyCard, daily_card_times(from, until, options)?));
+    }
+    if options.weekly {
+        let times = weekly_spread_times(from, until, options)?;
+        readings.extend(clock(ScheduleKind::WeeklySpread, times));
+    }
+    if options.lunar {
+        readings.extend(lunar_readings(from, until));
+    }
+    // Stable, so a daily card and weekly spread due together keep that order
+    readings.sort_by_key(|r| r.due);
+    Ok(readings)
+}
+
+/// The next reading of each enabled kind at or after `from`.
+///
+/// # Errors
+/// Returns [`ScheduleError::InvalidTime`] if an enabled daily or weekly
+/// time is not a time of day.
+pub fn next_readings(
+    from: u64,
+    options: &ScheduleOptions,
+) -> Result<Vec<ScheduledReading>, ScheduleError> {
+    // A lunar month and a day of slack covers both moons
+    let until = from.saturating_add(31 * MS_PER_DAY as u64);
+    let mut next: Vec<ScheduledReading> = Vec::new();
+    for reading in upcoming_readings(from, until, options)? {
+        if next.iter().all(|r| r.kind != reading.kind) {
+            next.push(reading);
+        }
+    }
+    Ok(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-01-01T00:00:00Z, a Monday.
+    const NEW_YEAR_2024: u64 = 1_704_067_200_000;
+    const HOUR: u64 = 3_600_000;
+    const DAY: u64 = 24 * HOUR;
+
+    #[test]
+    fn local_clock_times() {
+        let options = ScheduleOptions {
+            utc_offset_minutes: -300,
+            ..Default::default()
+        };
+        // 08:00 in UTC-5 is 13:00 UTC
+        let daily = daily_card_times(NEW_YEAR_2024, NEW_YEAR_2024 + 3 * DAY, &options).unwrap();
+        assert_eq!(daily, [0, 1, 2].map(|d| NEW_YEAR_2024 + d * DAY + 13 * HOUR));
+        // Starting after today's slot moves to tomorrow
+        let later = daily_card_times(NEW_YEAR_2024 + 14 * HOUR, NEW_YEAR_2024 + 2 * DAY, &options);
+        assert_eq!(later.unwrap(), [NEW_YEAR_2024 + DAY + 13 * HOUR]);
+
+        assert_eq!(Weekday::from_epoch_day(0), Weekday::Thursday);
+        let weekly = weekly_spread_times(NEW_YEAR_2024, NEW_YEAR_2024 + 14 * DAY, &options);
+        assert_eq!(weekly.unwrap(), [0, 7].map(|d| NEW_YEAR_2024 + d * DAY + 13 * HOUR));
+
+        let bad = ScheduleOptions {
+            daily_time: (24, 0),
+            ..Default::default()
+        };
+        assert_eq!(
+            upcoming_readings(0, DAY, &bad).unwrap_err(),
+            ScheduleError::InvalidTime { hour: 24, minute: 0 }
+        );
+    }
+
+    #[test]
+    fn moons_and_next_due() {
+        // January 2024: full moon on the 25th at 17:54 UT, new moon on the
+        // 11th at 11:57 UT
+        let january = lunar_readings(NEW_YEAR_2024, NEW_YEAR_2024 + 31 * DAY);
+        let kinds: Vec<ScheduleKind> = january.iter().map(|r| r.kind).collect();
+        assert_eq!(kinds, [ScheduleKind::NewMoon, ScheduleKind::FullMoon]);
+        let hours_off = |r: &ScheduledReading, day: u64, hour: u64| {
+            (r.due as f64 - (NEW_YEAR_2024 + (day - 1) * DAY + hour * HOUR) as f64).abs()
+                / HOUR as f64
+        };
+        assert!(hours_off(&january[0], 11, 12) < 2.0);
+        assert!(hours_off(&january[1], 25, 18) < 2.0);
+        assert_eq!(january[1].moon.as_ref().unwrap().sign, "leo");
+
+        // From 09:00 on Monday the 1st, this week's spread has passed
+        let next = next_readings(NEW_YEAR_2024 + 9 * HOUR, &ScheduleOptions::default()).unwrap();
+        let kinds: Vec<ScheduleKind> = next.iter().map(|r| r.kind).collect();
+        use ScheduleKind::*;
+        assert_eq!(kinds, [DailyCard, WeeklySpread, NewMoon, FullMoon]);
+        assert_eq!(next[1].due, NEW_YEAR_2024 + 7 * DAY + 8 * HOUR);
+    }
+
+    #[test]
+    fn timestamps_near_the_end_of_time_do_not_overflow() {
+        let options = ScheduleOptions {
+            utc_offset_minutes: 840,
+            lunar: false,
+            ..Default::default()
+        };
+        assert!(daily_card_times(u64::MAX - DAY, u64::MAX, &options).unwrap().is_empty());
+        assert!(next_readings(u64::MAX - HOUR, &options).unwrap().is_empty());
+        let edge = i64::MAX as u64 - DAY;
+        assert!(daily_card_times(edge, edge + 2 * DAY, &options).unwrap().len() <= 1);
+    }
+}
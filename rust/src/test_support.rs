@@ -0,0 +1,28 @@
+use crate::types::BirthData;
+
+// ---------------------------------------------------------------------------
+// Fixtures shared by the unit tests
+// ---------------------------------------------------------------------------
+
+/// New York at 14:30 EDT on 15 June 1990, the birth most tests chart.
+pub(crate) fn birth() -> BirthData {
+    BirthData {
+        minute: Some(30),
+        timezone: Some(-4.0),
+        ..birth_on(1990, 6, 15, Some(14))
+    }
+}
+
+/// New York on the hour (or at an unknown time) on a date, on EST.
+pub(crate) fn birth_on(year: i32, month: u32, day: u32, hour: Option<i32>) -> BirthData {
+    BirthData {
+        year,
+        month,
+        day: Some(day),
+        hour,
+        minute: Some(0),
+        latitude: Some(40.7128),
+        longitude: Some(-74.0060),
+        timezone: Some(-5.0),
+    }
+}
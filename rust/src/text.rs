@@ -0,0 +1,63 @@
+// ---------------------------------------------------------------------------
+// Text helpers shared by the engines
+// ---------------------------------------------------------------------------
+
+/// `word` with its first letter in upper case.
+pub(crate) fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Lowercase words of `text` separated by single spaces, punctuation
+/// dropped.
+fn simplify(text: &str) -> String {
+    let cleaned: String = text
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .flat_map(char::to_lowercase)
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1).min(row[j] + 1).min(diagonal + usize::from(ca != cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Similarity of two names from 0.0 (nothing alike) to 1.0 (equal ignoring
+/// case and punctuation): Levenshtein distance normalised by the longer
+/// name's length.
+pub(crate) fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (simplify(a), simplify(b));
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f64 / longest as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn similarity_bounds() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(name_similarity("Black  Cat!", "black cat"), 1.0);
+        assert_eq!(name_similarity("", ""), 1.0);
+        assert_eq!(name_similarity("owl", "owls"), 0.75);
+    }
+}
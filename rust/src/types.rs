@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -9,9 +11,9 @@ pub struct TarotCard {
     pub id: String,
     pub name: String,
     pub number: i32,
-    pub arcana: String,
+    pub arcana: Arcana,
     /// null for major arcana cards
-    pub suit: Option<String>,
+    pub suit: Option<Suit>,
     pub keywords_upright: Vec<String>,
     pub keywords_reversed: Vec<String>,
     pub meaning_upright: String,
@@ -21,6 +23,71 @@ pub struct TarotCard {
     pub planet: Option<String>,
     pub zodiac: Option<String>,
     pub numerology: i32,
+    /// Card art, when the deck pack provides it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<CardImage>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Arcana {
+    Major,
+    Minor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Suit {
+    Wands,
+    Cups,
+    Swords,
+    Pentacles,
+}
+
+/// Rank of a minor arcana card, in order from ace to king.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rank {
+    Ace,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Page,
+    Knight,
+    Queen,
+    King,
+}
+
+/// A problem found by [`validate_deck`](crate::engines::tarot::validate_deck).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "issue", rename_all = "snake_case")]
+pub enum DeckIssue {
+    /// A full deck has 78 cards
+    WrongCardCount { found: usize },
+    DuplicateId { id: String },
+    /// A minor arcana card without a suit
+    MissingSuit { id: String },
+    /// A major arcana card with a suit
+    UnexpectedSuit { id: String },
+    /// Majors are numbered 0–21, minors 1–14
+    NumberOutOfRange { id: String, number: i32 },
+    /// `numerology` should equal the card's number
+    NumerologyMismatch { id: String, number: i32, numerology: i32 },
+}
+
+/// Reference to a card's artwork.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardImage {
+    /// File name or URL relative to the pack's asset directory
+    pub file: String,
+    pub attribution: Option<String>,
+    pub alt_text: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +95,58 @@ pub struct DrawnCard {
     pub card: TarotCard,
     pub reversed: bool,
     pub position_index: usize,
+    /// Position this card was drawn to clarify; `None` for cards dealt into
+    /// the spread itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clarifies: Option<usize>,
+}
+
+/// Restricts a draw to part of the deck.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardFilter {
+    MajorsOnly,
+    MinorsOnly,
+    Suit(Suit),
+    /// Pages, knights, queens and kings
+    CourtsOnly,
+    /// Ace through ten of every suit
+    PipsOnly,
+}
+
+/// Settings for [`TarotEngine::draw`](crate::engines::tarot::TarotEngine::draw).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawOptions {
+    pub count: usize,
+    pub allow_reversals: bool,
+    /// Draw only from cards matching this filter
+    #[serde(default)]
+    pub filter: Option<CardFilter>,
+    /// Card ids removed from the deck before shuffling
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl Default for DrawOptions {
+    fn default() -> Self {
+        Self {
+            count: 1,
+            allow_reversals: true,
+            filter: None,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// A rigged deck order for tests and demos; see
+/// [`TarotEngine::stack_deck`](crate::engines::tarot::TarotEngine::stack_deck).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeckStack {
+    /// These card ids come off the top in order; the rest follow shuffled
+    Order(Vec<String>),
+    /// Relative chance of each card surfacing first; unlisted cards weigh 1.0
+    Weights(BTreeMap<String, f64>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +154,22 @@ pub struct SpreadPosition {
     pub index: usize,
     pub name: String,
     pub description: String,
+    /// Orientation rule for cards dealt here
+    #[serde(default, skip_serializing_if = "ReversalRule::is_random")]
+    pub reversal: ReversalRule,
+}
+
+/// How a spread position treats card orientation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReversalRule {
+    /// Reversed at random when the reading allows reversals
+    #[default]
+    Random,
+    /// Always read upright, e.g. an outcome position
+    Never,
+    /// Always read reversed, e.g. a shadow position
+    Always,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -45,6 +180,502 @@ pub struct SpreadDefinition {
     pub positions: Vec<SpreadPosition>,
     #[serde(rename = "cardCount")]
     pub card_count: usize,
+    /// Whether the spread reserves a significator card, and how it is chosen
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub significator: Option<SignificatorMode>,
+}
+
+/// The everyday readings served by
+/// [`TarotEngine::quick_reading`](crate::engines::tarot::TarotEngine::quick_reading).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadingKind {
+    /// One card for the question at hand
+    Single,
+    /// Past, present and future
+    ThreeCard,
+}
+
+/// How a spread's significator card leaves the deck.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignificatorMode {
+    /// A chosen card (usually a court card) is removed before shuffling
+    Removed,
+    /// The first card off the shuffled deck becomes the significator
+    Drawn,
+}
+
+/// A spread dealt after setting aside its significator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignificatorReading {
+    pub significator: Option<TarotCard>,
+    pub cards: Vec<DrawnCard>,
+}
+
+/// Replacement values for one card in a [`DeckPack`]. Unset fields keep the
+/// base deck's value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardOverride {
+    /// Id of the base card being overridden
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meaning_upright: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meaning_reversed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<CardImage>,
+}
+
+/// Translated text for one card. Unset fields fall back to English.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardTranslation {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keywords_upright: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keywords_reversed: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meaning_upright: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meaning_reversed: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Card texts for one language.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalePack {
+    /// Language code, e.g. `"es"`
+    pub locale: String,
+    pub cards: Vec<CardTranslation>,
+}
+
+/// A deck tradition expressed as changes to the embedded Rider-Waite-Smith
+/// deck. Card ids and suit keys stay the same so lookups and filters keep
+/// working across decks; only display names, numbering and texts change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeckPack {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Display name per suit key (`wands`, `cups`, `swords`, `pentacles`)
+    #[serde(default, rename = "suitNames")]
+    pub suit_names: BTreeMap<String, String>,
+    /// Display title per court rank (`page`, `knight`, `queen`, `king`)
+    #[serde(default, rename = "courtNames")]
+    pub court_names: BTreeMap<String, String>,
+    #[serde(default)]
+    pub cards: Vec<CardOverride>,
+    /// File name template for every card's image; `{id}` is replaced with
+    /// the card id, e.g. `"thoth/{id}.jpg"`
+    #[serde(default, rename = "imagePattern", skip_serializing_if = "Option::is_none")]
+    pub image_pattern: Option<String>,
+    /// Credit applied to images from `imagePattern`
+    #[serde(default, rename = "imageAttribution", skip_serializing_if = "Option::is_none")]
+    pub image_attribution: Option<String>,
+}
+
+/// Golden Dawn elemental dignity between two cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ElementalDignity {
+    /// Same or friendly elements (Fire–Air, Water–Earth)
+    Strengthening,
+    /// Contrary elements (Fire–Water, Air–Earth)
+    Weakening,
+    /// Neither friendly nor contrary (Fire–Earth, Air–Water)
+    Neutral,
+}
+
+/// Elemental dignity of one dealt card relative to its neighbours.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionDignity {
+    pub position_index: usize,
+    pub card_id: String,
+    pub element: String,
+    /// Relation to the previous position's card, if any
+    pub previous: Option<ElementalDignity>,
+    /// Relation to the next position's card, if any
+    pub next: Option<ElementalDignity>,
+    /// Net effect of both neighbours on this card
+    pub overall: ElementalDignity,
+}
+
+/// A notable pairing of two cards and what they mean together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardCombination {
+    /// The two card ids; order does not matter
+    pub cards: [String; 2],
+    pub name: String,
+    pub meaning: String,
+}
+
+/// A known combination found in a dealt spread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombinationMatch {
+    pub combination: CardCombination,
+    /// Positions of the two cards, in the order of `combination.cards`
+    pub positions: [usize; 2],
+}
+
+/// One spread position read with the card dealt into it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionReading {
+    pub position_index: usize,
+    pub position_name: String,
+    pub position_description: String,
+    pub card_id: String,
+    pub card_name: String,
+    pub reversed: bool,
+    /// Keywords for the card's orientation
+    pub keywords: Vec<String>,
+    /// Meaning for the card's orientation
+    pub meaning: String,
+    /// Names of clarifier cards drawn for this position
+    pub clarifiers: Vec<String>,
+    /// Position, card, keywords and meaning merged into one paragraph
+    pub text: String,
+}
+
+/// Patterns across the whole spread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingSynthesis {
+    pub major_count: usize,
+    pub reversed_count: usize,
+    /// Suit appearing most often, if one clearly leads
+    pub dominant_suit: Option<Suit>,
+    /// Element appearing most often, if one clearly leads
+    pub dominant_element: Option<String>,
+    pub combinations: Vec<CombinationMatch>,
+    pub summary: String,
+}
+
+/// Structured interpretation of a dealt spread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingInterpretation {
+    pub spread_id: String,
+    pub spread_name: String,
+    pub positions: Vec<PositionReading>,
+    pub synthesis: ReadingSynthesis,
+}
+
+/// A card in a physical deck, remembering which way up it lies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrientedCard {
+    pub card: TarotCard,
+    pub reversed: bool,
+}
+
+/// Settings for a simulated hand shuffle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShuffleOptions {
+    /// Riffle shuffles to perform (seven randomise a 52-card deck well)
+    pub riffles: u32,
+    /// Overhand passes after the riffles
+    pub overhand_passes: u32,
+    /// Chance that one half is turned end over end before each riffle
+    pub rotate_chance: f64,
+    /// Chance that a card jumps out of the deck during each riffle
+    #[serde(default)]
+    pub jumper_chance: f64,
+}
+
+impl Default for ShuffleOptions {
+    fn default() -> Self {
+        Self {
+            riffles: 7,
+            overhand_passes: 1,
+            rotate_chance: 0.5,
+            jumper_chance: 0.0,
+        }
+    }
+}
+
+/// A spread dealt from a hand-shuffled deck, with any cards that jumped out
+/// while shuffling. Jumpers are read as an extra message beside the spread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShuffledReading {
+    pub cards: Vec<DrawnCard>,
+    pub jumpers: Vec<OrientedCard>,
+}
+
+/// One position of an exported reading.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedPosition {
+    pub index: usize,
+    pub name: String,
+    pub card_id: String,
+    pub card_name: String,
+    /// `"upright"` or `"reversed"`
+    pub orientation: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+    pub clarifiers: Vec<String>,
+}
+
+/// A drawn reading with its context: the canonical record for storage and
+/// the journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reading {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+    /// Caller-defined id of the person the reading is for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub querent_id: Option<String>,
+    pub deck_id: String,
+    pub spread_id: String,
+    /// RNG seed, when the reading was seeded and can be replayed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Where the deck was cut before dealing, if it was
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cut_index: Option<usize>,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Dealt cards in position order, followed by any clarifiers
+    pub cards: Vec<DrawnCard>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// What to draw for [`TarotEngine::read`](crate::engines::tarot::TarotEngine::read).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingRequest {
+    pub spread_id: String,
+    /// Named deck to draw from; `None` is the primary deck
+    #[serde(default)]
+    pub deck_id: Option<String>,
+    #[serde(default)]
+    pub question: Option<String>,
+    #[serde(default)]
+    pub querent_id: Option<String>,
+    /// Seed for a reproducible shuffle, cut and reversals
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Cut the deck at a random point before dealing
+    #[serde(default)]
+    pub cut: bool,
+    pub allow_reversals: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Default for ReadingRequest {
+    fn default() -> Self {
+        Self {
+            spread_id: "three_card".to_string(),
+            deck_id: None,
+            question: None,
+            querent_id: None,
+            seed: None,
+            cut: false,
+            allow_reversals: true,
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// Archival form of a completed reading. Field names and meanings only
+/// change together with `schema_version`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedReading {
+    pub schema_version: u32,
+    pub spread_id: String,
+    pub spread_name: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    pub positions: Vec<ExportedPosition>,
+    /// Names of card-pair combinations found in the spread
+    pub combinations: Vec<String>,
+    pub synthesis: String,
+}
+
+/// How often one card has come up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardFrequency {
+    pub card_id: String,
+    pub card_name: String,
+    pub draws: u64,
+    pub reversals: u64,
+}
+
+/// Summary of recorded draws.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawStatsSummary {
+    pub total_draws: u64,
+    pub reversal_rate: f64,
+    pub major_draws: u64,
+    /// Cards sorted by draw count, most frequent first (ties by id)
+    pub most_drawn: Vec<CardFrequency>,
+    /// Draw count per suit
+    pub suit_distribution: BTreeMap<Suit, u64>,
+}
+
+/// Unit of a card-based timing prediction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// Which time unit each suit counts in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimingRules {
+    pub wands: TimeUnit,
+    pub cups: TimeUnit,
+    pub swords: TimeUnit,
+    pub pentacles: TimeUnit,
+}
+
+impl Default for TimingRules {
+    fn default() -> Self {
+        Self {
+            wands: TimeUnit::Days,
+            cups: TimeUnit::Weeks,
+            swords: TimeUnit::Months,
+            pentacles: TimeUnit::Years,
+        }
+    }
+}
+
+/// Timeframe suggested by a card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimingPrediction {
+    pub card_id: String,
+    /// Number of units; `None` when the card gives no count (majors, courts)
+    pub amount: Option<u32>,
+    pub unit: Option<TimeUnit>,
+    /// Zodiac season for majors with a sign attribution
+    pub season: Option<String>,
+    pub reversed: bool,
+    pub description: String,
+}
+
+/// A Golden Dawn decan: a 10° slice of the zodiac and its pip card.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decan {
+    pub card_id: String,
+    /// Capitalized sign name, e.g. `"Aries"`
+    pub sign: String,
+    /// 1, 2 or 3 within the sign
+    pub decan: u8,
+    /// Chaldean planetary ruler, e.g. `"Mars"`
+    pub planet: String,
+    /// Ecliptic longitude where the decan starts
+    pub start_degree: f64,
+    pub end_degree: f64,
+}
+
+/// A natal placement and the decan card it falls in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NatalDecan {
+    /// Planet name or `"ascendant"` / `"midheaven"`
+    pub point: String,
+    pub longitude: f64,
+    pub decan: Decan,
+}
+
+/// Kabbalistic attribution of a card on the Tree of Life.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KabbalahAttribution {
+    pub card_id: String,
+    /// Transliterated Hebrew letter for majors, e.g. `"Aleph"`
+    pub hebrew_letter: Option<String>,
+    /// The letter itself, e.g. `"א"`
+    pub hebrew_glyph: Option<String>,
+    /// Path number 11–32 for majors
+    pub path: Option<u8>,
+    /// The two sephiroth a major's path joins
+    pub connects: Option<[String; 2]>,
+    /// Sephirah for aces, pips and courts
+    pub sephirah: Option<String>,
+    /// Sephirah number 1–10
+    pub sephirah_number: Option<u8>,
+    /// Kabbalistic world of the suit (Atziluth, Briah, Yetzirah, Assiah)
+    pub world: Option<String>,
+}
+
+/// A minor-arcana rank appearing more than once in a spread.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankCount {
+    pub rank: Rank,
+    pub count: usize,
+}
+
+/// Numerological profile of a dealt spread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpreadNumerology {
+    /// Sum of major and pip numbers; court cards carry no number
+    pub total: u32,
+    /// `total` reduced to a single digit
+    pub reduced_number: u32,
+    /// Major arcana number (0–21) from reducing `total` to 22 or less, with
+    /// 22 read as The Fool; `None` when nothing was counted
+    pub quintessence: Option<u32>,
+    /// Numbers appearing most often (at least twice), ascending
+    pub dominant_numbers: Vec<i32>,
+    pub repeated_ranks: Vec<RankCount>,
+    pub suit_counts: BTreeMap<Suit, usize>,
+    pub major_count: usize,
+    pub minor_count: usize,
+    /// Share of majors among dealt cards, 0.0–1.0
+    pub major_ratio: f64,
+}
+
+/// The major arcana card governing one year of a person's life.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonalYearCard {
+    pub year: i32,
+    /// Birth month + birth day + `year`, reduced to 0–21
+    pub number: u32,
+    pub card: TarotCard,
+}
+
+/// Act of a three-act narrative arc built from a card sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArcStage {
+    Setup,
+    Conflict,
+    Resolution,
+}
+
+/// A single story beat derived from one drawn card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoryBeat {
+    pub stage: ArcStage,
+    pub card_id: String,
+    pub card_name: String,
+    pub reversed: bool,
+    /// Short motifs taken from the card's keywords and element
+    pub motifs: Vec<String>,
+    /// "light" for upright cards, "shadow" for reversed ones
+    pub tone: String,
+}
+
+/// A drawn card sequence arranged as setup → conflict → resolution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NarrativeArc {
+    pub setup: Vec<StoryBeat>,
+    pub conflict: Vec<StoryBeat>,
+    pub resolution: Vec<StoryBeat>,
+    /// Element appearing most often across the arc
+    pub dominant_element: String,
+    /// Number of major arcana cards, i.e. archetypal turning points
+    pub archetypal_beats: usize,
 }
 
 // ---------------------------------------------------------------------------
@@ -69,7 +700,7 @@ pub struct Trigram {
     pub body_part: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HexagramLine {
     pub position: u32,
     pub text: String,
@@ -95,7 +726,42 @@ pub struct Hexagram {
     pub description: String,
 }
 
+/// Translated text for one line. An unset meaning keeps the embedded one.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LineTranslation {
+    pub position: u32,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meaning: Option<String>,
+}
+
+/// One hexagram's texts in another translation. Unset fields keep the
+/// embedded text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HexagramTranslation {
+    pub number: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub english_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub judgment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lines: Vec<LineTranslation>,
+}
+
+/// An alternative set of hexagram texts, e.g. Legge's or Wilhelm's.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranslationPack {
+    /// Short key, e.g. `"legge"`
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translator: Option<String>,
+    pub hexagrams: Vec<HexagramTranslation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CastResult {
     /// Raw coin-sum values for each of the 6 lines (6, 7, 8, or 9)
     pub lines: Vec<u8>,
@@ -105,6 +771,438 @@ pub struct CastResult {
     pub transformed_hexagram_number: Option<u32>,
     pub binary: String,
     pub transformed_binary: Option<String>,
+    /// Unicode symbol of the hexagram, e.g. "䷀"
+    #[serde(default)]
+    pub glyph: String,
+    #[serde(default)]
+    pub transformed_glyph: Option<String>,
+    /// How the lines were produced
+    #[serde(default)]
+    pub method: CastMethod,
+    /// Raw toss behind each line, bottom first. Empty when the lines were
+    /// entered by hand or derived from numbers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tosses: Vec<LineToss>,
+}
+
+/// The raw toss behind one line of a cast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LineToss {
+    /// Three coins in toss order, heads counting 3 and tails 2
+    ThreeCoins { heads: [bool; 3] },
+    /// Two coins tossed twice, read as a 4-bit number with heads as 1
+    TwoCoins { heads: [bool; 4] },
+    /// A token drawn from a bag of sixteen, numbered 0–15
+    Token { number: u8 },
+}
+
+/// A journal prompt built from the hexagram of the day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReflectionPrompt {
+    /// ISO date, e.g. "2024-03-05"
+    pub date: String,
+    pub cast: CastResult,
+    pub hexagram_number: u32,
+    pub name: String,
+    pub english_name: String,
+    pub glyph: String,
+    pub keywords: Vec<String>,
+    /// The text given most weight under Zhu Xi's rules: the changing line
+    /// when there is one, otherwise a judgment
+    pub focus: SelectedText,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transformed_name: Option<String>,
+    /// One-sentence framing of the day
+    pub theme: String,
+    /// Questions to write about
+    pub questions: Vec<String>,
+}
+
+/// A compass direction, as used by the bagua and in `trigrams.json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    North,
+    Northeast,
+    East,
+    Southeast,
+    South,
+    Southwest,
+    West,
+    Northwest,
+}
+
+/// How the eight trigrams are set around the compass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Arrangement {
+    /// Fu Xi's order, pairing each trigram with its opposite across the
+    /// circle
+    EarlierHeaven,
+    /// King Wen's order, following the seasons; the one feng shui uses and
+    /// the `direction` recorded in `trigrams.json`
+    #[default]
+    LaterHeaven,
+}
+
+/// The five phases (wu xing) of Chinese cosmology.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FivePhase {
+    Wood,
+    Fire,
+    Earth,
+    Metal,
+    Water,
+}
+
+/// The ten heavenly stems (tian gan), in cycle order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeavenlyStem {
+    Jia,
+    Yi,
+    Bing,
+    Ding,
+    Wu,
+    Ji,
+    Geng,
+    Xin,
+    Ren,
+    Gui,
+}
+
+/// The twelve earthly branches (di zhi), in cycle order from Zi (rat).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EarthlyBranch {
+    Zi,
+    Chou,
+    Yin,
+    Mao,
+    Chen,
+    Si,
+    Wu,
+    Wei,
+    Shen,
+    You,
+    Xu,
+    Hai,
+}
+
+/// The stem and branch the najia system attaches to one hexagram line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NajiaLine {
+    /// 1-based, counted from the bottom
+    pub position: u32,
+    pub stem: HeavenlyStem,
+    pub branch: EarthlyBranch,
+    /// Phase of the branch, which is what Liu Yao reads
+    pub phase: FivePhase,
+}
+
+/// The six relatives (liu qin): how a line's phase relates to the phase of
+/// its hexagram's palace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SixRelative {
+    /// Same phase: siblings, friends, rivals
+    Brothers,
+    /// Produced by the palace: children, pleasure, remedies
+    Offspring,
+    /// Overcome by the palace: money, goods, a wife
+    Wealth,
+    /// Overcomes the palace: office, authority, illness, a husband
+    Officer,
+    /// Produces the palace: parents, documents, shelter
+    Parents,
+}
+
+/// The six spirits (liu shen), assigned from the bottom line by day stem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SixSpirit {
+    GreenDragon,
+    VermilionBird,
+    HookedArray,
+    FlyingSerpent,
+    WhiteTiger,
+    BlackTortoise,
+}
+
+/// How the month branch treats a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MonthStrength {
+    /// Same phase as the month
+    Prosperous,
+    /// The month's phase produces the line's
+    Supported,
+    /// Neither prosperous nor supported
+    Weak,
+    /// The month branch clashes with the line's
+    Broken,
+}
+
+/// Overall reading of a Liu Yao consultation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Indication {
+    Favorable,
+    Mixed,
+    Unfavorable,
+}
+
+/// The day and month a Liu Yao cast is read against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiuYaoContext {
+    pub day_stem: HeavenlyStem,
+    pub day_branch: EarthlyBranch,
+    pub month_branch: EarthlyBranch,
+}
+
+/// One of the twelve sovereign (bi gua) hexagrams, which trace yang
+/// waxing from the winter solstice to midsummer and yin waxing back.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SovereignHexagram {
+    pub hexagram_number: u32,
+    /// Branch of the solar month the hexagram rules
+    pub month_branch: EarthlyBranch,
+    /// Month of the Chinese year, 1 being the Yin month that opens spring
+    pub month: u32,
+    /// The solar term (jie qi) that opens the month
+    pub solar_term: String,
+    /// Sun longitude, in degrees, at which that term begins
+    pub solar_term_longitude: u32,
+    /// Solid lines, all rising from the bottom or all falling from the top
+    pub yang_lines: u32,
+    /// True from Fu to Qian, while yang is growing
+    pub waxing: bool,
+}
+
+/// One line of a Liu Yao chart.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiuYaoLine {
+    pub najia: NajiaLine,
+    pub relative: SixRelative,
+    pub spirit: SixSpirit,
+    pub moving: bool,
+    pub is_self: bool,
+    pub is_response: bool,
+    pub month_strength: MonthStrength,
+    /// The day branch shares or produces the line's phase
+    pub day_supports: bool,
+    /// The day branch's phase overcomes the line's
+    pub day_harms: bool,
+    /// The branch is empty (kong wang) in the day's ten-day week
+    pub void: bool,
+    /// What a moving line changes into, read against the same palace
+    pub changes_to: Option<(NajiaLine, SixRelative)>,
+}
+
+/// A cast laid out and judged by Wen Wang Gua (Liu Yao) rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiuYaoReading {
+    pub hexagram_number: u32,
+    pub transformed_hexagram_number: Option<u32>,
+    /// Trigram number of the palace the hexagram belongs to
+    pub palace: u32,
+    pub palace_phase: FivePhase,
+    /// 1-based self (shi) and response (ying) lines
+    pub self_line: u32,
+    pub response_line: u32,
+    pub context: LiuYaoContext,
+    /// Lines bottom first
+    pub lines: Vec<LiuYaoLine>,
+    /// The relative that stands for the matter asked about
+    pub use_god: SixRelative,
+    /// 1-based line chosen to represent the use god, if it appears
+    pub use_god_line: Option<u32>,
+    pub indication: Indication,
+    /// Plain-language reasons behind the indication
+    pub notes: Vec<String>,
+}
+
+/// How a cast was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CastMethod {
+    /// Three coins tossed by the engine
+    #[default]
+    Coins,
+    /// Two coins tossed twice, giving yarrow-stalk odds
+    TwoCoin,
+    /// One of sixteen marked tokens drawn, giving yarrow-stalk odds
+    SixteenToken,
+    /// Line values entered from physical coins or stalks
+    Manual,
+    /// Plum Blossom numerology
+    PlumBlossom,
+    /// Derived from a hash of the question and the time it was asked, or of
+    /// the date for the hexagram of the day
+    QuestionHash,
+}
+
+/// An I Ching cast with its context: the canonical record for the journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IChingConsultation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+    #[serde(default)]
+    pub method: CastMethod,
+    pub cast: CastResult,
+    /// Rule used to choose the texts that were read
+    #[serde(default)]
+    pub rule: ChangingLineRule,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Which text pack to read a cast through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InterpretationProfile {
+    /// Classical judgment and image texts
+    #[default]
+    Traditional,
+    /// Decision-making language: options, timing, risk posture
+    Strategy,
+}
+
+/// How to choose texts when a cast has changing lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangingLineRule {
+    /// Zhu Xi's rules, which depend on how many lines change
+    #[default]
+    ZhuXi,
+    /// The primary judgment, every changing line and the resulting judgment
+    AllLines,
+    /// Only the judgment of the hexagram the cast changes into
+    ResultingOnly,
+}
+
+/// One judgment or line text selected for a cast.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectedText {
+    pub hexagram_number: u32,
+    /// 1-based line the text belongs to; `None` for the judgment
+    pub line: Option<u32>,
+    pub text: String,
+    /// Plain-language meaning of a line text
+    pub meaning: Option<String>,
+    /// Whether the rule gives this text the most weight
+    pub principal: bool,
+}
+
+/// The texts that apply to a cast under a changing-line rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangingLineReading {
+    pub rule: ChangingLineRule,
+    pub hexagram_number: u32,
+    pub transformed_hexagram_number: Option<u32>,
+    /// Texts in reading order
+    pub texts: Vec<SelectedText>,
+}
+
+/// A cast together with the full data of the hexagrams it involves.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedCast {
+    pub cast: CastResult,
+    pub hexagram: Hexagram,
+    /// Texts of the changing lines, bottom first
+    pub changing_lines: Vec<HexagramLine>,
+    pub transformed: Option<Hexagram>,
+}
+
+/// Everything needed to present a cast, gathered from the hexagram texts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CastInterpretation {
+    pub hexagram_number: u32,
+    pub name: String,
+    pub english_name: String,
+    pub glyph: String,
+    pub judgment: String,
+    pub image: String,
+    /// Texts of the changing lines, bottom first
+    pub changing_lines: Vec<HexagramLine>,
+    pub transformed_hexagram_number: Option<u32>,
+    pub transformed_name: Option<String>,
+    pub transformed_judgment: Option<String>,
+}
+
+/// Strategy-profile text for one hexagram (parallel to `hexagrams.json`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyText {
+    pub number: u32,
+    pub situation: String,
+    pub guidance: String,
+    pub timing: String,
+    #[serde(rename = "riskPosture")]
+    pub risk_posture: String,
+}
+
+/// The hexagram reached by changing a single line of another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineDerivative {
+    /// 1-based line that changes, counted from the bottom
+    pub line: usize,
+    /// Whether the changed line becomes solid (yang)
+    pub becomes_yang: bool,
+    pub binary: String,
+    pub hexagram_number: u32,
+}
+
+/// How the two hexagrams of a King Wen pair relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PairRelation {
+    /// The second is the first turned upside down
+    Inverted,
+    /// The first reads the same upside down, so the second swaps every line
+    Complemented,
+}
+
+/// One of the 32 consecutive pairs (1–2, 3–4, …) of the King Wen sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequencePair {
+    /// The odd-numbered hexagram
+    pub first: u32,
+    pub second: u32,
+    pub relation: PairRelation,
+}
+
+/// A Plum Blossom (Mei Hua Yi Shu) cast derived from numbers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlumBlossomCast {
+    /// Trigram numbers, as in `trigrams.json`
+    pub upper_trigram: u32,
+    pub lower_trigram: u32,
+    /// 1-based moving line, counted from the bottom
+    pub moving_line: usize,
+    /// The primary hexagram with its moving line, and the transformed
+    /// hexagram it becomes
+    pub cast: CastResult,
+    /// Nuclear hexagram formed from lines 2–4 and 3–5
+    pub mutual_hexagram_number: u32,
+}
+
+/// A cast read through a particular interpretation profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfiledInterpretation {
+    pub profile: InterpretationProfile,
+    pub hexagram_number: u32,
+    pub headline: String,
+    pub guidance: String,
+    /// Only set by the strategy profile
+    pub timing: Option<String>,
+    /// Only set by the strategy profile
+    pub risk_posture: Option<String>,
+    pub transformed_hexagram_number: Option<u32>,
+    pub transformed_headline: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -182,13 +1280,1594 @@ pub struct NatalChart {
     pub house_cusps: Vec<f64>,
 }
 
-// ---------------------------------------------------------------------------
-// Feedback
-// ---------------------------------------------------------------------------
+/// Options controlling what accompanies a computed chart.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChartOptions {
+    /// Attach "how this was computed" notes drawn from the glossary
+    pub explain: bool,
+}
 
+/// A teaching note explaining one aspect of how a chart was computed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartExplanation {
+    /// What the note is about, e.g. "ascendant" or "mercury"
+    pub topic: String,
+    /// Glossary term the note draws on
+    pub term: String,
+    pub note: String,
+}
+
+/// A chart together with optional learning-mode explanations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainedChart {
+    pub chart: NatalChart,
+    /// Empty unless `ChartOptions::explain` was set
+    pub explanations: Vec<ChartExplanation>,
+}
+
+/// Options controlling what accompanies computed transits.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransitOptions {
+    /// Attach "how this was computed" notes drawn from the glossary
+    pub explain: bool,
+}
+
+/// Transit aspects together with optional learning-mode explanations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainedTransits {
+    pub aspects: Vec<ChartAspect>,
+    /// Empty unless `TransitOptions::explain` was set
+    pub explanations: Vec<ChartExplanation>,
+}
+
+/// Where a planet lies on the horizon compass as seen from a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalSpacePosition {
+    pub planet: String,
+    /// Compass bearing, 0 = north, 90 = east
+    pub azimuth: f64,
+    /// Degrees above (+) or below (−) the horizon
+    pub altitude: f64,
+    /// 16-point compass name, e.g. "northeast"
+    pub direction: String,
+}
+
+/// One life area in a generated sun-sign horoscope.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoroscopeTheme {
+    /// "love", "career" or "health"
+    pub area: String,
+    /// 0 (quiet) to 10 (very active)
+    pub intensity: u8,
+    /// "harmonious", "challenging", "mixed" or "quiet"
+    pub tone: String,
+    /// Human-readable transits behind the score
+    pub drivers: Vec<String>,
+}
+
+/// Structured daily horoscope data for a sun sign, meant for an LLM to
+/// verbalize.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyHoroscope {
+    pub sign: String,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    /// Transiting planets; `house` is the whole-sign solar house
+    pub transits: Vec<PlanetPosition>,
+    /// Aspects from transiting planets to a generic natal Sun at 15° of the sign
+    pub aspects: Vec<ChartAspect>,
+    pub love: HoroscopeTheme,
+    pub career: HoroscopeTheme,
+    pub health: HoroscopeTheme,
+}
+
+/// Whether a chart is diurnal (Sun above the horizon) or nocturnal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sect {
+    Day,
+    Night,
+}
+
+/// Sect of a chart with the traditional planets it favors and harms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SectAnalysis {
+    pub sect: Sect,
+    /// "sun" by day, "moon" by night
+    pub luminary_of_sect: String,
+    /// "jupiter" by day, "venus" by night
+    pub benefic_of_sect: String,
+    /// "saturn" by day, "mars" by night
+    pub malefic_of_sect: String,
+    pub benefic_contrary_to_sect: String,
+    pub malefic_contrary_to_sect: String,
+    /// Mercury is diurnal as a morning star (rising before the Sun)
+    pub mercury_sect: Sect,
+}
+
+/// An exact conjunction or opposition between two bodies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SynodicEvent {
+    pub body1: String,
+    pub body2: String,
+    /// "conjunction" or "opposition"
+    pub aspect: String,
+    pub jd: f64,
+    /// Position of `body1` at the moment of perfection
+    pub position: SignPosition,
+}
+
+/// The astrological age implied by the vernal point's sidereal position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AstrologicalAge {
+    /// Sidereal sign containing the vernal point, e.g. "pisces"
+    pub sign: String,
+    /// Sidereal longitude of the vernal point (0-359)
+    pub vernal_point: f64,
+    /// Degrees the vernal point still has to travel before the next age
+    pub degrees_remaining: f64,
+    pub next_sign: String,
+    /// Julian Day when the vernal point enters the next sign
+    pub next_age_jd: f64,
+}
+
+/// A dated life event used to score candidate birth times.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifeEvent {
+    pub label: String,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// Settings for a birth-time rectification scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RectificationOptions {
+    /// Scan from −window to +window minutes around the recorded time
+    pub window_minutes: u32,
+    pub step_minutes: u32,
+    /// Optional events whose transits to the angles score each candidate
+    pub events: Vec<LifeEvent>,
+    /// Maximum orb in degrees for a transit to count as a hit
+    pub orb: f64,
+}
+
+impl Default for RectificationOptions {
+    fn default() -> Self {
+        Self {
+            window_minutes: 180,
+            step_minutes: 4,
+            events: Vec::new(),
+            orb: 2.0,
+        }
+    }
+}
+
+/// One candidate birth time produced by a rectification scan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RectificationCandidate {
+    /// Offset from the recorded birth time
+    pub offset_minutes: i32,
+    pub jd: f64,
+    pub ascendant: SignPosition,
+    pub midheaven: SignPosition,
+    /// Planet name → house (1-12)
+    pub houses: BTreeMap<String, usize>,
+    /// What changed compared with the previous candidate
+    pub changes: Vec<String>,
+    /// Sum of event transit hits to the angles; 0 when no events were given
+    pub score: f64,
+    pub hits: Vec<String>,
+}
+
+/// Hellenistic lots used as starting points for time-lord techniques.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lot {
+    Fortune,
+    Spirit,
+}
+
+/// One zodiacal releasing period.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleasingPeriod {
+    /// 1 for major periods, 2 for sub-periods
+    pub level: u8,
+    pub sign: String,
+    pub start_jd: f64,
+    pub end_jd: f64,
+    /// True when the sequence jumped to the opposite sign
+    pub loosing_of_the_bond: bool,
+}
+
+/// Zodiacal releasing timeline from a lot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZodiacalReleasing {
+    pub lot: Lot,
+    pub lot_position: SignPosition,
+    pub level1: Vec<ReleasingPeriod>,
+    pub level2: Vec<ReleasingPeriod>,
+}
+
+/// A chart computed for an arbitrary moment (UT Julian Day) at a location.
+/// Used by live "current sky" views that refresh incrementally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkySnapshot {
+    pub jd: f64,
+    /// Decimal degrees, north positive
+    pub latitude: f64,
+    /// Decimal degrees, east positive
+    pub longitude: f64,
+    /// Julian Day (UT) at which Jupiter through Pluto were last computed;
+    /// snapshots without it are recomputed in full on the next update
+    #[serde(default)]
+    pub slow_jd: f64,
+    pub chart: NatalChart,
+}
+
+/// The eight phases of the Sun-Moon lunation cycle, 45° each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LunationPhase {
+    New,
+    Crescent,
+    FirstQuarter,
+    Gibbous,
+    Full,
+    Disseminating,
+    LastQuarter,
+    Balsamic,
+}
+
+/// Secondary-progressed Sun-Moon phase for a moment in a person's life.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressedLunation {
+    pub phase: LunationPhase,
+    /// Moon minus Sun, 0-359
+    pub phase_angle: f64,
+    /// Julian Day of the progressed (symbolic) moment
+    pub progressed_jd: f64,
+    pub progressed_sun: SignPosition,
+    pub progressed_moon: SignPosition,
+}
+
+/// The Moon's phase at a moment, as seen from Earth.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoonPhase {
+    pub phase: LunationPhase,
+    /// Moon minus Sun, 0-359
+    pub phase_angle: f64,
+    /// Lit fraction of the disc, 0.0 (new) to 1.0 (full)
+    pub illumination: f64,
+    pub waxing: bool,
+    /// Where the Moon is in the zodiac
+    pub moon: SignPosition,
+}
+
+/// Something a lunar calendar marks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LunarEventKind {
+    NewMoon,
+    FirstQuarter,
+    FullMoon,
+    LastQuarter,
+    /// A new moon close enough to a lunar node to eclipse the Sun
+    SolarEclipse,
+    /// A full moon close enough to a lunar node to be eclipsed, including
+    /// penumbral eclipses
+    LunarEclipse,
+    /// From the Moon's last major aspect in a sign until it enters the next
+    VoidOfCourse,
+}
+
+/// A lunar phase, eclipse or void-of-course window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LunarEvent {
+    pub kind: LunarEventKind,
+    /// Julian Day (UT) the event is exact or, for a window, begins
+    pub jd: f64,
+    /// Julian Day (UT) a void-of-course window ends; `None` for a moment
+    pub end_jd: Option<f64>,
+    /// Where the Moon is at `jd`
+    pub moon: SignPosition,
+    /// Sign the Moon enters when a void-of-course window ends
+    pub next_sign: Option<String>,
+    /// What the event suits in ritual work
+    pub description: String,
+}
+
+/// The lunar events of one calendar month (UT), in time order. An eclipse
+/// is listed alongside the new or full moon it falls on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LunarCalendar {
+    pub year: i32,
+    pub month: u32,
+    pub events: Vec<LunarEvent>,
+}
+
+/// Inter-chart aspects between two natal charts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Synastry {
+    /// `planet1` is from the first chart and `planet2` from the second,
+    /// tightest orb first
+    pub aspects: Vec<ChartAspect>,
+    /// Weighted strength of the flowing contacts
+    pub harmonious: f64,
+    /// Weighted strength of the tense contacts
+    pub challenging: f64,
+    /// 0 (all tension) to 100 (all flow); 50 with no contacts
+    pub score: u8,
+}
+
+/// Waxing or waning half of the lunar month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Paksha {
+    /// Bright fortnight, new moon to full
+    Shukla,
+    /// Dark fortnight, full moon to new
+    Krishna,
+}
+
+/// Lunar day: each 12° the Moon gains on the Sun.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tithi {
+    /// 1–30; 15 is the full moon and 30 the new moon
+    pub number: u32,
+    pub name: String,
+    pub paksha: Paksha,
+    /// How far through the tithi, 0.0–1.0
+    pub elapsed: f64,
+}
+
+/// Weekday, counted from sunrise.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vara {
+    /// 0 for Sunday to 6 for Saturday
+    pub number: u32,
+    pub name: String,
+    pub lord: String,
+}
+
+/// Lunar mansion: the Moon's sidereal longitude in 27 parts of 13°20'.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Nakshatra {
+    /// 1–27, Ashwini to Revati
+    pub number: u32,
+    pub name: String,
+    /// Vimshottari lord
+    pub lord: String,
+    /// Quarter 1–4
+    pub pada: u32,
+    pub elapsed: f64,
+}
+
+/// Sum of the Sun's and Moon's sidereal longitudes in 27 parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Yoga {
+    /// 1–27, Vishkambha to Vaidhriti
+    pub number: u32,
+    pub name: String,
+    pub elapsed: f64,
+}
+
+/// Half a tithi.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Karana {
+    /// 1–60 through the lunar month
+    pub number: u32,
+    pub name: String,
+    pub elapsed: f64,
+}
+
+/// The five limbs of the Hindu almanac for a moment and place.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Panchanga {
+    /// Julian Day (UT) of the moment
+    pub jd: f64,
+    /// Julian Day (UT) of the sunrise that opened the vara; `None` where
+    /// the Sun does not rise, in which case local midnight is used
+    pub sunrise_jd: Option<f64>,
+    /// Lahiri ayanamsa in degrees
+    pub ayanamsa: f64,
+    /// Sidereal longitudes, 0-359
+    pub sun_longitude: f64,
+    pub moon_longitude: f64,
+    pub tithi: Tithi,
+    pub vara: Vara,
+    pub nakshatra: Nakshatra,
+    pub yoga: Yoga,
+    pub karana: Karana,
+}
+
+// ---------------------------------------------------------------------------
+// BaZi types
+// ---------------------------------------------------------------------------
+
+/// A stem and branch pair: one of the four pillars of destiny.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Pillar {
+    pub stem: HeavenlyStem,
+    pub branch: EarthlyBranch,
+}
+
+/// How well the rest of a chart supports the day master.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DayMasterStrength {
+    Strong,
+    Balanced,
+    Weak,
+}
+
+/// A Four Pillars (BaZi) chart for a moment of birth.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaziChart {
+    pub year: Pillar,
+    pub month: Pillar,
+    pub day: Pillar,
+    /// `None` when the birth hour is unknown
+    pub hour: Option<Pillar>,
+    /// Stems and branches of each phase across the pillars
+    pub phase_counts: BTreeMap<FivePhase, u32>,
+    /// The day stem, which stands for the person
+    pub day_master: HeavenlyStem,
+    pub day_master_phase: FivePhase,
+    pub strength: DayMasterStrength,
+    /// Weight of the characters that share or feed the day master's phase,
+    /// the month branch counting double
+    pub support: u32,
+    /// Weight of every character other than the day stem
+    pub total_weight: u32,
+    /// Phases that would bring the chart toward balance
+    pub favorable_phases: Vec<FivePhase>,
+}
+
+/// How two birth-year animals of the Chinese zodiac relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BranchRelation {
+    /// One of the six pairs that combine (rat and ox, tiger and pig, …)
+    SixHarmony,
+    /// Members of the same trine (rat, dragon and monkey, …)
+    ThreeHarmony,
+    /// The same animal
+    Same,
+    Neutral,
+    /// One of the six pairs that harm each other (rat and goat, …)
+    Harm,
+    /// Opposite animals, six years apart
+    Clash,
+}
+
+/// The Chinese zodiac pairing of two birth years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZodiacPairing {
+    pub first: EarthlyBranch,
+    pub second: EarthlyBranch,
+    pub relation: BranchRelation,
+    /// 0 (clash) to 100 (six harmony)
+    pub score: u8,
+}
+
+// ---------------------------------------------------------------------------
+// Numerology types
+// ---------------------------------------------------------------------------
+
+/// Table used to turn letters into numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumerologySystem {
+    /// A–I are 1–9, then the count starts again at J and S
+    #[default]
+    Pythagorean,
+    /// The Babylonian table of values 1–8; no letter is worth 9
+    Chaldean,
+}
+
+/// When Y is read as a vowel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum YVowelRule {
+    /// A vowel unless a neighbouring letter in the word is a vowel, so the
+    /// Y of "Mary" and "Lynn" counts but that of "Maya" and "Yolanda" does
+    /// not
+    #[default]
+    Contextual,
+    Always,
+    Never,
+}
+
+/// One number derived from a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameNumber {
+    /// Sum of the letter values, the compound number
+    pub total: u32,
+    /// Reduced to a single digit, keeping the master numbers 11, 22 and 33
+    pub value: u32,
+}
+
+/// The core numbers of a name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NameNumerology {
+    pub system: NumerologySystem,
+    /// The letters that were counted: uppercase A–Z words after folding
+    /// diacritics, separated by single spaces
+    pub normalized: String,
+    /// Every letter: the expression or destiny number
+    pub expression: NameNumber,
+    /// The vowels: the soul urge or heart's desire number
+    pub soul_urge: NameNumber,
+    /// The consonants: the personality number
+    pub personality: NameNumber,
+}
+
+/// The shape of a significant number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberPatternKind {
+    /// One digit three or more times: 111, 4444
+    Repeating,
+    /// Two different digits alternating: 1212, 2020
+    RepeatingPair,
+    /// A clock time that reads the same backwards: 12:21, 10:01
+    MirroredTime,
+    /// Any other number that reads the same backwards: 1221, 404
+    Palindrome,
+    /// Consecutive rising digits: 123, 3456
+    Ascending,
+    /// Consecutive falling digits: 321, 9876
+    Descending,
+}
+
+/// A significant number found in text or a time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NumberPattern {
+    /// The number as written, e.g. "11:11" or "4.44"
+    pub text: String,
+    /// Its digits alone, e.g. "1111"
+    pub digits: String,
+    pub kind: NumberPatternKind,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// How two life path numbers relate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifePathRelation {
+    /// Both in the same triad: 1-5-7, 2-4-8 or 3-6-9
+    Natural,
+    Compatible,
+    Challenging,
+}
+
+/// Life path compatibility of two birth dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifePathCompatibility {
+    pub first: NameNumber,
+    pub second: NameNumber,
+    pub relation: LifePathRelation,
+    /// 0 to 100
+    pub score: u8,
+}
+
+// ---------------------------------------------------------------------------
+// Rune types
+// ---------------------------------------------------------------------------
+
+/// One of the 24 runes of the Elder Futhark.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rune {
+    /// 1–24 in futhark order
+    pub number: u32,
+    pub name: String,
+    /// Unicode rune, e.g. "ᚠ"
+    pub character: String,
+    pub phoneme: String,
+    /// The aett (family of eight) the rune belongs to: freyr, heimdall or tyr
+    pub aett: String,
+    /// What the rune's name means
+    pub translation: String,
+    /// Whether the rune looks different upside down. Symmetric runes cannot
+    /// be reversed and are read merkstave instead.
+    pub reversible: bool,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+    /// Reading when reversed or, for symmetric runes, merkstave
+    #[serde(rename = "reversedMeaning")]
+    pub reversed_meaning: String,
+}
+
+/// Which way a cast rune lies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuneOrientation {
+    #[default]
+    Upright,
+    /// A reversible rune lying upside down
+    Reversed,
+    /// A symmetric rune lying on its side or face turned, read darkly
+    Merkstave,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunePosition {
+    pub index: usize,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuneSpread {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub positions: Vec<RunePosition>,
+    #[serde(rename = "runeCount")]
+    pub rune_count: usize,
+}
+
+/// How runes may land when cast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuneCastOptions {
+    /// Let reversible runes land upside down
+    pub allow_reversals: bool,
+    /// Let symmetric runes land merkstave
+    pub allow_merkstave: bool,
+}
+
+impl Default for RuneCastOptions {
+    fn default() -> Self {
+        Self {
+            allow_reversals: true,
+            allow_merkstave: true,
+        }
+    }
+}
+
+/// A rune as it landed in a spread position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CastRune {
+    pub rune: Rune,
+    pub orientation: RuneOrientation,
+    pub position_index: usize,
+}
+
+/// The runes of one cast, in position order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuneCast {
+    pub spread_id: String,
+    pub runes: Vec<CastRune>,
+}
+
+// ---------------------------------------------------------------------------
+// Geomancy types
+// ---------------------------------------------------------------------------
+
+/// One of the 16 figures of Western geomancy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeomanticFigure {
+    /// Latin name, e.g. "Fortuna Major"
+    pub name: String,
+    pub translation: String,
+    /// Points in the head, neck, body and feet rows: 1 (odd) or 2 (even)
+    pub rows: [u8; 4],
+    pub planet: String,
+    pub element: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// The fifteen figures of a geomantic shield, each derived from the four
+/// Mothers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShieldChart {
+    pub mothers: [GeomanticFigure; 4],
+    pub daughters: [GeomanticFigure; 4],
+    pub nieces: [GeomanticFigure; 4],
+    pub right_witness: GeomanticFigure,
+    pub left_witness: GeomanticFigure,
+    pub judge: GeomanticFigure,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GeomanticHouse {
+    /// 1–12
+    pub number: u32,
+    /// What the house governs, e.g. "wealth and possessions"
+    pub topic: String,
+    pub figure: GeomanticFigure,
+}
+
+/// The twelve Mothers, Daughters and Nieces of a shield placed in the
+/// houses of a horoscope.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HouseChart {
+    pub houses: Vec<GeomanticHouse>,
+}
+
+// ---------------------------------------------------------------------------
+// Ogham types
+// ---------------------------------------------------------------------------
+
+/// One of the 20 feda (letters) of the Ogham alphabet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OghamFid {
+    /// 1–20 in alphabet order
+    pub number: u32,
+    pub name: String,
+    /// Unicode Ogham letter, e.g. "ᚁ"
+    pub character: String,
+    /// The Latin letter it stands for, e.g. "B"
+    pub letter: String,
+    pub tree: String,
+    /// The aicme (group of five) the fid belongs to: beithe, huatha, muine
+    /// or ailme
+    pub aicme: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+    pub advice: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OghamPosition {
+    pub index: usize,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OghamSpread {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub positions: Vec<OghamPosition>,
+    #[serde(rename = "fidCount")]
+    pub fid_count: usize,
+}
+
+/// A fid drawn into a spread position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DrawnFid {
+    pub fid: OghamFid,
+    pub position_index: usize,
+}
+
+/// The feda of one draw, in position order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OghamDraw {
+    pub spread_id: String,
+    pub feda: Vec<DrawnFid>,
+}
+
+/// One position of a draw with its fid's reading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FidReading {
+    pub position_index: usize,
+    pub position_name: String,
+    pub fid_name: String,
+    pub tree: String,
+    pub text: String,
+}
+
+/// One of the 13 lunar-month trees of the Celtic tree zodiac.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TreeSign {
+    pub tree: String,
+    /// Name of the fid the tree belongs to
+    pub ogham: String,
+    pub animal: String,
+    /// First day as month and day
+    pub start: [u32; 2],
+    /// Last day as month and day
+    pub end: [u32; 2],
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// A composed reading of an Ogham draw.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OghamReading {
+    pub spread_id: String,
+    pub spread_name: String,
+    pub positions: Vec<FidReading>,
+    /// Aicme holding most of the draw, when one does
+    pub dominant_aicme: Option<String>,
+    pub summary: String,
+}
+
+// ---------------------------------------------------------------------------
+// Dream types
+// ---------------------------------------------------------------------------
+
+/// An entry in the dream symbol dictionary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DreamSymbol {
+    pub id: String,
+    pub name: String,
+    /// Other words and phrases for the same image, e.g. "serpent"
+    pub aliases: Vec<String>,
+    /// animals, nature, body, people, places, actions or objects
+    pub category: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// A reading for two symbols that appear in the same dream.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DreamCombination {
+    /// The two symbol ids; order does not matter
+    pub symbols: [String; 2],
+    pub name: String,
+    pub meaning: String,
+}
+
+/// A symbol found by fuzzy search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolMatch {
+    pub symbol: DreamSymbol,
+    /// Similarity from 0.0 to 1.0
+    pub score: f64,
+}
+
+/// Several dream symbols read together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DreamInterpretation {
+    /// Symbols in the order they were mentioned
+    pub symbols: Vec<DreamSymbol>,
+    pub combinations: Vec<DreamCombination>,
+    /// Category holding most of the symbols, when one does
+    pub dominant_category: Option<String>,
+    pub summary: String,
+}
+
+// ---------------------------------------------------------------------------
+// Tzolkin types
+// ---------------------------------------------------------------------------
+
+/// How Gregorian dates are matched to the 260-day count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TzolkinCount {
+    /// The unbroken Maya count under the GMT correlation (584283), in which
+    /// 21 December 2012 is 4 Ahau
+    #[default]
+    Traditional,
+    /// Argüelles' Dreamspell count, which skips 29 February; 21 December
+    /// 2012 is Kin 207, Blue Crystal Hand
+    Dreamspell,
+}
+
+/// One of the 20 day signs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaySign {
+    /// 1–20, Imix to Ahau
+    pub number: u32,
+    /// Yucatec name, e.g. "Imix"
+    pub name: String,
+    pub translation: String,
+    /// Dreamspell seal name, e.g. "Dragon"
+    #[serde(rename = "dreamspellName")]
+    pub dreamspell_name: String,
+    /// Red, White, Blue or Yellow
+    pub color: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// One of the 13 galactic tones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TzolkinTone {
+    pub number: u32,
+    /// Dreamspell name, e.g. "Magnetic"
+    pub name: String,
+    pub keywords: Vec<String>,
+    /// The question the tone asks
+    pub question: String,
+    pub meaning: String,
+}
+
+/// One of the twenty 13-day periods of the count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wavespell {
+    /// 1–20
+    pub number: u32,
+    pub first_kin: u32,
+    /// The sign of the wavespell's first day, which names it
+    pub sign: DaySign,
+}
+
+/// A Gregorian date in the 260-day count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TzolkinDay {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub count: TzolkinCount,
+    /// 1–260
+    pub kin: u32,
+    pub tone: TzolkinTone,
+    pub sign: DaySign,
+    /// Tone and Yucatec sign, e.g. "4 Ahau"
+    pub name: String,
+    /// Colour, tone and seal, e.g. "Yellow Self-Existing Sun"
+    pub galactic_name: String,
+    pub wavespell: Wavespell,
+}
+
+/// The kin of a birth date with the signs of its destiny oracle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GalacticSignature {
+    pub day: TzolkinDay,
+    /// Higher self: a sign of the same colour chosen by the tone
+    pub guide: DaySign,
+    /// Support: the sign whose number adds to 19 with this one
+    pub analog: DaySign,
+    /// Challenge: the sign ten places away
+    pub antipode: DaySign,
+    /// Hidden power: the sign whose number adds to 21 with this one
+    pub occult: DaySign,
+}
+
+// ---------------------------------------------------------------------------
+// Pendulum types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PendulumAnswer {
+    Yes,
+    No,
+    Unclear,
+}
+
+/// Relative weights of the three answers; they need not sum to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PendulumBias {
+    pub yes: f64,
+    pub no: f64,
+    pub unclear: f64,
+}
+
+impl Default for PendulumBias {
+    fn default() -> Self {
+        Self {
+            yes: 0.45,
+            no: 0.45,
+            unclear: 0.1,
+        }
+    }
+}
+
+/// What happens when the same question is asked again within the
+/// cooldown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AskAgainPolicy {
+    /// Swing again as if the question were new
+    Allow,
+    /// Give the first answer again, marked as repeated
+    #[default]
+    RepeatAnswer,
+    /// Refuse until the cooldown has passed
+    Refuse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PendulumOptions {
+    pub bias: PendulumBias,
+    pub ask_again: AskAgainPolicy,
+    /// How long a question's answer stands, in milliseconds
+    pub cooldown_ms: u64,
+}
+
+impl Default for PendulumOptions {
+    fn default() -> Self {
+        Self {
+            bias: PendulumBias::default(),
+            ask_again: AskAgainPolicy::default(),
+            cooldown_ms: 10 * 60 * 1000,
+        }
+    }
+}
+
+/// One answer from the pendulum.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendulumReading {
+    pub question: String,
+    pub answer: PendulumAnswer,
+    /// How the pendulum moved, e.g. "swings forward and back"
+    pub swing: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Whether this repeats an earlier answer to the same question
+    pub repeated: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Bibliomancy types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorpusPassage {
+    /// Where the passage sits in its book, e.g. "3:5"
+    pub reference: String,
+    pub text: String,
+}
+
+/// A chapter, poem or other run of consecutive passages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorpusSection {
+    pub title: String,
+    pub passages: Vec<CorpusPassage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorpusBook {
+    pub title: String,
+    pub sections: Vec<CorpusSection>,
+}
+
+/// A body of text to open at random.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Corpus {
+    pub id: String,
+    pub title: String,
+    pub books: Vec<CorpusBook>,
+}
+
+/// A passage chosen by bibliomancy with the passages around it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BibliomancyDraw {
+    pub corpus_id: String,
+    pub book: String,
+    pub section: String,
+    pub passage: CorpusPassage,
+    /// Passages just before, in reading order, within the same section
+    pub before: Vec<CorpusPassage>,
+    /// Passages just after, within the same section
+    pub after: Vec<CorpusPassage>,
+}
+
+// ---------------------------------------------------------------------------
+// Kabbalah types
+// ---------------------------------------------------------------------------
+
+/// The three pillars of the Tree of Life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TreePillar {
+    /// Right-hand pillar: Chokmah, Chesed, Netzach
+    Mercy,
+    /// Left-hand pillar: Binah, Geburah, Hod
+    Severity,
+    /// Kether, Tiphareth, Yesod and Malkuth
+    Middle,
+}
+
+/// One of the ten sephiroth of the Tree of Life.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sephirah {
+    /// 1 (Kether) to 10 (Malkuth)
+    pub number: u8,
+    pub name: String,
+    /// The name in Hebrew, e.g. "כתר"
+    pub hebrew: String,
+    /// English title, e.g. "Crown"
+    pub title: String,
+    pub pillar: TreePillar,
+    /// Heavenly sphere, e.g. "Primum Mobile" or "Saturn"
+    pub celestial: String,
+    /// Lowercase planet key for the seven planetary sephiroth
+    pub planet: Option<String>,
+    pub divine_name: String,
+    pub archangel: String,
+    /// Queen scale colour
+    pub color: String,
+    pub virtue: String,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// The three classes of Hebrew letter in the Sepher Yetzirah.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LetterClass {
+    /// Aleph, Mem and Shin, given to the elements
+    Mother,
+    /// Seven letters with two sounds, given to the planets
+    Double,
+    /// Twelve letters given to the signs
+    Simple,
+}
+
+/// What a path's letter is attributed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CorrespondenceKind {
+    Element,
+    Planet,
+    Sign,
+}
+
+/// One of the 22 paths joining the sephiroth.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreePath {
+    /// 11–32, continuing the count after the ten sephiroth
+    pub number: u8,
+    /// Transliterated Hebrew letter, e.g. "Aleph"
+    pub letter: String,
+    /// The letter itself, e.g. "א"
+    pub glyph: String,
+    /// What the letter's name means, e.g. "ox"
+    pub letter_meaning: String,
+    pub letter_class: LetterClass,
+    pub correspondence_kind: CorrespondenceKind,
+    /// Lowercase element, planet or sign key, e.g. "air", "mercury", "aries"
+    pub correspondence: String,
+    /// Upper and lower sephirah numbers
+    pub connects: [u8; 2],
+    /// Id of the major arcana card on the path
+    pub tarot_card: String,
+    pub keywords: Vec<String>,
+    /// A short guided image for working the path
+    pub theme: String,
+}
+
+/// One path travelled in a pathworking.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathworkingStep {
+    /// Sephirah the step starts from
+    pub from: String,
+    /// Sephirah the step arrives at
+    pub to: String,
+    pub path: TreePath,
+}
+
+/// A route across the Tree from one sephirah to another, path by path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pathworking {
+    pub from: String,
+    pub to: String,
+    /// Fewest paths between the two; empty when `from` and `to` are the same
+    pub steps: Vec<PathworkingStep>,
+}
+
+// ---------------------------------------------------------------------------
+// Tasseography types
+// ---------------------------------------------------------------------------
+
+/// Where in the cup a tea-leaf symbol lies, which sets when it comes to
+/// pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CupZone {
+    /// Near the rim: the present and the next few days
+    Rim,
+    /// On the sides: the coming weeks
+    Middle,
+    /// In the bottom: months away or the distant future
+    Bottom,
+}
+
+/// Whether a symbol is a good or a bad sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Omen {
+    Favourable,
+    Cautionary,
+    /// Read from the symbols around it
+    Neutral,
+}
+
+/// An entry in the tea-leaf symbol dictionary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeaLeafSymbol {
+    pub id: String,
+    pub name: String,
+    /// Other names for the same shape, e.g. "ship" for the boat
+    pub aliases: Vec<String>,
+    pub omen: Omen,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// A shape the reader saw in the cup and where it lay.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerceivedSymbol {
+    /// Id, name or alias of the shape, e.g. "ship"
+    pub symbol: String,
+    pub zone: CupZone,
+}
+
+/// A recognised symbol with its timing and reading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlacedSymbol {
+    pub symbol: TeaLeafSymbol,
+    pub zone: CupZone,
+    /// When the zone says it will come to pass, e.g. "within weeks"
+    pub timing: String,
+    pub text: String,
+}
+
+/// A reading of the symbols seen in one cup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeaLeafReading {
+    /// Symbols from the rim down, so the soonest come first
+    pub symbols: Vec<PlacedSymbol>,
+    /// Omen shared by more than half of the symbols, if any
+    pub overall: Option<Omen>,
+    pub summary: String,
+}
+
+// ---------------------------------------------------------------------------
+// Dice types
+// ---------------------------------------------------------------------------
+
+/// The meaning of one total when casting two or three dice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiceTotal {
+    /// 2 or 3
+    pub dice: u8,
+    pub total: u8,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// A cast of dice read against the table of totals.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiceReading {
+    /// Face of each die, 1–6, in the order cast
+    pub faces: Vec<u8>,
+    pub total: u8,
+    pub meaning: DiceTotal,
+    /// Every die shows the same face, which strengthens the omen
+    pub all_match: bool,
+    pub summary: String,
+}
+
+// ---------------------------------------------------------------------------
+// Domino types
+// ---------------------------------------------------------------------------
+
+/// One of the 28 tiles of a double-six set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Domino {
+    /// Pips on the larger end, 0–6
+    pub high: u8,
+    /// Pips on the smaller end, 0–6
+    pub low: u8,
+    pub keywords: Vec<String>,
+    pub meaning: String,
+}
+
+/// A drawn tile with its reading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DrawnDomino {
+    pub tile: Domino,
+    pub text: String,
+}
+
+/// The tiles of one draw, in the order drawn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DominoReading {
+    pub tiles: Vec<DrawnDomino>,
+    pub summary: String,
+}
+
+// ---------------------------------------------------------------------------
+// Daily reading types
+// ---------------------------------------------------------------------------
+
+/// The tarot card, hexagram, Moon and transits for one calendar date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyReading {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    /// User the card and hexagram were personalised for, if any
+    pub user: Option<String>,
+    pub card: DrawnCard,
+    pub hexagram: CastResult,
+    /// Moon phase at 12:00 UT
+    pub moon: MoonPhase,
+    /// Tightest aspects from the day's planets (`planet1`) to the user's
+    /// stored natal chart (`planet2`); empty when no chart is stored
+    pub transits: Vec<ChartAspect>,
+}
+
+// ---------------------------------------------------------------------------
+// Compatibility types
+// ---------------------------------------------------------------------------
+
+/// A system a compatibility report draws on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompatibilitySystem {
+    /// Western synastry
+    Synastry,
+    ChineseZodiac,
+    /// Numerology life paths
+    LifePath,
+}
+
+/// One system's verdict on a pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemScore {
+    pub system: CompatibilitySystem,
+    /// 0 to 100
+    pub score: u8,
+    pub summary: String,
+}
+
+/// Compatibility of two people across several systems.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    /// `None` unless both births have a full time and place
+    pub synastry: Option<Synastry>,
+    pub zodiac: ZodiacPairing,
+    pub life_path: LifePathCompatibility,
+    /// One score per system that could be read
+    pub scores: Vec<SystemScore>,
+    /// Mean of `scores`, 0 to 100
+    pub aggregate: u8,
+}
+
+// ---------------------------------------------------------------------------
+// Prompting types
+// ---------------------------------------------------------------------------
+
+/// How much detail prompt context carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verbosity {
+    /// One or two lines: the headline facts only
+    Brief,
+    /// Each element with its keywords
+    #[default]
+    Standard,
+    /// Full meanings, texts and every aspect
+    Detailed,
+}
+
+/// Settings for turning a reading into prompt context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PromptOptions {
+    pub verbosity: Verbosity,
+    /// Estimated token budget; unlimited when `None`
+    pub max_tokens: Option<usize>,
+}
+
+// ---------------------------------------------------------------------------
+// Scheduler types
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// A recurring reading a host can schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduleKind {
+    DailyCard,
+    WeeklySpread,
+    NewMoon,
+    FullMoon,
+}
+
+/// When recurring readings fall due.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleOptions {
+    /// The user's offset from UTC in minutes, east positive. Fixed, so a
+    /// daylight saving change needs new options.
+    pub utc_offset_minutes: i32,
+    pub daily: bool,
+    /// Local time of the daily card as (hour, minute)
+    pub daily_time: (u32, u32),
+    pub weekly: bool,
+    pub weekly_day: Weekday,
+    /// Local time of the weekly spread as (hour, minute)
+    pub weekly_time: (u32, u32),
+    /// Readings at each exact new and full moon
+    pub lunar: bool,
+}
+
+impl Default for ScheduleOptions {
+    fn default() -> Self {
+        Self {
+            utc_offset_minutes: 0,
+            daily: true,
+            daily_time: (8, 0),
+            weekly: true,
+            weekly_day: Weekday::Monday,
+            weekly_time: (8, 0),
+            lunar: true,
+        }
+    }
+}
+
+/// One upcoming reading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledReading {
+    pub kind: ScheduleKind,
+    /// Milliseconds since the Unix epoch
+    pub due: u64,
+    /// The Moon's place at a new or full moon; `None` otherwise
+    pub moon: Option<SignPosition>,
+}
+
+// ---------------------------------------------------------------------------
+// Router types
+// ---------------------------------------------------------------------------
+
+/// An engine a question can be routed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Modality {
+    Tarot,
+    Iching,
+    /// The birth chart itself: personality, gifts, purpose
+    NatalAstrology,
+    /// What the current sky brings: timing and forecasts
+    TransitAstrology,
+}
+
+/// A modality with an optional template, such as a tarot spread id.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteTarget {
+    pub modality: Modality,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Keywords that point a question at one target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteRule {
+    pub modality: Modality,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Lowercase words or phrases and the weight each adds
+    pub keywords: BTreeMap<String, f64>,
+}
+
+/// Every rule a router scores, and where questions matching none go.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteTable {
+    pub fallback: RouteTarget,
+    pub routes: Vec<RouteRule>,
+}
+
+/// One way to answer a question, with how sure the router is of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteSuggestion {
+    pub target: RouteTarget,
+    /// 0.0 to 1.0; suggestions for one question sum to less than 1.0
+    pub confidence: f64,
+    /// Keywords found in the question; empty for the fallback
+    pub matched: Vec<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Journal types
+// ---------------------------------------------------------------------------
+
+/// The kind of payload a journal entry records, matching its serialized
+/// tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalKind {
+    Tarot,
+    TarotReading,
+    Iching,
+    IchingConsultation,
+    Chart,
+    Feedback,
+}
+
+/// What a journal entry records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum JournalPayload {
+    Tarot(ExportedReading),
+    TarotReading(Box<Reading>),
+    Iching(CastResult),
+    IchingConsultation(Box<IChingConsultation>),
+    Chart(Box<NatalChart>),
+    Feedback(FeedbackEntry),
+}
+
+impl JournalPayload {
+    /// Which kind of payload this is.
+    pub fn kind(&self) -> JournalKind {
+        match self {
+            Self::Tarot(_) => JournalKind::Tarot,
+            Self::TarotReading(_) => JournalKind::TarotReading,
+            Self::Iching(_) => JournalKind::Iching,
+            Self::IchingConsultation(_) => JournalKind::IchingConsultation,
+            Self::Chart(_) => JournalKind::Chart,
+            Self::Feedback(_) => JournalKind::Feedback,
+        }
+    }
+
+    /// The I Ching cast this payload records, if any.
+    pub fn cast(&self) -> Option<&CastResult> {
+        match self {
+            Self::Iching(cast) => Some(cast),
+            Self::IchingConsultation(c) => Some(&c.cast),
+            _ => None,
+        }
+    }
+}
+
+/// One stored reading, cast or chart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Content-addressed id of the timestamp, tags and payload
+    pub id: String,
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    pub tags: Vec<String>,
+    pub note: Option<String>,
+    pub payload: JournalPayload,
+}
+
+/// Filter for journal queries; unset fields match everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JournalQuery {
+    /// Inclusive lower bound, in epoch milliseconds
+    pub from: Option<u64>,
+    /// Exclusive upper bound, in epoch milliseconds
+    pub to: Option<u64>,
+    pub tag: Option<String>,
+    /// Payload kind, as returned by [`JournalPayload::kind`]
+    pub kind: Option<JournalKind>,
+    /// Casts whose primary or resulting hexagram has this number
+    #[serde(default)]
+    pub hexagram: Option<u32>,
+}
+
+// ---------------------------------------------------------------------------
+// Feedback
+// ---------------------------------------------------------------------------
+
+/// A user's reaction to one element of a reading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FeedbackEntry {
+    /// What the reaction is about: a card id such as `"major_16_tower"`, a
+    /// hexagram as `"hexagram:44"`, or any other reading element
     pub element: String,
     pub user_text: String,
     pub timestamp: u64,
+    /// 1 (missed) to 5 (spot on), if the user gave one
+    #[serde(default)]
+    pub rating: Option<u8>,
+}
+
+/// Feedback on one element gathered over time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackSummary {
+    pub element: String,
+    pub count: usize,
+    /// Entries with a rating
+    pub rated: usize,
+    pub mean_rating: Option<f64>,
+    /// Mean of -1.0 (inaccurate) to 1.0 (accurate) over the entries whose
+    /// rating or text carries a sentiment
+    pub sentiment: Option<f64>,
+    /// Timestamp of the latest entry
+    pub last_timestamp: u64,
 }
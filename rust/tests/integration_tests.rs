@@ -38,7 +38,7 @@ fn tarot_engine_spreads() {
 
 #[test]
 fn cast_hexagram_produces_valid_result() {
-    let result = iching::cast_hexagram();
+    let result = iching::cast_hexagram().unwrap();
     assert_eq!(result.lines.len(), 6);
     for &line in &result.lines {
         assert!(
@@ -0,0 +1,24 @@
+import { describe, expect, it } from "vitest";
+import vectors from "../../../data/identity/canonical.json" with { type: "json" };
+import { canonicalJson, contentId, fnv1a64 } from "../../src/utils/identity";
+
+describe("content ids", () => {
+  it("matches the FNV-1a reference values", () => {
+    expect(fnv1a64("")).toBe(0xcbf29ce484222325n);
+    expect(fnv1a64("a")).toBe(0xaf63dc4c8601ec8cn);
+  });
+
+  it.each(vectors)("matches the shared vector $name", (vector) => {
+    expect(canonicalJson(vector.input)).toBe(vector.canonical);
+    expect(contentId(vector.engine, vector.seed, vector.input)).toBe(vector.id);
+  });
+
+  it("writes integral numbers without a fraction", () => {
+    expect(canonicalJson({ b: -4.0, a: [1.0, 0.5] })).toBe('{"a":[1,0.5],"b":-4}');
+  });
+
+  it("sorts integer-like keys as strings and writes numbers as serde_json does", () => {
+    expect(canonicalJson({ 2: "two", 10: "ten", a: 1 })).toBe('{"10":"ten","2":"two","a":1}');
+    expect(canonicalJson([1e21, 1e-6, 0.0001, Number.NaN])).toBe("[1e+21,1e-6,0.0001,null]");
+  });
+});
@@ -569,8 +569,10 @@ function computeAscendant(lstDeg: number, latDeg: number, oblDeg: number): numbe
   const latRad = latDeg * DEG2RAD;
   const oblRad = oblDeg * DEG2RAD;
 
-  const y = -Math.cos(lstRad);
-  const x = Math.sin(oblRad) * Math.tan(latRad) + Math.cos(oblRad) * Math.sin(lstRad);
+  // atan2(-cos θ, sin ε tan φ + cos ε sin θ) lands on the descending
+  // point; negating both arguments selects the rising (eastern) one.
+  const y = Math.cos(lstRad);
+  const x = -(Math.sin(oblRad) * Math.tan(latRad) + Math.cos(oblRad) * Math.sin(lstRad));
 
   const asc = Math.atan2(y, x) * RAD2DEG;
   return normDeg(asc);
@@ -24,6 +24,7 @@ export { readingContextProvider } from "./providers/reading-context";
 export { createReadingRoutes } from "./routes/readings";
 export { MysticismService } from "./services/mysticism-service";
 export * from "./types";
+export { canonicalJson, contentId, fnv1a64 } from "./utils/identity";
 
 export const mysticismPlugin: Plugin = {
   name: "mysticism",
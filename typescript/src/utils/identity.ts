@@ -0,0 +1,124 @@
+// Content-addressed ids matching the Rust crate's `identity` module.
+//
+// Ids have the form `<engine>:<16 hex digits>` where the digits are the
+// 64-bit FNV-1a hash of `engine`, the seed (or `-` when absent) and the
+// canonical JSON of the inputs, joined with NUL bytes. Both sides check
+// themselves against data/identity/canonical.json.
+
+const FNV_OFFSET_BASIS = 0xcbf29ce484222325n;
+const FNV_PRIME = 0x100000001b3n;
+const MASK_64 = 0xffffffffffffffffn;
+
+/** 64-bit FNV-1a hash of the UTF-8 bytes of `text`. */
+export function fnv1a64(text: string): bigint {
+  let hash = FNV_OFFSET_BASIS;
+  for (const byte of new TextEncoder().encode(text)) {
+    hash ^= BigInt(byte);
+    hash = (hash * FNV_PRIME) & MASK_64;
+  }
+  return hash;
+}
+
+/**
+ * Write a number as serde_json writes an `f64`, with the Rust side's one
+ * exception: integral values up to 2^53 lose their fraction. Digits are the
+ * shortest that round-trip, as in `JSON.stringify`, but the layout follows
+ * serde_json: plain up to 16 integer digits, `0.0000ddd` down to 1e-5,
+ * otherwise an exponent that always carries its sign (`1e+21`, `1.5e-7`).
+ */
+function canonicalNumber(value: number): string {
+  if (!Number.isFinite(value)) {
+    return "null";
+  }
+  if (Number.isInteger(value) && Math.abs(value) <= Number.MAX_SAFE_INTEGER) {
+    return Object.is(value, -0) ? "0" : String(value);
+  }
+  const [mantissa, exponent] = Math.abs(value).toExponential().split("e");
+  const digits = mantissa.replace(".", "");
+  const length = digits.length;
+  // 10^(point - 1) <= |value| < 10^point
+  const point = Number(exponent) + 1;
+  const sign = value < 0 ? "-" : "";
+  if (point >= length && point <= 16) {
+    return `${sign}${digits}${"0".repeat(point - length)}.0`;
+  }
+  if (point > 0 && point <= 16) {
+    return `${sign}${digits.slice(0, point)}.${digits.slice(point)}`;
+  }
+  if (point > -5 && point <= 0) {
+    return `${sign}0.${"0".repeat(-point)}${digits}`;
+  }
+  const fraction = length > 1 ? `.${digits.slice(1)}` : "";
+  const power = point - 1;
+  return `${sign}${digits[0]}${fraction}e${power < 0 ? "-" : "+"}${Math.abs(power)}`;
+}
+
+/** Order keys by code point, as Rust's `BTreeMap<String, _>` does. */
+function compareKeys(a: string, b: string): number {
+  const left = Array.from(a, (c) => c.codePointAt(0) ?? 0);
+  const right = Array.from(b, (c) => c.codePointAt(0) ?? 0);
+  for (let i = 0; i < Math.min(left.length, right.length); i++) {
+    if (left[i] !== right[i]) {
+      return left[i] - right[i];
+    }
+  }
+  return left.length - right.length;
+}
+
+function writeCanonical(value: unknown): string | undefined {
+  if (value !== null && typeof value === "object" && "toJSON" in value) {
+    const toJSON = (value as { toJSON: unknown }).toJSON;
+    if (typeof toJSON === "function") {
+      return writeCanonical(toJSON.call(value));
+    }
+  }
+  switch (typeof value) {
+    case "number":
+      return canonicalNumber(value);
+    case "bigint":
+      return value.toString();
+    case "string":
+    case "boolean":
+      return JSON.stringify(value);
+    case "undefined":
+    case "function":
+    case "symbol":
+      return undefined;
+  }
+  if (value === null) {
+    return "null";
+  }
+  if (Array.isArray(value)) {
+    return `[${value.map((item) => writeCanonical(item) ?? "null").join(",")}]`;
+  }
+  const entries: string[] = [];
+  for (const key of Object.keys(value).sort(compareKeys)) {
+    const item = writeCanonical((value as Record<string, unknown>)[key]);
+    if (item !== undefined) {
+      entries.push(`${JSON.stringify(key)}:${item}`);
+    }
+  }
+  return `{${entries.join(",")}}`;
+}
+
+/**
+ * Serialize a value to compact JSON with object keys sorted, so that
+ * structurally equal inputs always produce the same text. The output is
+ * written by hand to match the Rust side byte for byte: keys are sorted
+ * as strings (JavaScript objects would put "2" before "10" and both before
+ * other keys) and numbers follow serde_json rather than `JSON.stringify`.
+ */
+export function canonicalJson(value: unknown): string {
+  return writeCanonical(value) ?? "null";
+}
+
+/** Build a content-addressed id from an engine name, optional seed and inputs. */
+export function contentId(
+  engine: string,
+  seed: number | bigint | null | undefined,
+  inputs: unknown
+): string {
+  const seedPart = seed === null || seed === undefined ? "-" : seed.toString();
+  const hash = fnv1a64(`${engine}\0${seedPart}\0${canonicalJson(inputs)}`);
+  return `${engine}:${hash.toString(16).padStart(16, "0")}`;
+}